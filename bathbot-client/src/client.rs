@@ -75,10 +75,10 @@ impl Client {
             ratelimiter(10), // OsuAvatar
             ratelimiter(10), // OsuBadge
             ratelimiter(2),  // OsuMapFile
+            ratelimiter(2),  // OsuMapMirror
             ratelimiter(10), // OsuMapsetCover
             ratelimiter(2),  // OsuStats
             ratelimiter(2),  // OsuTrack
-            ratelimiter(2),  // OsuWorld
             ratelimiter(1),  // Respektive
             ratelimiter(5),  // Twitch
         ];
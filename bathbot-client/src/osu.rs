@@ -62,4 +62,22 @@ impl Client {
 
         self.make_get_request(&url, Site::OsuMapFile).await
     }
+
+    /// Request a map's file from a beatmap mirror.
+    ///
+    /// The mirror url may contain a `{map_id}` placeholder, otherwise the map
+    /// id will be appended.
+    pub async fn get_map_file_from_mirror(
+        &self,
+        mirror: &str,
+        map_id: u32,
+    ) -> Result<Bytes, ClientError> {
+        let url = if mirror.contains("{map_id}") {
+            mirror.replace("{map_id}", &map_id.to_string())
+        } else {
+            format!("{mirror}{map_id}")
+        };
+
+        self.make_get_request(&url, Site::OsuMapMirror).await
+    }
 }
@@ -11,6 +11,7 @@ pub enum Site {
     OsuAvatar,
     OsuBadge,
     OsuMapFile,
+    OsuMapMirror,
     OsuMapsetCover,
     OsuStats,
     OsuTrack,
@@ -31,6 +32,7 @@ impl Site {
             Self::OsuAvatar => "OsuAvatar",
             Self::OsuBadge => "OsuBadge",
             Self::OsuMapFile => "OsuMapFile",
+            Self::OsuMapMirror => "OsuMapMirror",
             Self::OsuMapsetCover => "OsuMapsetCover",
             Self::OsuStats => "OsuStats",
             Self::OsuTrack => "OsuTrack",
@@ -14,6 +14,13 @@ use time::{Date, OffsetDateTime, format_description::FormatItem};
 
 use crate::{Client, site::Site};
 
+const DATETIME_FORMAT: &[FormatItem<'_>] = &[
+    FormatItem::Compound(DATE_FORMAT),
+    FormatItem::Literal(b"T"),
+    FormatItem::Compound(TIME_FORMAT),
+    FormatItem::Literal(b"Z"),
+];
+
 pub async fn get_snipe_player(
     client: &Client,
     country: &str,
@@ -91,13 +98,6 @@ pub async fn get_national_snipes(
     sniper: bool,
     since: OffsetDateTime,
 ) -> Result<Vec<SnipeRecent>> {
-    pub const DATETIME_FORMAT: &[FormatItem<'_>] = &[
-        FormatItem::Compound(DATE_FORMAT),
-        FormatItem::Literal(b"T"),
-        FormatItem::Compound(TIME_FORMAT),
-        FormatItem::Literal(b"Z"),
-    ];
-
     let url = format!(
         "{HUISMETBENEN}changes/{version}/{user_id}?since={since}&until={until}&\
         includeOwnSnipes=false",
@@ -115,6 +115,28 @@ pub async fn get_national_snipes(
     })
 }
 
+pub async fn get_country_snipes(
+    client: &Client,
+    country: &str,
+    since: OffsetDateTime,
+) -> Result<Vec<SnipeRecent>> {
+    let url = format!(
+        "{HUISMETBENEN}changes/country/{country}?since={since}&until={until}&\
+        includeOwnSnipes=false",
+        country = country.to_lowercase(),
+        since = since.format(DATETIME_FORMAT).unwrap(),
+        until = OffsetDateTime::now_utc().format(DATETIME_FORMAT).unwrap()
+    );
+
+    let bytes = client.make_get_request(url, Site::Huismetbenen).await?;
+
+    serde_json::from_slice(&bytes).wrap_err_with(|| {
+        let body = String::from_utf8_lossy(&bytes);
+
+        format!("Failed to deserialize huismetbenen country snipes: {body}")
+    })
+}
+
 pub async fn get_national_firsts(
     client: &Client,
     params: &SnipeScoreParams,
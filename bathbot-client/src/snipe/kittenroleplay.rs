@@ -120,6 +120,28 @@ pub async fn get_national_snipes(
     })
 }
 
+pub async fn get_country_snipes(
+    client: &Client,
+    country_code: &str,
+    offset: u32,
+    days_since: u32,
+    mode: GameMode,
+) -> Result<Vec<KittenRoleplaySnipe>> {
+    let url = format!(
+        "https://snipes.kittenroleplay.com/api/country/snipes?mode={mode}&\
+        country={country_code}&since={days_since}&self_snipes=0&offset={offset}&limit=50",
+        mode = mode as u8,
+    );
+
+    let bytes = client.make_get_request(url, Site::KittenRoleplay).await?;
+
+    serde_json::from_slice(&bytes).wrap_err_with(|| {
+        let body = String::from_utf8_lossy(&bytes);
+
+        format!("Failed to deserialize kittenroleplay country snipes: {body}")
+    })
+}
+
 pub async fn get_national_firsts(
     client: &Client,
     params: &SnipeScoreParams,
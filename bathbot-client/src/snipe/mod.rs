@@ -5,8 +5,9 @@ use std::{
 };
 
 use bathbot_model::{
-    SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer, SnipeCountryStatistics, SnipePlayer,
-    SnipePlayerListOrder, SnipeRecent, SnipeScore, SnipeScoreParams, SnipedPlayer, SnipedWeek,
+    KittenRoleplaySnipe, SnipeCountries, SnipeCountryListOrder, SnipeCountryPlayer,
+    SnipeCountryStatistics, SnipePlayer, SnipePlayerListOrder, SnipeRecent, SnipeScore,
+    SnipeScoreParams, SnipedPlayer, SnipedWeek,
 };
 use bathbot_util::IntHasher;
 use eyre::Result;
@@ -312,22 +313,56 @@ impl Client {
                     let new_scores = new_scores_fut.await?;
                     let new_count = new_scores.len();
 
-                    scores.extend(new_scores.into_iter().map(|snipe| SnipeRecent {
-                        map_id: snipe.map_id,
-                        user_id,
-                        pp: snipe.pp,
-                        stars: Some(snipe.stars),
-                        accuracy: snipe.accuracy,
-                        date: Some(snipe.sniped_at),
-                        mods: GameModsIntermode::from_bits(snipe.mods).try_with_mode(mode),
-                        max_combo: Some(snipe.max_combo),
-                        artist: snipe.artist,
-                        title: snipe.title,
-                        version: snipe.version,
-                        sniper: Some(snipe.sniper_username),
-                        sniper_id: snipe.sniper_user_id,
-                        sniped: snipe.victim_username,
-                        sniped_id: snipe.victim_user_id,
+                    scores.extend(
+                        new_scores
+                            .into_iter()
+                            .map(|snipe| kittenroleplay_snipe_recent(snipe, user_id, mode)),
+                    );
+
+                    if new_count < 50 {
+                        break;
+                    }
+
+                    offset += 50;
+                }
+
+                Ok(scores)
+            }
+            GameMode::Taiko => unimplemented!(),
+        }
+    }
+
+    /// Recently claimed national #1s of all players of a country.
+    pub async fn get_country_snipes(
+        &self,
+        country_code: &str,
+        since: OffsetDateTime,
+        mode: GameMode,
+    ) -> Result<Vec<SnipeRecent>> {
+        match mode {
+            GameMode::Osu => huismetbenen::get_country_snipes(self, country_code, since).await,
+            GameMode::Catch | GameMode::Mania => {
+                let days_since = (OffsetDateTime::now_utc() - since).whole_days() as u32;
+                let mut offset = 0;
+
+                let mut scores = Vec::new();
+
+                loop {
+                    let new_scores_fut = kittenroleplay::get_country_snipes(
+                        self,
+                        country_code,
+                        offset,
+                        days_since,
+                        mode,
+                    );
+
+                    let new_scores = new_scores_fut.await?;
+                    let new_count = new_scores.len();
+
+                    scores.extend(new_scores.into_iter().map(|snipe| {
+                        let user_id = snipe.sniper_user_id;
+
+                        kittenroleplay_snipe_recent(snipe, user_id, mode)
                     }));
 
                     if new_count < 50 {
@@ -394,3 +429,27 @@ impl Client {
         Ok(countries)
     }
 }
+
+fn kittenroleplay_snipe_recent(
+    snipe: KittenRoleplaySnipe,
+    user_id: u32,
+    mode: GameMode,
+) -> SnipeRecent {
+    SnipeRecent {
+        map_id: snipe.map_id,
+        user_id,
+        pp: snipe.pp,
+        stars: Some(snipe.stars),
+        accuracy: snipe.accuracy,
+        date: Some(snipe.sniped_at),
+        mods: GameModsIntermode::from_bits(snipe.mods).try_with_mode(mode),
+        max_combo: Some(snipe.max_combo),
+        artist: snipe.artist,
+        title: snipe.title,
+        version: snipe.version,
+        sniper: Some(snipe.sniper_username),
+        sniper_id: snipe.sniper_user_id,
+        sniped: snipe.victim_username,
+        sniped_id: snipe.victim_user_id,
+    }
+}
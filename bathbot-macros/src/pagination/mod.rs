@@ -104,12 +104,14 @@ pub(super) fn impl_derive(input: DeriveInput) -> Result<TokenStream, Error> {
             pub fn builder() -> #builder_name {
                 #builder_name {
                     #( #assigned_fields ,)*
+                    start_index: None,
                 }
             }
         }
 
         pub struct #builder_name {
             #( #builder_fields ,)*
+            start_index: ::core::option::Option<usize>,
         }
 
         impl #builder_name {
@@ -117,13 +119,25 @@ pub(super) fn impl_derive(input: DeriveInput) -> Result<TokenStream, Error> {
                 #( #finalized_vars ;)*
 
                 let pages_len: usize = #pages_len;
+                let mut pages = crate::active::pagination::Pages::new( #per_page, pages_len);
+
+                if let Some(start_index) = self.start_index.take() {
+                    pages.jump_to_index(start_index);
+                }
 
                 #ident {
-                    pages: crate::active::pagination::Pages::new( #per_page, pages_len),
+                    pages,
                     #( #finalized_fields ,)*
                 }
             }
 
+            /// Start on the page that contains the entry at the given index
+            pub fn start_index(&mut self, start_index: usize) -> &mut Self {
+                self.start_index = Some(start_index);
+
+                self
+            }
+
             #( #builder_methods )*
         }
     };
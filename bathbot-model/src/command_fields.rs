@@ -4,6 +4,8 @@ use rosu_v2::prelude::{GameMode, Grade};
 use time::UtcOffset;
 use twilight_interactions::command::{CommandOption, CreateOption};
 
+use crate::embed_builder::SettingsImage;
+
 #[derive(Copy, Clone, CommandOption, CreateOption, Debug, Eq, PartialEq)]
 pub enum ShowHideOption {
     #[option(name = "Show", value = "show")]
@@ -20,6 +22,23 @@ pub enum EnableDisable {
     Disable,
 }
 
+#[derive(Copy, Clone, CommandOption, CreateOption, Eq, PartialEq)]
+pub enum ScoreEmbedImage {
+    #[option(name = "Thumbnail", value = "thumbnail")]
+    Thumbnail,
+    #[option(name = "Image", value = "image")]
+    Image,
+}
+
+impl From<ScoreEmbedImage> for SettingsImage {
+    fn from(image: ScoreEmbedImage) -> Self {
+        match image {
+            ScoreEmbedImage::Thumbnail => Self::Thumbnail,
+            ScoreEmbedImage::Image => Self::Image,
+        }
+    }
+}
+
 #[derive(CommandOption, CreateOption)]
 pub enum ThreadChannel {
     #[option(name = "Stay in channel", value = "channel")]
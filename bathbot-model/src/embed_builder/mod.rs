@@ -33,6 +33,17 @@ macro_rules! define_enum {
                 s.serialize_u8(*self as u8)
             }
         }
+
+        impl TryFrom<u8> for $enum_name {
+            type Error = u8;
+
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match value {
+                    $( $discriminant => Ok(Self::$variant), )*
+                    other => Err(other),
+                }
+            }
+        }
     }
 }
 
@@ -21,6 +21,23 @@ pub struct ScoreEmbedSettings {
         skip_serializing_if = "super::is_true"
     )]
     pub show_sr_in_title: bool,
+    /// Whether the position on the leaderboard filtered by the score's mods
+    /// is shown
+    #[serde(
+        rename = "l",
+        default = "ScoreEmbedSettings::default_show_mod_idx",
+        with = "bool_as_u8",
+        skip_serializing_if = "super::is_true"
+    )]
+    pub show_mod_idx: bool,
+    /// Whether the comparison with the user's best score on the map is shown
+    #[serde(
+        rename = "p",
+        default = "ScoreEmbedSettings::default_show_map_pb",
+        with = "bool_as_u8",
+        skip_serializing_if = "super::is_true"
+    )]
+    pub show_map_pb: bool,
     #[serde(rename = "i")]
     pub image: SettingsImage,
     #[serde(rename = "b")]
@@ -35,6 +52,14 @@ impl ScoreEmbedSettings {
     fn default_show_sr_in_title() -> bool {
         true
     }
+
+    fn default_show_mod_idx() -> bool {
+        true
+    }
+
+    fn default_show_map_pb() -> bool {
+        true
+    }
 }
 
 impl Default for ScoreEmbedSettings {
@@ -73,6 +98,11 @@ impl Default for ScoreEmbedSettings {
                     inner: Value::Hitresults(Default::default()),
                     y: 1,
                 },
+                // Only shown for mania scores
+                SettingValue {
+                    inner: Value::Ratio,
+                    y: 1,
+                },
                 SettingValue {
                     inner: Value::Length,
                     y: 2,
@@ -108,6 +138,8 @@ impl Default for ScoreEmbedSettings {
             ],
             show_artist: Self::default_show_artist(),
             show_sr_in_title: Self::default_show_sr_in_title(),
+            show_mod_idx: Self::default_show_mod_idx(),
+            show_map_pb: Self::default_show_map_pb(),
             image: SettingsImage::default(),
             buttons: SettingsButtons::default(),
         }
@@ -11,6 +11,7 @@ use twilight_interactions::command::{CommandOption, CreateOption};
 pub struct BgGameScore {
     pub discord_id: i64,
     pub score: i32,
+    pub best_streak: i32,
 }
 
 pub struct HlGameScore {
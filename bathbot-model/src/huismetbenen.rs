@@ -653,3 +653,67 @@ fn deser_sniped_players<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<SnipedPla
 
     d.deserialize_seq(SnipedPlayersVisitor)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_country_snipes() {
+        let json = r#"[
+            {
+                "map_id": 1234,
+                "player_id": 2,
+                "pp": 321.5,
+                "sr": 6.25,
+                "accuracy": 0.9875,
+                "date_set": "2025-03-01 12:34:56",
+                "mods": "HDDT",
+                "max_combo": 1500,
+                "artist": "Artist",
+                "title": "Title",
+                "diff_name": "Insane",
+                "sniper_name": "Sniper",
+                "sniper_id": 2,
+                "sniped_name": "Victim",
+                "sniped_id": 3
+            },
+            {
+                "map_id": 5678,
+                "player_id": 4,
+                "pp": null,
+                "sr": null,
+                "accuracy": 1.0,
+                "date_set": null,
+                "mods": "nomod",
+                "max_combo": null,
+                "artist": "Artist",
+                "title": "Title",
+                "diff_name": "Normal",
+                "sniper_id": 4,
+                "sniped_id": null
+            }
+        ]"#;
+
+        let snipes: Vec<SnipeRecent> = serde_json::from_str(json).unwrap();
+        assert_eq!(snipes.len(), 2);
+
+        let first = &snipes[0];
+        assert_eq!(first.map_id, 1234);
+        assert_eq!(first.accuracy, 98.75);
+        assert_eq!(
+            first.date,
+            OffsetDateTime::from_unix_timestamp(1740832496).ok()
+        );
+        assert_eq!(first.mods.as_ref().unwrap().to_string(), "HDDT");
+        assert_eq!(first.sniper.as_deref(), Some("Sniper"));
+        assert_eq!(first.sniped_id, Some(3));
+
+        let second = &snipes[1];
+        assert!(second.pp.is_none());
+        assert!(second.date.is_none());
+        assert!(second.mods.as_ref().unwrap().is_empty());
+        assert!(second.sniper.is_none());
+        assert!(second.sniped.is_none());
+    }
+}
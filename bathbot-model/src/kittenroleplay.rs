@@ -149,3 +149,67 @@ pub struct KittenRoleplaySnipe {
     pub victim_user_id: Option<u32>,
     pub victim_username: Option<Username>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_country_snipes() {
+        let json = r#"[
+            {
+                "accuracy": 0.9875,
+                "artist": "Artist",
+                "beatmap_id": 1234,
+                "max_combo": 1500,
+                "mods": 72,
+                "pp": 321.5,
+                "score": 12345678,
+                "sniped_at": "Sat, 01 Mar 2025 12:34:56 GMT",
+                "sniper_user_id": 2,
+                "sniper_username": "Sniper",
+                "stars": 6.25,
+                "title": "Title",
+                "version": "Insane",
+                "victim_user_id": 3,
+                "victim_username": "Victim"
+            },
+            {
+                "accuracy": 1.0,
+                "artist": "Artist",
+                "beatmap_id": 5678,
+                "max_combo": 500,
+                "mods": 0,
+                "pp": null,
+                "score": 1000000,
+                "sniped_at": "Sun, 02 Mar 2025 01:02:03 GMT",
+                "sniper_user_id": 4,
+                "sniper_username": "Other",
+                "stars": 4.5,
+                "title": "Title",
+                "version": "Normal",
+                "victim_user_id": null,
+                "victim_username": null
+            }
+        ]"#;
+
+        let snipes: Vec<KittenRoleplaySnipe> = serde_json::from_str(json).unwrap();
+        assert_eq!(snipes.len(), 2);
+
+        let first = &snipes[0];
+        assert_eq!(first.map_id, 1234);
+        assert_eq!(first.accuracy, 98.75);
+        assert_eq!(first.mods, 72);
+        assert_eq!(
+            first.sniped_at,
+            OffsetDateTime::from_unix_timestamp(1740832496).unwrap()
+        );
+        assert_eq!(first.sniper_username.as_str(), "Sniper");
+        assert_eq!(first.victim_user_id, Some(3));
+
+        let second = &snipes[1];
+        assert!(second.pp.is_none());
+        assert!(second.victim_user_id.is_none());
+        assert!(second.victim_username.is_none());
+    }
+}
@@ -27,9 +27,17 @@ impl<V> From<UserStatsEntry<V>> for RankingEntry<V> {
     }
 }
 
+/// An amount alongside the best streak that contributed to it
+#[derive(Copy, Clone)]
+pub struct AmountWithStreak {
+    pub amount: u64,
+    pub streak: u32,
+}
+
 pub enum RankingEntries {
     Accuracy(BTreeMap<usize, RankingEntry<f32>>),
     Amount(BTreeMap<usize, RankingEntry<u64>>),
+    AmountWithStreak(BTreeMap<usize, RankingEntry<AmountWithStreak>>),
     AmountWithNegative(BTreeMap<usize, RankingEntry<i64>>),
     Date(BTreeMap<usize, RankingEntry<OffsetDateTime>>),
     Float(BTreeMap<usize, RankingEntry<f32>>),
@@ -44,6 +52,7 @@ impl RankingEntries {
         match self {
             RankingEntries::Accuracy(entries) => entries.contains_key(&key),
             RankingEntries::Amount(entries) => entries.contains_key(&key),
+            RankingEntries::AmountWithStreak(entries) => entries.contains_key(&key),
             RankingEntries::AmountWithNegative(entries) => entries.contains_key(&key),
             RankingEntries::Date(entries) => entries.contains_key(&key),
             RankingEntries::Float(entries) => entries.contains_key(&key),
@@ -58,6 +67,7 @@ impl RankingEntries {
         match self {
             RankingEntries::Accuracy(entries) => entries.is_empty(),
             RankingEntries::Amount(entries) => entries.is_empty(),
+            RankingEntries::AmountWithStreak(entries) => entries.is_empty(),
             RankingEntries::AmountWithNegative(entries) => entries.is_empty(),
             RankingEntries::Date(entries) => entries.is_empty(),
             RankingEntries::Float(entries) => entries.is_empty(),
@@ -72,6 +82,7 @@ impl RankingEntries {
         match self {
             RankingEntries::Accuracy(entries) => entries.len(),
             RankingEntries::Amount(entries) => entries.len(),
+            RankingEntries::AmountWithStreak(entries) => entries.len(),
             RankingEntries::AmountWithNegative(entries) => entries.len(),
             RankingEntries::Date(entries) => entries.len(),
             RankingEntries::Float(entries) => entries.len(),
@@ -86,6 +97,7 @@ impl RankingEntries {
         match self {
             RankingEntries::Accuracy(entries) => entries.range(range).count(),
             RankingEntries::Amount(entries) => entries.range(range).count(),
+            RankingEntries::AmountWithStreak(entries) => entries.range(range).count(),
             RankingEntries::AmountWithNegative(entries) => entries.range(range).count(),
             RankingEntries::Date(entries) => entries.range(range).count(),
             RankingEntries::Float(entries) => entries.range(range).count(),
@@ -104,6 +116,7 @@ impl RankingEntries {
         match self {
             RankingEntries::Accuracy(entries) => entries.values().position(cmp_name(name)),
             RankingEntries::Amount(entries) => entries.values().position(cmp_name(name)),
+            RankingEntries::AmountWithStreak(entries) => entries.values().position(cmp_name(name)),
             RankingEntries::AmountWithNegative(entries) => {
                 entries.values().position(cmp_name(name))
             }
@@ -202,6 +215,11 @@ pub enum RankingKind {
     OsekaiRankedMapsets,
     OsekaiLovedMapsets,
     OsekaiSubscribers,
+    NochokePp {
+        guild_icon: Option<(Id<GuildMarker>, ImageHashRkyv)>,
+        mode: GameMode,
+        by_total: bool,
+    },
     PpCountry {
         country: Box<str>,
         country_code: CountryCode,
@@ -238,9 +256,9 @@ impl RankingKind {
         match self {
             Self::BgScores { global, .. } => {
                 let text = if *global {
-                    "Global leaderboard for correct guesses"
+                    "Global leaderboard for correct guesses (best streak)"
                 } else {
-                    "Server leaderboard for correct guesses"
+                    "Server leaderboard for correct guesses (best streak)"
                 };
 
                 EmbedHeader::Author(AuthorBuilder::new(text))
@@ -311,6 +329,31 @@ impl RankingKind {
 
                 EmbedHeader::title(text, url)
             }
+            Self::NochokePp {
+                guild_icon,
+                mode,
+                by_total,
+            } => {
+                let text = format!(
+                    "Server leaderboard for osu!{mode}: {kind}",
+                    mode = mode_str(*mode),
+                    kind = if *by_total {
+                        "No-choke total pp"
+                    } else {
+                        "No-choke pp gain"
+                    },
+                );
+
+                let mut author = AuthorBuilder::new(text);
+
+                if let Some((id, icon)) = guild_icon {
+                    let ext = if icon.animated { "gif" } else { "webp" };
+                    let url = format!("https://cdn.discordapp.com/icons/{id}/{icon}.{ext}");
+                    author = author.icon_url(url);
+                }
+
+                EmbedHeader::Author(author)
+            }
             Self::PpCountry {
                 country,
                 country_code,
@@ -439,6 +482,7 @@ impl RankingKind {
             | RankingKind::OsekaiSubscribers => {
                 text.push_str(" • Check out osekai.net for more info")
             }
+            RankingKind::NochokePp { .. } => text.push_str(" • Refreshes at most once per hour"),
             _ => {}
         };
 
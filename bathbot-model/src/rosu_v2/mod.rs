@@ -1,4 +1,5 @@
 pub mod grade;
 pub mod mode;
+pub mod news;
 pub mod ranking;
 pub mod user;
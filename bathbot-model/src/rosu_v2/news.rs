@@ -0,0 +1,18 @@
+use rkyv::{Archive, Serialize};
+use rosu_v2::prelude::NewsPost;
+use time::OffsetDateTime;
+
+use crate::rkyv_util::{NicheDerefAsBox, time::DateTimeRkyv};
+
+#[derive(Archive, Serialize)]
+#[rkyv(remote = NewsPost, archived = ArchivedNewsPost)]
+pub struct NewsPostRkyv {
+    pub post_id: u32,
+    pub author: String,
+    #[rkyv(with = DateTimeRkyv)]
+    pub published_at: OffsetDateTime,
+    pub slug: String,
+    pub title: String,
+    #[rkyv(with = NicheDerefAsBox)]
+    pub preview: Option<String>,
+}
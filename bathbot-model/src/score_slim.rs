@@ -156,3 +156,44 @@ impl ScoreHasMode for ScoreSlim {
 impl ScoreHasEndedAt for ScoreSlim {
     #[inline] fn ended_at(&self) -> OffsetDateTime { self.ended_at }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(max_combo: u32) -> ScoreSlim {
+        ScoreSlim {
+            accuracy: 99.0,
+            ended_at: OffsetDateTime::UNIX_EPOCH,
+            grade: Grade::S,
+            max_combo,
+            mode: GameMode::Osu,
+            mods: GameMods::new(),
+            pp: 500.0,
+            score: 0,
+            classic_score: 0,
+            score_id: 0,
+            is_legacy: false,
+            statistics: ScoreStatistics {
+                great: 1000,
+                ..Default::default()
+            },
+            set_on_lazer: true,
+        }
+    }
+
+    #[test]
+    fn default_combo_tolerance() {
+        assert!(score(1996).is_fc(GameMode::Osu, 2000));
+        assert!(!score(1995).is_fc(GameMode::Osu, 2000));
+        assert!(score(2995).is_fc(GameMode::Osu, 3000));
+    }
+
+    #[test]
+    fn custom_combo_tolerance() {
+        assert!(!score(1999).is_fc_with_tolerance(GameMode::Osu, 2000, Some(0)));
+        assert!(score(2000).is_fc_with_tolerance(GameMode::Osu, 2000, Some(0)));
+        assert!(score(1990).is_fc_with_tolerance(GameMode::Osu, 2000, Some(10)));
+        assert!(score(3).is_fc_with_tolerance(GameMode::Osu, 5, Some(10)));
+    }
+}
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN pagination_lifetime;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN pagination_lifetime INT2;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN use_threads;
\ No newline at end of file
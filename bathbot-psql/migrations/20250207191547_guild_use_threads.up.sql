@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN use_threads BOOLEAN;
\ No newline at end of file
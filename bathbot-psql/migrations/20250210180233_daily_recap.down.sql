@@ -0,0 +1 @@
+DROP TABLE daily_recap_channels;
\ No newline at end of file
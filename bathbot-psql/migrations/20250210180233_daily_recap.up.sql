@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS daily_recap_channels (
+    channel_id INT8 NOT NULL,
+    guild_id   INT8 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    hour       INT2 NOT NULL,
+    skip_quiet BOOLEAN NOT NULL,
+    PRIMARY KEY (channel_id)
+);
+
+CREATE INDEX daily_recap_channels_guild_index ON daily_recap_channels (guild_id);
\ No newline at end of file
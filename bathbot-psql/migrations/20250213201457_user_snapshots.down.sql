@@ -0,0 +1 @@
+DROP TABLE osu_user_snapshots;
\ No newline at end of file
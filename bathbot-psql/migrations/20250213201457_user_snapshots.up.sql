@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS osu_user_snapshots (
+    user_id       INT4 NOT NULL,
+    gamemode      INT2 NOT NULL,
+    snapshot_date DATE NOT NULL,
+    pp            FLOAT4 NOT NULL,
+    accuracy      FLOAT4 NOT NULL,
+    global_rank   INT4 NOT NULL,
+    PRIMARY KEY (user_id, gamemode, snapshot_date)
+);
\ No newline at end of file
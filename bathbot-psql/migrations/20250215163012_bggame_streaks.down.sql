@@ -0,0 +1 @@
+ALTER TABLE bggame_scores DROP COLUMN best_streak;
\ No newline at end of file
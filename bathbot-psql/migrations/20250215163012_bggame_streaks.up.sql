@@ -0,0 +1 @@
+ALTER TABLE bggame_scores ADD COLUMN best_streak INT4 NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE tracking_channels;
\ No newline at end of file
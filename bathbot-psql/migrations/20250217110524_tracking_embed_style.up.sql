@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS tracking_channels (
+    channel_id  INT8 NOT NULL,
+    embed_style INT2 NOT NULL,
+    PRIMARY KEY (channel_id)
+);
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE osu_map_pp;
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS osu_map_pp (
+    checksum  VARCHAR(32) NOT NULL,
+    gamemode  INT2 NOT NULL,
+    mods      INT4 NOT NULL,
+    lazer     BOOL NOT NULL,
+    max_pp    FLOAT4 NOT NULL,
+    stars     FLOAT4 NOT NULL,
+    max_combo INT4 NOT NULL,
+    PRIMARY KEY (checksum, gamemode, mods, lazer)
+);
\ No newline at end of file
@@ -0,0 +1,6 @@
+ALTER TABLE tracking_channels DROP COLUMN milestones;
+
+ALTER TABLE osu_users_100th_pp
+  DROP COLUMN playcount,
+  DROP COLUMN ranked_score,
+  DROP COLUMN total_hits;
\ No newline at end of file
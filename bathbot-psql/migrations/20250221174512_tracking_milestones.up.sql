@@ -0,0 +1,6 @@
+ALTER TABLE tracking_channels ADD COLUMN milestones BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE osu_users_100th_pp
+  ADD COLUMN playcount INT4,
+  ADD COLUMN ranked_score INT8,
+  ADD COLUMN total_hits INT8;
\ No newline at end of file
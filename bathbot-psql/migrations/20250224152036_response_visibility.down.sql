@@ -0,0 +1,2 @@
+ALTER TABLE user_configs DROP COLUMN response_visibility;
+ALTER TABLE guild_configs DROP COLUMN force_public_responses;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE user_configs ADD COLUMN response_visibility INT2;
+ALTER TABLE guild_configs ADD COLUMN force_public_responses BOOLEAN;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN default_mode;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN default_mode INT2;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE user_configs DROP COLUMN number_format;
+ALTER TABLE guild_configs DROP COLUMN number_format;
\ No newline at end of file
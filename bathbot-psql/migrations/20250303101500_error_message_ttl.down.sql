@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs DROP COLUMN error_message_ttl;
+ALTER TABLE guild_configs DROP COLUMN error_delete_invoke;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE guild_configs ADD COLUMN error_message_ttl INT2;
+ALTER TABLE guild_configs ADD COLUMN error_delete_invoke BOOLEAN;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE news_channels;
\ No newline at end of file
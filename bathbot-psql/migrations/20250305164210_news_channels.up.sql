@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS news_channels (
+    channel_id   INT8 NOT NULL,
+    guild_id     INT8 NOT NULL,
+    last_post_id INT4 NOT NULL,
+    PRIMARY KEY (channel_id)
+);
+
+CREATE INDEX news_channels_guild_index ON news_channels (guild_id);
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN embed_color;
\ No newline at end of file
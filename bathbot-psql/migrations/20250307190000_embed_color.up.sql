@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN embed_color INT4;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE notable_scores;
\ No newline at end of file
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS notable_scores (
+    score_id  INT8 NOT NULL,
+    user_id   INT4 NOT NULL,
+    username  VARCHAR(32) NOT NULL,
+    gamemode  INT2 NOT NULL,
+    map_id    INT4 NOT NULL,
+    map_title TEXT NOT NULL,
+    mods      TEXT NOT NULL,
+    pp        FLOAT4 NOT NULL,
+    accuracy  FLOAT4 NOT NULL,
+    ended_at  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (score_id)
+);
+
+CREATE INDEX notable_scores_ended_at_index ON notable_scores (ended_at);
\ No newline at end of file
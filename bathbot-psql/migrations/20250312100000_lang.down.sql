@@ -0,0 +1,2 @@
+ALTER TABLE user_configs DROP COLUMN lang;
+ALTER TABLE guild_configs DROP COLUMN lang;
\ No newline at end of file
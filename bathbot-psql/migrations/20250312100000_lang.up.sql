@@ -0,0 +1,2 @@
+ALTER TABLE user_configs ADD COLUMN lang INT2;
+ALTER TABLE guild_configs ADD COLUMN lang INT2;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE tracking_channels DROP COLUMN first_place_min_pp;
\ No newline at end of file
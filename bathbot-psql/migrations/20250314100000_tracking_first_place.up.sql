@@ -0,0 +1 @@
+ALTER TABLE tracking_channels ADD COLUMN first_place_min_pp FLOAT4;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN auto_track_channel;
\ No newline at end of file
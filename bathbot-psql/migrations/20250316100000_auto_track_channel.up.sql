@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN auto_track_channel INT8;
\ No newline at end of file
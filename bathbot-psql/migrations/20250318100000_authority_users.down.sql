@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN authority_users;
\ No newline at end of file
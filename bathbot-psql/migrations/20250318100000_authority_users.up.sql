@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN authority_users INT8[];
\ No newline at end of file
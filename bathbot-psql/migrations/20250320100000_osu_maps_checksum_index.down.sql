@@ -0,0 +1 @@
+DROP INDEX IF EXISTS osu_maps_checksum_index;
\ No newline at end of file
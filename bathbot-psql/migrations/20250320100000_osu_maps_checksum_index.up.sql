@@ -0,0 +1 @@
+CREATE INDEX osu_maps_checksum_index ON osu_maps (checksum);
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN score_embed_image;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN score_embed_image INT2;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE bg_image_hashes;
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS bg_image_hashes (
+    mapset_id      INT4 NOT NULL,
+    image_filename VARCHAR(16) NOT NULL,
+    -- NULL if the image could not be hashed
+    hash           INT8,
+    PRIMARY KEY (mapset_id)
+);
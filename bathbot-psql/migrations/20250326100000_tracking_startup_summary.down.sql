@@ -0,0 +1 @@
+ALTER TABLE tracking_channels DROP COLUMN startup_summary;
\ No newline at end of file
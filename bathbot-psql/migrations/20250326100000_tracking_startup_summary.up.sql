@@ -0,0 +1 @@
+ALTER TABLE tracking_channels ADD COLUMN startup_summary BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
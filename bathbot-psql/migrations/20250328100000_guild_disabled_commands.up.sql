@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN disabled_commands TEXT[];
\ No newline at end of file
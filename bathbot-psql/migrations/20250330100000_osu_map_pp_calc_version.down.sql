@@ -0,0 +1 @@
+ALTER TABLE osu_map_pp DROP COLUMN calc_version;
@@ -0,0 +1 @@
+ALTER TABLE osu_map_pp ADD COLUMN calc_version INT2 NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS osu_maps_version_search_index;
+DROP INDEX IF EXISTS osu_mapsets_search_index;
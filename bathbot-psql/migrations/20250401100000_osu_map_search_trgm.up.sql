@@ -0,0 +1,4 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX osu_mapsets_search_index ON osu_mapsets USING GIN ((artist || ' ' || title || ' ' || creator) gin_trgm_ops);
+CREATE INDEX osu_maps_version_search_index ON osu_maps USING GIN (map_version gin_trgm_ops);
@@ -31,7 +31,20 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  pagination_lifetime, 
+  use_threads, 
+  force_public_responses, 
+  default_mode, 
+  number_format, 
+  error_message_ttl, 
+  error_delete_invoke, 
+  embed_color, 
+  lang, 
+  auto_track_channel, 
+  authority_users, 
+  score_embed_image, 
+  disabled_commands 
 FROM 
   guild_configs"#
         );
@@ -63,6 +76,19 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            pagination_lifetime,
+            use_threads,
+            force_public_responses,
+            default_mode,
+            number_format,
+            error_message_ttl,
+            error_delete_invoke,
+            embed_color,
+            lang,
+            auto_track_channel,
+            authority_users,
+            score_embed_image,
+            disabled_commands,
         } = config;
 
         let authorities = rkyv::util::with_arena(|arena| {
@@ -80,10 +106,22 @@ INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, 
+  pagination_lifetime, use_threads, 
+  force_public_responses, default_mode, 
+  number_format, error_message_ttl, 
+  error_delete_invoke, embed_color, 
+  lang, auto_track_channel, 
+  authority_users, 
+  score_embed_image, 
+  disabled_commands
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, 
+    $23
+  )
 ON CONFLICT
   (guild_id)
 DO 
@@ -97,7 +135,20 @@ SET
   render_button = $7, 
   allow_custom_skins = $8, 
   hide_medal_solution = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  pagination_lifetime = $11, 
+  use_threads = $12, 
+  force_public_responses = $13, 
+  default_mode = $14, 
+  number_format = $15, 
+  error_message_ttl = $16, 
+  error_delete_invoke = $17, 
+  embed_color = $18, 
+  lang = $19, 
+  auto_track_channel = $20, 
+  authority_users = $21, 
+  score_embed_image = $22, 
+  disabled_commands = $23"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             Json(prefixes) as _,
@@ -108,6 +159,22 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            pagination_lifetime.map(|secs| secs as i16),
+            *use_threads,
+            *force_public_responses,
+            default_mode.map(|mode| mode as i16) as Option<i16>,
+            number_format.map(i16::from),
+            error_message_ttl.map(i16::from),
+            *error_delete_invoke,
+            embed_color.map(|color| color as i32),
+            lang.map(i16::from),
+            auto_track_channel.map(|channel| channel.get() as i64),
+            &authority_users
+                .iter()
+                .map(|user| user.get() as i64)
+                .collect::<Vec<_>>() as &[i64],
+            score_embed_image.map(|image| image as i16),
+            disabled_commands as &[String],
         );
 
         query
@@ -8,7 +8,10 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use crate::{
     Database,
-    model::configs::{DbSkinEntry, DbUserConfig, OsuUserId, SkinEntry, UserConfig},
+    model::configs::{
+        DbOsuLink, DbSkinEntry, DbUserConfig, Lang, NumberFormat, OsuLink, OsuUserId,
+        ResponseVisibility, SkinEntry, UserConfig,
+    },
 };
 
 impl Database {
@@ -28,7 +31,10 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  response_visibility, 
+  number_format, 
+  lang 
 FROM 
   user_configs 
 WHERE 
@@ -68,6 +74,63 @@ WHERE
         Ok(osu_id.map(|id| id as u32))
     }
 
+    /// Returns the osu! user ids of all given discord users that are linked
+    pub async fn select_osu_ids_by_discord_ids(&self, discord_ids: &[i64]) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  osu_id 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = ANY($1) 
+  AND osu_id IS NOT NULL"#,
+            discord_ids
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        let osu_ids = rows
+            .into_iter()
+            .filter_map(|row| row.osu_id)
+            .map(|id| id as u32)
+            .collect();
+
+        Ok(osu_ids)
+    }
+
+    /// Returns the osu! links of all given discord users that are linked
+    pub async fn select_osu_links_by_discord_ids(
+        &self,
+        discord_ids: &[i64],
+    ) -> Result<Vec<OsuLink>> {
+        let query = sqlx::query_as!(
+            DbOsuLink,
+            r#"
+SELECT 
+  discord_id, 
+  osu_id, 
+  username 
+FROM 
+  user_configs 
+  LEFT JOIN osu_user_names ON osu_id = user_id 
+WHERE 
+  discord_id = ANY($1) 
+  AND osu_id IS NOT NULL"#,
+            discord_ids
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        rows.into_iter().map(OsuLink::try_from).collect()
+    }
+
     pub async fn select_all_skins(&self) -> Result<Vec<SkinEntry>> {
         let query = sqlx::query_as!(
             DbSkinEntry,
@@ -262,6 +325,9 @@ FROM
             timezone,
             render_button,
             score_data,
+            response_visibility,
+            number_format,
+            lang,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +335,11 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  response_visibility, number_format, lang
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +350,10 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  response_visibility = $11, 
+  number_format = $12, 
+  lang = $13"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,6 +364,9 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            response_visibility.map(i16::from),
+            number_format.map(i16::from),
+            lang.map(i16::from),
         );
 
         query
@@ -402,4 +475,76 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_response_visibility(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<ResponseVisibility>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  response_visibility 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.response_visibility)
+            .and_then(|visibility| ResponseVisibility::try_from(visibility).ok()))
+    }
+
+    pub async fn select_user_lang(&self, user_id: Id<UserMarker>) -> Result<Option<Lang>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  lang 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.lang)
+            .and_then(|lang| Lang::try_from(lang).ok()))
+    }
+
+    pub async fn select_user_number_format(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<NumberFormat>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  number_format 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt
+            .and_then(|row| row.number_format)
+            .and_then(|number_format| NumberFormat::try_from(number_format).ok()))
+    }
 }
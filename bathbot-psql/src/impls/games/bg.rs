@@ -6,23 +6,34 @@ use rosu_v2::prelude::GameMode;
 
 use crate::{
     Database,
-    model::games::{DbBgGameScore, DbMapTagEntry, DbMapTagsParams},
+    model::games::{
+        DbBgGameScore, DbBgImageHash, DbMapTagEntry, DbMapTagsParams, DbUnhashedBgImage,
+    },
 };
 
 impl Database {
-    pub async fn increment_bggame_scores(&self, user_ids: &[i64], amounts: &[i32]) -> Result<()> {
+    /// Increments the scores of the given users and updates their best streak
+    /// if the new one is higher.
+    pub async fn increment_bggame_scores(
+        &self,
+        user_ids: &[i64],
+        amounts: &[i32],
+        streaks: &[i32],
+    ) -> Result<()> {
         let query = sqlx::query!(
             r#"
-INSERT INTO bggame_scores (discord_id, score) 
+INSERT INTO bggame_scores (discord_id, score, best_streak) 
 SELECT
   *
 FROM
-  UNNEST($1::INT8[], $2::INT4[]) ON CONFLICT (discord_id) DO 
+  UNNEST($1::INT8[], $2::INT4[], $3::INT4[]) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
-  score = bggame_scores.score + excluded.score"#,
+  score = bggame_scores.score + excluded.score, 
+  best_streak = GREATEST(bggame_scores.best_streak, excluded.best_streak)"#,
             user_ids,
             amounts,
+            streaks,
         );
 
         query
@@ -39,7 +50,8 @@ SET
             r#"
 SELECT 
   discord_id, 
-  score 
+  score, 
+  best_streak 
 FROM 
   bggame_scores"#
         );
@@ -90,4 +102,80 @@ SET
             .await
             .wrap_err("failed to fetch all")
     }
+
+    /// Map tags whose image was not hashed yet or whose image filename changed
+    /// since it was hashed.
+    pub async fn select_unhashed_bg_images(&self, limit: i64) -> Result<Vec<DbUnhashedBgImage>> {
+        let query = sqlx::query_as!(
+            DbUnhashedBgImage,
+            r#"
+SELECT 
+  tags.mapset_id, 
+  tags.image_filename, 
+  tags.gamemode 
+FROM 
+  map_tags AS tags 
+  LEFT JOIN bg_image_hashes AS hashes ON tags.mapset_id = hashes.mapset_id 
+WHERE 
+  hashes.mapset_id IS NULL 
+  OR hashes.image_filename != tags.image_filename 
+ORDER BY 
+  tags.mapset_id 
+LIMIT 
+  $1"#,
+            limit
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Store the hash of a background image.
+    ///
+    /// A hash of `None` marks the image as unhashable so that it won't be
+    /// retried until its filename changes.
+    pub async fn upsert_bg_image_hash(
+        &self,
+        mapset_id: u32,
+        filename: &str,
+        hash: Option<u64>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO bg_image_hashes (
+  mapset_id, image_filename, hash
+) 
+VALUES 
+  ($1, $2, $3) ON CONFLICT (mapset_id) DO 
+UPDATE 
+SET 
+  image_filename = $2, 
+  hash = $3"#,
+            mapset_id as i32,
+            filename,
+            hash.map(|hash| hash as i64),
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_bg_image_hashes(&self) -> Result<Vec<DbBgImageHash>> {
+        let query = sqlx::query_as!(
+            DbBgImageHash,
+            r#"
+SELECT 
+  mapset_id, 
+  hash AS "hash!" 
+FROM 
+  bg_image_hashes 
+WHERE 
+  hash IS NOT NULL"#
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
 }
@@ -0,0 +1,99 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::{Database, model::osu::DbDailyRecap};
+
+impl Database {
+    pub async fn select_daily_recaps(&self) -> Result<Vec<DbDailyRecap>> {
+        let query = sqlx::query_as!(
+            DbDailyRecap,
+            r#"
+SELECT
+  channel_id,
+  guild_id,
+  gamemode,
+  hour,
+  skip_quiet
+FROM
+  daily_recap_channels"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_guild_daily_recaps(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbDailyRecap>> {
+        let query = sqlx::query_as!(
+            DbDailyRecap,
+            r#"
+SELECT
+  channel_id,
+  guild_id,
+  gamemode,
+  hour,
+  skip_quiet
+FROM
+  daily_recap_channels
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_daily_recap(&self, recap: &DbDailyRecap) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO daily_recap_channels (
+  channel_id, guild_id, gamemode, hour, skip_quiet
+)
+VALUES
+  ($1, $2, $3, $4, $5)
+ON CONFLICT
+  (channel_id)
+DO
+  UPDATE
+SET
+  gamemode = $3,
+  hour = $4,
+  skip_quiet = $5"#,
+            recap.channel_id,
+            recap.guild_id,
+            recap.gamemode,
+            recap.hour,
+            recap.skip_quiet,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_daily_recap(&self, channel_id: Id<ChannelMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  daily_recap_channels
+WHERE
+  channel_id = $1"#,
+            channel_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -2,12 +2,12 @@ use std::{collections::HashMap, hash::BuildHasher};
 
 use eyre::{Result, WrapErr};
 use futures::StreamExt;
-use rosu_v2::prelude::BeatmapExtended;
+use rosu_v2::prelude::{BeatmapExtended, GameMode, RankStatus};
 use sqlx::{Postgres, Transaction};
 
 use crate::{
     Database,
-    model::osu::{DbBeatmap, DbBeatmapset, DbMapFilename, MapVersion},
+    model::osu::{DbBeatmap, DbBeatmapset, DbMapFilename, DbMapSearchEntry, MapVersion},
 };
 
 impl Database {
@@ -76,10 +76,20 @@ FROM
 
         let Some(row) = row_opt else { return Ok(None) };
 
+        let filepath = match (row.map_filepath, checksum) {
+            (Some(path), Some(checksum)) if row.checksum == checksum => {
+                DbMapFilename::Present(path.into_boxed_str())
+            }
+            (Some(path), None) => DbMapFilename::Present(path.into_boxed_str()),
+            (Some(_), Some(_)) => DbMapFilename::ChecksumMismatch,
+            (None, _) => DbMapFilename::Missing,
+        };
+
         let map = DbBeatmap {
             map_id: row.map_id,
             mapset_id: row.mapset_id,
             user_id: row.user_id,
+            checksum: row.checksum,
             map_version: row.map_version,
             seconds_drain: row.seconds_drain,
             count_circles: row.count_circles,
@@ -100,18 +110,31 @@ FROM
             cover: row.cover,
         };
 
-        let filepath = match (row.map_filepath, checksum) {
-            (Some(path), Some(checksum)) if row.checksum == checksum => {
-                DbMapFilename::Present(path.into_boxed_str())
-            }
-            (Some(path), None) => DbMapFilename::Present(path.into_boxed_str()),
-            (Some(_), Some(_)) => DbMapFilename::ChecksumMismatch,
-            (None, _) => DbMapFilename::Missing,
-        };
-
         Ok(Some((map, mapset, filepath)))
     }
 
+    pub async fn select_map_id_by_checksum(&self, checksum: &str) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  map_id
+FROM
+  osu_maps
+WHERE
+  checksum = $1
+LIMIT
+  1"#,
+            checksum
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| row.map_id as u32))
+    }
+
     pub async fn select_osu_maps_full<S>(
         &self,
         maps_id_checksum: &HashMap<i32, Option<&str>, S>,
@@ -180,10 +203,22 @@ FROM
         while let Some(row_res) = rows.next().await {
             let row = row_res.wrap_err("Failed to fetch next")?;
 
+            let checksum = maps_id_checksum.get(&row.map_id).and_then(Option::as_ref);
+
+            let filepath = match (row.map_filepath, checksum) {
+                (Some(path), Some(&checksum)) if row.checksum == checksum => {
+                    DbMapFilename::Present(path.into_boxed_str())
+                }
+                (Some(path), None) => DbMapFilename::Present(path.into_boxed_str()),
+                (Some(_), Some(_)) => DbMapFilename::ChecksumMismatch,
+                (None, _) => DbMapFilename::Missing,
+            };
+
             let map = DbBeatmap {
                 map_id: row.map_id,
                 mapset_id: row.mapset_id,
                 user_id: row.user_id,
+                checksum: row.checksum,
                 map_version: row.map_version,
                 seconds_drain: row.seconds_drain,
                 count_circles: row.count_circles,
@@ -204,17 +239,6 @@ FROM
                 cover: row.cover,
             };
 
-            let checksum = maps_id_checksum.get(&map.map_id).and_then(Option::as_ref);
-
-            let filepath = match (row.map_filepath, checksum) {
-                (Some(path), Some(&checksum)) if row.checksum == checksum => {
-                    DbMapFilename::Present(path.into_boxed_str())
-                }
-                (Some(path), None) => DbMapFilename::Present(path.into_boxed_str()),
-                (Some(_), Some(_)) => DbMapFilename::ChecksumMismatch,
-                (None, _) => DbMapFilename::Missing,
-            };
-
             maps.insert(map.map_id, (map, mapset, filepath));
         }
 
@@ -293,6 +317,103 @@ ORDER BY
         query.fetch_all(self).await.wrap_err("failed to fetch all")
     }
 
+    /// Search stored maps whose artist, title, creator, and version contain
+    /// all patterns.
+    ///
+    /// Be sure wildcards (_, %) are escaped as required! Maps without stored
+    /// star rating are dropped if a star bound is given.
+    pub async fn search_osu_maps(
+        &self,
+        patterns: &[String],
+        mode: Option<GameMode>,
+        statuses: Option<&[RankStatus]>,
+        min_stars: Option<f32>,
+        max_stars: Option<f32>,
+    ) -> Result<Vec<DbMapSearchEntry>> {
+        let statuses: Option<Vec<i16>> =
+            statuses.map(|statuses| statuses.iter().map(|&status| status as i16).collect());
+
+        // The longest pattern narrows down the candidates through the trigram
+        // indices; patterns contain no whitespace so they can't span fields
+        let longest = patterns
+            .iter()
+            .max_by_key(|pattern| pattern.len())
+            .map_or("%", String::as_str);
+
+        let query = sqlx::query_as!(
+            DbMapSearchEntry,
+            r#"
+WITH candidates AS (
+  SELECT 
+    map.map_id 
+  FROM 
+    osu_maps AS map 
+    JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id 
+  WHERE 
+    (
+      mapset.artist || ' ' || mapset.title || ' ' || mapset.creator
+    ) ILIKE $6 
+  UNION 
+  SELECT 
+    map_id 
+  FROM 
+    osu_maps 
+  WHERE 
+    map_version ILIKE $6
+) 
+SELECT 
+  map.map_id, 
+  map.mapset_id, 
+  mapset.artist, 
+  mapset.title, 
+  mapset.creator, 
+  map.map_version, 
+  map.gamemode, 
+  mapset.rank_status, 
+  map.seconds_drain, 
+  map.bpm, 
+  map_pp.stars AS "stars?" 
+FROM 
+  candidates 
+  JOIN osu_maps AS map ON candidates.map_id = map.map_id 
+  JOIN osu_mapsets AS mapset ON map.mapset_id = mapset.mapset_id 
+  LEFT JOIN LATERAL (
+    SELECT 
+      stars 
+    FROM 
+      osu_map_pp 
+    WHERE 
+      checksum = map.checksum 
+      AND gamemode = map.gamemode 
+      AND mods = 0 
+    LIMIT 
+      1
+  ) AS map_pp ON true 
+WHERE 
+  CONCAT_WS(
+    ' ', mapset.artist, mapset.title, mapset.creator, 
+    map.map_version
+  ) ILIKE ALL($1) 
+  AND ($2::INT2 IS NULL OR map.gamemode = $2) 
+  AND ($3::INT2[] IS NULL OR mapset.rank_status = ANY($3)) 
+  AND ($4::FLOAT4 IS NULL OR map_pp.stars >= $4) 
+  AND ($5::FLOAT4 IS NULL OR map_pp.stars <= $5) 
+ORDER BY 
+  mapset.ranked_date DESC NULLS LAST, 
+  map.map_id DESC 
+LIMIT 
+  100"#,
+            patterns,
+            mode.map(|mode| mode as i16) as _,
+            statuses.as_deref() as _,
+            min_stars as _,
+            max_stars as _,
+            longest,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
     pub async fn insert_beatmap_file(&self, map_id: u32, path: impl AsRef<str>) -> Result<()> {
         let query = sqlx::query!(
             r#"
@@ -391,7 +512,7 @@ VALUES
             .await
             .wrap_err("Failed to execute query")?;
 
-        if old_checksum.is_some_and(|old| old != checksum) {
+        if let Some(old_checksum) = old_checksum.filter(|old| old != checksum) {
             let query = sqlx::query!(
                 r#"
 DELETE FROM 
@@ -405,6 +526,21 @@ WHERE
                 .execute(&mut **tx)
                 .await
                 .wrap_err("Failed to delete from osu_map_files")?;
+
+            // Cached pp values are keyed by checksum so they're stale now
+            let query = sqlx::query!(
+                r#"
+DELETE FROM 
+  osu_map_pp 
+WHERE 
+  checksum = $1"#,
+                old_checksum,
+            );
+
+            query
+                .execute(&mut **tx)
+                .await
+                .wrap_err("Failed to delete from osu_map_pp")?;
         }
 
         Ok(())
@@ -0,0 +1,85 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+
+use crate::{Database, model::osu::DbMapPp};
+
+impl Database {
+    /// Cached values are only returned if they were calculated by the given
+    /// calculator version.
+    pub async fn select_map_pp(
+        &self,
+        checksum: &str,
+        mode: GameMode,
+        mods: u32,
+        lazer: bool,
+        calc_version: i16,
+    ) -> Result<Option<DbMapPp>> {
+        let query = sqlx::query_as!(
+            DbMapPp,
+            r#"
+SELECT 
+  max_pp, 
+  stars, 
+  max_combo 
+FROM 
+  osu_map_pp 
+WHERE 
+  checksum = $1 
+  AND gamemode = $2 
+  AND mods = $3 
+  AND lazer = $4 
+  AND calc_version = $5"#,
+            checksum,
+            mode as i16,
+            mods as i32,
+            lazer,
+            calc_version,
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("Failed to fetch optional")
+    }
+
+    pub async fn upsert_map_pp(
+        &self,
+        checksum: &str,
+        mode: GameMode,
+        mods: u32,
+        lazer: bool,
+        calc_version: i16,
+        map_pp: &DbMapPp,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_map_pp (
+  checksum, gamemode, mods, lazer, max_pp, 
+  stars, max_combo, calc_version
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (checksum, gamemode, mods, lazer) DO 
+UPDATE 
+SET 
+  max_pp = $5, 
+  stars = $6, 
+  max_combo = $7, 
+  calc_version = $8"#,
+            checksum,
+            mode as i16,
+            mods as i32,
+            lazer,
+            map_pp.max_pp,
+            map_pp.stars,
+            map_pp.max_combo,
+            calc_version,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
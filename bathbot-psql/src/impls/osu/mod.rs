@@ -1,8 +1,13 @@
+pub mod daily_recap;
 pub mod map;
+pub mod map_pp;
 pub mod mapset;
 pub mod name;
+pub mod news_channel;
+pub mod notable_score;
 pub mod rank_pp;
 pub mod render;
 pub mod score;
 pub mod tracked_users;
 pub mod user;
+pub mod user_snapshot;
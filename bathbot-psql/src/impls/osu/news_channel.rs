@@ -0,0 +1,114 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::{Database, model::osu::DbNewsChannel};
+
+impl Database {
+    pub async fn select_news_channels(&self) -> Result<Vec<DbNewsChannel>> {
+        let query = sqlx::query_as!(
+            DbNewsChannel,
+            r#"
+SELECT
+  channel_id,
+  guild_id,
+  last_post_id
+FROM
+  news_channels"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn select_guild_news_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbNewsChannel>> {
+        let query = sqlx::query_as!(
+            DbNewsChannel,
+            r#"
+SELECT
+  channel_id,
+  guild_id,
+  last_post_id
+FROM
+  news_channels
+WHERE
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_news_channel(&self, channel: &DbNewsChannel) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO news_channels (channel_id, guild_id, last_post_id)
+VALUES
+  ($1, $2, $3)
+ON CONFLICT
+  (channel_id)
+DO
+  UPDATE
+SET
+  last_post_id = $3"#,
+            channel.channel_id,
+            channel.guild_id,
+            channel.last_post_id,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn update_news_channel_last_post(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        last_post_id: u32,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  news_channels
+SET
+  last_post_id = $2
+WHERE
+  channel_id = $1"#,
+            channel_id.get() as i64,
+            last_post_id as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether an entry was deleted
+    pub async fn delete_news_channel(&self, channel_id: Id<ChannelMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  news_channels
+WHERE
+  channel_id = $1"#,
+            channel_id.get() as i64
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
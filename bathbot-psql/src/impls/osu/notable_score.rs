@@ -0,0 +1,104 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::OffsetDateTime;
+
+use crate::{Database, model::osu::DbNotableScore};
+
+impl Database {
+    /// Notable scores that were set after `since`, sorted by pp.
+    ///
+    /// Returns at most 500 scores.
+    pub async fn select_notable_scores(
+        &self,
+        since: OffsetDateTime,
+        mode: Option<GameMode>,
+        min_pp: Option<f32>,
+    ) -> Result<Vec<DbNotableScore>> {
+        let query = sqlx::query_as!(
+            DbNotableScore,
+            r#"
+SELECT
+  score_id,
+  user_id,
+  username,
+  gamemode,
+  map_id,
+  map_title,
+  mods,
+  pp,
+  accuracy,
+  ended_at
+FROM
+  notable_scores
+WHERE
+  ended_at >= $1
+  AND ($2::INT2 IS NULL OR gamemode = $2)
+  AND ($3::FLOAT4 IS NULL OR pp >= $3)
+ORDER BY
+  pp DESC
+LIMIT
+  500"#,
+            since,
+            mode.map(|mode| mode as i16) as _,
+            min_pp as _,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_notable_score(&self, score: &DbNotableScore) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO notable_scores (
+  score_id, user_id, username, gamemode,
+  map_id, map_title, mods, pp, accuracy,
+  ended_at
+)
+VALUES
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+ON CONFLICT
+  (score_id)
+DO
+  UPDATE
+SET
+  username = $3,
+  pp = $8"#,
+            score.score_id,
+            score.user_id,
+            score.username,
+            score.gamemode,
+            score.map_id,
+            score.map_title,
+            score.mods,
+            score.pp,
+            score.accuracy,
+            score.ended_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Remove all notable scores that were set before `before`.
+    pub async fn delete_notable_scores_before(&self, before: OffsetDateTime) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  notable_scores
+WHERE
+  ended_at < $1"#,
+            before
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+}
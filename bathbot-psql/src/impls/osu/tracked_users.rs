@@ -4,7 +4,7 @@ use time::OffsetDateTime;
 
 use crate::{
     Database,
-    model::osu::{DbTrackedOsuUser, DbTrackedOsuUserInChannel},
+    model::osu::{DbTrackedOsuUser, DbTrackedOsuUserInChannel, DbTrackingChannel, TrackEmbedStyle},
 };
 
 impl Database {
@@ -17,7 +17,10 @@ WITH pps AS (
     user_id,
     gamemode,
     pp as last_pp,
-    last_updated
+    last_updated,
+    playcount,
+    ranked_score,
+    total_hits
   FROM
     osu_users_100th_pp
   AS
@@ -138,6 +141,40 @@ SET
         Ok(())
     }
 
+    pub async fn update_tracked_milestone_stats(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        playcount: u32,
+        ranked_score: u64,
+        total_hits: u64,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE
+  osu_users_100th_pp
+SET
+  playcount = $3,
+  ranked_score = $4,
+  total_hits = $5
+WHERE
+  user_id = $1
+  AND gamemode = $2"#,
+            user_id as i32,
+            mode as i16,
+            playcount as i32,
+            ranked_score as i64,
+            total_hits as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
     pub async fn delete_tracked_osu_user(
         &self,
         user_id: u32,
@@ -194,4 +231,78 @@ WHERE
 
         Ok(())
     }
+
+    pub async fn select_tracking_channels(&self) -> Result<Vec<DbTrackingChannel>> {
+        let query = sqlx::query_as!(
+            DbTrackingChannel,
+            r#"
+SELECT
+  channel_id,
+  embed_style,
+  milestones,
+  first_place_min_pp,
+  startup_summary
+FROM
+  tracking_channels"#
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_tracking_channel(
+        &self,
+        channel_id: u64,
+        embed_style: TrackEmbedStyle,
+        milestones: bool,
+        first_place_min_pp: Option<f32>,
+        startup_summary: bool,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO tracking_channels (
+  channel_id, embed_style, milestones, first_place_min_pp, startup_summary
+)
+VALUES
+  ($1, $2, $3, $4, $5)
+ON CONFLICT
+  (channel_id)
+DO
+  UPDATE
+SET
+  embed_style = $2,
+  milestones = $3,
+  first_place_min_pp = $4,
+  startup_summary = $5"#,
+            channel_id as i64,
+            i16::from(embed_style),
+            milestones,
+            first_place_min_pp,
+            startup_summary,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn delete_tracking_channel(&self, channel_id: u64) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  tracking_channels
+WHERE
+  channel_id = $1"#,
+            channel_id as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
 }
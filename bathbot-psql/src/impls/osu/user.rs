@@ -824,6 +824,31 @@ SET
                 .execute(&mut *tx)
                 .await
                 .wrap_err("failed to execute osu_user_mode_stats query")?;
+
+            let query = sqlx::query!(
+                r#"
+INSERT INTO osu_user_snapshots (
+  user_id, gamemode, snapshot_date, 
+  pp, accuracy, global_rank
+) 
+VALUES 
+  ($1, $2, CURRENT_DATE, $3, $4, $5) ON CONFLICT (user_id, gamemode, snapshot_date) DO 
+UPDATE 
+SET 
+  pp = $3, 
+  accuracy = $4, 
+  global_rank = $5"#,
+                user.user_id as i32,
+                mode as i16,
+                stats.pp,
+                stats.accuracy,
+                stats.global_rank.unwrap_or(0) as i32,
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute osu_user_snapshots query")?;
         }
 
         tx.commit().await.wrap_err("failed to commit transaction")?;
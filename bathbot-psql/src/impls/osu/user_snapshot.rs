@@ -0,0 +1,38 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::Date;
+
+use crate::{Database, model::osu::DbUserSnapshot};
+
+impl Database {
+    /// Snapshots are ordered by date, oldest first
+    pub async fn select_osu_user_snapshots(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        since: Option<Date>,
+    ) -> Result<Vec<DbUserSnapshot>> {
+        let query = sqlx::query_as!(
+            DbUserSnapshot,
+            r#"
+SELECT
+  snapshot_date,
+  pp,
+  accuracy,
+  global_rank
+FROM
+  osu_user_snapshots
+WHERE
+  user_id = $1
+  AND gamemode = $2
+  AND ($3 :: DATE IS NULL OR snapshot_date >= $3)
+ORDER BY
+  snapshot_date ASC"#,
+            user_id as i32,
+            mode as i16,
+            since,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+}
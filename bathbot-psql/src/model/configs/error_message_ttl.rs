@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// How long error replies to prefix commands remain before being deleted.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum ErrorMessageTtl {
+    #[default]
+    #[option(name = "Off", value = "off")]
+    Off = 0,
+    #[option(name = "30 seconds", value = "30s")]
+    ThirtySeconds = 1,
+    #[option(name = "60 seconds", value = "60s")]
+    SixtySeconds = 2,
+    #[option(name = "5 minutes", value = "5min")]
+    FiveMinutes = 3,
+}
+
+impl ErrorMessageTtl {
+    /// Delay until deletion; `None` if error replies should not be deleted.
+    pub fn duration(self) -> Option<Duration> {
+        match self {
+            Self::Off => None,
+            Self::ThirtySeconds => Some(Duration::from_secs(30)),
+            Self::SixtySeconds => Some(Duration::from_secs(60)),
+            Self::FiveMinutes => Some(Duration::from_secs(300)),
+        }
+    }
+}
+
+impl From<ErrorMessageTtl> for i16 {
+    fn from(ttl: ErrorMessageTtl) -> Self {
+        ttl as Self
+    }
+}
+
+impl TryFrom<i16> for ErrorMessageTtl {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::ThirtySeconds),
+            2 => Ok(Self::SixtySeconds),
+            3 => Ok(Self::FiveMinutes),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,6 +1,15 @@
+use bathbot_model::embed_builder::SettingsImage;
+use rosu_v2::prelude::GameMode;
 use sqlx::types::JsonValue;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
 
-use super::{Authorities, HideSolutions, Retries, ScoreData, list_size::ListSize};
+use super::{
+    Authorities, ErrorMessageTtl, HideSolutions, Lang, NumberFormat, Retries, ScoreData,
+    list_size::ListSize,
+};
 
 pub struct DbGuildConfig {
     pub guild_id: i64,
@@ -13,6 +22,19 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub pagination_lifetime: Option<i16>,
+    pub use_threads: Option<bool>,
+    pub force_public_responses: Option<bool>,
+    pub default_mode: Option<i16>,
+    pub number_format: Option<i16>,
+    pub error_message_ttl: Option<i16>,
+    pub error_delete_invoke: Option<bool>,
+    pub embed_color: Option<i32>,
+    pub lang: Option<i16>,
+    pub auto_track_channel: Option<i64>,
+    pub authority_users: Option<Vec<i64>>,
+    pub score_embed_image: Option<i16>,
+    pub disabled_commands: Option<Vec<String>>,
 }
 
 #[derive(Clone)]
@@ -26,10 +48,40 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    /// Seconds until paginations are no longer interactive.
+    pub pagination_lifetime: Option<u16>,
+    pub use_threads: Option<bool>,
+    /// Overrides the members' preference for ephemeral responses.
+    pub force_public_responses: Option<bool>,
+    /// Mode for commands if neither the arguments nor the user config specify
+    /// one.
+    pub default_mode: Option<GameMode>,
+    pub number_format: Option<NumberFormat>,
+    /// Delay until error replies to prefix commands are deleted.
+    pub error_message_ttl: Option<ErrorMessageTtl>,
+    /// Whether the invoking message should be deleted alongside the error
+    /// reply.
+    pub error_delete_invoke: Option<bool>,
+    /// RGB color for embeds that don't specify their own color.
+    pub embed_color: Option<u32>,
+    /// Language of messages if the member's config doesn't specify one.
+    pub lang: Option<Lang>,
+    /// Channel to which members are added for tracking when they link.
+    pub auto_track_channel: Option<Id<ChannelMarker>>,
+    /// Members with authority status regardless of their roles.
+    pub authority_users: Vec<Id<UserMarker>>,
+    /// Layout of the map cover in score embeds of members without embed
+    /// settings.
+    pub score_embed_image: Option<SettingsImage>,
+    /// Names of commands that can't be used in the guild.
+    pub disabled_commands: Vec<String>,
 }
 
 impl GuildConfig {
     pub const DEFAULT_PREFIX: &str = "<";
+    pub const MAX_PAGINATION_LIFETIME: u16 = 900;
+    pub const MIN_PAGINATION_LIFETIME: u16 = 30;
+    pub const MAX_DISABLED_COMMANDS: usize = 50;
 }
 
 impl Default for GuildConfig {
@@ -44,6 +96,19 @@ impl Default for GuildConfig {
             allow_custom_skins: Default::default(),
             hide_medal_solution: Default::default(),
             score_data: Default::default(),
+            pagination_lifetime: Default::default(),
+            use_threads: Default::default(),
+            force_public_responses: Default::default(),
+            default_mode: Default::default(),
+            number_format: Default::default(),
+            error_message_ttl: Default::default(),
+            error_delete_invoke: Default::default(),
+            embed_color: Default::default(),
+            lang: Default::default(),
+            auto_track_channel: Default::default(),
+            authority_users: Default::default(),
+            score_embed_image: Default::default(),
+            disabled_commands: Default::default(),
         }
     }
 }
@@ -62,6 +127,19 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            pagination_lifetime,
+            use_threads,
+            force_public_responses,
+            default_mode,
+            number_format,
+            error_message_ttl,
+            error_delete_invoke,
+            embed_color,
+            lang,
+            auto_track_channel,
+            authority_users,
+            score_embed_image,
+            disabled_commands,
         } = config;
 
         let authorities = Authorities::deserialize(&authorities);
@@ -90,6 +168,29 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            pagination_lifetime: pagination_lifetime.map(u16::try_from).and_then(Result::ok),
+            use_threads,
+            force_public_responses,
+            default_mode: default_mode.map(|mode| GameMode::from(mode as u8)),
+            number_format: number_format
+                .map(NumberFormat::try_from)
+                .and_then(Result::ok),
+            error_message_ttl: error_message_ttl
+                .map(ErrorMessageTtl::try_from)
+                .and_then(Result::ok),
+            error_delete_invoke,
+            embed_color: embed_color.map(|color| color as u32),
+            lang: lang.map(Lang::try_from).and_then(Result::ok),
+            auto_track_channel: auto_track_channel.map(|channel| Id::new(channel as u64)),
+            authority_users: authority_users
+                .unwrap_or_default()
+                .into_iter()
+                .map(|user| Id::new(user as u64))
+                .collect(),
+            score_embed_image: score_embed_image
+                .map(|image| SettingsImage::try_from(image as u8))
+                .and_then(Result::ok),
+            disabled_commands: disabled_commands.unwrap_or_default(),
         }
     }
 }
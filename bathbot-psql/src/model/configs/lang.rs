@@ -0,0 +1,33 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Language of user-facing messages.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum Lang {
+    #[default]
+    #[option(name = "English", value = "en")]
+    English = 0,
+    #[option(name = "Deutsch", value = "de")]
+    German = 1,
+    #[option(name = "Русский", value = "ru")]
+    Russian = 2,
+}
+
+impl From<Lang> for i16 {
+    fn from(lang: Lang) -> Self {
+        lang as Self
+    }
+}
+
+impl TryFrom<i16> for Lang {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::English),
+            1 => Ok(Self::German),
+            2 => Ok(Self::Russian),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use eyre::{OptionExt, Report};
+use twilight_model::id::{Id, marker::UserMarker};
+
+pub struct DbOsuLink {
+    pub discord_id: i64,
+    pub osu_id: Option<i32>,
+    pub username: Option<String>,
+}
+
+impl TryFrom<DbOsuLink> for OsuLink {
+    type Error = Report;
+
+    fn try_from(link: DbOsuLink) -> Result<Self, Self::Error> {
+        let discord_id =
+            Id::new_checked(link.discord_id as u64).ok_or_eyre("Invalid discord id of link")?;
+
+        let osu_id = link.osu_id.ok_or_eyre("Missing osu! id of link")? as u32;
+
+        Ok(Self {
+            discord_id,
+            osu_id,
+            username: link.username.map(String::into_boxed_str),
+        })
+    }
+}
+
+/// A discord user linked to an osu! user
+pub struct OsuLink {
+    pub discord_id: Id<UserMarker>,
+    pub osu_id: u32,
+    /// `None` if the osu! user's name has not been stored yet
+    pub username: Option<Box<str>>,
+}
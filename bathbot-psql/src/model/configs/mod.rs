@@ -1,8 +1,13 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    error_message_ttl::ErrorMessageTtl,
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
+    lang::Lang,
+    link::{DbOsuLink, OsuLink},
     list_size::ListSize,
+    number_format::NumberFormat,
+    response_visibility::ResponseVisibility,
     retries::Retries,
     score_data::ScoreData,
     skin::{DbSkinEntry, SkinEntry},
@@ -10,9 +15,14 @@ pub use self::{
 };
 
 mod authorities;
+mod error_message_ttl;
 mod guild;
 mod hide_solutions;
+mod lang;
+mod link;
 mod list_size;
+mod number_format;
+mod response_visibility;
 mod retries;
 mod score_data;
 mod skin;
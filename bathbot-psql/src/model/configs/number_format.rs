@@ -0,0 +1,51 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Separators used to display large numbers.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum NumberFormat {
+    #[default]
+    #[option(name = "1,234.5", value = "comma")]
+    Comma = 0,
+    #[option(name = "1.234,5", value = "dot")]
+    Dot = 1,
+    #[option(name = "1 234,5", value = "space")]
+    Space = 2,
+}
+
+impl NumberFormat {
+    pub fn thousands_separator(self) -> char {
+        match self {
+            Self::Comma => ',',
+            Self::Dot => '.',
+            // Non-breaking so that numbers don't get split across lines
+            Self::Space => '\u{a0}',
+        }
+    }
+
+    pub fn decimal_separator(self) -> char {
+        match self {
+            Self::Comma => '.',
+            Self::Dot | Self::Space => ',',
+        }
+    }
+}
+
+impl From<NumberFormat> for i16 {
+    fn from(number_format: NumberFormat) -> Self {
+        number_format as Self
+    }
+}
+
+impl TryFrom<i16> for NumberFormat {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Comma),
+            1 => Ok(Self::Dot),
+            2 => Ok(Self::Space),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+/// Whether responses to commands that display personal data should be visible
+/// to everyone or only to the command author.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum ResponseVisibility {
+    #[default]
+    #[option(name = "Public", value = "public")]
+    Public = 0,
+    #[option(name = "Ephemeral", value = "ephemeral")]
+    Ephemeral = 1,
+}
+
+impl ResponseVisibility {
+    pub fn is_ephemeral(self) -> bool {
+        self == Self::Ephemeral
+    }
+}
+
+impl From<ResponseVisibility> for i16 {
+    fn from(visibility: ResponseVisibility) -> Self {
+        visibility as Self
+    }
+}
+
+impl TryFrom<i16> for ResponseVisibility {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Public),
+            1 => Ok(Self::Ephemeral),
+            _ => Err(()),
+        }
+    }
+}
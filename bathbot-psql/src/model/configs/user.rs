@@ -3,7 +3,7 @@ use rosu_v2::prelude::{GameMode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{Retries, ScoreData, list_size::ListSize};
+use super::{Lang, NumberFormat, ResponseVisibility, Retries, ScoreData, list_size::ListSize};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,9 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub response_visibility: Option<i16>,
+    pub number_format: Option<i16>,
+    pub lang: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +49,9 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub response_visibility: Option<ResponseVisibility>,
+    pub number_format: Option<NumberFormat>,
+    pub lang: Option<Lang>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +67,9 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            response_visibility: None,
+            number_format: None,
+            lang: None,
         }
     }
 }
@@ -78,6 +87,9 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            response_visibility,
+            number_format,
+            lang,
         } = config;
 
         Self {
@@ -92,6 +104,13 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            response_visibility: response_visibility
+                .map(ResponseVisibility::try_from)
+                .and_then(Result::ok),
+            number_format: number_format
+                .map(NumberFormat::try_from)
+                .and_then(Result::ok),
+            lang: lang.map(Lang::try_from).and_then(Result::ok),
         }
     }
 }
@@ -8,6 +8,7 @@ use sqlx::FromRow;
 pub struct DbBgGameScore {
     pub discord_id: i64,
     pub score: i32,
+    pub best_streak: i32,
 }
 
 pub struct MapsetTagsEntries {
@@ -21,6 +22,17 @@ pub struct DbMapTagEntry {
     pub image_filename: String,
 }
 
+pub struct DbUnhashedBgImage {
+    pub mapset_id: i32,
+    pub image_filename: String,
+    pub gamemode: i16,
+}
+
+pub struct DbBgImageHash {
+    pub mapset_id: i32,
+    pub hash: i64,
+}
+
 macro_rules! define_map_tags {
     ( $( $column:ident ,)* ) => {
         pub struct DbMapTagsParams {
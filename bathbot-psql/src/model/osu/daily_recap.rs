@@ -0,0 +1,7 @@
+pub struct DbDailyRecap {
+    pub channel_id: i64,
+    pub guild_id: i64,
+    pub gamemode: i16,
+    pub hour: i16,
+    pub skip_quiet: bool,
+}
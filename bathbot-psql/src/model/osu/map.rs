@@ -5,6 +5,7 @@ pub struct DbBeatmap {
     pub map_id: i32,
     pub mapset_id: i32,
     pub user_id: i32,
+    pub checksum: String,
     pub map_version: String,
     pub seconds_drain: i32,
     pub count_circles: i32,
@@ -25,3 +26,17 @@ pub struct MapVersion {
     pub map_id: i32,
     pub version: String,
 }
+
+pub struct DbMapSearchEntry {
+    pub map_id: i32,
+    pub mapset_id: i32,
+    pub artist: String,
+    pub title: String,
+    pub creator: String,
+    pub map_version: String,
+    pub gamemode: i16,
+    pub rank_status: i16,
+    pub seconds_drain: i32,
+    pub bpm: f32,
+    pub stars: Option<f32>,
+}
@@ -0,0 +1,6 @@
+#[derive(Copy, Clone)]
+pub struct DbMapPp {
+    pub max_pp: f32,
+    pub stars: f32,
+    pub max_combo: i32,
+}
@@ -1,7 +1,15 @@
-pub use self::{bookmark::*, map::*, mapset::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, daily_recap::*, map::*, map_pp::*, mapset::*, news_channel::*, notable_score::*,
+    tracked_user::*, user::*, user_snapshot::*,
+};
 
 mod bookmark;
+mod daily_recap;
 mod map;
+mod map_pp;
 mod mapset;
+mod news_channel;
+mod notable_score;
 mod tracked_user;
 mod user;
+mod user_snapshot;
@@ -0,0 +1,6 @@
+pub struct DbNewsChannel {
+    pub channel_id: i64,
+    pub guild_id: i64,
+    /// Id of the latest news post that was sent to the channel
+    pub last_post_id: i32,
+}
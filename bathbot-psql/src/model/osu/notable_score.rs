@@ -0,0 +1,16 @@
+use time::OffsetDateTime;
+
+/// A tracked top score that is kept for a week to be shown in a global feed
+pub struct DbNotableScore {
+    pub score_id: i64,
+    pub user_id: i32,
+    pub username: String,
+    pub gamemode: i16,
+    pub map_id: i32,
+    /// Formatted as `Artist - Title [Version]`
+    pub map_title: String,
+    pub mods: String,
+    pub pp: f32,
+    pub accuracy: f32,
+    pub ended_at: OffsetDateTime,
+}
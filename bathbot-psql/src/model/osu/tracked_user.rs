@@ -1,4 +1,5 @@
 use time::OffsetDateTime;
+use twilight_interactions::command::{CommandOption, CreateOption};
 
 pub struct DbTrackedOsuUser {
     pub user_id: i32,
@@ -12,6 +13,9 @@ pub struct DbTrackedOsuUser {
     pub max_combo_percent: Option<f32>,
     pub last_pp: f32,
     pub last_updated: OffsetDateTime,
+    pub playcount: Option<i32>,
+    pub ranked_score: Option<i64>,
+    pub total_hits: Option<i64>,
 }
 
 pub struct DbTrackedOsuUserInChannel {
@@ -24,3 +28,43 @@ pub struct DbTrackedOsuUserInChannel {
     pub min_combo_percent: Option<f32>,
     pub max_combo_percent: Option<f32>,
 }
+
+pub struct DbTrackingChannel {
+    pub channel_id: i64,
+    pub embed_style: i16,
+    pub milestones: bool,
+    pub first_place_min_pp: Option<f32>,
+    pub startup_summary: bool,
+}
+
+/// How tracking notifications are displayed in a channel.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum TrackEmbedStyle {
+    #[default]
+    #[option(name = "Full embed", value = "full")]
+    Full = 0,
+    #[option(name = "Compact embed", value = "compact")]
+    Compact = 1,
+    #[option(name = "Text without embed", value = "text")]
+    Text = 2,
+}
+
+impl From<TrackEmbedStyle> for i16 {
+    fn from(style: TrackEmbedStyle) -> Self {
+        style as Self
+    }
+}
+
+impl TryFrom<i16> for TrackEmbedStyle {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Full),
+            1 => Ok(Self::Compact),
+            2 => Ok(Self::Text),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use time::Date;
+
+pub struct DbUserSnapshot {
+    pub snapshot_date: Date,
+    pub pp: f32,
+    pub accuracy: f32,
+    pub global_rank: i32,
+}
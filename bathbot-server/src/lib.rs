@@ -8,6 +8,7 @@ mod standby;
 mod state;
 
 pub use self::{
+    routes::health::{Health, ShardHealth},
     server::Server,
     standby::{AuthenticationStandby, AuthenticationStandbyError},
     state::AppStateBuilder,
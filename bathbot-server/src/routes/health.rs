@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use axum::{Json, extract::State, http::StatusCode};
+use serde::Serialize;
+
+use crate::state::AppState;
+
+pub async fn get_health(State(state): State<Arc<AppState>>) -> Result<Json<Health>, StatusCode> {
+    (state.health)()
+        .map(Json)
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)
+}
+
+/// Current condition of the bot as provided by the bot itself.
+#[derive(Serialize)]
+pub struct Health {
+    pub uptime_secs: i64,
+    pub shards: Vec<ShardHealth>,
+    pub guild_count: u64,
+    /// Amount of tracked scores that await processing
+    pub tracking_queue: u64,
+    /// Unix timestamp of the last response of the osu!api
+    pub last_osu_request: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct ShardHealth {
+    pub id: u32,
+    pub state: &'static str,
+    pub latency_ms: Option<u64>,
+}
@@ -1,4 +1,5 @@
 pub mod auth;
 pub mod guild_count;
+pub mod health;
 pub mod metrics;
 pub mod osudirect;
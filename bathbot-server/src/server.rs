@@ -1,4 +1,9 @@
-use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use axum::{
     Router,
@@ -22,6 +27,7 @@ use crate::{
     routes::{
         auth::{osu::auth_osu, twitch::auth_twitch},
         guild_count::get_guild_count,
+        health::get_health,
         metrics::get_metrics,
         osudirect::redirect_osudirect,
     },
@@ -50,7 +56,7 @@ impl Server {
         Ok((server, standby, shutdown_tx))
     }
 
-    pub async fn run(self, port: u16) {
+    pub async fn run(self, interface: IpAddr, port: u16) {
         let Self {
             state,
             website_path,
@@ -60,7 +66,7 @@ impl Server {
         let state = Arc::new(state);
         let app = Self::bathbot_app(website_path, Arc::clone(&state));
 
-        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+        let addr = SocketAddr::new(interface, port);
 
         let listener = match TcpListener::bind(addr).await {
             Ok(listener) => listener,
@@ -92,6 +98,7 @@ impl Server {
             });
 
         Router::new()
+            .route("/health", get(get_health))
             .route("/metrics", get(get_metrics))
             .route("/guild_count", get(get_guild_count))
             .nest("/auth", Self::auth_app(website_path))
@@ -13,13 +13,14 @@ use hyper_util::{
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-use crate::standby::AuthenticationStandby;
+use crate::{routes::health::Health, standby::AuthenticationStandby};
 
 pub struct AppState {
     pub client: HyperClient<HttpsConnector<HttpConnector>, Empty<Bytes>>,
     pub handlebars: Handlebars<'static>,
     pub prometheus: PrometheusHandle,
     pub metrics_reader: MetricsReader,
+    pub health: fn() -> Option<Health>,
     pub osu_client_id: u64,
     pub osu_client_secret: Box<str>,
     pub twitch_client_id: Box<str>,
@@ -32,6 +33,8 @@ pub struct AppStateBuilder {
     pub website_path: PathBuf,
     pub prometheus: PrometheusHandle,
     pub metrics_reader: MetricsReader,
+    /// Returns `None` if the bot is not yet ready
+    pub health: fn() -> Option<Health>,
     pub osu_client_id: u64,
     pub osu_client_secret: String,
     pub twitch_client_id: String,
@@ -45,6 +48,7 @@ impl AppStateBuilder {
             website_path,
             prometheus,
             metrics_reader,
+            health,
             osu_client_id,
             osu_client_secret,
             twitch_client_id,
@@ -85,6 +89,7 @@ impl AppStateBuilder {
             handlebars,
             prometheus,
             metrics_reader,
+            health,
             osu_client_id,
             osu_client_secret: osu_client_secret.into_boxed_str(),
             twitch_client_id: twitch_client_id.into_boxed_str(),
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, slice};
+use std::{borrow::Cow, num::NonZeroU32, slice};
 
 use twilight_model::{
     channel::message::{Component, embed::Embed},
@@ -34,6 +34,13 @@ impl EmbedOption {
             EmbedOption::None => BuiltEmbedOption::None,
         }
     }
+
+    /// Use the given color for the embed unless it already specifies one.
+    pub fn default_color(&mut self, color: Option<NonZeroU32>) {
+        if let (EmbedOption::Include(embed), Some(color)) = (self, color) {
+            embed.color.get_or_insert(color);
+        }
+    }
 }
 
 impl From<EmbedOption> for Option<Vec<Embed>> {
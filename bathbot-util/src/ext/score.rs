@@ -50,11 +50,29 @@ pub trait ScoreExt {
 
     #[inline]
     fn is_fc(&self, mode: GameMode, max_combo: u32) -> bool {
+        self.is_fc_with_tolerance(mode, max_combo, None)
+    }
+
+    /// Same as [`ScoreExt::is_fc`] but for osu!standard the amount of combo
+    /// that may be missing can be specified.
+    ///
+    /// If `None`, 1 missed sliderend per 500 combo is allowed with a minimum
+    /// of 4.
+    #[inline]
+    fn is_fc_with_tolerance(
+        &self,
+        mode: GameMode,
+        max_combo: u32,
+        combo_tolerance: Option<u32>,
+    ) -> bool {
         match mode {
             _ if self.count_miss() > 0 || self.grade() == Grade::F => false,
             GameMode::Osu if self.count_large_tick_miss() > 0 => false,
-            // Allow 1 missed sliderend per 500 combo
-            GameMode::Osu => self.max_combo() >= (max_combo - (max_combo / 500).max(4)),
+            GameMode::Osu => {
+                let tolerance = combo_tolerance.unwrap_or_else(|| (max_combo / 500).max(4));
+
+                self.max_combo() >= max_combo.saturating_sub(tolerance)
+            }
             GameMode::Taiko | GameMode::Mania => true,
             GameMode::Catch => self.max_combo() == max_combo,
         }
@@ -25,7 +25,7 @@ pub use self::{
     hasher::{IntHash, IntHasher},
     matrix::Matrix,
     metrics::MetricsReader,
-    mods_fmt::ModsFormatter,
+    mods_fmt::{IntermodeFormatter, ModsFormatter},
     msg_origin::MessageOrigin,
     tourney_badges::TourneyBadges,
 };
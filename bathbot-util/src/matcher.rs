@@ -62,17 +62,28 @@ pub fn get_osu_user_id(msg: &str) -> Option<OsuUserId> {
 }
 
 pub fn get_osu_map_id(msg: &str) -> Option<u32> {
+    get_osu_map_id_and_mode(msg).map(|(map_id, _)| map_id)
+}
+
+/// Same as [`get_osu_map_id`] but also returns the mode if the url specifies
+/// one, e.g. through a `#fruits/` fragment or a `?m=` query.
+pub fn get_osu_map_id_and_mode(msg: &str) -> Option<(u32, Option<GameMode>)> {
     if let Some(id) = msg.parse().ok().filter(|_| !msg.starts_with('+')) {
-        return Some(id);
+        return Some((id, None));
     }
 
-    let matcher = if let Some(c) = OSU_URL_MAP_OLD_MATCHER.captures(msg) {
-        c.get(1)
+    let (map_id, mode) = if let Some(c) = OSU_URL_MAP_OLD_MATCHER.captures(msg) {
+        (c.get(1), c.get(2))
     } else {
-        OSU_URL_MAP_NEW_MATCHER.captures(msg).and_then(|c| c.get(2))
+        let c = OSU_URL_MAP_NEW_MATCHER.captures(msg)?;
+
+        (c.get(3), c.get(2))
     };
 
-    matcher.and_then(|c| c.as_str().parse().ok())
+    let map_id = map_id?.as_str().parse().ok()?;
+    let mode = mode.and_then(|mode| parse_url_mode(mode.as_str()));
+
+    Some((map_id, mode))
 }
 
 pub fn get_single_osu_map_id(msg: &str) -> Option<u32> {
@@ -101,20 +112,24 @@ pub fn get_osu_score_id(msg: &str) -> Option<(u64, Option<GameMode>)> {
         .captures(msg)
         .and_then(|c| c.get(2).map(|x| (x, c.get(1))))
         .and_then(|(id, mode)| {
-            let mode = mode.map(|mode| match mode.as_str() {
-                "osu" => GameMode::Osu,
-                "taiko" => GameMode::Taiko,
-                "fruits" => GameMode::Catch,
-                "mania" => GameMode::Mania,
-                _ => unreachable!(),
-            });
-
+            let mode = mode.and_then(|mode| parse_url_mode(mode.as_str()));
             let id = id.as_str().parse().ok()?;
 
             Some((id, mode))
         })
 }
 
+/// Parse the mode of an osu! url, either as name or as number.
+fn parse_url_mode(mode: &str) -> Option<GameMode> {
+    match mode {
+        "osu" | "0" => Some(GameMode::Osu),
+        "taiko" | "1" => Some(GameMode::Taiko),
+        "fruits" | "catch" | "2" => Some(GameMode::Catch),
+        "mania" | "3" => Some(GameMode::Mania),
+        _ => None,
+    }
+}
+
 pub fn get_osu_match_id(msg: &str) -> Option<u32> {
     if let Ok(id) = msg.parse::<u32>() {
         return Some(id);
@@ -128,7 +143,7 @@ pub fn get_osu_match_id(msg: &str) -> Option<u32> {
 
 pub fn get_mods(msg: &str) -> Option<ModSelection> {
     if let Some(captures) = MOD_PLUS_MATCHER.captures(msg) {
-        let mods = GameModsIntermode::try_from_acronyms(captures.get(1)?.as_str())?;
+        let mods = parse_mods(captures.get(1)?.as_str())?;
 
         Some(if msg.ends_with('!') {
             ModSelection::Exact(mods)
@@ -199,12 +214,275 @@ pub fn get_mods(msg: &str) -> Option<ModSelection> {
             Some(ModSelection::Exclude { mods, nomod })
         }
 
-        try_from_acronyms(captures.get(1)?.as_str())
+        try_from_acronyms(&expand_mod_names(captures.get(1)?.as_str()))
     } else {
         None
     }
 }
 
+/// Parse mods given as acronyms and/or full names e.g. `hdhr`,
+/// `doubletime hidden`, or `hard rock, hd`.
+///
+/// Since `NC` implies `DT` and `PF` implies `SD`, the implied mod is dropped if
+/// both are given e.g. `dtnc` results in just `NC`.
+pub fn parse_mods(s: &str) -> Option<GameModsIntermode> {
+    let mut mods = GameModsIntermode::try_from_acronyms(&expand_mod_names(s))?;
+
+    if mods.contains(GameModIntermode::Nightcore) {
+        mods.remove(GameModIntermode::DoubleTime);
+    }
+
+    if mods.contains(GameModIntermode::Perfect) {
+        mods.remove(GameModIntermode::SuddenDeath);
+    }
+
+    Some(mods)
+}
+
+/// Full mod names alongside their acronym. Names are compared
+/// case-insensitively and without whitespace so that `Hard Rock` matches too.
+const MOD_NAMES: [(&str, &str); 25] = [
+    ("nomod", ""),
+    ("nofail", "NF"),
+    ("easy", "EZ"),
+    ("touchdevice", "TD"),
+    ("touchscreen", "TD"),
+    ("hidden", "HD"),
+    ("hardrock", "HR"),
+    ("suddendeath", "SD"),
+    ("perfect", "PF"),
+    ("doubletime", "DT"),
+    ("nightcore", "NC"),
+    ("halftime", "HT"),
+    ("daycore", "DC"),
+    ("flashlight", "FL"),
+    ("relax", "RX"),
+    ("autopilot", "AP"),
+    ("spunout", "SO"),
+    ("fadein", "FI"),
+    ("mirror", "MR"),
+    ("classic", "CL"),
+    ("scorev2", "SV2"),
+    ("v2", "SV2"),
+    ("autoplay", "AT"),
+    ("cinema", "CN"),
+    ("random", "RD"),
+];
+
+fn mod_name_acronym(name: &str) -> Option<&'static str> {
+    MOD_NAMES
+        .iter()
+        .find(|(mod_name, _)| mod_name.eq_ignore_ascii_case(name))
+        .map(|(_, acronym)| *acronym)
+}
+
+/// Replace full mod names with their acronym and remove separators between
+/// mods.
+///
+/// Mod names may be split into two words e.g. `double time`.
+fn expand_mod_names(s: &str) -> Cow<'_, str> {
+    let is_separator = |c: char| c.is_whitespace() || c == ',';
+
+    if !s.contains(is_separator) && mod_name_acronym(s).is_none() {
+        return Cow::Borrowed(s);
+    }
+
+    let words: Vec<_> = s
+        .split(is_separator)
+        .filter(|word| !word.is_empty())
+        .collect();
+    let mut expanded = String::with_capacity(s.len());
+    let mut i = 0;
+
+    while i < words.len() {
+        let joined_acronym = words
+            .get(i + 1)
+            .and_then(|next| mod_name_acronym(&format!("{}{next}", words[i])));
+
+        match joined_acronym {
+            Some(acronym) => {
+                expanded.push_str(acronym);
+                i += 2;
+            }
+            None => {
+                expanded.push_str(mod_name_acronym(words[i]).unwrap_or(words[i]));
+                i += 1;
+            }
+        }
+    }
+
+    // Only `nomod` was specified
+    if expanded.is_empty() {
+        expanded.push_str("NM");
+    }
+
+    Cow::Owned(expanded)
+}
+
+/// Acronyms considered when suggesting corrections for mods that failed to
+/// parse. Restricted to commonly typed mods so that typos resolve to few
+/// candidates.
+const SUGGESTION_ACRONYMS: [&str; 25] = [
+    "NF", "EZ", "TD", "HD", "HR", "SD", "DT", "RX", "HT", "NC", "FL", "SO", "AP", "PF", "MR", "CL",
+    "1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K",
+];
+
+/// Suggestion acronyms that are rarely typed. Candidates introducing them are
+/// deemed slightly less likely.
+const UNCOMMON_ACRONYMS: [&str; 5] = ["TD", "RX", "AP", "MR", "CL"];
+
+/// Characters that may be inserted or substituted when generating candidates.
+const SUGGESTION_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Keyboard rows used to determine whether two keys are next to each other.
+const KEYBOARD_ROWS: [&[u8]; 4] = [b"1234567890", b"QWERTYUIOP", b"ASDFGHJKL", b"ZXCVBNM"];
+
+/// Suggest a correction for mods that failed to parse.
+///
+/// Considers all inputs within an edit distance of one i.e. a single
+/// insertion, deletion, substitution, or swap of adjacent characters, and
+/// keeps those that consist of distinct known acronyms. Doubled keys are deemed
+/// most likely, followed by swaps and hitting a neighbouring key. Each acronym
+/// pair that differs from the input is additionally weighted by how commonly
+/// its acronym is used.
+///
+/// Returns `None` if there is no unique most likely candidate. A leading `+`
+/// or `-` and a trailing `!` are kept as is.
+pub fn suggest_mods(msg: &str) -> Option<String> {
+    let (prefix, rest) = match msg.as_bytes().first() {
+        Some(b'+' | b'-') => msg.split_at(1),
+        _ => ("", msg),
+    };
+
+    let (body, suffix) = match rest.strip_suffix('!') {
+        Some(body) => (body, "!"),
+        None => (rest, ""),
+    };
+
+    if body.is_empty() || !body.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let body = body.to_ascii_uppercase().into_bytes();
+
+    // Candidates alongside the cost of their edit
+    let mut candidates: Vec<(Vec<u8>, u8)> = Vec::new();
+
+    let mut push = |candidate: Vec<u8>, edit_cost: u8| {
+        if !is_acronym_sequence(&candidate) {
+            return;
+        }
+
+        let uncommon_pairs = candidate
+            .chunks_exact(2)
+            .enumerate()
+            .filter(|(i, pair)| body.get(2 * i..2 * i + 2) != Some(*pair))
+            .filter(|(_, pair)| {
+                UNCOMMON_ACRONYMS
+                    .iter()
+                    .any(|acronym| acronym.as_bytes() == *pair)
+            })
+            .count();
+
+        let cost = edit_cost + uncommon_pairs as u8;
+
+        match candidates.iter_mut().find(|(prev, _)| *prev == candidate) {
+            Some((_, prev_cost)) => *prev_cost = (*prev_cost).min(cost),
+            None => candidates.push((candidate, cost)),
+        }
+    };
+
+    for i in 0..body.len() {
+        let is_doubled = (i > 0 && body[i - 1] == body[i])
+            || body.get(i + 1).is_some_and(|&next| next == body[i]);
+
+        let mut deleted = body.clone();
+        deleted.remove(i);
+        push(deleted, if is_doubled { 2 } else { 4 });
+
+        for &c in SUGGESTION_CHARS.iter().filter(|&&c| c != body[i]) {
+            let mut substituted = body.clone();
+            substituted[i] = c;
+
+            // A doubled key likely replaced the intended one
+            let cost = if is_doubled {
+                2
+            } else if are_neighbours(c, body[i]) {
+                3
+            } else {
+                4
+            };
+
+            push(substituted, cost);
+        }
+
+        if i + 1 < body.len() && body[i] != body[i + 1] {
+            let mut swapped = body.clone();
+            swapped.swap(i, i + 1);
+            push(swapped, 3);
+        }
+    }
+
+    for i in 0..=body.len() {
+        for &c in SUGGESTION_CHARS {
+            let mut inserted = body.clone();
+            inserted.insert(i, c);
+            push(inserted, 4);
+        }
+    }
+
+    let min_cost = candidates.iter().map(|(_, cost)| *cost).min()?;
+    let mut cheapest = candidates.into_iter().filter(|(_, cost)| *cost == min_cost);
+
+    let (candidate, _) = cheapest.next()?;
+
+    if cheapest.next().is_some() {
+        return None;
+    }
+
+    let candidate = String::from_utf8_lossy(&candidate).to_ascii_lowercase();
+
+    Some(format!("{prefix}{candidate}{suffix}"))
+}
+
+/// Whether the given uppercased bytes are a non-empty sequence of distinct
+/// acronyms in [`SUGGESTION_ACRONYMS`].
+fn is_acronym_sequence(bytes: &[u8]) -> bool {
+    if bytes.is_empty() || bytes.len() % 2 != 0 {
+        return false;
+    }
+
+    bytes.chunks_exact(2).enumerate().all(|(i, chunk)| {
+        let is_known = SUGGESTION_ACRONYMS
+            .iter()
+            .any(|acronym| acronym.as_bytes() == chunk);
+
+        is_known && !bytes[..2 * i].chunks_exact(2).any(|prev| prev == chunk)
+    })
+}
+
+/// Whether the two uppercased keys are next to each other on a QWERTY keyboard.
+fn are_neighbours(a: u8, b: u8) -> bool {
+    let position = |key: u8| {
+        KEYBOARD_ROWS.iter().enumerate().find_map(|(row, keys)| {
+            keys.iter()
+                .position(|&k| k == key)
+                .map(|col| (row as isize, col as isize))
+        })
+    };
+
+    match (position(a), position(b)) {
+        // Rows are staggered so the key diagonally down-left is a neighbour too
+        (Some((row_a, col_a)), Some((row_b, col_b))) => match row_b - row_a {
+            0 => (col_a - col_b).abs() == 1,
+            1 => col_b == col_a || col_b == col_a - 1,
+            -1 => col_a == col_b || col_a == col_b - 1,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 #[allow(dead_code)]
 pub fn is_hit_results(msg: &str) -> bool {
     HIT_RESULTS_MATCHER.is_match(msg)
@@ -229,14 +507,14 @@ define_regex! {
 
     OSU_URL_USER_MATCHER: r"^https://osu\.ppy\.sh/u(?:sers)?/(?:(\d+)|(\w+))$";
 
-    OSU_URL_MAP_NEW_MATCHER: r"https://osu\.ppy\.sh/beatmapsets/(\d+)(?:(?:/?#(?:osu|mania|taiko|fruits)|<#\d+>)/(\d+))?";
-    OSU_URL_MAP_OLD_MATCHER: r"https://osu\.ppy\.sh/b(?:eatmaps)?/(\d+)";
-    OSU_URL_MAPSET_OLD_MATCHER: r"https://osu\.ppy\.sh/s/(\d+)";
+    OSU_URL_MAP_NEW_MATCHER: r"(?:https?://)?(?:osu|lazer)\.ppy\.sh/beatmapsets/(\d+)(?:(?:/?#(osu|mania|taiko|fruits)|<#\d+>)/(\d+))?";
+    OSU_URL_MAP_OLD_MATCHER: r"(?:https?://)?(?:osu|lazer)\.ppy\.sh/b(?:eatmaps)?/(\d+)(?:[?&]m(?:ode)?=(osu|mania|taiko|fruits|[0-3]))?";
+    OSU_URL_MAPSET_OLD_MATCHER: r"(?:https?://)?(?:osu|lazer)\.ppy\.sh/s/(\d+)";
 
     OSU_URL_MATCH_MATCHER: r"https://osu\.ppy\.sh/(?:community/matches|mp)/(\d+)";
 
-    MOD_PLUS_MATCHER: r"^\+(\w+)!?$";
-    MOD_MINUS_MATCHER: r"^-(\w+)!$";
+    MOD_PLUS_MATCHER: r"^\+(\w[\w ,]*?)!?$";
+    MOD_MINUS_MATCHER: r"^-(\w[\w ,]*)!$";
 
     HIT_RESULTS_MATCHER: r".*\{(\d+/){2,}\d+}.*";
 
@@ -248,5 +526,205 @@ define_regex! {
 
     APPROVED_SKIN_SITE: r"^https://(?:(?:www\.)?(?:drive\.google\.com|dropbox\.com|mega\.nz|mediafire\.com|(?:gist\.)?github\.com)/.*$|(?:skins\.osuck\.net/skins|osu\.ppy\.sh/community/forums/topics)/\d+.*|link.issou.best/skin/\d+$)";
 
-    pub QUERY_SYNTAX_REGEX: r#"\b(?P<key>\w+)(?P<op>(:|=|(>|<)(:|=)?))(?P<value>(".*")|(\S*))"#;
+    pub QUERY_SYNTAX_REGEX: r#"(?P<neg>-)?\b(?P<key>\w+)(?P<op>(:|=|(>|<)(:|=)?))(?P<value>("[^"]*"?)|(\S*))"#;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_id_plain() {
+        assert_eq!(get_osu_map_id("123"), Some(123));
+        assert_eq!(get_osu_map_id("+123"), None);
+    }
+
+    #[test]
+    fn map_id_old_short() {
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/b/123"),
+            Some((123, None))
+        );
+        assert_eq!(
+            get_osu_map_id_and_mode("osu.ppy.sh/b/123"),
+            Some((123, None))
+        );
+    }
+
+    #[test]
+    fn map_id_beatmaps() {
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/beatmaps/123"),
+            Some((123, None))
+        );
+        assert_eq!(
+            get_osu_map_id_and_mode("http://osu.ppy.sh/beatmaps/123"),
+            Some((123, None))
+        );
+    }
+
+    #[test]
+    fn map_id_query_mode() {
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/b/123?m=2"),
+            Some((123, Some(GameMode::Catch)))
+        );
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/beatmaps/123?mode=mania"),
+            Some((123, Some(GameMode::Mania)))
+        );
+    }
+
+    #[test]
+    fn map_id_lazer() {
+        assert_eq!(
+            get_osu_map_id_and_mode("https://lazer.ppy.sh/beatmaps/123"),
+            Some((123, None))
+        );
+        assert_eq!(
+            get_osu_map_id_and_mode("https://lazer.ppy.sh/beatmapsets/456#taiko/123"),
+            Some((123, Some(GameMode::Taiko)))
+        );
+    }
+
+    #[test]
+    fn map_id_fragment_mode() {
+        let cases = [
+            ("osu", GameMode::Osu),
+            ("taiko", GameMode::Taiko),
+            ("fruits", GameMode::Catch),
+            ("mania", GameMode::Mania),
+        ];
+
+        for (fragment, mode) in cases {
+            let url = format!("https://osu.ppy.sh/beatmapsets/456#{fragment}/123");
+
+            assert_eq!(get_osu_map_id_and_mode(&url), Some((123, Some(mode))));
+        }
+
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/beatmapsets/456/#fruits/123"),
+            Some((123, Some(GameMode::Catch)))
+        );
+    }
+
+    #[test]
+    fn map_id_channel_mention() {
+        assert_eq!(
+            get_osu_map_id_and_mode("https://osu.ppy.sh/beatmapsets/456<#123456>/123"),
+            Some((123, None))
+        );
+    }
+
+    #[test]
+    fn map_id_mapset_only() {
+        assert_eq!(get_osu_map_id("https://osu.ppy.sh/beatmapsets/456"), None);
+    }
+
+    #[test]
+    fn mapset_id() {
+        assert_eq!(get_osu_mapset_id("https://osu.ppy.sh/s/456"), Some(456));
+        assert_eq!(
+            get_osu_mapset_id("https://osu.ppy.sh/beatmapsets/456"),
+            Some(456)
+        );
+        assert_eq!(
+            get_osu_mapset_id("https://osu.ppy.sh/beatmapsets/456#fruits/123"),
+            Some(456)
+        );
+        assert_eq!(
+            get_osu_mapset_id("https://lazer.ppy.sh/beatmapsets/456"),
+            Some(456)
+        );
+        assert_eq!(get_osu_mapset_id("osu.ppy.sh/s/456"), Some(456));
+    }
+
+    #[test]
+    fn single_map_id() {
+        assert_eq!(
+            get_single_osu_map_id("see https://osu.ppy.sh/b/123 for details"),
+            Some(123)
+        );
+        assert_eq!(
+            get_single_osu_map_id("https://osu.ppy.sh/b/123 and https://osu.ppy.sh/b/456"),
+            None
+        );
+    }
+
+    fn mods(acronyms: &str) -> GameModsIntermode {
+        GameModsIntermode::try_from_acronyms(acronyms).unwrap()
+    }
+
+    #[test]
+    fn parse_mod_names() {
+        assert_eq!(parse_mods("doubletime"), Some(mods("DT")));
+        assert_eq!(parse_mods("DoubleTime Hidden"), Some(mods("HDDT")));
+        assert_eq!(parse_mods("hidden doubletime"), Some(mods("HDDT")));
+        assert_eq!(parse_mods("hard rock, hd"), Some(mods("HDHR")));
+        assert_eq!(parse_mods("rx"), Some(mods("RX")));
+        assert_eq!(parse_mods("relax hidden"), Some(mods("HDRX")));
+        assert_eq!(parse_mods("nomod"), Some(GameModsIntermode::new()));
+        assert_eq!(parse_mods("doubletme"), None);
+    }
+
+    #[test]
+    fn parse_implied_mods() {
+        assert_eq!(parse_mods("dtnc"), Some(mods("NC")));
+        assert_eq!(parse_mods("hdncdt"), Some(mods("HDNC")));
+        assert_eq!(parse_mods("sdpf"), Some(mods("PF")));
+        assert_eq!(parse_mods("dt"), Some(mods("DT")));
+    }
+
+    #[test]
+    fn get_mods_names() {
+        assert_eq!(
+            get_mods("+doubletime hidden"),
+            Some(ModSelection::Include(mods("HDDT")))
+        );
+        assert_eq!(
+            get_mods("+Hard Rock!"),
+            Some(ModSelection::Exact(mods("HR")))
+        );
+        assert_eq!(get_mods("+dtnc!"), Some(ModSelection::Exact(mods("NC"))));
+        assert_eq!(
+            get_mods("-hidden flashlight!"),
+            Some(ModSelection::Exclude {
+                mods: mods("HDFL"),
+                nomod: false
+            })
+        );
+        assert_eq!(get_mods("+hdhr"), Some(ModSelection::Include(mods("HDHR"))));
+        assert_eq!(get_mods("+"), None);
+        assert_eq!(get_mods("+ hd"), None);
+    }
+
+    #[test]
+    fn suggest_mods_typos() {
+        // Note that `+hdgr` is no typo since `GR` is the acronym of Grow
+        assert!(get_mods("+hdgr").is_some());
+        assert_eq!(suggest_mods("+hdyr").as_deref(), Some("+hdhr"));
+        assert_eq!(suggest_mods("+hdrh").as_deref(), Some("+hdhr"));
+        assert_eq!(suggest_mods("+hhdr").as_deref(), Some("+hdhr"));
+        assert_eq!(suggest_mods("+hdhrr").as_deref(), Some("+hdhr"));
+        assert_eq!(suggest_mods("+hsdt").as_deref(), Some("+hddt"));
+        assert_eq!(suggest_mods("+hddr").as_deref(), Some("+hdhr"));
+        assert_eq!(suggest_mods("hdhe").as_deref(), Some("hdhr"));
+    }
+
+    #[test]
+    fn suggest_mods_keeps_syntax() {
+        assert_eq!(suggest_mods("+hdyr!").as_deref(), Some("+hdhr!"));
+        assert_eq!(suggest_mods("-hdhe!").as_deref(), Some("-hdhr!"));
+        assert_eq!(suggest_mods("-nff!").as_deref(), Some("-nf!"));
+    }
+
+    #[test]
+    fn suggest_mods_none() {
+        assert_eq!(suggest_mods("xyz"), None);
+        assert_eq!(suggest_mods("+"), None);
+        assert_eq!(suggest_mods("+hd-hr"), None);
+
+        // Ambiguous between `HTHR` and `HDHR`
+        assert_eq!(suggest_mods("+hfhr"), None);
+    }
 }
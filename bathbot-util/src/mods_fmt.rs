@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use rosu_v2::model::mods::{
-    GameMod, GameMods,
+    GameMod, GameModIntermode, GameMods, GameModsIntermode,
     generated_mods::{
         DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DoubleTimeCatch, DoubleTimeMania,
         DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu, HalfTimeTaiko,
@@ -25,7 +25,10 @@ impl Display for ModsFormatter<'_> {
             return f.write_str("NM");
         }
 
-        for gamemod in self.mods.iter() {
+        let mut mods: Vec<_> = self.mods.iter().collect();
+        mods.sort_by_key(|gamemod| canonical_key(gamemod.intermode()));
+
+        for gamemod in mods {
             f.write_str(gamemod.acronym().as_str())?;
 
             match gamemod {
@@ -62,3 +65,95 @@ impl Display for ModsFormatter<'_> {
         Ok(())
     }
 }
+
+/// Mods that are displayed first and in this order. All other mods follow
+/// in their usual order.
+const CANONICAL_ORDER: [GameModIntermode; 11] = [
+    GameModIntermode::Easy,
+    GameModIntermode::NoFail,
+    GameModIntermode::HalfTime,
+    GameModIntermode::Daycore,
+    GameModIntermode::Hidden,
+    GameModIntermode::DoubleTime,
+    GameModIntermode::Nightcore,
+    GameModIntermode::HardRock,
+    GameModIntermode::SuddenDeath,
+    GameModIntermode::Perfect,
+    GameModIntermode::Flashlight,
+];
+
+/// Sort key so that mods are displayed in the same order no matter how they
+/// were specified, e.g. both `dthd` and `HDDT` become `HDDT`.
+fn canonical_key(gamemod: GameModIntermode) -> (usize, GameModIntermode) {
+    let idx = CANONICAL_ORDER
+        .iter()
+        .position(|canonical| *canonical == gamemod)
+        .unwrap_or(CANONICAL_ORDER.len());
+
+    (idx, gamemod)
+}
+
+/// Displays [`GameModsIntermode`] in their canonical order.
+pub struct IntermodeFormatter<'a> {
+    mods: &'a GameModsIntermode,
+}
+
+impl<'a> IntermodeFormatter<'a> {
+    pub fn new(mods: &'a GameModsIntermode) -> Self {
+        Self { mods }
+    }
+}
+
+impl Display for IntermodeFormatter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.mods.is_empty() {
+            return f.write_str("NM");
+        }
+
+        let mut mods: Vec<_> = self.mods.iter().collect();
+        mods.sort_by_key(|gamemod| canonical_key(*gamemod));
+
+        for gamemod in mods {
+            f.write_str(gamemod.acronym().as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(acronyms: &str) -> String {
+        let mods = GameModsIntermode::try_from_acronyms(acronyms).unwrap();
+
+        IntermodeFormatter::new(&mods).to_string()
+    }
+
+    #[test]
+    fn order_independent_of_input() {
+        assert_eq!(canonical("dthd"), "HDDT");
+        assert_eq!(canonical("HDDT"), "HDDT");
+        assert_eq!(canonical("hrdthd"), "HDDTHR");
+        assert_eq!(canonical("FLHRHDNC"), "HDNCHRFL");
+        assert_eq!(canonical("hdez"), "EZHD");
+        assert_eq!(canonical("sdhdnf"), "NFHDSD");
+    }
+
+    #[test]
+    fn order_stable() {
+        for acronyms in ["hddthr", "hrhddt", "dthrhd", "DTHDHR"] {
+            assert_eq!(canonical(acronyms), "HDDTHR");
+        }
+    }
+
+    #[test]
+    fn unlisted_mods_last() {
+        assert_eq!(canonical("tdhd"), "HDTD");
+        assert_eq!(
+            IntermodeFormatter::new(&GameModsIntermode::new()).to_string(),
+            "NM"
+        );
+    }
+}
@@ -3,6 +3,28 @@ use std::{
     ops::{AddAssign, Div},
 };
 
+tokio::task_local! {
+    /// Thousands and decimal separators that [`WithComma`] uses by default.
+    static SEPARATORS: (char, char);
+}
+
+const DEFAULT_SEPARATORS: (char, char) = (',', '.');
+
+/// Run `fut` while [`WithComma`] uses the given separators by default.
+pub async fn with_separators<F: Future>(thousands: char, decimal: char, fut: F) -> F::Output {
+    SEPARATORS.scope((thousands, decimal), fut).await
+}
+
+/// Wrap `fut` so that it keeps the separators of the current scope.
+///
+/// Task-local separators are not inherited by spawned tasks so futures must
+/// be wrapped before being spawned.
+pub fn keep_separators<F: Future>(fut: F) -> impl Future<Output = F::Output> {
+    let separators = SEPARATORS.try_with(|s| *s).unwrap_or(DEFAULT_SEPARATORS);
+
+    SEPARATORS.scope(separators, fut)
+}
+
 /// Round with two decimal positions
 pub fn round(n: f32) -> f32 {
     (100.0 * n).round() / 100.0
@@ -10,11 +32,29 @@ pub fn round(n: f32) -> f32 {
 
 pub struct WithComma<N> {
     num: N,
+    thousands: char,
+    decimal: char,
 }
 
 impl<N> WithComma<N> {
+    /// Uses the separators of the surrounding [`with_separators`] scope or
+    /// `,` for thousands and `.` for decimals.
     pub fn new(num: N) -> Self {
-        Self { num }
+        let (thousands, decimal) = SEPARATORS.try_with(|s| *s).unwrap_or(DEFAULT_SEPARATORS);
+
+        Self {
+            num,
+            thousands,
+            decimal,
+        }
+    }
+
+    /// Use the given separators instead of the default ones.
+    pub fn separators(mut self, thousands: char, decimal: char) -> Self {
+        self.thousands = thousands;
+        self.decimal = decimal;
+
+        self
     }
 }
 
@@ -45,7 +85,7 @@ macro_rules! impl_with_comma {
 
                     for _ in 0..triples - 1 {
                         rev /= 1000;
-                        write!(f, ",{:0>3}", rev % 1000)?;
+                        write!(f, "{}{:0>3}", self.thousands, rev % 1000)?;
                     }
 
                     let dec = n.fract();
@@ -53,12 +93,12 @@ macro_rules! impl_with_comma {
                     if let Some(precision) = f.precision() {
                         let dec = (dec * 10_u32.pow(precision as u32) as $ty) as u32;
 
-                        write!(f, ".{dec:0<precision$}")?;
+                        write!(f, "{}{dec:0<precision$}", self.decimal)?;
                     } else {
                         let dec = (100.0 * dec).round() as u32;
 
                         if dec > 0 {
-                            f.write_str(".")?;
+                            write!(f, "{}", self.decimal)?;
 
                             if dec < 10 {
                                 write!(f, "0{dec}")?;
@@ -82,7 +122,9 @@ macro_rules! impl_with_comma {
                     $(
                         // Preventing potential overflows
                         if self.num.abs() > $cutoff {
-                            return WithComma::new(self.num as $backup).fmt(f);
+                            return WithComma::new(self.num as $backup)
+                                .separators(self.thousands, self.decimal)
+                                .fmt(f);
                         }
                     )?
 
@@ -107,7 +149,7 @@ macro_rules! impl_with_comma {
 
                     for _ in 0..triples - 1 {
                         rev /= 1000;
-                        write!(f, ",{:0>3}", rev % 1000)?;
+                        write!(f, "{}{:0>3}", self.thousands, rev % 1000)?;
                     }
 
                     Ok(())
@@ -122,7 +164,9 @@ macro_rules! impl_with_comma {
                     $(
                         // Preventing potential overflows
                         if self.num > $cutoff {
-                            return WithComma::new(self.num as $backup).fmt(f);
+                            return WithComma::new(self.num as $backup)
+                                .separators(self.thousands, self.decimal)
+                                .fmt(f);
                         }
                     )?
 
@@ -140,7 +184,7 @@ macro_rules! impl_with_comma {
 
                     for _ in 0..triples - 1 {
                         rev /= 1000;
-                        write!(f, ",{:0>3}", rev % 1000)?;
+                        write!(f, "{}{:0>3}", self.thousands, rev % 1000)?;
                     }
 
                     Ok(())
@@ -231,6 +275,10 @@ impl<N: Number> MinMaxAvg<N> {
     pub fn avg(&self) -> N {
         self.sum / self.len
     }
+
+    pub fn sum(&self) -> N {
+        self.sum
+    }
 }
 
 pub trait AsFloat {
@@ -337,6 +385,29 @@ mod tests {
         assert_eq!(format!("{:.3}", WithComma::new(12345.0_f64)), "12,345.000");
     }
 
+    #[test]
+    fn test_with_comma_separators() {
+        assert_eq!(
+            WithComma::new(31_415_926_u32)
+                .separators('.', ',')
+                .to_string(),
+            "31.415.926"
+        );
+
+        assert_eq!(
+            format!("{}", WithComma::new(12345.6789_f64).separators(' ', ',')),
+            "12 345,68"
+        );
+
+        assert_eq!(
+            format!("{:.1}", WithComma::new(-1234.5_f32).separators('.', ',')),
+            "-1.234,5"
+        );
+
+        let scoped = SEPARATORS.sync_scope(('.', ','), || WithComma::new(1234.5_f32).to_string());
+        assert_eq!(scoped, "1.234,5");
+    }
+
     #[test]
     fn test_abbreviated_score() {
         assert_eq!(
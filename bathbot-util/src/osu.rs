@@ -186,7 +186,9 @@ pub enum ModsResult {
 }
 
 impl ModSelection {
+    const DC: GameModIntermode = GameModIntermode::Daycore;
     const DT: GameModIntermode = GameModIntermode::DoubleTime;
+    const HT: GameModIntermode = GameModIntermode::HalfTime;
     const NC: GameModIntermode = GameModIntermode::Nightcore;
     const PF: GameModIntermode = GameModIntermode::Perfect;
     const SD: GameModIntermode = GameModIntermode::SuddenDeath;
@@ -196,7 +198,7 @@ impl ModSelection {
             return ModsResult::None;
         };
 
-        if let Some(mods) = GameModsIntermode::try_from_acronyms(mods) {
+        if let Some(mods) = matcher::parse_mods(mods) {
             return ModsResult::Mods(ModSelection::Exact(mods));
         };
 
@@ -217,6 +219,17 @@ impl ModSelection {
         }
     }
 
+    /// Same as [`ModSelection::filter_score`] but exact mods are compared
+    /// through [`ModSelection::filter_exact_equivalent`].
+    pub fn filter_score_equivalent(&self, score: &Score) -> bool {
+        match self {
+            ModSelection::Exact(mods) if !mods.is_empty() => {
+                Self::filter_exact_equivalent(mods, &score.mods)
+            }
+            _ => self.filter_score(score),
+        }
+    }
+
     /// Remove all scores whose mods do not coincide with this [`ModSelection`]
     pub fn filter_scores(&self, scores: &mut Vec<Score>) {
         match self {
@@ -270,6 +283,34 @@ impl ModSelection {
         mods.iter().map(GameMod::intermode).eq(selection.iter())
     }
 
+    /// Same as [`ModSelection::filter_exact`] but mods of the same
+    /// equivalence class are considered equal regardless of order.
+    ///
+    /// The equivalence classes are:
+    /// - `DT` and `NC`
+    /// - `HT` and `DC`
+    /// - `SD` and `PF`
+    pub fn filter_exact_equivalent(selection: &GameModsIntermode, mods: &GameMods) -> bool {
+        let selection: GameModsIntermode = selection.iter().map(Self::equivalent).collect();
+
+        let mods: GameModsIntermode = mods
+            .iter()
+            .map(|gamemod| Self::equivalent(gamemod.intermode()))
+            .collect();
+
+        selection == mods
+    }
+
+    /// Maps a mod onto the representative of its equivalence class.
+    fn equivalent(gamemod: GameModIntermode) -> GameModIntermode {
+        match gamemod {
+            Self::NC => Self::DT,
+            Self::DC => Self::HT,
+            Self::PF => Self::SD,
+            _ => gamemod,
+        }
+    }
+
     /// Make sure included or exact mods don't exclude each other e.g. EZHR
     pub fn validate(self, mode: GameMode) -> Result<(), &'static str> {
         let mods = match self {
@@ -453,6 +494,16 @@ pub enum MapIdType {
     Set(u32),
 }
 
+impl MapIdType {
+    /// Determines the mode in which a map argument should be processed.
+    ///
+    /// An explicitly specified mode takes precedence over the mode of a map
+    /// url such as `osu.ppy.sh/beatmapsets/1#mania/2`.
+    pub fn resolve_mode(arg: &str, explicit: Option<GameMode>) -> Option<GameMode> {
+        explicit.or_else(|| matcher::get_osu_map_id_and_mode(arg)?.1)
+    }
+}
+
 // Credits to https://github.com/RoanH/osu-BonusPP/blob/master/BonusPP/src/me/roan/bonuspp/BonusPP.java#L202
 pub struct BonusPP {
     pp: f32,
@@ -626,6 +677,71 @@ impl GradeGameModsData {
     }
 }
 
+/// The first mod for which pp are not defined i.e. `RX` or `AP`.
+pub fn pp_undefined_mod(mods: &GameModsIntermode) -> Option<GameModIntermode> {
+    mods.iter().find(|gamemod| {
+        matches!(
+            gamemod,
+            GameModIntermode::Relax | GameModIntermode::Autopilot
+        )
+    })
+}
+
+/// Mods of a score that prevent it from appearing on the global leaderboards.
+///
+/// Follows the mods that are ranked on lazer. Rate-changing mods only count
+/// as ranked with their default speed.
+pub fn leaderboard_ineligible_mods(mods: &GameMods, mode: GameMode) -> GameModsIntermode {
+    let custom_rate = mods.clock_rate().is_some_and(|rate| {
+        [0.75, 1.0, 1.5]
+            .iter()
+            .all(|default| (rate - default).abs() > f64::EPSILON)
+    });
+
+    mods.iter()
+        .map(GameMod::intermode)
+        .filter(|&gamemod| {
+            let rate_mod = matches!(
+                gamemod,
+                GameModIntermode::DoubleTime
+                    | GameModIntermode::Nightcore
+                    | GameModIntermode::HalfTime
+                    | GameModIntermode::Daycore
+            );
+
+            !is_leaderboard_eligible(gamemod, mode) || (custom_rate && rate_mod)
+        })
+        .collect()
+}
+
+fn is_leaderboard_eligible(gamemod: GameModIntermode, mode: GameMode) -> bool {
+    match gamemod {
+        GameModIntermode::Easy
+        | GameModIntermode::NoFail
+        | GameModIntermode::HalfTime
+        | GameModIntermode::Daycore
+        | GameModIntermode::HardRock
+        | GameModIntermode::SuddenDeath
+        | GameModIntermode::Perfect
+        | GameModIntermode::DoubleTime
+        | GameModIntermode::Nightcore
+        | GameModIntermode::Hidden
+        | GameModIntermode::Flashlight
+        | GameModIntermode::Classic
+        | GameModIntermode::Mirror
+        | GameModIntermode::Muted => true,
+        GameModIntermode::TouchDevice | GameModIntermode::SpunOut => mode == GameMode::Osu,
+        GameModIntermode::FadeIn
+        | GameModIntermode::FourKeys
+        | GameModIntermode::FiveKeys
+        | GameModIntermode::SixKeys
+        | GameModIntermode::SevenKeys
+        | GameModIntermode::EightKeys
+        | GameModIntermode::NineKeys => mode == GameMode::Mania,
+        _ => false,
+    }
+}
+
 pub fn calculate_grade(
     mode: GameMode,
     mods: &impl GradeGameMods,
@@ -888,8 +1004,60 @@ fn mania_grade_legacy(
 
 #[cfg(test)]
 mod tests {
+    use rosu_v2::prelude::DoubleTimeOsu;
+
     use super::*;
 
+    #[test]
+    fn leaderboard_eligibility() {
+        let hddt: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::DoubleTimeOsu(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(leaderboard_ineligible_mods(&hddt, GameMode::Osu).is_empty());
+
+        let td: GameMods = [GameMod::TouchDeviceOsu(Default::default())]
+            .into_iter()
+            .collect();
+
+        assert!(leaderboard_ineligible_mods(&td, GameMode::Osu).is_empty());
+
+        let relax: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::RelaxOsu(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        let ineligible: GameModsIntermode = [GameModIntermode::Relax].into_iter().collect();
+        assert_eq!(
+            leaderboard_ineligible_mods(&relax, GameMode::Osu),
+            ineligible
+        );
+
+        let fi: GameMods = [GameMod::FadeInMania(Default::default())]
+            .into_iter()
+            .collect();
+
+        assert!(leaderboard_ineligible_mods(&fi, GameMode::Mania).is_empty());
+
+        let custom_rate: GameMods = [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.2),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        let ineligible: GameModsIntermode = [GameModIntermode::DoubleTime].into_iter().collect();
+        assert_eq!(
+            leaderboard_ineligible_mods(&custom_rate, GameMode::Osu),
+            ineligible
+        );
+    }
+
     #[test]
     fn mod_selection_filter_exclude() {
         let hdnc: GameMods = [
@@ -913,4 +1081,65 @@ mod tests {
         assert!(!ModSelection::filter_exclude(&selection, false, &hdnc)); // -hddt!
         assert!(!ModSelection::filter_exclude(&selection, true, &hdnc)); // -hddtnm!
     }
+
+    #[test]
+    fn mod_selection_filter_exact_equivalent() {
+        let hdnc: GameMods = [
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::NightcoreOsu(Default::default()),
+        ]
+        .into_iter()
+        .collect();
+
+        let hddt: GameModsIntermode = [GameModIntermode::Hidden, GameModIntermode::DoubleTime]
+            .into_iter()
+            .collect();
+        assert!(!ModSelection::filter_exact(&hddt, &hdnc)); // +hddt!
+        assert!(ModSelection::filter_exact_equivalent(&hddt, &hdnc));
+
+        let hdnc_selection: GameModsIntermode =
+            [GameModIntermode::Nightcore, GameModIntermode::Hidden]
+                .into_iter()
+                .collect();
+        assert!(ModSelection::filter_exact_equivalent(
+            &hdnc_selection,
+            &hdnc
+        )); // +nchd!
+
+        let hd: GameModsIntermode = [GameModIntermode::Hidden].into_iter().collect();
+        assert!(!ModSelection::filter_exact_equivalent(&hd, &hdnc)); // +hd!
+
+        let pf: GameMods = [GameMod::PerfectOsu(Default::default())]
+            .into_iter()
+            .collect();
+        let sd: GameModsIntermode = [GameModIntermode::SuddenDeath].into_iter().collect();
+        assert!(ModSelection::filter_exact_equivalent(&sd, &pf)); // +sd!
+    }
+
+    #[test]
+    fn map_mode_from_url_fragment() {
+        let url = "https://osu.ppy.sh/beatmapsets/123#mania/456";
+        assert_eq!(MapIdType::resolve_mode(url, None), Some(GameMode::Mania));
+
+        let url = "https://osu.ppy.sh/beatmapsets/123#osu/456";
+        assert_eq!(MapIdType::resolve_mode(url, None), Some(GameMode::Osu));
+
+        let url = "https://osu.ppy.sh/beatmaps/456";
+        assert_eq!(MapIdType::resolve_mode(url, None), None);
+        assert_eq!(MapIdType::resolve_mode("456", None), None);
+    }
+
+    #[test]
+    fn map_mode_explicit_overrides_url() {
+        let url = "https://osu.ppy.sh/beatmapsets/123#mania/456";
+        let mode = MapIdType::resolve_mode(url, Some(GameMode::Taiko));
+        assert_eq!(mode, Some(GameMode::Taiko));
+
+        let url = "https://osu.ppy.sh/b/456?m=2";
+        let mode = MapIdType::resolve_mode(url, Some(GameMode::Osu));
+        assert_eq!(mode, Some(GameMode::Osu));
+
+        let mode = MapIdType::resolve_mode("456", Some(GameMode::Catch));
+        assert_eq!(mode, Some(GameMode::Catch));
+    }
 }
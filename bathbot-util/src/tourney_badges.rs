@@ -1,57 +1,72 @@
 pub struct TourneyBadges;
 
+/// Badges whose lowercase description starts with any of these are not
+/// considered tourney badges.
+const EXCLUDED_PREFIXES: &[&str] = &[
+    "art contest",
+    "aspire",
+    "assessment",
+    "beatmap",
+    "community choice",
+    "contrib",
+    "centurion mapper",
+    "elite",
+    "exemplary",
+    "global",
+    "longstanding",
+    "moderation",
+    "monthly",
+    "nominat",
+    "outstanding",
+    "pending",
+    "spotlight",
+];
+
+/// Badges whose lowercase description contains any of these are not
+/// considered tourney badges.
+const EXCLUDED_KEYWORDS: &[&str] = &[" art contest", "fanart contest", "playlist", "pickem"];
+
 impl TourneyBadges {
     pub fn count<I, S>(badges: I) -> usize
     where
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
-        fn filter_badge(badge: &str, buf: &mut String) -> bool {
-            buf.clear();
-
-            let iter = badge.bytes().map(|byte| byte.to_ascii_lowercase());
-
-            // SAFETY: from `str::make_ascii_lowercase`:
-            // "changing ASCII letters only does not invalidate UTF-8."
-            unsafe { buf.as_mut_vec() }.extend(iter);
-
-            TourneyBadges::is_tourney_badge(&*buf)
-        }
-
         let mut lowercase = String::new();
 
         badges
             .into_iter()
-            .filter(|badge| filter_badge(badge.as_ref(), &mut lowercase))
+            .filter(|badge| Self::is_tourney_badge_with_buf(badge.as_ref(), &mut lowercase))
             .count()
     }
 
-    fn is_tourney_badge(badge: &str) -> bool {
-        !(badge.contains("fanart contest")
-            || badge.contains(" art contest")
-            || badge.starts_with("art contest")
-            || badge.starts_with("aspire")
-            || badge.starts_with("assessment")
-            || badge.starts_with("beatmap")
-            || badge.starts_with("community choice")
-            || badge.starts_with("contrib")
-            || badge.starts_with("centurion mapper")
-            || badge.starts_with("elite")
-            || badge.starts_with("exemplary")
-            || badge.starts_with("global")
+    /// Whether the badge description belongs to a tourney badge.
+    pub fn is_tourney_badge(badge: &str) -> bool {
+        Self::is_tourney_badge_with_buf(badge, &mut String::new())
+    }
+
+    fn is_tourney_badge_with_buf(badge: &str, buf: &mut String) -> bool {
+        buf.clear();
+
+        let iter = badge.bytes().map(|byte| byte.to_ascii_lowercase());
+
+        // SAFETY: from `str::make_ascii_lowercase`:
+        // "changing ASCII letters only does not invalidate UTF-8."
+        unsafe { buf.as_mut_vec() }.extend(iter);
+
+        !Self::is_excluded(buf)
+    }
+
+    fn is_excluded(badge: &str) -> bool {
+        EXCLUDED_PREFIXES
+            .iter()
+            .any(|prefix| badge.starts_with(prefix))
+            || EXCLUDED_KEYWORDS
+                .iter()
+                .any(|keyword| badge.contains(keyword))
             || (badge.starts_with("idol") && !badge.starts_with("idol@"))
-            || badge.starts_with("global")
-            || badge.starts_with("longstanding")
             || (badge.starts_with("map") && !badge.starts_with("maple"))
-            || badge.starts_with("moderation")
-            || badge.starts_with("monthly")
-            || badge.starts_with("nominat")
             || (badge.starts_with("osu!") && badge.contains("completionist"))
-            || badge.starts_with("outstanding")
-            || badge.starts_with("pending")
-            || badge.starts_with("spotlight")
-            || badge.contains("playlist")
-            || badge.contains("pickem"))
     }
 }
 
@@ -97,4 +112,17 @@ mod tests {
 
         assert_eq!(TourneyBadges::count(badges), 0);
     }
+
+    #[test]
+    fn single_badge() {
+        assert!(TourneyBadges::is_tourney_badge(
+            "osu! World Cup 2023 Winning Team"
+        ));
+        assert!(!TourneyBadges::is_tourney_badge(
+            "Aspire V Community Pick Grand Award"
+        ));
+        assert!(!TourneyBadges::is_tourney_badge(
+            "Community Choice Award: Project Loved (2020)"
+        ));
+    }
 }
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
-use bathbot_util::MessageBuilder;
+use bathbot_psql::model::configs::GuildConfig;
+use bathbot_util::{MessageBuilder, numbers};
 use eyre::{Report, Result, WrapErr};
 use tokio::{
     sync::watch::{self, Receiver},
@@ -12,7 +13,7 @@ use super::{
     origin::{ActiveMessageOrigin, ActiveMessageOriginError},
     response::ActiveResponse,
 };
-use crate::core::Context;
+use crate::core::{Context, commands::CommandOrigin};
 
 pub struct ActiveMessagesBuilder {
     inner: ActiveMessage,
@@ -54,6 +55,7 @@ impl ActiveMessagesBuilder {
                 embed,
                 content,
                 defer: _,
+                attachment: page_attachment,
             } = active_msg
                 .build_page()
                 .await
@@ -67,7 +69,7 @@ impl ActiveMessagesBuilder {
                 builder = builder.content(content.as_ref());
             }
 
-            if let Some((name, bytes)) = attachment {
+            if let Some((name, bytes)) = attachment.or(page_attachment) {
                 builder = builder.attachment(name, bytes);
             }
 
@@ -86,7 +88,22 @@ impl ActiveMessagesBuilder {
             let response = ActiveResponse::new(&orig, &response);
             let (activity_tx, activity_rx) = watch::channel(());
 
-            if let Some(until_timeout) = active_msg.until_timeout() {
+            if let Some(mut until_timeout) = active_msg.until_timeout() {
+                if let Some(guild_id) = orig.guild_id() {
+                    let lifetime = Context::guild_config()
+                        .peek(guild_id, |config| config.pagination_lifetime)
+                        .await;
+
+                    if let Some(secs) = lifetime {
+                        let secs = secs.clamp(
+                            GuildConfig::MIN_PAGINATION_LIFETIME,
+                            GuildConfig::MAX_PAGINATION_LIFETIME,
+                        );
+
+                        until_timeout = Duration::from_secs(secs as u64);
+                    }
+                }
+
                 ActiveMessagesBuilder::spawn_timeout(activity_rx, response, until_timeout);
 
                 let full = FullActiveMessage {
@@ -103,6 +120,12 @@ impl ActiveMessagesBuilder {
         inner(self, orig.into()).await
     }
 
+    /// Turn the response that was already sent for the command into the
+    /// active message by editing it.
+    pub async fn begin_by_edit(self, response: CommandOrigin<'_>) -> Result<()> {
+        self.begin(ActiveMessageOrigin::Response(response)).await
+    }
+
     pub fn attachment(self, attachment: Option<(String, Vec<u8>)>) -> Self {
         Self { attachment, ..self }
     }
@@ -115,7 +138,7 @@ impl ActiveMessagesBuilder {
     }
 
     fn spawn_timeout(mut rx: Receiver<()>, response: ActiveResponse, until_timeout: Duration) {
-        tokio::spawn(async move {
+        tokio::spawn(numbers::keep_separators(async move {
             loop {
                 tokio::select! {
                     res = rx.changed() => if res.is_ok() {
@@ -136,6 +159,6 @@ impl ActiveMessagesBuilder {
                     },
                 }
             }
-        });
+        }));
     }
 }
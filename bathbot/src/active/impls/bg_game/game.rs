@@ -145,6 +145,18 @@ impl Game {
         self.mapset.mapset_id
     }
 
+    /// Message content revealing the mapset when nobody guessed it.
+    pub fn reveal(&self) -> String {
+        let mapset_id = self.mapset_id();
+
+        format!(
+            "The answer was **{name}**\n\
+            Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
+            Full background: https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg",
+            name = self.mapset.display_name(),
+        )
+    }
+
     fn check_msg_content(&self, content: &str) -> ContentResult {
         match self.mapset.matches_title(content, self.difficulty) {
             Some(true) => return ContentResult::Title(true),
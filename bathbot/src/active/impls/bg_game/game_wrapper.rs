@@ -6,7 +6,7 @@ use std::{
 
 use bathbot_model::Effects;
 use bathbot_psql::model::games::MapsetTagsEntries;
-use bathbot_util::{IntHasher, MessageBuilder, constants::OSU_BASE};
+use bathbot_util::{IntHasher, MessageBuilder};
 use eyre::Result;
 use tokio::{
     sync::{
@@ -46,6 +46,10 @@ impl BackgroundGame {
         let mut previous_ids = VecDeque::with_capacity(50);
         let mut scores = HashMap::with_hasher(IntHasher);
 
+        // Best streak of each user and the currently ongoing streak
+        let mut streaks = HashMap::with_hasher(IntHasher);
+        let mut curr_streak = None;
+
         // Initialize game
         let (game, mut img) = Game::new(&entries, &mut previous_ids, effects, difficulty).await;
         let game = Arc::new(RwLock::new(game));
@@ -73,34 +77,28 @@ impl BackgroundGame {
                 // Process the result
                 match result {
                     LoopResult::Restart => {
-                        let mapset_id = game_clone.read().await.mapset_id();
+                        // Nobody guessed correctly so the streak is over
+                        curr_streak = None;
 
-                        // Send message
-                        let content = format!(
-                            "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
-                            Full background: https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg"
-                        );
+                        let content = game_clone.read().await.reveal();
 
                         if let Err(err) = channel.plain_message(&content).await {
                             warn!(?err, "Failed to show resolve for bg game restart");
                         }
                     }
                     LoopResult::Stop => {
-                        let mapset_id = game_clone.read().await.mapset_id();
-
-                        // Send message
-                        let content = format!(
-                            "Mapset: {OSU_BASE}beatmapsets/{mapset_id}\n\
-                            Full background: https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg\n\
-                            End of game, see you next time o/"
-                        );
+                        let mut content = game_clone.read().await.reveal();
+                        content.push_str("\nEnd of game, see you next time o/");
 
                         if let Err(err) = channel.plain_message(&content).await {
                             warn!(?err, "Failed to show resolve for bg game stop");
                         }
 
                         // Store score for winners
-                        if let Err(err) = Context::games().bggame_increment_scores(&scores).await {
+                        if let Err(err) = Context::games()
+                            .bggame_increment_scores(&scores, &streaks)
+                            .await
+                        {
                             warn!("{err:?}");
                         }
 
@@ -111,6 +109,16 @@ impl BackgroundGame {
                     LoopResult::Winner(user_id) => {
                         if entries.tags.len() >= 20 {
                             *scores.entry(user_id).or_insert(0) += 1;
+
+                            let streak = match curr_streak {
+                                Some((streak_user, streak)) if streak_user == user_id => streak + 1,
+                                Some(_) | None => 1,
+                            };
+
+                            curr_streak = Some((user_id, streak));
+
+                            let best_streak = streaks.entry(user_id).or_insert(0);
+                            *best_streak = streak.max(*best_streak);
                         }
                     }
                 }
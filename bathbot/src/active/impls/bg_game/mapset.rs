@@ -9,19 +9,26 @@ pub struct GameMapset {
     artist: Box<str>,
     title: Box<str>,
     title_adjusted: Option<Box<str>>,
+    /// `"{artist} - {title}"` with the original casing
+    display_name: Box<str>,
 }
 
 impl GameMapset {
     pub async fn new(mapset_id: u32) -> Result<Self> {
-        let ArtistTitle { artist, title } = match Context::osu_map().artist_title(mapset_id).await {
-            Ok(mut artist_title) => {
-                artist_title.title.make_ascii_lowercase();
-                artist_title.artist.make_ascii_lowercase();
+        let (ArtistTitle { artist, title }, display_name) =
+            match Context::osu_map().artist_title(mapset_id).await {
+                Ok(mut artist_title) => {
+                    let display_name = format!("{} - {}", artist_title.artist, artist_title.title);
 
-                artist_title
-            }
-            Err(err) => return Err(Report::new(err).wrap_err("failed to get artist and title")),
-        };
+                    artist_title.title.make_ascii_lowercase();
+                    artist_title.artist.make_ascii_lowercase();
+
+                    (artist_title, display_name)
+                }
+                Err(err) => {
+                    return Err(Report::new(err).wrap_err("failed to get artist and title"));
+                }
+            };
 
         let title_adjusted = if let (Some(open), Some(close)) = (title.find('('), title.rfind(')'))
         {
@@ -51,6 +58,7 @@ impl GameMapset {
             artist: artist.into_boxed_str(),
             title: title.into_boxed_str(),
             title_adjusted: title_adjusted.map(String::into_boxed_str),
+            display_name: display_name.into_boxed_str(),
         };
 
         Ok(mapset)
@@ -67,6 +75,10 @@ impl GameMapset {
         self.artist.as_ref()
     }
 
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_ref()
+    }
+
     pub fn matches_title(&self, content: &str, difficulty: f32) -> Option<bool> {
         self.title_adjusted
             .as_deref()
@@ -396,15 +396,12 @@ impl BackgroundGameSetup {
             "Included", include_value, true;
             "Excluded", excluded_value, true;
             "Effects", effects_value, true;
+            "Difficulty", self.difficulty.name().to_owned(), true;
         ];
 
-        let footer = FooterBuilder::new(format!("Difficulty: {:?}", self.difficulty));
         let title = format!("Selected tags ({} backgrounds)", entries.tags.len());
 
-        let embed = EmbedBuilder::new()
-            .fields(fields)
-            .footer(footer)
-            .title(title);
+        let embed = EmbedBuilder::new().fields(fields).title(title);
 
         if entries.tags.is_empty() {
             let description = "No stored backgrounds match these tags, try different ones";
@@ -414,6 +411,8 @@ impl BackgroundGameSetup {
             info!(
                 included = self.included.join(','),
                 excluded = self.excluded.join(','),
+                effects = self.effects.join(','),
+                difficulty = self.difficulty.name(),
                 "Starting game"
             );
 
@@ -422,7 +421,9 @@ impl BackgroundGameSetup {
             let game = game_fut.await;
             Context::bg_games().own(channel).await.insert(game);
 
-            Ok(BuildPage::new(embed, false))
+            let footer = FooterBuilder::new("Game started, good luck!");
+
+            Ok(BuildPage::new(embed.footer(footer), false))
         }
     }
 
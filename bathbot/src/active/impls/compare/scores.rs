@@ -40,6 +40,7 @@ pub struct CompareScoresPagination {
     pinned: Box<[Score]>,
     pp_idx: usize,
     score_data: ScoreData,
+    content: Option<Box<str>>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -158,7 +159,13 @@ impl IActiveMessage for CompareScoresPagination {
                 .thumbnail(self.map.thumbnail())
         };
 
-        BuildPage::new(embed, false).boxed()
+        let mut build = BuildPage::new(embed, false);
+
+        if let Some(ref content) = self.content {
+            build = build.content(content.clone());
+        }
+
+        build.boxed()
     }
 
     fn build_components(&self) -> Vec<Component> {
@@ -1,7 +1,7 @@
-use std::{cmp::Ordering, collections::HashMap, fmt::Write};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write};
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_util::{EmbedBuilder, FooterBuilder, IntHasher, constants::OSU_BASE};
+use bathbot_util::{CowUtils, EmbedBuilder, IntHasher, constants::OSU_BASE};
 use eyre::Result;
 use futures::future::BoxFuture;
 use rosu_v2::prelude::Username;
@@ -20,16 +20,19 @@ use crate::{
     util::interaction::{InteractionComponent, InteractionModal},
 };
 
-type CachedMaps = HashMap<u32, ([CommonScore; 2], CompareTopMap), IntHasher>;
+type CachedMaps = HashMap<u32, (Box<[CommonScore]>, CompareTopMap), IntHasher>;
+
+/// Maximum amount of characters of a map's title and version combined so that
+/// a full page with four users still fits into the embed description.
+const MAX_MAP_LEN: usize = 60;
 
 #[derive(PaginationBuilder)]
 pub struct CompareTopPagination {
-    name1: Username,
-    name2: Username,
+    names: Box<[Username]>,
     #[pagination(per_page = 10)]
     maps: CachedMaps,
     map_pps: Box<[(u32, f32)]>,
-    wins: [u8; 2],
+    content: Box<str>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -40,46 +43,48 @@ impl IActiveMessage for CompareTopPagination {
         let idx = pages.index();
         let map_pps = &self.map_pps[idx..(idx + pages.per_page()).min(self.maps.len())];
 
-        let mut description = String::with_capacity(1024);
+        let mut description = String::with_capacity(2048);
 
         for ((map_id, _), i) in map_pps.iter().zip(pages.index() + 1..) {
-            let ([score1, score2], map) = &self.maps[map_id];
-
-            let (medal1, medal2) = match score1.cmp(score2) {
-                Ordering::Less => ("second", "first"),
-                Ordering::Equal => ("first", "first"),
-                Ordering::Greater => ("first", "second"),
-            };
+            let (scores, map) = &self.maps[map_id];
+            let (title, version) = truncate_map(&map.title, &map.version);
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
-                "**{i}.** [{title} [{version}]]({OSU_BASE}b/{map_id})\n\
-                - :{medal1}_place: `{name1}`: {pp1:.2}pp :{medal2}_place: `{name2}`: {pp2:.2}pp",
-                title = map.title,
-                version = map.version,
-                name1 = self.name1,
-                pp1 = score1.pp,
-                name2 = self.name2,
-                pp2 = score2.pp,
+                "**{i}.** [{title} [{version}]]({OSU_BASE}b/{map_id})\n-",
+                title = title.as_ref().cow_escape_markdown(),
+                version = version.as_ref().cow_escape_markdown(),
             );
+
+            for (score, name) in scores.iter().zip(self.names.iter()) {
+                // Ties share the better placement
+                let place = 1 + scores
+                    .iter()
+                    .filter(|other| other.cmp(&score) == Ordering::Greater)
+                    .count();
+
+                let medal = match place {
+                    1 => ":first_place:",
+                    2 => ":second_place:",
+                    3 => ":third_place:",
+                    _ => ":black_small_square:",
+                };
+
+                let _ = write!(description, " {medal} `{name}`: {pp:.2}pp", pp = score.pp);
+            }
+
+            description.push('\n');
         }
 
         description.pop();
 
-        let footer_text = format!(
-            "🥇 count • {name1}: {wins1} • {name2}: {wins2}",
-            name1 = self.name1,
-            wins1 = self.wins[0],
-            name2 = self.name2,
-            wins2 = self.wins[1]
-        );
-
         let embed = EmbedBuilder::new()
             .description(description)
-            .footer(FooterBuilder::new(footer_text))
             .thumbnail(attachment("avatar_fuse.png"));
 
-        BuildPage::new(embed, false).boxed()
+        BuildPage::new(embed, false)
+            .content(self.content.clone())
+            .boxed()
     }
 
     fn build_components(&self) -> Vec<Component> {
@@ -100,3 +105,33 @@ impl IActiveMessage for CompareTopPagination {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
     }
 }
+
+/// Shortens the title and version so that they don't exceed [`MAX_MAP_LEN`]
+/// characters combined.
+///
+/// The version is preferably kept intact since it distinguishes maps of the
+/// same mapset.
+fn truncate_map<'m>(title: &'m str, version: &'m str) -> (Cow<'m, str>, Cow<'m, str>) {
+    let title_len = title.chars().count();
+    let version_len = version.chars().count();
+
+    if title_len + version_len <= MAX_MAP_LEN {
+        return (title.into(), version.into());
+    }
+
+    let version_max = version_len.min(MAX_MAP_LEN - title_len.min(MAX_MAP_LEN / 2));
+    let title_max = MAX_MAP_LEN - version_max;
+
+    (truncate(title, title_max), truncate(version, version_max))
+}
+
+fn truncate(s: &str, max: usize) -> Cow<'_, str> {
+    if s.chars().count() <= max {
+        return s.into();
+    }
+
+    let mut truncated: String = s.chars().take(max.saturating_sub(1)).collect();
+    truncated.push('…');
+
+    truncated.into()
+}
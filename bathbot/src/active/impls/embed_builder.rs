@@ -127,6 +127,8 @@ impl ScoreEmbedBuilderActive {
                     "n_spinners" => ValueKind::CountSpinners,
                     "ranked_date" => ValueKind::MapRankedDate,
                     "mapper" => ValueKind::Mapper,
+                    "mod_idx" => ValueKind::ModLeaderboard,
+                    "map_pb" => ValueKind::MapPersonalBest,
                     _ => {
                         return ComponentResult::Err(eyre!(
                             "Invalid value `{value}` for builder component `{}`",
@@ -195,11 +197,17 @@ impl ScoreEmbedBuilderActive {
                 self.inner.settings.values = default.values;
                 self.inner.settings.show_artist = default.show_artist;
                 self.inner.settings.show_sr_in_title = default.show_sr_in_title;
+                self.inner.settings.show_mod_idx = default.show_mod_idx;
+                self.inner.settings.show_map_pb = default.show_map_pb;
             }
             "embed_builder_show_artist_button" => self.inner.settings.show_artist = true,
             "embed_builder_hide_artist_button" => self.inner.settings.show_artist = false,
             "embed_builder_show_sr_title" => self.inner.settings.show_sr_in_title = true,
             "embed_builder_hide_sr_title" => self.inner.settings.show_sr_in_title = false,
+            "embed_builder_show_mod_idx_button" => self.inner.settings.show_mod_idx = true,
+            "embed_builder_hide_mod_idx_button" => self.inner.settings.show_mod_idx = false,
+            "embed_builder_show_map_pb_button" => self.inner.settings.show_map_pb = true,
+            "embed_builder_hide_map_pb_button" => self.inner.settings.show_map_pb = false,
             "embed_builder_value_left" => {
                 let Some(idx) = self
                     .inner
@@ -791,6 +799,24 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                                 value: "ranked_date".to_owned(),
                             },
                             kind_option!("Mapper", "mapper", Mapper),
+                            SelectMenuOption {
+                                default: matches!(self.value_kind, ValueKind::ModLeaderboard),
+                                description: Some(
+                                    "Position on the leaderboard of the score's mods".to_owned(),
+                                ),
+                                emoji: None,
+                                label: "Mod leaderboard".to_owned(),
+                                value: "mod_idx".to_owned(),
+                            },
+                            SelectMenuOption {
+                                default: matches!(self.value_kind, ValueKind::MapPersonalBest),
+                                description: Some(
+                                    "Comparison with the user's best score on the map".to_owned(),
+                                ),
+                                emoji: None,
+                                label: "Map personal best".to_owned(),
+                                value: "map_pb".to_owned(),
+                            },
                         ]),
                         placeholder: Some("Select a value to display".to_owned()),
                         channel_types: None,
@@ -968,6 +994,72 @@ impl IActiveMessage for ScoreEmbedBuilderActive {
                             ],
                         }));
                     }
+                    ValueKind::ModLeaderboard => {
+                        components.push(Component::ActionRow(ActionRow {
+                            components: vec![
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_show_mod_idx_button".to_owned()),
+                                    disabled: self.inner.settings.show_mod_idx,
+                                    emoji: None,
+                                    label: Some("Show".to_owned()),
+                                    style: ButtonStyle::Primary,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_hide_mod_idx_button".to_owned()),
+                                    disabled: !self.inner.settings.show_mod_idx,
+                                    emoji: None,
+                                    label: Some("Hide".to_owned()),
+                                    style: ButtonStyle::Primary,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_reset_button".to_owned()),
+                                    disabled: false,
+                                    emoji: None,
+                                    label: Some("Reset all".to_owned()),
+                                    style: ButtonStyle::Danger,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                            ],
+                        }));
+                    }
+                    ValueKind::MapPersonalBest => {
+                        components.push(Component::ActionRow(ActionRow {
+                            components: vec![
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_show_map_pb_button".to_owned()),
+                                    disabled: self.inner.settings.show_map_pb,
+                                    emoji: None,
+                                    label: Some("Show".to_owned()),
+                                    style: ButtonStyle::Primary,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_hide_map_pb_button".to_owned()),
+                                    disabled: !self.inner.settings.show_map_pb,
+                                    emoji: None,
+                                    label: Some("Hide".to_owned()),
+                                    style: ButtonStyle::Primary,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                                Component::Button(Button {
+                                    custom_id: Some("embed_builder_reset_button".to_owned()),
+                                    disabled: false,
+                                    emoji: None,
+                                    label: Some("Reset all".to_owned()),
+                                    style: ButtonStyle::Danger,
+                                    url: None,
+                                    sku_id: None,
+                                }),
+                            ],
+                        }));
+                    }
                     ValueKind::Grade => {
                         components.push(show_hide_row(idx));
                         components.push(arrow_row(idx));
@@ -1598,6 +1690,8 @@ pub enum ValueKind {
     CountSpinners,
     MapRankedDate,
     Mapper,
+    ModLeaderboard,
+    MapPersonalBest,
 }
 
 impl ValueKind {
@@ -1652,7 +1746,10 @@ impl From<ValueKind> for Value {
             ValueKind::CountSpinners => Self::CountSpinners(Default::default()),
             ValueKind::MapRankedDate => Self::MapRankedDate,
             ValueKind::Mapper => Self::Mapper(Default::default()),
-            ValueKind::Artist | ValueKind::None => unreachable!(),
+            ValueKind::Artist
+            | ValueKind::ModLeaderboard
+            | ValueKind::MapPersonalBest
+            | ValueKind::None => unreachable!(),
         }
     }
 }
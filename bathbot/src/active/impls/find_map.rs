@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_psql::model::osu::DbMapSearchEntry;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE, datetime::SecToMinSec,
+    numbers::round,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::{GameMode, RankStatus};
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct FindMapPagination {
+    #[pagination(per_page = 10)]
+    maps: Box<[DbMapSearchEntry]>,
+    query: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for FindMapPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let limit = self.maps.len().min(idx + pages.per_page());
+
+        let mut description = String::with_capacity(1024);
+
+        for (map, i) in self.maps[idx..limit].iter().zip(idx + 1..) {
+            let _ = writeln!(
+                description,
+                "**{i}.** [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})\n\
+                `{map_id}` • {mode} • {status}",
+                artist = map.artist.cow_escape_markdown(),
+                title = map.title.cow_escape_markdown(),
+                version = map.map_version.cow_escape_markdown(),
+                map_id = map.map_id,
+                mode = mode_name(map.gamemode),
+                status = status_name(map.rank_status),
+            );
+
+            if let Some(stars) = map.stars {
+                let _ = write!(description, " • {stars:.2}★");
+            }
+
+            let _ = writeln!(
+                description,
+                " • `{len}` • {bpm} BPM • by {creator}",
+                len = SecToMinSec::new(map.seconds_drain as u32),
+                bpm = round(map.bpm),
+                creator = map.creator.cow_escape_markdown(),
+            );
+        }
+
+        let footer_text = format!(
+            "Page {}/{} • {} stored map{}",
+            pages.curr_page(),
+            pages.last_page(),
+            self.maps.len(),
+            if self.maps.len() == 1 { "" } else { "s" },
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title(format!("Stored maps matching `{}`", self.query));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
+
+fn mode_name(gamemode: i16) -> &'static str {
+    match GameMode::from(gamemode as u8) {
+        GameMode::Osu => "osu!",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "catch",
+        GameMode::Mania => "mania",
+    }
+}
+
+fn status_name(rank_status: i16) -> &'static str {
+    match RankStatus::try_from(rank_status as i8) {
+        Ok(RankStatus::Graveyard) => "Graveyard",
+        Ok(RankStatus::WIP) => "WIP",
+        Ok(RankStatus::Pending) => "Pending",
+        Ok(RankStatus::Ranked) => "Ranked",
+        Ok(RankStatus::Approved) => "Approved",
+        Ok(RankStatus::Qualified) => "Qualified",
+        Ok(RankStatus::Loved) => "Loved",
+        Err(_) => "Unknown",
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_psql::model::configs::ScoreData;
@@ -6,18 +9,21 @@ use bathbot_util::{
     AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, constants::OSU_BASE,
     datetime::HowLongAgoDynamic, numbers::WithComma,
 };
-use eyre::Result;
+use eyre::{Report, Result};
 use futures::future::BoxFuture;
 use rosu_v2::prelude::GameMode;
 use twilight_model::{
-    channel::message::Component,
+    channel::message::{
+        Component,
+        component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+    },
     id::{Id, marker::UserMarker},
 };
 
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
-        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+        pagination::{Pages, async_handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::{LeaderboardScore, LeaderboardUserScore},
     embeds::PpFormatter,
@@ -29,6 +35,15 @@ use crate::{
     },
 };
 
+/// Value of the menu option to show all scores
+const ANY_VALUE: &str = "any";
+
+/// Value of the menu option to show only nomod scores
+const NOMOD_VALUE: &str = "NM";
+
+/// Maximum amount of mod combinations listed in the menu
+const MAX_MOD_OPTIONS: usize = 10;
+
 #[derive(PaginationBuilder)]
 pub struct LeaderboardPagination {
     map: OsuMap,
@@ -41,23 +56,42 @@ pub struct LeaderboardPagination {
     score_data: ScoreData,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
+    /// Currently selected mod combination; `None` shows all scores
+    mod_filter: Option<ModFilter>,
     pages: Pages,
 }
 
+/// Filtered view of the leaderboard scores
+struct ModFilter {
+    mods: Box<str>,
+    /// Indices into the unfiltered scores
+    indices: Box<[usize]>,
+}
+
 impl IActiveMessage for LeaderboardPagination {
     fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
         Box::pin(self.async_build_page())
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        if self.scores.is_empty() {
+            return components;
+        }
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![Component::SelectMenu(self.mods_menu())],
+        }));
+
+        components
     }
 
     fn handle_component<'a>(
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
-        handle_pagination_component(component, self.msg_owner, true, &mut self.pages)
+        Box::pin(self.async_handle_component(component))
     }
 
     fn handle_modal<'a>(
@@ -70,8 +104,13 @@ impl IActiveMessage for LeaderboardPagination {
 
 impl LeaderboardPagination {
     async fn async_build_page(&mut self) -> Result<BuildPage> {
+        let len = self
+            .mod_filter
+            .as_ref()
+            .map_or(self.scores.len(), |filter| filter.indices.len());
+
         let start_idx = self.pages.index();
-        let end_idx = self.scores.len().min(start_idx + self.pages.per_page());
+        let end_idx = len.min(start_idx + self.pages.per_page());
 
         let mut author_text = String::with_capacity(32);
 
@@ -95,9 +134,17 @@ impl LeaderboardPagination {
         let author_name = self.author_data.as_ref().map(|score| score.score.user_id);
 
         let mut description = String::with_capacity(1024);
+        let mut author_shown = false;
 
-        for score in self.scores[start_idx..end_idx].iter_mut() {
+        let indices: Vec<usize> = match self.mod_filter {
+            Some(ref filter) => filter.indices[start_idx..end_idx].to_vec(),
+            None => (start_idx..end_idx).collect(),
+        };
+
+        for idx in indices {
+            let score = &mut self.scores[idx];
             let found_author = Some(score.user_id) == author_name;
+            author_shown |= found_author;
 
             let fmt_fut = ScoreFormatter::new(
                 score,
@@ -110,11 +157,11 @@ impl LeaderboardPagination {
             let _ = write!(description, "{}", fmt_fut.await);
         }
 
-        if let Some(score) = self
-            .author_data
-            .as_mut()
-            .filter(|score| !(start_idx + 1..=end_idx).contains(&score.score.pos))
-        {
+        if let Some(filter) = self.mod_filter.as_ref().filter(|f| f.indices.is_empty()) {
+            let _ = writeln!(description, "No scores with mods `{}`", filter.mods);
+        }
+
+        if let Some(score) = self.author_data.as_mut().filter(|_| !author_shown) {
             let _ = writeln!(description, "\n__**<@{}>'s score:**__", score.discord_id);
 
             let fmt_fut = ScoreFormatter::new(
@@ -153,7 +200,132 @@ impl LeaderboardPagination {
             .footer(footer)
             .thumbnail(self.map.thumbnail());
 
-        Ok(BuildPage::new(embed, true).content(self.content.clone()))
+        let content = match self.mod_filter {
+            Some(ref filter) if self.content.is_empty() => {
+                format!("Showing {} scores only", filter.mods).into_boxed_str()
+            }
+            Some(ref filter) => {
+                format!("{}\nShowing {} scores only", self.content, filter.mods).into_boxed_str()
+            }
+            None => self.content.clone(),
+        };
+
+        Ok(BuildPage::new(embed, true).content(content))
+    }
+
+    fn mods_menu(&self) -> SelectMenu {
+        let mut counts = HashMap::<String, usize>::new();
+
+        for score in self.scores.iter() {
+            *counts
+                .entry(ModsFormatter::new(&score.mods).to_string())
+                .or_default() += 1;
+        }
+
+        let nomod_count = counts.remove(NOMOD_VALUE).unwrap_or(0);
+
+        let mut combos: Vec<_> = counts.into_iter().collect();
+        combos.sort_unstable_by(|(a_mods, a_count), (b_mods, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_mods.cmp(b_mods))
+        });
+        combos.truncate(MAX_MOD_OPTIONS);
+
+        let selected = self.mod_filter.as_ref().map(|filter| filter.mods.as_ref());
+
+        let any = SelectMenuOption {
+            default: selected.is_none(),
+            description: Some(format!("{} scores", self.scores.len())),
+            emoji: None,
+            label: "Any".to_owned(),
+            value: ANY_VALUE.to_owned(),
+        };
+
+        let nomod = SelectMenuOption {
+            default: selected == Some(NOMOD_VALUE),
+            description: Some(format!("{nomod_count} scores")),
+            emoji: None,
+            label: "NoMod".to_owned(),
+            value: NOMOD_VALUE.to_owned(),
+        };
+
+        let combos = combos.into_iter().map(|(mods, count)| SelectMenuOption {
+            default: selected == Some(mods.as_str()),
+            description: Some(format!("{count} scores")),
+            emoji: None,
+            label: mods.clone(),
+            value: mods,
+        });
+
+        let options = [any, nomod].into_iter().chain(combos).collect();
+
+        SelectMenu {
+            custom_id: "leaderboard_mods".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: Some(options),
+            placeholder: Some("Filter by mods".to_owned()),
+            channel_types: None,
+            default_values: None,
+            kind: SelectMenuType::Text,
+        }
+    }
+
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        if component.data.custom_id != "leaderboard_mods" {
+            return async_handle_pagination_component(
+                component,
+                self.msg_owner,
+                true,
+                &mut self.pages,
+            )
+            .await
+            .unwrap_or_else(ComponentResult::Err);
+        }
+
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(value) = component.data.values.pop() else {
+            return ComponentResult::Err(eyre!("Missing value in leaderboard mods menu"));
+        };
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        self.mod_filter = (value != ANY_VALUE).then(|| {
+            let indices = self
+                .scores
+                .iter()
+                .enumerate()
+                .filter(|(_, score)| ModsFormatter::new(&score.mods).to_string() == value)
+                .map(|(i, _)| i)
+                .collect();
+
+            ModFilter {
+                mods: value.into_boxed_str(),
+                indices,
+            }
+        });
+
+        let len = self
+            .mod_filter
+            .as_ref()
+            .map_or(self.scores.len(), |filter| filter.indices.len());
+
+        self.pages = Pages::new(self.pages.per_page(), len);
+
+        ComponentResult::BuildPage
     }
 }
 
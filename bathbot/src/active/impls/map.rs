@@ -8,42 +8,52 @@ use bathbot_util::{
     fields,
     numbers::{WithComma, round},
 };
-use eyre::{Result, WrapErr};
+use eyre::{Report, Result, WrapErr, eyre};
 use futures::future::BoxFuture;
-use rosu_pp::Difficulty;
+use rosu_pp::{Beatmap as PpMap, Difficulty};
 use rosu_v2::prelude::{
     BeatmapExtended, BeatmapsetExtended, GameMode, GameModsIntermode, Username,
 };
 use twilight_model::{
-    channel::message::Component,
+    channel::message::{
+        Component,
+        component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+    },
     id::{Id, marker::UserMarker},
 };
 
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
-        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+        pagination::{Pages, async_handle_pagination_component, handle_pagination_modal},
     },
-    commands::osu::CustomAttrs,
+    commands::osu::{CustomAttrs, map_strain_graph},
     core::Context,
     embeds::attachment,
     manager::redis::osu::UserArgs,
     util::{
-        Emote,
+        Authored, ComponentExt, Emote,
         interaction::{InteractionComponent, InteractionModal},
     },
 };
 
+/// Discord allows at most 25 options in a select menu
+const MENU_OPTIONS: usize = 25;
+
 #[derive(PaginationBuilder)]
 pub struct MapPagination {
     mapset: BeatmapsetExtended,
     #[pagination(per_page = 1)]
     maps: Box<[BeatmapExtended]>,
     mods: GameModsIntermode,
+    /// Mode to convert osu!standard maps to
+    mode: Option<GameMode>,
     attrs: CustomAttrs,
     origin: MessageOrigin,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
+    /// Index of the map that the attached strain graph belongs to
+    graph_idx: Option<usize>,
     pages: Pages,
 }
 
@@ -53,14 +63,58 @@ impl IActiveMessage for MapPagination {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        if self.maps.len() <= 1 {
+            return components;
+        }
+
+        let idx = self.pages.index();
+
+        // Show a window of maps around the current one if there are too many
+        let start = idx
+            .saturating_sub(MENU_OPTIONS / 2)
+            .min(self.maps.len().saturating_sub(MENU_OPTIONS));
+
+        let options = self
+            .maps
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MENU_OPTIONS)
+            .map(|(i, map)| SelectMenuOption {
+                default: i == idx,
+                description: Some(format!("{:.2}★", map.stars)),
+                emoji: None,
+                label: map.version.chars().take(100).collect(),
+                value: i.to_string(),
+            })
+            .collect();
+
+        let menu = SelectMenu {
+            custom_id: "map_diff_menu".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: Some(options),
+            placeholder: Some("Select a difficulty".to_owned()),
+            channel_types: None,
+            default_values: None,
+            kind: SelectMenuType::Text,
+        };
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        }));
+
+        components
     }
 
     fn handle_component<'a>(
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
-        handle_pagination_component(component, self.msg_owner, true, &mut self.pages)
+        Box::pin(self.async_handle_component(component))
     }
 
     fn handle_modal<'a>(
@@ -121,6 +175,30 @@ impl MapPagination {
 
         let mut rosu_map = map_res.wrap_err("Failed to get pp map")?;
 
+        let mode = match self.mode {
+            Some(mode) if map.mode == GameMode::Osu => {
+                let _ = rosu_map.convert_mut((mode as u8).into(), &Default::default());
+
+                mode
+            }
+            Some(_) | None => map.mode,
+        };
+
+        // The graph has to be created before custom attributes are applied
+        let graph = if self.graph_idx == Some(self.pages.index()) {
+            None
+        } else {
+            let graph = self.strain_graph(&rosu_map, mode).await;
+
+            // Only mark the graph as attached if it could be created so that
+            // it's attempted again on the next build
+            if graph.is_some() {
+                self.graph_idx = Some(self.pages.index());
+            }
+
+            graph
+        };
+
         if let Some(ar_) = self.attrs.ar {
             rosu_map.ar = ar_ as f32;
         }
@@ -285,8 +363,6 @@ impl MapPagination {
 
         let footer = FooterBuilder::new(footer_text);
 
-        let image = attachment("map_graph.png");
-
         let mut description = format!(
             ":musical_note: [Song preview](https://b.ppy.sh/preview/{mapset_id}.mp3) \
             :frame_photo: [Full background](https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg)",
@@ -312,23 +388,92 @@ impl MapPagination {
             GameMode::Catch => {}
         }
 
-        let embed = EmbedBuilder::new()
+        let mut embed = EmbedBuilder::new()
             .author(author)
             .description(description)
             .fields(fields)
             .footer(footer)
-            .image(image)
             .timestamp(timestamp)
             .title(title)
             .url(map.url.as_str());
 
-        let build = BuildPage::new(embed, true).content(self.content.clone());
+        // Don't show a previous difficulty's graph if the current one is missing
+        if self.graph_idx == Some(self.pages.index()) {
+            embed = embed.image(attachment("map_graph.png"));
+        }
+
+        let mut build = BuildPage::new(embed, true).content(self.content.clone());
+
+        if let Some(graph) = graph {
+            build = build.attachment("map_graph.png", graph);
+        }
 
         Ok(build)
     }
 
-    pub fn set_index(&mut self, index: usize) {
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        if component.data.custom_id != "map_diff_menu" {
+            return async_handle_pagination_component(
+                component,
+                self.msg_owner,
+                true,
+                &mut self.pages,
+            )
+            .await
+            .unwrap_or_else(ComponentResult::Err);
+        }
+
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(value) = component.data.values.pop() else {
+            return ComponentResult::Err(eyre!("Missing value in map difficulty menu"));
+        };
+
+        let idx = match value.parse() {
+            Ok(idx) if idx < self.maps.len() => idx,
+            _ => return ComponentResult::Err(eyre!("Invalid map difficulty index `{value}`")),
+        };
+
+        if let Err(err) = component.defer().await.map_err(Report::new) {
+            return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+        }
+
+        self.pages.set_index(idx);
+
+        ComponentResult::BuildPage
+    }
+
+    async fn strain_graph(&self, map: &PpMap, mode: GameMode) -> Option<Vec<u8>> {
+        let mods = self
+            .mods
+            .clone()
+            .try_with_mode(mode)
+            .filter(|mods| mods.is_valid())?;
+
+        match map_strain_graph(map, mods, &self.mapset.covers.cover).await {
+            Ok(graph) => Some(graph),
+            Err(err) => {
+                warn!(?err, "Failed to create graph");
+
+                None
+            }
+        }
+    }
+
+    /// Set the initial index and whether its strain graph is already attached
+    pub fn set_index(&mut self, index: usize, graph_attached: bool) {
         self.pages.set_index(index);
+        self.graph_idx = graph_attached.then_some(index);
     }
 }
 
@@ -8,6 +8,7 @@ pub use self::{
     changelog::ChangelogPagination,
     compare::{CompareMostPlayedPagination, CompareScoresPagination, CompareTopPagination},
     embed_builder::ScoreEmbedBuilderActive,
+    find_map::FindMapPagination,
     help::{HelpInteractionCommand, HelpPrefixMenu},
     higherlower::HigherLowerGame,
     leaderboard::LeaderboardPagination,
@@ -20,6 +21,7 @@ pub use self::{
         MedalsRecentPagination,
     },
     most_played::MostPlayedPagination,
+    news::NewsPagination,
     nochoke::NoChokePagination,
     osekai::{MedalCountPagination, MedalRarityPagination},
     osustats::{OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination},
@@ -28,14 +30,21 @@ pub use self::{
     ranking_countries::RankingCountriesPagination,
     recent_list::RecentListPagination,
     render::{CachedRender, RenderSettingsActive, SettingsImport},
+    scorepost::ScorepostActive,
+    shards::ShardsPagination,
     simulate::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
     single_score::{SingleScoreContent, SingleScorePagination},
     skins::SkinsPagination,
     slash_commands::SlashCommandsPagination,
-    snipe::{SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination},
+    snipe::{
+        SnipeCountryFeedPagination, SnipeCountryListPagination, SnipeDifferencePagination,
+        SnipePlayerListPagination,
+    },
     top::TopPagination,
     top_if::TopIfPagination,
+    top_mapper::{TopMapperEntry, TopMapperPagination},
     track_list::TrackListPagination,
+    weekly_best::WeeklyBestPagination,
 };
 
 mod badges;
@@ -44,6 +53,7 @@ mod bookmarks;
 mod changelog;
 mod compare;
 mod embed_builder;
+mod find_map;
 mod help;
 mod higherlower;
 mod leaderboard;
@@ -53,6 +63,7 @@ mod match_compare;
 mod match_costs;
 mod medals;
 mod most_played;
+mod news;
 mod nochoke;
 mod osekai;
 mod osustats;
@@ -61,6 +72,8 @@ mod ranking;
 mod ranking_countries;
 mod recent_list;
 mod render;
+mod scorepost;
+mod shards;
 mod simulate;
 mod single_score;
 mod skins;
@@ -68,4 +81,6 @@ mod slash_commands;
 mod snipe;
 mod top;
 mod top_if;
+mod top_mapper;
 mod track_list;
+mod weekly_best;
@@ -0,0 +1,106 @@
+use std::fmt::Write;
+
+use bathbot_cache::model::CachedArchive;
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::rosu_v2::news::ArchivedNewsPost;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rkyv::{
+    rancor::{Panic, ResultExt},
+    vec::ArchivedVec,
+};
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+/// Maximum amount of characters of a post's preview
+const PREVIEW_LEN: usize = 200;
+
+#[derive(PaginationBuilder)]
+pub struct NewsPagination {
+    #[pagination(per_page = 5)]
+    posts: CachedArchive<ArchivedVec<ArchivedNewsPost>>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for NewsPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let limit = self.posts.len().min(idx + pages.per_page());
+
+        let mut description = String::with_capacity(1024);
+
+        for post in self.posts[idx..limit].iter() {
+            let published_at = post.published_at.try_deserialize::<Panic>().always_ok();
+
+            let _ = writeln!(
+                description,
+                "**[{title}]({OSU_BASE}home/news/{slug})**\n\
+                by {author} • <t:{timestamp}:D>",
+                title = post.title.cow_escape_markdown(),
+                slug = post.slug,
+                author = post.author.cow_escape_markdown(),
+                timestamp = published_at.unix_timestamp(),
+            );
+
+            if let Some(preview) = post.preview.as_deref() {
+                let preview = preview.trim();
+
+                match preview.char_indices().nth(PREVIEW_LEN) {
+                    Some((end, _)) => {
+                        let _ = writeln!(description, "> {}…", preview[..end].trim_end());
+                    }
+                    None => {
+                        let _ = writeln!(description, "> {preview}");
+                    }
+                }
+            }
+
+            description.push('\n');
+        }
+
+        let footer_text = format!(
+            "Page {}/{} • Latest osu! news",
+            pages.curr_page(),
+            pages.last_page()
+        );
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("osu! news")
+            .url(format!("{OSU_BASE}home/news"));
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
@@ -32,6 +32,7 @@ pub struct OsuStatsPlayersPagination {
     first_place_id: u32,
     content: Box<str>,
     msg_owner: Id<UserMarker>,
+    amount: usize,
     pages: Pages,
 }
 
@@ -65,37 +66,64 @@ impl OsuStatsPlayersPagination {
         params: OsuStatsPlayersArgs,
         first_place_id: u32,
         amount: usize,
+        start_idx: usize,
         content: String,
         msg_owner: Id<UserMarker>,
     ) -> Self {
+        let mut pages = Pages::new(15, amount);
+        pages.jump_to_index(start_idx);
+
         Self {
             players,
             params,
             first_place_id,
             content: content.into_boxed_str(),
             msg_owner,
-            pages: Pages::new(15, amount),
+            amount,
+            pages,
         }
     }
 
     async fn async_build_page(&mut self) -> Result<BuildPage> {
-        let pages = &self.pages;
-        let page = pages.curr_page();
-
-        let players = match self.players.entry(page) {
-            Entry::Occupied(e) => e.into_mut(),
-            Entry::Vacant(e) => {
-                self.params.page = page;
-
-                let players = Context::client()
-                    .get_country_globals(&self.params)
-                    .await
-                    .wrap_err("Failed to get country globals")?;
+        let players = loop {
+            let page = self.pages.curr_page();
+
+            let players = match self.players.entry(page) {
+                Entry::Occupied(e) => e.into_mut(),
+                Entry::Vacant(e) => {
+                    self.params.page = page;
+
+                    let players = Context::client()
+                        .get_country_globals(&self.params)
+                        .await
+                        .wrap_err("Failed to get country globals")?;
+
+                    e.insert(players.into_boxed_slice())
+                }
+            };
+
+            // The amount of entries was only estimated so if the page is not
+            // full, we reached the end of the data.
+            let per_page = self.pages.per_page();
+
+            if players.len() < per_page {
+                let amount = (page - 1) * per_page + players.len();
+
+                if amount < self.amount {
+                    let index = self.pages.index();
+                    self.amount = amount;
+                    self.pages = Pages::new(per_page, amount);
+                    self.pages.set_index(index);
+                }
+            }
 
-                e.insert(players.into_boxed_slice())
+            if !players.is_empty() || page == 1 {
+                break &self.players[&page];
             }
         };
 
+        let pages = &self.pages;
+
         let mut author = AuthorBuilder::new("Most global leaderboard scores");
 
         if let Some(ref country) = self.params.country {
@@ -104,7 +132,9 @@ impl OsuStatsPlayersPagination {
 
         let mut description = String::with_capacity(1024);
 
-        for (player, i) in players.iter().zip(pages.index() + 1..) {
+        let limit = self.amount.saturating_sub(pages.index());
+
+        for (player, i) in players.iter().take(limit).zip(pages.index() + 1..) {
             let _ = writeln!(
                 description,
                 "**#{i} [{username}]({OSU_BASE}users/{user_id})**: {count}",
@@ -96,6 +96,11 @@ impl OsuStatsScoresPagination {
             let mode = self.params.mode;
 
             for (score, i) in scores.into_iter().zip((osustats_page - 1) * 24..) {
+                // Scores past a min pp cutoff are not part of the list
+                if i >= self.total {
+                    break;
+                }
+
                 let map_opt = maps.remove(&score.map.map_id);
                 let Some(map) = map_opt else { continue };
 
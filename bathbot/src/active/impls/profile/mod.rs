@@ -2,12 +2,12 @@ use std::fmt::{Display, Write};
 
 use bathbot_model::RankAccPeaks;
 use bathbot_util::{
-    EmbedBuilder, FooterBuilder, MessageOrigin,
-    constants::OSU_BASE,
+    CowUtils, EmbedBuilder, FooterBuilder, MessageOrigin, ModsFormatter,
+    constants::{OSU_API_ISSUE, OSU_BASE},
     datetime::{HowLongAgoText, NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
     numbers::{MinMaxAvg, Number, WithComma, round},
-    osu::{BonusPP, total_score_to_reach_level},
+    osu::total_score_to_reach_level,
 };
 use eyre::Result;
 use futures::future::BoxFuture;
@@ -27,21 +27,30 @@ use twilight_model::{
 
 use self::{
     availability::{Availability, MapperNames, ScoreData, SkinUrl},
+    pp_breakdown::PpBreakdown,
+    pp_ceiling::PpCeiling,
     top100_mappers::Top100Mappers,
     top100_mods::Top100Mods,
     top100_stats::Top100Stats,
 };
 use crate::{
-    active::{BuildPage, ComponentResult, IActiveMessage},
+    Context,
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        mode_select::{mode_select_component, selected_mode},
+    },
     commands::osu::ProfileKind,
-    manager::redis::osu::CachedUser,
+    manager::redis::osu::{CachedUser, UserArgs},
     util::{
-        Authored, CachedUserExt, ComponentExt, Emote, interaction::InteractionComponent,
-        osu::grade_emote,
+        Authored, CachedUserExt, ComponentExt, Emote,
+        interaction::InteractionComponent,
+        osu::{TopCount, TopCounts, grade_emote},
     },
 };
 
 mod availability;
+mod pp_breakdown;
+mod pp_ceiling;
 mod top100_mappers;
 mod top100_mods;
 mod top100_stats;
@@ -56,6 +65,7 @@ pub struct ProfileMenu {
     score_rank: Availability<ScoreData>,
     osutrack_peaks: Option<RankAccPeaks>,
     top100stats: Option<Top100Stats>,
+    pp_breakdown: Option<PpBreakdown>,
     mapper_names: Availability<MapperNames>,
     kind: ProfileKind,
     origin: MessageOrigin,
@@ -71,6 +81,8 @@ impl IActiveMessage for ProfileMenu {
             ProfileKind::Top100Mods => Box::pin(self.top100_mods()),
             ProfileKind::Top100Mappers => Box::pin(self.top100_mappers()),
             ProfileKind::MapperStats => Box::pin(self.mapper_stats()),
+            ProfileKind::PpCeiling => Box::pin(self.pp_ceiling()),
+            ProfileKind::LeaderboardCounts => Box::pin(self.leaderboard_counts()),
         }
     }
 
@@ -118,6 +130,20 @@ impl IActiveMessage for ProfileMenu {
                 label: "Mapper Statistics".to_owned(),
                 value: "mapper_stats".to_owned(),
             },
+            SelectMenuOption {
+                default: matches!(self.kind, ProfileKind::PpCeiling),
+                description: Some("Highest pp play of each mode".to_owned()),
+                emoji: None,
+                label: "PP Ceiling".to_owned(),
+                value: "pp_ceiling".to_owned(),
+            },
+            SelectMenuOption {
+                default: matches!(self.kind, ProfileKind::LeaderboardCounts),
+                description: Some("Amount of #1s, top 8s, and top 50s".to_owned()),
+                emoji: None,
+                label: "Leaderboard Counts".to_owned(),
+                value: "lb_counts".to_owned(),
+            },
         ];
 
         let menu = SelectMenu {
@@ -134,7 +160,10 @@ impl IActiveMessage for ProfileMenu {
 
         let components = vec![Component::SelectMenu(menu)];
 
-        vec![Component::ActionRow(ActionRow { components })]
+        vec![
+            Component::ActionRow(ActionRow { components }),
+            mode_select_component("profile_mode", self.user.mode),
+        ]
     }
 
     fn handle_component<'a>(
@@ -164,6 +193,8 @@ impl IActiveMessage for ProfileMenu {
                 Some("top100_mods") => ProfileKind::Top100Mods,
                 Some("top100_mappers") => ProfileKind::Top100Mappers,
                 Some("mapper_stats") => ProfileKind::MapperStats,
+                Some("pp_ceiling") => ProfileKind::PpCeiling,
+                Some("lb_counts") => ProfileKind::LeaderboardCounts,
                 Some(other) => {
                     return ComponentResult::Err(eyre!("Unknown profile menu option `{other}`"));
                 }
@@ -177,7 +208,10 @@ impl IActiveMessage for ProfileMenu {
             ComponentResult::BuildPage
         }
 
-        Box::pin(inner(component, &mut self.kind, self.msg_owner))
+        match component.data.custom_id.as_str() {
+            "profile_mode" => Box::pin(self.switch_mode(component)),
+            _ => Box::pin(inner(component, &mut self.kind, self.msg_owner)),
+        }
     }
 }
 
@@ -207,9 +241,72 @@ impl ProfileMenu {
             mapper_names: Availability::NotRequested,
             origin,
             top100stats: None,
+            pp_breakdown: None,
         }
     }
 
+    async fn switch_mode(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let mode = match selected_mode(component) {
+            Ok(mode) => mode,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if let Err(err) = component.defer().await {
+            warn!(?err, "Failed to defer component");
+        }
+
+        if mode == self.user.mode {
+            return ComponentResult::BuildPage;
+        }
+
+        let osu_id = self.user.user_id.to_native();
+        let user_fut = Context::redis().osu_user(UserArgs::user_id(osu_id, mode));
+        let peaks_fut = Context::client().osu_user_rank_acc_peak(osu_id, mode);
+
+        let (user_res, peaks_res) = tokio::join!(user_fut, peaks_fut);
+
+        let user = match user_res {
+            Ok(user) => user,
+            Err(err) => {
+                warn!(?err, %mode, "Failed to get user for mode switch");
+
+                if let Err(err) = component.error_followup(OSU_API_ISSUE).await {
+                    warn!(?err, "Failed to send error followup");
+                }
+
+                // Rebuild the previous page
+                return ComponentResult::BuildPage;
+            }
+        };
+
+        self.osutrack_peaks = match peaks_res {
+            Ok(peaks) => peaks,
+            Err(err) => {
+                warn!(?err, "Failed to get osutrack peaks");
+
+                None
+            }
+        };
+
+        self.user = user;
+        self.scores = Availability::NotRequested;
+        self.score_rank = Availability::NotRequested;
+        self.mapper_names = Availability::NotRequested;
+        self.top100stats = None;
+        self.pp_breakdown = None;
+
+        ComponentResult::BuildPage
+    }
+
     async fn compact(&mut self) -> Result<BuildPage> {
         let user_id = self.user.user_id.to_native();
 
@@ -298,20 +395,23 @@ impl ProfileMenu {
             missing_score = WithComma::new(missing_score),
         );
 
-        let bonus_pp = match scores_opt {
-            Some(scores) => {
-                let mut bonus_pp = BonusPP::new();
-
-                for (i, score) in scores.iter().enumerate() {
-                    if let Some(weight) = score.weight {
-                        bonus_pp.update(weight.pp, i);
-                    }
-                }
-
-                let pp = bonus_pp.calculate(self.user.statistics.as_ref().expect("missing stats"));
+        let pp_breakdown = match (self.pp_breakdown, scores_opt) {
+            (Some(breakdown), _) => Some(breakdown),
+            (None, Some(scores)) => {
+                let breakdown = PpBreakdown::new(scores, stats.pp.to_native());
+                self.pp_breakdown = breakdown;
 
-                format!("{pp:.2}pp")
+                breakdown
             }
+            (None, None) => None,
+        };
+
+        let pp_breakdown = match pp_breakdown {
+            Some(PpBreakdown { raw, bonus }) => format!(
+                "Raw pp: {raw} • Bonus: {bonus}",
+                raw = WithComma::new(raw.round() as u32),
+                bonus = WithComma::new(bonus.round() as u32),
+            ),
             None => "-".to_string(),
         };
 
@@ -444,7 +544,7 @@ impl ProfileMenu {
             "Level", level, true;
             "Total score", WithComma::new(stats.total_score.to_native()).to_string(), true;
             "Total hits", WithComma::new(stats.total_hits.to_native()).to_string(), true;
-            "Bonus PP", bonus_pp, true;
+            "PP breakdown", pp_breakdown, true;
             "Ranked score", WithComma::new(stats.ranked_score.to_native()).to_string(), true;
             "Peak score rank", peak_score_rank, true;
             "Score rank", score_rank, true;
@@ -644,6 +744,16 @@ impl ProfileMenu {
             );
 
             description.push_str("```");
+
+            // Mod-adjusted drain times summed up
+            let total_mins = len.sum() as u32 / 60;
+
+            let _ = write!(
+                description,
+                "\nTime spent on these plays: ~`{}:{:02}` hours",
+                total_mins / 60,
+                total_mins % 60,
+            );
         } else {
             description.push_str("No top scores :(");
         };
@@ -893,6 +1003,97 @@ impl ProfileMenu {
         Ok(BuildPage::new(embed, true))
     }
 
+    async fn pp_ceiling(&mut self) -> Result<BuildPage> {
+        let user_id = self.user.user_id.to_native();
+        let ceilings = PpCeiling::prepare(user_id, self.legacy_scores).await;
+
+        let mut description = "__**Highest pp play per mode".to_owned();
+
+        if let Some(discord_id) = self.discord_id {
+            let _ = write!(description, " for <@{discord_id}>");
+        }
+
+        description.push_str(":**__\n");
+
+        let modes = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        for (mode, entry) in modes.into_iter().zip(ceilings) {
+            let _ = write!(description, "{} ", Emote::from(mode));
+
+            match entry {
+                Some(entry) => {
+                    let _ = writeln!(
+                        description,
+                        "**{pp}pp** [{map}]({OSU_BASE}b/{map_id}) +{mods}",
+                        pp = WithComma::new(round(entry.pp)),
+                        map = entry.map.cow_escape_markdown(),
+                        map_id = entry.map_id,
+                        mods = ModsFormatter::new(&entry.mods),
+                    );
+                }
+                None => description.push_str("-\n"),
+            }
+        }
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(true))
+            .description(description)
+            .footer(self.footer())
+            .thumbnail(self.user.avatar_url.as_ref());
+
+        Ok(BuildPage::new(embed, true))
+    }
+
+    async fn leaderboard_counts(&mut self) -> Result<BuildPage> {
+        let mut description = "__**Map leaderboard placements".to_owned();
+
+        if let Some(discord_id) = self.discord_id {
+            let _ = write!(description, " for <@{discord_id}>");
+        }
+
+        description.push_str(":**__\n");
+
+        match TopCounts::request(&self.user, self.user.mode).await {
+            Ok(counts) => {
+                let count_len = counts.count_len();
+
+                description.push_str("```\n");
+
+                for TopCount { top_n, count, .. } in counts
+                    .into_iter()
+                    .filter(|count| matches!(count.top_n, 1 | 8 | 50))
+                {
+                    let _ = writeln!(description, "Top {top_n:<2}: {count:>count_len$}");
+                }
+
+                description.push_str("```");
+
+                if self.user.scores_first_count.to_native() > 0 {
+                    description.push_str(
+                        "Use `/osustats scores max_rank:1` to list all of the user's #1 scores",
+                    );
+                }
+            }
+            Err(err) => {
+                warn!(?err, "Failed to get top counts");
+                description.push_str("Failed to request counts from osustats, try again later");
+            }
+        }
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(true))
+            .description(description)
+            .footer(self.footer())
+            .thumbnail(self.user.avatar_url.as_ref());
+
+        Ok(BuildPage::new(embed, true))
+    }
+
     async fn own_maps_in_top100(&mut self) -> Option<usize> {
         let user_id = self.user.user_id.to_native();
         let mode = self.user.mode;
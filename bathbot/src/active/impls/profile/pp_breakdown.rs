@@ -0,0 +1,27 @@
+use bathbot_util::osu::{ExtractablePp, PpListUtil};
+use rosu_v2::prelude::Score;
+
+/// Splits a user's pp into the weighted sum of their top scores and the bonus
+/// pp awarded for their score count.
+#[derive(Copy, Clone)]
+pub(super) struct PpBreakdown {
+    pub raw: f32,
+    pub bonus: f32,
+}
+
+impl PpBreakdown {
+    /// Returns `None` for inactive users since their pp are zero regardless of
+    /// their top scores.
+    pub(super) fn new(scores: &[Score], total_pp: f32) -> Option<Self> {
+        if total_pp <= 0.0 {
+            return None;
+        }
+
+        let raw = scores.extract_pp().accum_weighted();
+
+        Some(Self {
+            raw,
+            bonus: (total_pp - raw).max(0.0),
+        })
+    }
+}
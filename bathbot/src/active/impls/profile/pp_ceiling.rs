@@ -0,0 +1,68 @@
+use rosu_v2::prelude::{GameMode, Score};
+
+use crate::{
+    core::{Context, PpCeilingEntry, PpCeilings},
+    manager::redis::osu::UserArgsSlim,
+};
+
+pub(super) struct PpCeiling;
+
+impl PpCeiling {
+    /// Highest pp play of each mode, either from the cache or freshly requested.
+    pub(super) async fn prepare(user_id: u32, legacy_scores: bool) -> PpCeilings {
+        if let Some(ceilings) = Context::get().pp_ceilings(user_id, legacy_scores) {
+            return ceilings;
+        }
+
+        let top_score = |mode: GameMode| {
+            Context::osu_scores()
+                .top(legacy_scores)
+                .limit(1)
+                .exec(UserArgsSlim::user_id(user_id).mode(mode))
+        };
+
+        let (osu, taiko, catch, mania) = tokio::join!(
+            top_score(GameMode::Osu),
+            top_score(GameMode::Taiko),
+            top_score(GameMode::Catch),
+            top_score(GameMode::Mania),
+        );
+
+        let mut errored = false;
+
+        let ceilings = [osu, taiko, catch, mania].map(|res| match res {
+            Ok(scores) => scores.into_iter().next().and_then(entry_from_score),
+            Err(err) => {
+                warn!(?err, "Failed to get top score for pp ceiling");
+                errored = true;
+
+                None
+            }
+        });
+
+        // Don't cache partial results so that the next request tries again
+        if !errored {
+            Context::get().store_pp_ceilings(user_id, legacy_scores, ceilings.clone());
+        }
+
+        ceilings
+    }
+}
+
+fn entry_from_score(score: Score) -> Option<PpCeilingEntry> {
+    let pp = score.pp?;
+
+    let map = match (score.mapset.as_ref(), score.map.as_ref()) {
+        (Some(mapset), Some(map)) => {
+            format!("{} - {} [{}]", mapset.artist, mapset.title, map.version).into_boxed_str()
+        }
+        _ => format!("<map {}>", score.map_id).into_boxed_str(),
+    };
+
+    Some(PpCeilingEntry {
+        map_id: score.map_id,
+        map,
+        mods: score.mods,
+        pp,
+    })
+}
@@ -7,7 +7,9 @@ use std::{
 };
 
 use bathbot_macros::PaginationBuilder;
-use bathbot_model::{BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{
+    AmountWithStreak, BgGameScore, EmbedHeader, RankingEntries, RankingEntry, RankingKind,
+};
 use bathbot_util::{
     EmbedBuilder,
     numbers::{WithComma, round},
@@ -38,6 +40,8 @@ pub struct RankingPagination {
     kind: RankingKind,
     defer: bool,
     msg_owner: Id<UserMarker>,
+    /// Entries below this pp value are cut off
+    min_pp: Option<u32>,
     pages: Pages,
 }
 
@@ -99,6 +103,9 @@ impl RankingPagination {
             RankingEntries::Amount(ref entries) => {
                 Self::finalize::<_, Amount<'_>>(&mut buf, &mut description, entries, idx)
             }
+            RankingEntries::AmountWithStreak(ref entries) => {
+                Self::finalize::<_, AmountStreak<'_>>(&mut buf, &mut description, entries, idx)
+            }
             RankingEntries::AmountWithNegative(ref entries) => {
                 Self::finalize::<_, AmountWithNegative<'_>>(
                     &mut buf,
@@ -127,9 +134,17 @@ impl RankingPagination {
             }
         };
 
+        if let Some(min_pp) = self.min_pp.filter(|_| self.total == 0) {
+            let _ = write!(description, "No players with at least {min_pp}pp");
+        }
+
         let page = self.pages.curr_page();
         let pages = self.pages.last_page();
-        let footer = self.kind.footer(page, pages, self.author_idx);
+        let mut footer = self.kind.footer(page, pages, self.author_idx);
+
+        if let Some(min_pp) = self.min_pp {
+            let _ = write!(footer.text, " • Min pp: {min_pp}");
+        }
 
         let mut builder = EmbedBuilder::new().description(description).footer(footer);
 
@@ -199,14 +214,14 @@ impl RankingPagination {
         let range = pages.index()..pages.index() + pages.per_page();
         let count = self.entries.entry_count(range);
 
-        if count < pages.per_page() && count < self.total - pages.index() {
+        if count < pages.per_page() && count < self.total.saturating_sub(pages.index()) {
             let offset = page - 1;
             let page = page as u32;
             let kind = &self.kind;
 
             match kind {
                 RankingKind::BgScores { scores, .. } => {
-                    let RankingEntries::Amount(ref mut entries) = self.entries else {
+                    let RankingEntries::AmountWithStreak(ref mut entries) = self.entries else {
                         unreachable!()
                     };
 
@@ -214,7 +229,11 @@ impl RankingPagination {
                     #[allow(clippy::needless_range_loop)]
                     for i in pages.index()..(pages.index() + pages.per_page()).min(self.total) {
                         if let Entry::Vacant(entry) = entries.entry(i) {
-                            let BgGameScore { discord_id, score } = scores[i];
+                            let BgGameScore {
+                                discord_id,
+                                score,
+                                best_streak,
+                            } = scores[i];
                             let id = Id::new(discord_id as u64);
 
                             let mut name_opt = match Context::user_config().osu_name(id).await {
@@ -243,7 +262,10 @@ impl RankingPagination {
                             entry.insert(RankingEntry {
                                 country: None,
                                 name: name_opt.unwrap_or_else(|| "Unknown user".into()),
-                                value: score as u64,
+                                value: AmountWithStreak {
+                                    amount: score as u64,
+                                    streak: best_streak as u32,
+                                },
                             });
                         }
                     }
@@ -280,7 +302,8 @@ impl RankingPagination {
                         (offset * 50 + i, entry)
                     });
 
-                    entries.extend(iter);
+                    // Entries past a min pp cutoff are not part of the list
+                    entries.extend(iter.filter(|(idx, _)| *idx < self.total));
                 }
                 RankingKind::PpGlobal { mode } => {
                     let ranking = Context::redis()
@@ -310,7 +333,8 @@ impl RankingPagination {
                         (offset * 50 + i, entry)
                     });
 
-                    entries.extend(iter);
+                    // Entries past a min pp cutoff are not part of the list
+                    entries.extend(iter.filter(|(idx, _)| *idx < self.total));
                 }
                 RankingKind::RankedScore { mode } => {
                     let ranking = Context::osu()
@@ -425,6 +449,7 @@ macro_rules! formatter {
 formatter! {
     Accuracy<f32>,
     Amount<u64>,
+    AmountStreak<AmountWithStreak>,
     AmountWithNegative<i64>,
     Date<OffsetDateTime>,
     Float<f32>,
@@ -448,6 +473,18 @@ impl Display for Amount<'_> {
     }
 }
 
+impl Display for AmountStreak<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} ({})",
+            Amount::from(&self.inner.amount),
+            self.inner.streak
+        )
+    }
+}
+
 impl Display for AmountWithNegative<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -0,0 +1,125 @@
+use bathbot_util::{AuthorBuilder, EmbedBuilder, constants::OSU_BASE};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+use twilight_model::{
+    channel::message::{
+        Component, EmojiReactionType,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::osu::{ScorepostTitle, global_position},
+    manager::{OsuMap, redis::osu::CachedUser},
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+pub struct ScorepostActive {
+    title: ScorepostTitle,
+    author: AuthorBuilder,
+    map_id: u32,
+    thumbnail: Box<str>,
+    mode: GameMode,
+    legacy_scores: bool,
+    score_id: u64,
+    msg_owner: Id<UserMarker>,
+    defer: bool,
+}
+
+impl ScorepostActive {
+    pub fn new(
+        title: ScorepostTitle,
+        user: &CachedUser,
+        map: &OsuMap,
+        mode: GameMode,
+        legacy_scores: bool,
+        score_id: u64,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            title,
+            author: user.author_builder(false),
+            map_id: map.map_id(),
+            thumbnail: map.thumbnail().into(),
+            mode,
+            legacy_scores,
+            score_id,
+            msg_owner,
+            defer: false,
+        }
+    }
+
+    async fn refresh_rank(&mut self, component: &InteractionComponent) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        if let Err(err) = component.defer().await {
+            warn!(?err, "Failed to defer component");
+        }
+
+        self.defer = true;
+
+        let position_fut =
+            global_position(self.map_id, self.mode, self.legacy_scores, self.score_id);
+
+        match position_fut.await {
+            Ok(global_idx) => self.title.global_idx = global_idx,
+            Err(err) => warn!(?err, "Failed to refresh global position for scorepost"),
+        }
+
+        ComponentResult::BuildPage
+    }
+}
+
+impl IActiveMessage for ScorepostActive {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let embed = EmbedBuilder::new()
+            .author(self.author.clone())
+            .description(format!("```\n{}\n```", self.title))
+            .thumbnail(self.thumbnail.as_ref())
+            .url(format!("{OSU_BASE}b/{}", self.map_id));
+
+        BuildPage::new(embed, self.defer).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let refresh = Button {
+            custom_id: Some("scorepost_refresh".to_owned()),
+            disabled: false,
+            emoji: Some(EmojiReactionType::Unicode {
+                name: "🔄".to_owned(),
+            }),
+            label: Some("Refresh rank".to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        };
+
+        let components = vec![Component::Button(refresh)];
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        match component.data.custom_id.as_str() {
+            "scorepost_refresh" => Box::pin(self.refresh_rank(component)),
+            other => {
+                warn!(name = %other, ?component, "Unknown scorepost component");
+
+                ComponentResult::Ignore.boxed()
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{EmbedBuilder, FooterBuilder, numbers::WithComma};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_gateway::ShardState;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    core::ShardStatsEntry,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct ShardsPagination {
+    #[pagination(per_page = 20)]
+    shards: Box<[(u32, ShardStatsEntry)]>,
+    /// Shard of the guild in which the command was invoked
+    guild_shard: Option<u32>,
+    content: Box<str>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl ShardsPagination {
+    /// Move to the page that contains the shard of the invoking guild.
+    pub fn jump_to_guild_shard(&mut self) {
+        let idx = self
+            .guild_shard
+            .and_then(|guild_shard| self.shards.iter().position(|(id, _)| *id == guild_shard));
+
+        if let Some(idx) = idx {
+            self.pages.jump_to_index(idx);
+        }
+    }
+}
+
+impl IActiveMessage for ShardsPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let end_idx = self.shards.len().min(pages.index() + pages.per_page());
+        let shards = &self.shards[pages.index()..end_idx];
+
+        let mut description = String::with_capacity(shards.len() * 50);
+
+        for (shard_id, entry) in shards {
+            let (emote, state) = match entry.state {
+                ShardState::Active => ("🟢", "Active"),
+                ShardState::Identifying => ("🟡", "Identifying"),
+                ShardState::Resuming => ("🟡", "Resuming"),
+                ShardState::Disconnected { .. } => ("🔴", "Disconnected"),
+                ShardState::FatallyClosed => ("🔴", "Fatally closed"),
+            };
+
+            let latency = match entry.latency {
+                Some(latency) => format!("{}ms", latency.as_millis()),
+                None => "-".to_owned(),
+            };
+
+            let line = format!(
+                "`#{shard_id}` {emote} {state} • {latency} • {events} events",
+                events = WithComma::new(entry.events),
+            );
+
+            if self.guild_shard == Some(*shard_id) {
+                let _ = writeln!(description, "**{line}** ⬅️");
+            } else {
+                let _ = writeln!(description, "{line}");
+            }
+        }
+
+        if description.is_empty() {
+            description.push_str("No shard information available yet");
+        }
+
+        let page = pages.curr_page();
+        let last_page = pages.last_page();
+
+        let mut footer_text = format!(
+            "Page {page}/{last_page} • Total shards: {}",
+            self.shards.len()
+        );
+
+        if let Some(shard_id) = self.guild_shard {
+            let _ = write!(footer_text, " • This server: #{shard_id}");
+        }
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title("Shard health");
+
+        BuildPage::new(embed, false)
+            .content(self.content.clone())
+            .boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
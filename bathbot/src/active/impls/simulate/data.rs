@@ -1,9 +1,14 @@
+use rosu_pp::Performance;
 use rosu_v2::{
     mods,
-    prelude::{GameMod, GameMods},
+    prelude::{GameMod, GameMode, GameMods},
 };
 
-use super::{attrs::SimulateAttributes, state::ScoreState, top_old::TopOldVersion};
+use super::{
+    attrs::SimulateAttributes,
+    state::ScoreState,
+    top_old::{TopOldVersion, generate_state},
+};
 use crate::{
     active::impls::SimulateMap,
     commands::osu::{TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion, TopOldTaikoVersion},
@@ -28,6 +33,8 @@ pub struct SimulateData {
     pub attrs: SimulateAttributes,
     pub max_combo: u32,
     pub set_on_lazer: bool,
+    /// Whether the pp for other mod combinations should be shown too
+    pub compare_mods: bool,
 }
 
 impl SimulateData {
@@ -546,6 +553,50 @@ impl SimulateData {
     }
 }
 
+impl SimulateData {
+    /// Calculate the pp of the same hitresults with a few common mod
+    /// combinations.
+    ///
+    /// Returns `None` if not enabled or the version is not the current one.
+    pub(super) fn mod_comparison(&self, map: &SimulateMap) -> Option<Vec<(&'static str, f32)>> {
+        if !self.compare_mods || !self.version.is_current() {
+            return None;
+        }
+
+        let mode = map.mode();
+
+        let comparisons = match mode {
+            GameMode::Osu | GameMode::Taiko | GameMode::Catch => {
+                vec![("HR", mods!(HR)), ("DT", mods!(DT)), ("HDDT", mods!(HD DT))]
+            }
+            GameMode::Mania => vec![("DT", mods!(DT)), ("HT", mods!(HT))],
+        };
+
+        let pp_map = map.pp_map();
+        let mut state = generate_state(pp_map, self);
+
+        if let Some(combo) = self.combo {
+            state.max_combo = combo;
+        }
+
+        let pps = comparisons
+            .into_iter()
+            .map(|(name, mods)| {
+                let pp = Performance::new(pp_map)
+                    .mods(mods.with_mode(mode))
+                    .lazer(self.set_on_lazer)
+                    .state(state.clone())
+                    .calculate()
+                    .pp();
+
+                (name, pp as f32)
+            })
+            .collect();
+
+        Some(pps)
+    }
+}
+
 fn score_multiplier(mods: &GameMods) -> f32 {
     mods.iter()
         .map(|gamemod| match gamemod {
@@ -253,6 +253,20 @@ impl IActiveMessage for SimulateComponents {
             fields.push(hits);
         }
 
+        if let Some(mod_pps) = self.data.mod_comparison(&self.map) {
+            let mut value = String::new();
+
+            for (name, pp) in mod_pps {
+                if !value.is_empty() {
+                    value.push_str(" • ");
+                }
+
+                let _ = write!(value, "`{name}`: {}pp", round(pp));
+            }
+
+            fields![fields { "PP with other mods", value, false }];
+        }
+
         let map_info = self
             .map
             .map_info(stars, mods.as_ref(), self.data.clock_rate);
@@ -1,8 +1,8 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use rosu_pp::{
-    Beatmap, Performance, catch::CatchScoreState, mania::ManiaScoreState, osu::OsuScoreState,
-    taiko::TaikoScoreState,
+    Beatmap, Performance, any::ScoreState as AnyScoreState, catch::CatchScoreState,
+    mania::ManiaScoreState, osu::OsuScoreState, taiko::TaikoScoreState,
 };
 use twilight_model::channel::message::{
     Component,
@@ -405,48 +405,19 @@ impl TopOldVersion {
         components
     }
 
-    pub(super) fn generate_hitresults(self, map: &Beatmap, data: &SimulateData) -> ScoreState {
-        let mut calc = Performance::new(map).lazer(data.set_on_lazer);
-
-        if let Some(acc) = data.acc {
-            calc = calc.accuracy(acc as f64);
-        }
-
-        if let Some(n_geki) = data.n_geki {
-            calc = calc.n_geki(n_geki);
-        }
-
-        if let Some(n_katu) = data.n_katu {
-            calc = calc.n_katu(n_katu);
-        }
-
-        if let Some(n300) = data.n300 {
-            calc = calc.n300(n300);
-        }
-
-        if let Some(n100) = data.n100 {
-            calc = calc.n100(n100);
-        }
-
-        if let Some(n50) = data.n50 {
-            calc = calc.n50(n50);
-        }
-
-        if let Some(n_miss) = data.n_miss {
-            calc = calc.misses(n_miss);
-        }
-
-        if let Some(n_slider_ends) = data.n_slider_ends {
-            calc = calc
-                .slider_end_hits(n_slider_ends)
-                .small_tick_hits(n_slider_ends);
-        }
-
-        if let Some(n_large_ticks) = data.n_large_ticks {
-            calc = calc.large_tick_hits(n_large_ticks);
-        }
+    /// Whether the version uses the current pp calculation
+    pub(super) fn is_current(self) -> bool {
+        matches!(
+            self,
+            Self::Osu(TopOldOsuVersion::March25Now)
+                | Self::Taiko(TopOldTaikoVersion::March25Now)
+                | Self::Catch(TopOldCatchVersion::October24Now)
+                | Self::Mania(TopOldManiaVersion::October24Now)
+        )
+    }
 
-        let state = calc.generate_state();
+    pub(super) fn generate_hitresults(self, map: &Beatmap, data: &SimulateData) -> ScoreState {
+        let state = generate_state(map, data);
 
         match self {
             Self::Osu(_) => ScoreState::Osu(OsuScoreState {
@@ -485,6 +456,51 @@ impl TopOldVersion {
     }
 }
 
+/// Generate the hitresults of the simulated score, independent of the version.
+pub(super) fn generate_state(map: &Beatmap, data: &SimulateData) -> AnyScoreState {
+    let mut calc = Performance::new(map).lazer(data.set_on_lazer);
+
+    if let Some(acc) = data.acc {
+        calc = calc.accuracy(acc as f64);
+    }
+
+    if let Some(n_geki) = data.n_geki {
+        calc = calc.n_geki(n_geki);
+    }
+
+    if let Some(n_katu) = data.n_katu {
+        calc = calc.n_katu(n_katu);
+    }
+
+    if let Some(n300) = data.n300 {
+        calc = calc.n300(n300);
+    }
+
+    if let Some(n100) = data.n100 {
+        calc = calc.n100(n100);
+    }
+
+    if let Some(n50) = data.n50 {
+        calc = calc.n50(n50);
+    }
+
+    if let Some(n_miss) = data.n_miss {
+        calc = calc.misses(n_miss);
+    }
+
+    if let Some(n_slider_ends) = data.n_slider_ends {
+        calc = calc
+            .slider_end_hits(n_slider_ends)
+            .small_tick_hits(n_slider_ends);
+    }
+
+    if let Some(n_large_ticks) = data.n_large_ticks {
+        calc = calc.large_tick_hits(n_large_ticks);
+    }
+
+    calc.generate_state()
+}
+
 impl Display for TopOldVersion {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -15,7 +15,8 @@ use bathbot_util::{
     constants::{GENERAL_ISSUE, ORDR_ISSUE, OSU_API_ISSUE, OSU_BASE},
     datetime::{HowLongAgoDynamic, HowLongAgoText, SHORT_NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
-    numbers::round,
+    numbers::{self, round},
+    osu::leaderboard_ineligible_mods,
 };
 use eyre::{Report, Result};
 use futures::future::BoxFuture;
@@ -23,7 +24,7 @@ use rosu_pp::model::beatmap::BeatmapAttributes;
 use rosu_render::{ClientError as OrdrError, client::error::ApiError as OrdrApiError};
 use rosu_v2::{
     error::OsuError,
-    model::{GameMode, Grade},
+    model::GameMode,
     prelude::{GameMod, GameMods, RankStatus},
 };
 use time::OffsetDateTime;
@@ -47,15 +48,15 @@ use crate::{
     },
     commands::{
         osu::{OngoingRender, RENDERER_NAME, RenderStatus, RenderStatusInner},
-        utility::{ScoreEmbedData, ScoreEmbedDataWrap},
+        utility::{MapPersonalBest, ScoreEmbedData, ScoreEmbedDataWrap},
     },
     core::{Context, buckets::BucketName},
-    embeds::{HitResultFormatter, attachment},
+    embeds::{HitResultFormatter, ManiaRatioFormatter, attachment},
     manager::{ReplayError, redis::osu::CachedUser},
     util::{
         Authored, CachedUserExt, Emote, MessageExt,
         interaction::{InteractionComponent, InteractionModal},
-        osu::{GradeFormatter, ScoreFormatter},
+        osu::{FailCompletion, GradeFormatter, ScoreFormatter},
     },
 };
 
@@ -68,6 +69,8 @@ pub struct SingleScorePagination {
 
     author: AuthorBuilder,
     content: SingleScoreContent,
+    /// Index of a score alongside its approximated attempt count
+    tries: Option<(usize, usize)>,
 }
 
 impl SingleScorePagination {
@@ -91,6 +94,7 @@ impl SingleScorePagination {
             pages,
             author: user.author_builder(false),
             content,
+            tries: None,
         }
     }
 
@@ -98,26 +102,44 @@ impl SingleScorePagination {
         self.pages.set_index(idx);
     }
 
+    /// Show the attempt count in the footer of the score at the given index.
+    pub fn set_tries(&mut self, idx: usize, tries: usize) {
+        self.tries = Some((idx, tries));
+    }
+
     pub async fn async_build_page(
         &mut self,
         content: Box<str>,
         mark_idx: MarkIndex,
     ) -> Result<BuildPage> {
-        let score = &*self.scores[self.pages.index()].get_mut().await?;
+        let idx = self.pages.index();
+        let score = &*self.scores[idx].get_mut(&self.settings).await?;
 
-        let embed = Self::apply_settings(&self.settings, score, self.score_data, mark_idx);
+        let tries = self
+            .tries
+            .filter(|&(tries_idx, tries)| tries_idx == idx && tries > 1)
+            .map(|(_, tries)| tries);
+
+        let embed = apply_settings(&self.settings, score, self.score_data, mark_idx, tries);
 
         let url = format!("{OSU_BASE}b/{}", score.map.map_id());
 
-        #[allow(unused_mut)]
-        let mut description = if score.pb_idx.is_some() || score.global_idx.is_some() {
+        // The mod leaderboard position is redundant if it matches the global one
+        let mod_idx = score
+            .mod_idx
+            .filter(|idx| self.settings.show_mod_idx && score.global_idx != Some(*idx));
+
+        let has_position =
+            score.pb_idx.is_some() || score.global_idx.is_some() || mod_idx.is_some();
+
+        let mut description = if has_position {
             let mut description = String::with_capacity(25);
             description.push_str("__**");
 
             if let Some(pb_idx) = &score.pb_idx {
                 description.push_str(&pb_idx.formatted);
 
-                if score.global_idx.is_some() {
+                if score.global_idx.is_some() || mod_idx.is_some() {
                     description.reserve(19);
                     description.push_str(" and ");
                 }
@@ -125,6 +147,14 @@ impl SingleScorePagination {
 
             if let Some(idx) = score.global_idx {
                 let _ = write!(description, "Global Top #{idx}");
+
+                if mod_idx.is_some() {
+                    description.push_str(" and ");
+                }
+            }
+
+            if let Some(idx) = mod_idx {
+                let _ = write!(description, "+{} Top #{idx}", score.score.mods);
             }
 
             description.push_str("**__");
@@ -143,6 +173,57 @@ impl SingleScorePagination {
             data.append_to_description(&score.score, &score.map, &mut description);
         }
 
+        if let Some(completion) = FailCompletion::new(&score.score, &score.map) {
+            if !description.is_empty() {
+                description.push('\n');
+            }
+
+            let _ = write!(description, "{completion}");
+
+            if let Some(pp) = score.if_passed_pp {
+                let _ = write!(description, " • if finished: ~{}pp", pp.round());
+            }
+        }
+
+        if let Some((acc, pp)) = score.if_fc_acc.zip(score.if_fc_pp) {
+            if !description.is_empty() {
+                description.push('\n');
+            }
+
+            let _ = write!(description, "FC: {acc:.2}% → {pp:.0}pp");
+        }
+
+        if let Some(percentile) = score.pp_percentile {
+            if !description.is_empty() {
+                description.push('\n');
+            }
+
+            let _ = write!(
+                description,
+                "Better than {}% of top scores",
+                percentile.round()
+            );
+        }
+
+        if let Some(map_pb) = score.map_pb.as_ref().filter(|_| self.settings.show_map_pb) {
+            if !description.is_empty() {
+                description.push('\n');
+            }
+
+            match map_pb {
+                MapPersonalBest::Current => description.push_str("PB on this map ✅"),
+                MapPersonalBest::Previous {
+                    pp: Some(pp),
+                    accuracy,
+                } => {
+                    let _ = write!(description, "Previous best: {pp:.2}pp ({accuracy:.2}%)");
+                }
+                MapPersonalBest::Previous { pp: None, accuracy } => {
+                    let _ = write!(description, "Previous best: {accuracy:.2}%");
+                }
+            }
+        }
+
         let builder = embed
             .author(self.author.clone())
             .description(description)
@@ -157,7 +238,7 @@ impl SingleScorePagination {
         score_data: ScoreData,
         mark_idx: MarkIndex,
     ) -> EmbedBuilder {
-        apply_settings(settings, data, score_data, mark_idx)
+        apply_settings(settings, data, score_data, mark_idx, None)
     }
 
     async fn async_handle_component(
@@ -188,7 +269,10 @@ impl SingleScorePagination {
         &mut self,
         component: &InteractionComponent,
     ) -> ComponentResult {
-        let data = match self.scores[self.pages.index()].get_mut().await {
+        let data = match self.scores[self.pages.index()]
+            .get_mut(&self.settings)
+            .await
+        {
             Ok(data) => data,
             Err(err) => return ComponentResult::Err(err),
         };
@@ -220,7 +304,10 @@ impl SingleScorePagination {
     }
 
     async fn handle_render_button(&mut self, component: &InteractionComponent) -> ComponentResult {
-        let data = match self.scores[self.pages.index()].get_mut().await {
+        let data = match self.scores[self.pages.index()]
+            .get_mut(&self.settings)
+            .await
+        {
             Ok(data) => data,
             Err(err) => return ComponentResult::Err(err),
         };
@@ -240,14 +327,14 @@ impl SingleScorePagination {
                 let channel_id = component.message.channel_id;
 
                 // Spawn in new task so that we're sure to callback the component in time
-                tokio::spawn(async move {
+                tokio::spawn(numbers::keep_separators(async move {
                     let cached = CachedRender::new(score_id, video_url, owner);
                     let begin_fut = ActiveMessages::builder(cached).begin(channel_id);
 
                     if let Err(err) = begin_fut.await {
                         error!(?err, "Failed to begin cached render message");
                     }
-                });
+                }));
 
                 return ComponentResult::BuildPage;
             }
@@ -262,13 +349,13 @@ impl SingleScorePagination {
             return self.render_cooldown_response(component, cooldown).await;
         }
 
-        tokio::spawn(Self::render_response(
+        tokio::spawn(numbers::keep_separators(Self::render_response(
             (component.message.id, component.message.channel_id),
             component.permissions,
             score_id,
             owner,
             component.guild_id,
-        ));
+        )));
 
         ComponentResult::BuildPage
     }
@@ -547,6 +634,7 @@ fn apply_settings(
     data: &ScoreEmbedData,
     score_data: ScoreData,
     mark_idx: MarkIndex,
+    tries: Option<usize>,
 ) -> EmbedBuilder {
     const SEP_NAME: &str = "\t";
     const SEP_VALUE: &str = " • ";
@@ -936,7 +1024,16 @@ fn apply_settings(
         }
     }
 
-    let fields = fields![field_name, field_value, false];
+    let mut fields = fields![field_name, field_value, false];
+
+    let ineligible_mods = leaderboard_ineligible_mods(&data.score.mods, data.score.mode);
+
+    if !ineligible_mods.is_empty() {
+        let value =
+            format!("Scores with `{ineligible_mods}` won't appear on the global leaderboards");
+
+        fields![fields { ":warning: Not eligible for leaderboards", value, false }];
+    }
 
     let mut title = String::with_capacity(32);
 
@@ -966,6 +1063,14 @@ fn apply_settings(
         SettingsImage::Hide => {}
     }
 
+    if let Some(tries) = tries {
+        if footer_text.is_empty() {
+            let _ = write!(footer_text, "Try #{tries}");
+        } else {
+            footer_text.insert_str(0, &format!("Try #{tries} • "));
+        }
+    }
+
     if !footer_text.is_empty() {
         let emote = Emote::from(data.score.mode).url();
         let footer = FooterBuilder::new(footer_text).icon_url(emote);
@@ -1002,21 +1107,8 @@ fn write_value(
                 )
             };
 
-            // The completion is very hard to calculate for `Catch` because
-            // `n_objects` is not correct due to juicestreams so we won't
-            // show it for that mode.
-            let is_fail = data.score.grade == Grade::F && data.score.mode != GameMode::Catch;
-
-            if is_fail {
-                let n_objects = data.map.n_objects();
-
-                let completion = if n_objects != 0 {
-                    100 * data.score.total_hits() / n_objects
-                } else {
-                    100
-                };
-
-                let _ = write!(writer, "@{completion}%");
+            if let Some(completion) = FailCompletion::new(&data.score, &data.map) {
+                let _ = write!(writer, "@{}%", completion.percent);
             }
         }
         Value::Mods => {
@@ -1106,17 +1198,13 @@ fn write_value(
             };
         }
         Value::Ratio => {
-            let mut ratio = data.score.statistics.perfect as f32;
-
-            let against: u8 = if data.score.statistics.great > 0 {
-                ratio /= data.score.statistics.great as f32;
+            let ratio = ManiaRatioFormatter::new(&data.score.statistics);
 
-                1
+            let _ = if data.score.statistics.great > 0 {
+                write!(writer, "{ratio}:1")
             } else {
-                0
+                write!(writer, "{ratio}")
             };
-
-            let _ = write!(writer, "{ratio:.2}:{against}");
         }
         Value::Stars => {
             let _ = write!(writer, "{}★", round(data.stars));
@@ -0,0 +1,138 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_model::SnipeRecent;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE, datetime::HowLongAgoDynamic,
+    numbers::round, osu::flag_url,
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::CountryCode;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    commands::osu::SnipeWindow,
+    embeds::ModsFormatter,
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct SnipeCountryFeedPagination {
+    country: String,
+    country_code: CountryCode,
+    window: SnipeWindow,
+    #[pagination(per_page = 10)]
+    scores: Box<[SnipeRecent]>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for SnipeCountryFeedPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let mut description = String::with_capacity(512);
+
+        let scores = self
+            .scores
+            .iter()
+            .zip(1..)
+            .skip(pages.index())
+            .take(pages.per_page());
+
+        for (score, idx) in scores {
+            let mods = score.mods.as_ref().map(Cow::Borrowed).unwrap_or_default();
+
+            let _ = writeln!(
+                description,
+                "**#{idx} [{artist} - {title} [{version}]]({OSU_BASE}b/{id}) {mods}**",
+                artist = score.artist.cow_escape_markdown(),
+                title = score.title.cow_escape_markdown(),
+                version = score.version.cow_escape_markdown(),
+                id = score.map_id,
+                mods = ModsFormatter::new(mods.as_ref()),
+            );
+
+            if let Some(stars) = score.stars {
+                let _ = write!(description, "[{stars:.2}★] • ");
+            }
+
+            let _ = write!(description, "{}% • ", round(score.accuracy));
+
+            let _ = match score.sniper.as_deref() {
+                Some(name) => write!(
+                    description,
+                    "[{name}]({OSU_BASE}u/{user_id}) ",
+                    name = name.cow_escape_markdown(),
+                    user_id = score.sniper_id,
+                ),
+                None => write!(
+                    description,
+                    "[<user {user_id}>]({OSU_BASE}u/{user_id}) ",
+                    user_id = score.sniper_id,
+                ),
+            };
+
+            let _ = match score.sniped.as_deref().zip(score.sniped_id) {
+                Some((name, user_id)) => write!(
+                    description,
+                    "sniped [{name}]({OSU_BASE}u/{user_id}) ",
+                    name = name.cow_escape_markdown(),
+                ),
+                None => write!(description, "claimed it "),
+            };
+
+            if let Some(ref date) = score.date {
+                let _ = write!(description, "{}", HowLongAgoDynamic::new(date));
+            } else {
+                description.push_str("<unknown date>");
+            }
+
+            description.push('\n');
+        }
+
+        description.pop();
+
+        let title = format!("New national #1s in {} {}", self.country, self.window);
+
+        let footer = FooterBuilder::new(format!(
+            "Page {}/{} • Total: {}",
+            self.pages.curr_page(),
+            self.pages.last_page(),
+            self.scores.len()
+        ));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .thumbnail(flag_url(self.country_code.as_str()))
+            .title(title);
+
+        BuildPage::new(embed, true).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, true, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, true, &mut self.pages)
+    }
+}
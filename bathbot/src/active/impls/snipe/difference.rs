@@ -23,7 +23,7 @@ use crate::{
         BuildPage, ComponentResult, IActiveMessage,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
-    commands::osu::Difference,
+    commands::osu::{Difference, SnipeWindow},
     core::Context,
     embeds::ModsFormatter,
     manager::redis::osu::CachedUser,
@@ -37,6 +37,7 @@ use crate::{
 pub struct SnipeDifferencePagination {
     user: CachedUser,
     diff: Difference,
+    window: SnipeWindow,
     #[pagination(per_page = 10)]
     scores: Box<[SnipeRecent]>,
     star_map: HashMap<u32, f32, IntHasher>,
@@ -155,8 +156,8 @@ impl SnipeDifferencePagination {
         description.pop();
 
         let title = match self.diff {
-            Difference::Gain => "New national #1s since last week",
-            Difference::Loss => "Lost national #1s since last week",
+            Difference::Gain => format!("New national #1s {}", self.window),
+            Difference::Loss => format!("Lost national #1s {}", self.window),
         };
 
         let footer = FooterBuilder::new(format!(
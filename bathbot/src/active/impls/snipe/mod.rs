@@ -1,8 +1,9 @@
 pub use self::{
-    country_list::SnipeCountryListPagination, difference::SnipeDifferencePagination,
-    player_list::SnipePlayerListPagination,
+    country_feed::SnipeCountryFeedPagination, country_list::SnipeCountryListPagination,
+    difference::SnipeDifferencePagination, player_list::SnipePlayerListPagination,
 };
 
+mod country_feed;
 mod country_list;
 mod difference;
 mod player_list;
@@ -1,13 +1,16 @@
-use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    mem,
+};
 
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
-    constants::OSU_BASE,
+    constants::{OSU_API_ISSUE, OSU_BASE},
     datetime::HowLongAgoDynamic,
     numbers::{WithComma, round},
 };
-use eyre::Result;
+use eyre::{Report, Result};
 use futures::future::BoxFuture;
 use rosu_v2::prelude::GameMode;
 use time::OffsetDateTime;
@@ -19,16 +22,19 @@ use twilight_model::{
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        mode_select::{mode_select_component, selected_mode},
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::{
-        osu::TopScoreOrder,
+        osu::{TopModeSwitch, TopScoreOrder},
         utility::{ScoreEmbedDataHalf, ScoreEmbedDataWrap},
     },
-    embeds::{ComboFormatter, HitResultFormatter, PpFormatter},
+    embeds::{
+        ComboFormatter, HitResultFormatter, ManiaRatioFormatter, PpFormatter, mania_accuracy,
+    },
     manager::{OsuMap, redis::osu::CachedUser},
     util::{
-        CachedUserExt, Emote,
+        Authored, CachedUserExt, ComponentExt, Emote,
         interaction::{InteractionComponent, InteractionModal},
         osu::{GradeFormatter, ScoreFormatter},
     },
@@ -42,7 +48,10 @@ pub struct TopPagination {
     condensed_list: bool,
     score_data: ScoreData,
     content: Box<str>,
+    mode_switch: Option<TopModeSwitch>,
     msg_owner: Id<UserMarker>,
+    highlight: Option<usize>,
+    defer_next: bool,
     pages: Pages,
 }
 
@@ -56,11 +65,74 @@ impl TopPagination {
             condensed_list: None,
             score_data: None,
             content: None,
+            mode_switch: None,
             msg_owner: None,
+            start_index: None,
+        }
+    }
+
+    async fn switch_mode(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(ref mode_switch) = self.mode_switch else {
+            return ComponentResult::Ignore;
+        };
+
+        let mode = match selected_mode(component) {
+            Ok(mode) => mode,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if let Err(err) = component.defer().await {
+            return ComponentResult::Err(Report::new(err).wrap_err("Failed to defer component"));
+        }
+
+        self.defer_next = true;
+
+        if mode == self.mode {
+            return ComponentResult::BuildPage;
+        }
+
+        let data = match mode_switch.fetch(mode).await {
+            Ok(data) => data,
+            Err(err) => {
+                warn!(?err, %mode, "Failed to switch top mode");
+
+                if let Err(err) = component.error_followup(OSU_API_ISSUE).await {
+                    warn!(?err, "Failed to send error followup");
+                }
+
+                // Rebuild the previous page
+                return ComponentResult::BuildPage;
+            }
+        };
+
+        self.pages = Pages::new(self.pages.per_page(), data.entries.len());
+        self.user = data.user;
+        self.mode = mode;
+        self.entries = data.entries;
+        self.content = data.content;
+        self.highlight = None;
+
+        ComponentResult::BuildPage
+    }
+
+    fn highlight_markdown(&self, idx: usize) -> &'static str {
+        if self.highlight == Some(idx) {
+            "__"
+        } else {
+            ""
         }
     }
 
-    fn build_condensed(&self) -> BuildPage {
+    fn build_condensed(&self, defer: bool) -> BuildPage {
         let pages = &self.pages;
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
 
@@ -85,13 +157,13 @@ impl TopPagination {
             .footer(FooterBuilder::new(footer_text))
             .thumbnail(self.user.avatar_url.as_ref());
 
-        BuildPage::new(embed, false).content(self.content.clone())
+        BuildPage::new(embed, defer).content(self.content.clone())
     }
 
     fn condensed_description(&self, entries: &[ScoreEmbedDataWrap]) -> String {
         let mut description = String::with_capacity(1024);
 
-        for entry in entries {
+        for (entry, i) in entries.iter().zip(self.pages.index()..) {
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -106,12 +178,13 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
+                "**{highlight}#{idx}{highlight} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
                 {grade} **{pp}pp** ({acc}%) [**{combo}x**/{max_combo}x] {miss}**+{mods}** {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
                     .expect("missing idx")
                     + 1,
+                highlight = self.highlight_markdown(i),
                 map = MapFormat::new(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
@@ -141,7 +214,7 @@ impl TopPagination {
     fn condensed_description_mania(&self, entries: &[ScoreEmbedDataWrap]) -> String {
         let mut description = String::with_capacity(1024);
 
-        for entry in entries {
+        for (entry, i) in entries.iter().zip(self.pages.index()..) {
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -157,24 +230,26 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
-                {grade} **{pp}pp** {acc}% `{score}` {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
+                "**{highlight}#{idx}{highlight} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
+                {grade} **{pp}pp** {acc}% `{score}` [**{ratio}**] {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
                     .expect("missing idx")
                     + 1,
+                highlight = self.highlight_markdown(i),
                 map = MapFormat::new(map),
                 map_id = map.map_id(),
                 stars = round(*stars),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = round(score.pp),
                 acc = if self.sort_by == TopScoreOrder::Acc {
-                    round_5(score.accuracy)
+                    round_5(mania_accuracy(stats))
                 } else {
-                    round(score.accuracy)
+                    round(mania_accuracy(stats))
                 },
                 // currently ignoring classic scoring, should it be considered for mania?
                 score = ScoreFormat(score.score),
+                ratio = ManiaRatioFormatter::new(stats),
                 n320 = stats.perfect,
                 n300 = stats.great,
                 miss = stats.miss,
@@ -192,14 +267,14 @@ impl TopPagination {
         description
     }
 
-    fn build_detailed(&self) -> BuildPage {
+    fn build_detailed(&self, defer: bool) -> BuildPage {
         let pages = &self.pages;
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
         let scores = &self.entries[pages.index()..end_idx];
 
         let mut description = String::with_capacity(512);
 
-        for entry in scores {
+        for (entry, i) in scores.iter().zip(self.pages.index()..) {
             let entry = entry.get_half();
 
             let ScoreEmbedDataHalf {
@@ -213,14 +288,29 @@ impl TopPagination {
                 ..
             } = entry;
 
+            // Mania players care more about the ratio than the combo
+            let (acc, combo) = if score.mode == GameMode::Mania {
+                let ratio = ManiaRatioFormatter::new(&score.statistics);
+
+                (
+                    mania_accuracy(&score.statistics),
+                    format!("Ratio **{ratio}**"),
+                )
+            } else {
+                let combo = ComboFormatter::new(score.max_combo, Some(*max_combo));
+
+                (score.accuracy, combo.to_string())
+            };
+
             let _ = writeln!(
                 description,
-                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
+                "**{highlight}#{idx}{highlight} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
                 {grade} {pp} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
                     .expect("missing idx")
                     + 1,
+                highlight = self.highlight_markdown(i),
                 title = map.title().cow_escape_markdown(),
                 version = map.version().cow_escape_markdown(),
                 id = map.map_id(),
@@ -228,12 +318,11 @@ impl TopPagination {
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
                 acc = if self.sort_by == TopScoreOrder::Acc {
-                    round_5(score.accuracy)
+                    round_5(acc)
                 } else {
-                    round(score.accuracy)
+                    round(acc)
                 },
                 score = ScoreFormatter::new(score, self.score_data),
-                combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
                 hits = HitResultFormatter::new(score.mode, &score.statistics),
                 appendix = OrderAppendix::new(
                     self.sort_by,
@@ -260,28 +349,39 @@ impl TopPagination {
             .footer(FooterBuilder::new(footer_text))
             .thumbnail(self.user.avatar_url.as_ref());
 
-        BuildPage::new(embed, false).content(self.content.clone())
+        BuildPage::new(embed, defer).content(self.content.clone())
     }
 }
 
 impl IActiveMessage for TopPagination {
     fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let defer = mem::replace(&mut self.defer_next, false);
+
         if self.condensed_list {
-            self.build_condensed().boxed()
+            self.build_condensed(defer).boxed()
         } else {
-            self.build_detailed().boxed()
+            self.build_detailed(defer).boxed()
         }
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        if self.mode_switch.is_some() {
+            components.push(mode_select_component("top_mode", self.mode));
+        }
+
+        components
     }
 
     fn handle_component<'a>(
         &'a mut self,
         component: &'a mut InteractionComponent,
     ) -> BoxFuture<'a, ComponentResult> {
-        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+        match component.data.custom_id.as_str() {
+            "top_mode" => Box::pin(self.switch_mode(component)),
+            _ => handle_pagination_component(component, self.msg_owner, false, &mut self.pages),
+        }
     }
 
     fn handle_modal<'a>(
@@ -300,7 +400,9 @@ pub struct TopPaginationBuilder {
     condensed_list: Option<bool>,
     score_data: Option<ScoreData>,
     content: Option<Box<str>>,
+    mode_switch: Option<TopModeSwitch>,
     msg_owner: Option<Id<UserMarker>>,
+    start_index: Option<usize>,
 }
 
 impl TopPaginationBuilder {
@@ -312,14 +414,21 @@ impl TopPaginationBuilder {
         let condensed_list = self.condensed_list.expect("missing condensed_list");
         let score_data = self.score_data.expect("missing score_data");
         let content = self.content.take().expect("missing content");
+        let mode_switch = self.mode_switch.take();
         let msg_owner = self.msg_owner.expect("missing msg_owner");
 
-        let pages = if condensed_list {
+        let start_index = self.start_index.take();
+
+        let mut pages = if condensed_list {
             Pages::new(10, entries.len())
         } else {
             Pages::new(5, entries.len())
         };
 
+        if let Some(idx) = start_index {
+            pages.jump_to_index(idx);
+        }
+
         TopPagination {
             user,
             mode,
@@ -328,7 +437,10 @@ impl TopPaginationBuilder {
             condensed_list,
             score_data,
             content,
+            mode_switch,
             msg_owner,
+            highlight: start_index,
+            defer_next: false,
             pages,
         }
     }
@@ -375,11 +487,25 @@ impl TopPaginationBuilder {
         self
     }
 
+    /// Allow switching modes through a select menu
+    pub fn mode_switch(&mut self, mode_switch: TopModeSwitch) -> &mut Self {
+        self.mode_switch = Some(mode_switch);
+
+        self
+    }
+
     pub fn msg_owner(&mut self, msg_owner: Id<UserMarker>) -> &mut Self {
         self.msg_owner = Some(msg_owner);
 
         self
     }
+
+    /// Start on the page of the entry at the given index and highlight it
+    pub fn start_index(&mut self, start_index: usize) -> &mut Self {
+        self.start_index = Some(start_index);
+
+        self
+    }
 }
 
 fn mode_str(mode: GameMode) -> &'static str {
@@ -66,7 +66,7 @@ impl IActiveMessage for TopIfPagination {
             let _ = writeln!(
                 description,
                 "**#{original_idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
-                {grade} {old_pp:.2} → {pp} • {acc}% • {score}\n\
+                {grade} {old_pp:.2} → {pp} ({pp_diff:+.2}) • {acc}% • {score}\n\
                 [ {combo} ] • {hits} • {ago}",
                 title = map.title().cow_escape_markdown(),
                 version = map.version().cow_escape_markdown(),
@@ -75,6 +75,7 @@ impl IActiveMessage for TopIfPagination {
                 grade =
                     GradeFormatter::new(score.grade, Some(score.score_id), entry.score.is_legacy()),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
+                pp_diff = score.pp - old_pp,
                 acc = round(score.accuracy),
                 score = WithComma::new(score.score),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
@@ -0,0 +1,274 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter,
+    constants::OSU_BASE,
+    numbers::{WithComma, round},
+};
+use eyre::Result;
+use futures::future::BoxFuture;
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, async_handle_pagination_component, handle_pagination_modal},
+    },
+    commands::utility::{ScoreEmbedDataHalf, ScoreEmbedDataWrap},
+    manager::redis::osu::CachedUser,
+    util::{
+        Authored, CachedUserExt,
+        interaction::{InteractionComponent, InteractionModal},
+        osu::{GradeFormatter, ScoreFormatter},
+    },
+};
+
+/// Value of the menu option to return to the mapper overview
+const BACK_VALUE: &str = "back";
+
+pub struct TopMapperEntry {
+    pub name: Box<str>,
+    pub weighted_pp: f32,
+    pub scores: Box<[ScoreEmbedDataWrap]>,
+}
+
+pub struct TopMapperPagination {
+    user: CachedUser,
+    mappers: Box<[TopMapperEntry]>,
+    score_data: ScoreData,
+    content: Box<str>,
+    msg_owner: Id<UserMarker>,
+    /// Index of the mapper whose scores are currently shown
+    selected: Option<usize>,
+    pages: Pages,
+}
+
+impl IActiveMessage for TopMapperPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let page = match self.selected {
+            Some(idx) => self.build_mapper_scores(idx),
+            None => self.build_overview(),
+        };
+
+        Box::pin(async { Ok(page) })
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let options = self
+            .selected
+            .map(|_| SelectMenuOption {
+                default: false,
+                description: Some("Return to the mapper overview".to_owned()),
+                emoji: None,
+                label: "Back".to_owned(),
+                value: BACK_VALUE.to_owned(),
+            })
+            .into_iter()
+            .chain(
+                self.mappers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, mapper)| SelectMenuOption {
+                        default: self.selected == Some(i),
+                        description: Some(format!(
+                            "{} score{}",
+                            mapper.scores.len(),
+                            if mapper.scores.len() == 1 { "" } else { "s" }
+                        )),
+                        emoji: None,
+                        label: mapper.name.as_ref().to_owned(),
+                        value: i.to_string(),
+                    }),
+            )
+            .collect();
+
+        let menu = SelectMenu {
+            custom_id: "top_mapper_menu".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: Some(options),
+            placeholder: Some("Show the scores of a mapper".to_owned()),
+            channel_types: None,
+            default_values: None,
+            kind: SelectMenuType::Text,
+        };
+
+        let mut components = self.pages.components();
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        }));
+
+        components
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        Box::pin(self.async_handle_component(component))
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
+
+impl TopMapperPagination {
+    /// Amount of scores per page when a mapper is selected
+    const SCORES_PER_PAGE: usize = 10;
+
+    pub fn new(
+        user: CachedUser,
+        mappers: Box<[TopMapperEntry]>,
+        score_data: ScoreData,
+        content: Box<str>,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        Self {
+            pages: Pages::new(mappers.len().max(1), mappers.len()),
+            user,
+            mappers,
+            score_data,
+            content,
+            msg_owner,
+            selected: None,
+        }
+    }
+
+    fn build_overview(&self) -> BuildPage {
+        let mut description = String::with_capacity(self.mappers.len() * 48);
+
+        for (mapper, i) in self.mappers.iter().zip(1..) {
+            let count = mapper.scores.len();
+
+            let _ = writeln!(
+                description,
+                "**{i}.** `{name}`: {count} score{plural}, {pp}pp weighted",
+                name = mapper.name,
+                plural = if count == 1 { "" } else { "s" },
+                pp = WithComma::new(round(mapper.weighted_pp)),
+            );
+        }
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(false))
+            .description(description)
+            .footer(FooterBuilder::new("Select a mapper to see their scores"))
+            .thumbnail(self.user.avatar_url.as_ref());
+
+        BuildPage::new(embed, false).content(self.content.clone())
+    }
+
+    fn build_mapper_scores(&self, idx: usize) -> BuildPage {
+        let mapper = &self.mappers[idx];
+        let pages = &self.pages;
+        let end_idx = mapper.scores.len().min(pages.index() + pages.per_page());
+
+        let mut description = String::with_capacity(1024);
+
+        for entry in mapper.scores[pages.index()..end_idx].iter() {
+            let ScoreEmbedDataHalf {
+                score,
+                map,
+                stars,
+                pb_idx,
+                ..
+            } = entry.get_half();
+
+            let _ = writeln!(
+                description,
+                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{map_id}) +{mods}** [{stars:.2}★]\n\
+                {grade} **{pp}pp** ({acc}%) • {score}",
+                idx = pb_idx
+                    .as_ref()
+                    .and_then(|idx| idx.idx)
+                    .map_or(0, |idx| idx + 1),
+                title = map.title().cow_escape_markdown(),
+                version = map.version().cow_escape_markdown(),
+                map_id = map.map_id(),
+                mods = ModsFormatter::new(&score.mods),
+                grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
+                pp = round(score.pp),
+                acc = round(score.accuracy),
+                score = ScoreFormatter::new(score, self.score_data),
+            );
+        }
+
+        let count = mapper.scores.len();
+
+        let footer = format!(
+            "Page {}/{} • {count} score{} • {}pp weighted",
+            pages.curr_page(),
+            pages.last_page(),
+            if count == 1 { "" } else { "s" },
+            WithComma::new(round(mapper.weighted_pp)),
+        );
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(false))
+            .description(description)
+            .footer(FooterBuilder::new(footer))
+            .thumbnail(self.user.avatar_url.as_ref())
+            .title(format!("Scores on maps by {}", mapper.name));
+
+        BuildPage::new(embed, false).content(self.content.clone())
+    }
+
+    async fn async_handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        if component.data.custom_id != "top_mapper_menu" {
+            return async_handle_pagination_component(
+                component,
+                self.msg_owner,
+                false,
+                &mut self.pages,
+            )
+            .await
+            .unwrap_or_else(ComponentResult::Err);
+        }
+
+        let Some(value) = component.data.values.pop() else {
+            return ComponentResult::Err(eyre!("Missing value in top mapper menu"));
+        };
+
+        if value == BACK_VALUE {
+            self.selected = None;
+            self.pages = Pages::new(self.mappers.len().max(1), self.mappers.len());
+
+            return ComponentResult::BuildPage;
+        }
+
+        let idx = match value.parse::<usize>() {
+            Ok(idx) if idx < self.mappers.len() => idx,
+            _ => return ComponentResult::Err(eyre!("Invalid top mapper menu value `{value}`")),
+        };
+
+        self.selected = Some(idx);
+        self.pages = Pages::new(Self::SCORES_PER_PAGE, self.mappers[idx].scores.len());
+
+        ComponentResult::BuildPage
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_psql::model::osu::DbNotableScore;
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, constants::OSU_BASE, numbers::round};
+use eyre::Result;
+use futures::future::BoxFuture;
+use rosu_v2::prelude::GameMode;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+#[derive(PaginationBuilder)]
+pub struct WeeklyBestPagination {
+    #[pagination(per_page = 10)]
+    scores: Box<[DbNotableScore]>,
+    mode: Option<GameMode>,
+    min_pp: Option<f32>,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+impl IActiveMessage for WeeklyBestPagination {
+    fn build_page(&mut self) -> BoxFuture<'_, Result<BuildPage>> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let limit = self.scores.len().min(idx + pages.per_page());
+
+        let mut description = String::with_capacity(1024);
+
+        for (score, i) in self.scores[idx..limit].iter().zip(idx + 1..) {
+            let _ = writeln!(
+                description,
+                "**{i}.** [{map}]({OSU_BASE}b/{map_id}) **+{mods}**\n\
+                [{name}]({OSU_BASE}u/{user_id}) • **{pp:.2}pp** • {acc}% • <t:{timestamp}:R>",
+                map = score.map_title.cow_escape_markdown(),
+                map_id = score.map_id,
+                mods = score.mods,
+                name = score.username.cow_escape_markdown(),
+                user_id = score.user_id,
+                pp = score.pp,
+                acc = round(score.accuracy),
+                timestamp = score.ended_at.unix_timestamp(),
+            );
+        }
+
+        let mut title = String::from("Best scores of the past week");
+
+        match self.mode {
+            Some(GameMode::Osu) => title.push_str(" (osu!)"),
+            Some(GameMode::Taiko) => title.push_str(" (taiko)"),
+            Some(GameMode::Catch) => title.push_str(" (catch)"),
+            Some(GameMode::Mania) => title.push_str(" (mania)"),
+            None => {}
+        }
+
+        let mut footer_text = format!("Page {}/{}", pages.curr_page(), pages.last_page());
+
+        if let Some(min_pp) = self.min_pp {
+            let _ = write!(footer_text, " • At least {min_pp}pp");
+        }
+
+        footer_text.push_str(" • Only includes tracked users");
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title(title);
+
+        BuildPage::new(embed, false).boxed()
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    fn handle_component<'a>(
+        &'a mut self,
+        component: &'a mut InteractionComponent,
+    ) -> BoxFuture<'a, ComponentResult> {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages)
+    }
+
+    fn handle_modal<'a>(
+        &'a mut self,
+        modal: &'a mut InteractionModal,
+    ) -> BoxFuture<'a, Result<()>> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages)
+    }
+}
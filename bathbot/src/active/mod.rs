@@ -20,30 +20,34 @@ use self::{
     impls::{
         BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
         ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
-        CompareTopPagination, HelpInteractionCommand, HelpPrefixMenu, HigherLowerGame,
-        LeaderboardPagination, MapPagination, MapSearchPagination, MatchComparePagination,
-        MatchCostPagination, MedalCountPagination, MedalRarityPagination, MedalsCommonPagination,
-        MedalsListPagination, MedalsMissingPagination, MedalsRecentPagination,
-        MostPlayedPagination, NoChokePagination, OsuStatsBestPagination, OsuStatsPlayersPagination,
-        OsuStatsScoresPagination, ProfileMenu, RankingCountriesPagination, RankingPagination,
-        RecentListPagination, RenderSettingsActive, ScoreEmbedBuilderActive, SettingsImport,
+        CompareTopPagination, FindMapPagination, HelpInteractionCommand, HelpPrefixMenu,
+        HigherLowerGame, LeaderboardPagination, MapPagination, MapSearchPagination,
+        MatchComparePagination, MatchCostPagination, MedalCountPagination, MedalRarityPagination,
+        MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
+        MedalsRecentPagination, MostPlayedPagination, NewsPagination, NoChokePagination,
+        OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
+        RankingCountriesPagination, RankingPagination, RecentListPagination, RenderSettingsActive,
+        ScoreEmbedBuilderActive, ScorepostActive, SettingsImport, ShardsPagination,
         SimulateComponents, SingleScorePagination, SkinsPagination, SlashCommandsPagination,
         SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
-        TopIfPagination, TopPagination, TrackListPagination,
+        TopIfPagination, TopMapperPagination, TopPagination, TrackListPagination,
+        WeeklyBestPagination,
     },
     response::ActiveResponse,
 };
 use crate::{
     core::{BotMetrics, Context, EventKind},
     util::{
-        ComponentExt, ModalExt,
+        Authored, ComponentExt, ModalExt,
         interaction::{InteractionComponent, InteractionModal},
+        locale,
     },
 };
 
 pub mod impls;
 
 mod builder;
+mod mode_select;
 mod origin;
 mod pagination;
 mod response;
@@ -58,6 +62,7 @@ pub enum ActiveMessage {
     CompareMostPlayedPagination,
     CompareScoresPagination,
     CompareTopPagination,
+    FindMapPagination,
     HelpInteractionCommand,
     HelpPrefixMenu,
     HigherLowerGame,
@@ -73,6 +78,7 @@ pub enum ActiveMessage {
     MedalsMissingPagination,
     MedalsRecentPagination,
     MostPlayedPagination,
+    NewsPagination,
     NoChokePagination,
     OsuStatsBestPagination,
     OsuStatsPlayersPagination,
@@ -83,7 +89,9 @@ pub enum ActiveMessage {
     RecentListPagination,
     RenderSettingsActive,
     ScoreEmbedBuilderActive,
+    ScorepostActive,
     SettingsImport,
+    ShardsPagination,
     SimulateComponents,
     SingleScorePagination,
     SkinsPagination,
@@ -93,7 +101,9 @@ pub enum ActiveMessage {
     SnipePlayerListPagination,
     TopPagination,
     TopIfPagination,
+    TopMapperPagination,
     TrackListPagination,
+    WeeklyBestPagination,
 }
 
 struct FullActiveMessage {
@@ -160,6 +170,10 @@ impl ActiveMessages {
                             builder = builder.content(content.as_ref());
                         }
 
+                        if let Some((name, bytes)) = build.attachment {
+                            builder = builder.attachment(name, bytes);
+                        }
+
                         if build.defer {
                             if let Err(err) = component.update(builder).await {
                                 BotMetrics::inc_command_error(
@@ -226,7 +240,10 @@ impl ActiveMessages {
             }
         }
 
-        handle_component_inner(active_msg, activity_tx, &mut component).await;
+        let user_id = component.user_id().ok();
+        let guild_id = component.guild_id;
+        let inner_fut = handle_component_inner(active_msg, activity_tx, &mut component);
+        locale::with_number_format(user_id, guild_id, inner_fut).await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("component", component.data.custom_id, elapsed);
@@ -271,6 +288,10 @@ impl ActiveMessages {
                         builder = builder.content(content.as_ref());
                     }
 
+                    if let Some((name, bytes)) = build.attachment {
+                        builder = builder.attachment(name, bytes);
+                    }
+
                     if build.defer {
                         if let Err(err) = modal.update(builder).await {
                             BotMetrics::inc_command_error("modal", modal.data.custom_id.clone());
@@ -305,7 +326,10 @@ impl ActiveMessages {
             }
         }
 
-        handle_modal_inner(active_msg, activity_tx, &mut modal).await;
+        let user_id = modal.user_id().ok();
+        let guild_id = modal.guild_id;
+        let inner_fut = handle_modal_inner(active_msg, activity_tx, &mut modal);
+        locale::with_number_format(user_id, guild_id, inner_fut).await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("modal", modal.data.custom_id, elapsed);
@@ -401,6 +425,7 @@ pub struct BuildPage {
     embed: EmbedBuilder,
     defer: bool,
     content: Option<Box<str>>,
+    attachment: Option<(String, Vec<u8>)>,
 }
 
 impl BuildPage {
@@ -409,6 +434,7 @@ impl BuildPage {
             embed,
             defer,
             content: None,
+            attachment: None,
         }
     }
 
@@ -424,6 +450,13 @@ impl BuildPage {
         self
     }
 
+    /// Replace the message's attachment with the given one
+    pub fn attachment(mut self, name: impl Into<String>, bytes: Vec<u8>) -> Self {
+        self.attachment = Some((name.into(), bytes));
+
+        self
+    }
+
     pub fn into_embed(self) -> EmbedBuilder {
         self.embed
     }
@@ -0,0 +1,59 @@
+use eyre::{ContextCompat, Result};
+use rosu_v2::prelude::GameMode;
+use twilight_model::channel::message::{
+    Component,
+    component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+};
+
+use crate::util::{Emote, interaction::InteractionComponent};
+
+const MODES: [(GameMode, &str, &str); 4] = [
+    (GameMode::Osu, "osu!", "osu"),
+    (GameMode::Taiko, "Taiko", "taiko"),
+    (GameMode::Catch, "Catch", "catch"),
+    (GameMode::Mania, "Mania", "mania"),
+];
+
+/// Action row containing a select menu to pick one of the four modes.
+pub fn mode_select_component(custom_id: &str, mode: GameMode) -> Component {
+    let options = MODES
+        .iter()
+        .map(|&(option, label, value)| SelectMenuOption {
+            default: option == mode,
+            description: None,
+            emoji: Some(Emote::from(option).reaction_type()),
+            label: label.to_owned(),
+            value: value.to_owned(),
+        })
+        .collect();
+
+    let menu = SelectMenu {
+        custom_id: custom_id.to_owned(),
+        disabled: false,
+        max_values: None,
+        min_values: None,
+        options: Some(options),
+        placeholder: Some("Switch mode".to_owned()),
+        channel_types: None,
+        default_values: None,
+        kind: SelectMenuType::Text,
+    };
+
+    let components = vec![Component::SelectMenu(menu)];
+
+    Component::ActionRow(ActionRow { components })
+}
+
+/// Retrieve the mode that was selected in a [`mode_select_component`].
+pub fn selected_mode(component: &mut InteractionComponent) -> Result<GameMode> {
+    let value = component
+        .data
+        .values
+        .pop()
+        .wrap_err("Missing value for mode select")?;
+
+    MODES
+        .iter()
+        .find_map(|&(mode, _, option)| (option == value).then_some(mode))
+        .wrap_err_with(|| format!("Unknown mode select option `{value}`"))
+}
@@ -7,7 +7,10 @@ use twilight_http::{
 };
 use twilight_model::{
     channel::Message,
-    id::{Id, marker::ChannelMarker},
+    id::{
+        Id,
+        marker::{ChannelMarker, GuildMarker},
+    },
 };
 
 use crate::{
@@ -18,9 +21,18 @@ use crate::{
 pub enum ActiveMessageOrigin<'d> {
     Channel(Id<ChannelMarker>),
     Command(CommandOrigin<'d>),
+    /// Edit the response that was previously sent for the command.
+    Response(CommandOrigin<'d>),
 }
 
 impl ActiveMessageOrigin<'_> {
+    pub(super) fn guild_id(&self) -> Option<Id<GuildMarker>> {
+        match self {
+            Self::Channel(_) => None,
+            Self::Command(orig) | Self::Response(orig) => orig.guild_id(),
+        }
+    }
+
     pub(super) async fn create_message(
         &self,
         builder: MessageBuilder<'_>,
@@ -39,6 +51,10 @@ impl ActiveMessageOrigin<'_> {
                 .create_message(builder)
                 .await
                 .map_err(ActiveMessageOriginError::Report),
+            Self::Response(orig) => orig
+                .update(builder)
+                .await
+                .map_err(ActiveMessageOriginError::Report),
         }
     }
 
@@ -60,6 +76,10 @@ impl ActiveMessageOrigin<'_> {
                 .callback_with_response(builder)
                 .await
                 .map_err(ActiveMessageOriginError::Report),
+            Self::Response(orig) => orig
+                .update(builder)
+                .await
+                .map_err(ActiveMessageOriginError::Report),
         }
     }
 }
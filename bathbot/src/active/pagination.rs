@@ -62,6 +62,13 @@ impl Pages {
         self.index = self.last_index.min(new_index);
     }
 
+    /// Move to the page that contains the entry at the given index.
+    ///
+    /// Indices beyond the last entry move to the last page.
+    pub fn jump_to_index(&mut self, entry_index: usize) {
+        self.set_index(entry_index - entry_index % self.per_page);
+    }
+
     /// Returns pagination components based on the current [`Pages`]
     pub fn components(&self) -> Vec<Component> {
         if self.last_index == 0 {
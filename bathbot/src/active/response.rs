@@ -20,20 +20,28 @@ pub struct ActiveResponse {
 }
 
 pub enum ActiveResponseInner {
-    Message { channel: Id<ChannelMarker> },
-    Interaction { token: Box<str> },
+    Message {
+        channel: Id<ChannelMarker>,
+    },
+    /// Ephemeral responses can't be edited through the channel so the
+    /// interaction token is used instead.
+    Interaction {
+        token: Box<str>,
+    },
 }
 
 impl ActiveResponse {
     pub fn new(orig: &ActiveMessageOrigin, response: &Message) -> Self {
         let inner = match orig {
             ActiveMessageOrigin::Channel(_)
-            | ActiveMessageOrigin::Command(CommandOrigin::Message { .. }) => {
+            | ActiveMessageOrigin::Command(CommandOrigin::Message { .. })
+            | ActiveMessageOrigin::Response(CommandOrigin::Message { .. }) => {
                 ActiveResponseInner::Message {
                     channel: response.channel_id,
                 }
             }
-            ActiveMessageOrigin::Command(CommandOrigin::Interaction { command }) => {
+            ActiveMessageOrigin::Command(CommandOrigin::Interaction { command })
+            | ActiveMessageOrigin::Response(CommandOrigin::Interaction { command }) => {
                 ActiveResponseInner::Interaction {
                     token: command.token.as_str().into(),
                 }
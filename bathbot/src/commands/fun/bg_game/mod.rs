@@ -4,8 +4,9 @@ use bathbot_macros::{SlashCommand, command};
 use bathbot_model::{Effects, command_fields::ThreadChannel};
 use bathbot_psql::model::games::DbMapTagsParams;
 use bathbot_util::{
-    CowUtils, MessageBuilder,
+    CowUtils, EmbedBuilder, MessageBuilder,
     constants::{GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, THREADS_UNAVAILABLE},
+    fields,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
@@ -154,6 +155,14 @@ impl GameDifficulty {
             GameDifficulty::Impossible => 0.95,
         }
     }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GameDifficulty::Normal => "Normal",
+            GameDifficulty::Hard => "Hard",
+            GameDifficulty::Impossible => "Impossible",
+        }
+    }
 }
 
 impl Default for GameDifficulty {
@@ -391,12 +400,19 @@ async fn slash_bg(mut command: InteractionCommand) -> Result<()> {
                 }
             };
 
-            let content = format!(
+            let title = format!(
                 "Starting mania background guessing game with {} different backgrounds",
                 entries.tags.len()
             );
 
-            let builder = MessageBuilder::new().embed(content);
+            let fields = fields![
+                "Tags", "Any".to_owned(), true;
+                "Effects", "None".to_owned(), true;
+                "Difficulty", difficulty.name().to_owned(), true;
+            ];
+
+            let embed = EmbedBuilder::new().fields(fields).title(title);
+            let builder = MessageBuilder::new().embed(embed);
 
             if matches!(thread, Some(ThreadChannel::Thread)) {
                 let res_builder = MessageBuilder::new().embed("Starting new thread...");
@@ -1,6 +1,6 @@
 use std::collections::{BTreeMap, HashSet};
 
-use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
+use bathbot_model::{AmountWithStreak, RankingEntries, RankingEntry, RankingKind};
 use bathbot_util::{IntHasher, constants::GENERAL_ISSUE};
 use eyre::Result;
 use twilight_model::{channel::Message, id::Id};
@@ -68,7 +68,10 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
         let name = name_opt.unwrap_or_else(|| "<unknown user>".into());
 
         let entry = RankingEntry {
-            value: row.score as u64,
+            value: AmountWithStreak {
+                amount: row.score as u64,
+                streak: row.best_streak as u32,
+            },
             name,
             country: None,
         };
@@ -76,7 +79,7 @@ pub async fn leaderboard(msg: &Message, global: bool) -> Result<()> {
         entries.insert(i, entry);
     }
 
-    let entries = RankingEntries::Amount(entries);
+    let entries = RankingEntries::AmountWithStreak(entries);
 
     // Prepare initial page
     let total = scores.len();
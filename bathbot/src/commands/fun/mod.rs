@@ -3,3 +3,4 @@ pub use self::bg_game::*;
 mod bg_game;
 mod higherlower_game;
 mod minesweeper;
+mod whatmap;
@@ -0,0 +1,158 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+};
+use eyre::{Result, WrapErr};
+use tokio::task;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use crate::{
+    Context,
+    manager::{hash_distance, hash_image_bytes},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Images larger than this are rejected
+const MAX_IMAGE_SIZE: u64 = 8 * 1024 * 1024;
+
+/// Hashes that differ in more bits are not considered a match
+const MAX_DISTANCE: u32 = 10;
+
+/// Maximum amount of matches to show
+const MAX_MATCHES: usize = 5;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "whatmap",
+    desc = "Find the mapset of a background image",
+    help = "Find the mapset of an attached background image.\n\
+    Only backgrounds of the background game can be found and \
+    heavily cropped or edited images likely won't match.\n\
+    If no background is similar enough, no guesses will be shown."
+)]
+pub struct WhatMap {
+    #[command(desc = "Specify a .png or .jpg image of a map background")]
+    image: Attachment,
+}
+
+async fn slash_whatmap(mut command: InteractionCommand) -> Result<()> {
+    let WhatMap { image } = WhatMap::from_interaction(command.input_data())?;
+
+    let valid_filetype = image
+        .filename
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.cow_to_ascii_lowercase())
+        .is_some_and(|ext| matches!(ext.as_ref(), "png" | "jpg" | "jpeg"));
+
+    if !valid_filetype {
+        let content = "The attached image must be a .png or .jpg file";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    if image.size > MAX_IMAGE_SIZE {
+        let content = "The attached image must not be larger than 8MB";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let bytes = match Context::client().get_discord_attachment(&image).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download image"));
+        }
+    };
+
+    let hash_res = task::spawn_blocking(move || hash_image_bytes(&bytes))
+        .await
+        .wrap_err("Hashing task panicked")?;
+
+    let Ok(hash) = hash_res else {
+        let content = "Failed to read the image, the file seems to be corrupt";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let hashes = match Context::games().bggame_image_hashes().await {
+        Ok(hashes) => hashes,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if hashes.is_empty() {
+        let content = "No backgrounds have been indexed yet, try again later";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mut matches: Vec<_> = hashes
+        .iter()
+        .map(|&(mapset_id, bg_hash)| (mapset_id, hash_distance(hash, bg_hash)))
+        .filter(|(_, distance)| *distance <= MAX_DISTANCE)
+        .collect();
+
+    if matches.is_empty() {
+        let content = format!(
+            "No confident match among {} indexed backgrounds.\n\
+            The background is either not part of the background game \
+            or the image differs too much e.g. due to cropping.",
+            hashes.len()
+        );
+
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    matches.sort_unstable_by_key(|(_, distance)| *distance);
+    matches.truncate(MAX_MATCHES);
+
+    let mut description = String::with_capacity(matches.len() * 64);
+
+    for (mapset_id, distance) in matches {
+        let similarity = 100.0 * (64 - distance) as f32 / 64.0;
+
+        let name = match Context::osu_map().artist_title(mapset_id).await {
+            Ok(artist_title) => format!(
+                "{} - {}",
+                artist_title.artist.cow_escape_markdown(),
+                artist_title.title.cow_escape_markdown()
+            ),
+            Err(err) => {
+                warn!(mapset_id, ?err, "Failed to get artist and title");
+
+                format!("Mapset {mapset_id}")
+            }
+        };
+
+        let _ = writeln!(
+            description,
+            "`{similarity:>5.1}%` [{name}]({OSU_BASE}beatmapsets/{mapset_id})"
+        );
+    }
+
+    let footer = FooterBuilder::new(format!("Compared against {} backgrounds", hashes.len()));
+
+    let embed = EmbedBuilder::new()
+        .title("Matching mapsets")
+        .description(description)
+        .footer(footer);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
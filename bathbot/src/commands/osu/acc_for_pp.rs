@@ -0,0 +1,235 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasMods, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    numbers::round,
+    osu::{MapIdType, ModSelection},
+};
+use eyre::Result;
+use rosu_v2::prelude::GameModsIntermode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::{HasMods, ModsResult, pp_undefined_content};
+use crate::{
+    Context,
+    manager::{MapError, PpManager},
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Amount of bisection steps; narrows the accuracy down to less than 0.0001%.
+const SEARCH_STEPS: usize = 20;
+
+#[derive(CommandModel, CreateCommand, HasMods, SlashCommand)]
+#[command(
+    name = "accforpp",
+    desc = "Calculate the accuracy required to reach an amount of pp on a map",
+    help = "Calculate the accuracy required to reach an amount of pp on a map.\n\
+    The score is assumed to be a full combo without misses.\n\
+    Whereas `/simulate` calculates the pp for a given accuracy, this command \
+    works the other way around."
+)]
+pub struct AccForPp<'a> {
+    #[command(min_value = 0.0, desc = "Specify the target pp")]
+    pp: f32,
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Whether the score is set on lazer or stable")]
+    lazer: Option<bool>,
+}
+
+async fn slash_accforpp(mut command: InteractionCommand) -> Result<()> {
+    let args = AccForPp::from_interaction(command.input_data())?;
+
+    let mods = match args.mods() {
+        ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => mods,
+        ModsResult::Mods(ModSelection::Exclude { .. }) | ModsResult::None => {
+            GameModsIntermode::new()
+        }
+        ModsResult::Invalid => {
+            let content =
+                "Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    if let Some(content) = pp_undefined_content(&mods) {
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let map_id = match args.map.as_deref().map(matcher::get_osu_map_id) {
+        Some(Some(id)) => id,
+        Some(None) => {
+            let content =
+                "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        None => {
+            let msgs = match Context::retrieve_channel_history(command.channel_id).await {
+                Ok(msgs) => msgs,
+                Err(_) => {
+                    let content = "No beatmap specified and lacking permission to search the channel \
+                        history for maps.\nTry specifying a map either by url to the map, or \
+                        just by map id, or give me the \"Read Message History\" permission.";
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+            };
+
+            match Context::find_map_id_in_msgs(&msgs, 0).await {
+                Some(MapIdType::Map(id)) => id,
+                None | Some(MapIdType::Set(_)) => {
+                    let content = "No beatmap specified and none found in recent channel history. \
+                        Try specifying a map either by url to the map, or just by map id.";
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => match args.mode {
+            Some(mode) => map.convert(mode.into()),
+            None => map,
+        },
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?"
+            );
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = map.mode();
+
+    let Some(game_mods) = mods.clone().try_with_mode(mode) else {
+        let content = format!("The mods `{mods}` are incompatible with the mode {mode:?}");
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let lazer = match args.lazer {
+        Some(lazer) => lazer,
+        None => {
+            let config = Context::user_config()
+                .with_osu_id(command.user_id()?)
+                .await?;
+
+            !match config.score_data {
+                Some(score_data) => score_data.is_legacy(),
+                None => match command.guild_id {
+                    Some(guild_id) => Context::guild_config()
+                        .peek(guild_id, |config| config.score_data)
+                        .await
+                        .is_some_and(ScoreData::is_legacy),
+                    None => false,
+                },
+            }
+        }
+    };
+
+    let target = args.pp;
+
+    let mut calc = Context::pp(&map).mods(game_mods).lazer(lazer);
+    let max_pp = calc.accuracy_performance(100.0, 0).await.pp() as f32;
+
+    let mut description = format!(
+        "Target: **{target}pp** ~ SS: **{max_pp}pp**\n",
+        target = round(target),
+        max_pp = round(max_pp),
+    );
+
+    if target > max_pp {
+        description.push_str("The target pp are **unreachable**, even an SS won't suffice");
+    } else {
+        let acc = required_accuracy(&mut calc, target).await;
+
+        let _ = write!(
+            description,
+            "A full combo requires at least **{acc:.2}%** accuracy"
+        );
+    }
+
+    let mods_str = if mods.is_empty() {
+        String::new()
+    } else {
+        format!(" +{mods}")
+    };
+
+    let title = format!(
+        "{artist} - {title} [{version}]{mods_str}",
+        artist = map.artist(),
+        title = map.title(),
+        version = map.version(),
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .thumbnail(map.thumbnail())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{map_id}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Bisect the accuracy until the full combo pp match the target.
+///
+/// Assumes that the target pp are reachable i.e. don't exceed the SS pp.
+async fn required_accuracy(calc: &mut PpManager<'_>, target: f32) -> f32 {
+    let mut lo = 0.0;
+    let mut hi = 100.0;
+
+    if calc.accuracy_performance(lo, 0).await.pp() as f32 >= target {
+        return lo;
+    }
+
+    for _ in 0..SEARCH_STEPS {
+        let mid = (lo + hi) / 2.0;
+        let pp = calc.accuracy_performance(mid, 0).await.pp() as f32;
+
+        if pp < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    hi
+}
@@ -14,7 +14,10 @@ use eyre::{Report, Result};
 use rkyv::rancor::{Panic, ResultExt};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{Id, marker::UserMarker};
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{Id, marker::UserMarker},
+};
 
 use super::{require_link, user_not_found};
 use crate::{
@@ -34,7 +37,8 @@ use crate::{
     Instead of considering a player's global rank at face value, tourneys calculate \
     the player's bws value and use that to determine if they are allowed to \
     participate based on the rank restrictions.\n\
-    There are various formulas around but this command uses `rank^(0.9937^(badges^2))`."
+    There are various formulas around but this command uses `rank^(0.9937^(badges^2))`.\n\
+    Badges that aren't from tournaments such as contest or mapping badges are not counted."
 )]
 pub struct Bws<'a> {
     #[command(desc = "Specify a username")]
@@ -153,6 +157,9 @@ async fn prefix_bws(msg: &Message, args: Args<'_>) -> Result<()> {
 
 const MIN_BADGES_OFFSET: usize = 2;
 
+/// Maximum amount of excluded badges that are listed in the embed
+const MAX_EXCLUDED_BADGES: usize = 10;
+
 async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
     let user_id = match user_id!(orig, args) {
         Some(user_id) => user_id,
@@ -188,7 +195,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
         }
     };
 
-    let badges_iter = user
+    let (tourney_badges, excluded_badges): (Vec<_>, Vec<_>) = user
         .badges
         .iter()
         .filter(|badge| {
@@ -197,9 +204,10 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
 
             awarded_at.year() >= year
         })
-        .map(|badge| &badge.description);
+        .map(|badge| badge.description.as_str())
+        .partition(|description| TourneyBadges::is_tourney_badge(description));
 
-    let badges_curr = TourneyBadges::count(badges_iter);
+    let badges_curr = tourney_badges.len();
 
     let (badges_min, badges_max) = match badges {
         Some(num) => {
@@ -217,7 +225,15 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
         None => (badges_curr, badges_curr + MIN_BADGES_OFFSET),
     };
 
-    let embed = bws_embed(&user, badges_curr, badges_min, badges_max, rank, year);
+    let embed = bws_embed(
+        &user,
+        badges_curr,
+        &excluded_badges,
+        badges_min,
+        badges_max,
+        rank,
+        year,
+    );
     let builder = MessageBuilder::new().embed(embed);
     orig.create_message(builder).await?;
 
@@ -227,6 +243,7 @@ async fn bws(orig: CommandOrigin<'_>, args: Bws<'_>) -> Result<()> {
 fn bws_embed(
     user: &CachedUser,
     badges_curr: usize,
+    excluded_badges: &[&str],
     badges_min: usize,
     badges_max: usize,
     rank: Option<u32>,
@@ -403,6 +420,26 @@ fn bws_embed(
         embed = embed.footer(format!("Badges from the year {year} onward"));
     }
 
+    if !excluded_badges.is_empty() {
+        let mut value = String::with_capacity(256);
+
+        for description in excluded_badges.iter().take(MAX_EXCLUDED_BADGES) {
+            let _ = writeln!(value, "- {description}");
+        }
+
+        if let Some(remaining @ 1..) = excluded_badges.len().checked_sub(MAX_EXCLUDED_BADGES) {
+            let _ = write!(value, "...and {remaining} more");
+        }
+
+        let field = EmbedField {
+            inline: false,
+            name: format!("Excluded non-tourney badges ({})", excluded_badges.len()),
+            value,
+        };
+
+        embed = embed.fields(vec![field]);
+    }
+
     embed
         .author(user.author_builder(false))
         .description(description)
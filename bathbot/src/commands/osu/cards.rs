@@ -112,6 +112,7 @@ async fn slash_card(mut command: InteractionCommand) -> Result<()> {
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let legacy_scores = match config.score_data {
@@ -184,7 +184,7 @@ async fn slash_claimname(mut command: InteractionCommand) -> Result<()> {
     let user = match user_fut.await {
         Ok(user) => user.unwrap(),
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get user");
 
             return Err(err);
@@ -1,9 +1,14 @@
-use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write, iter};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt::Write};
 
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_util::{IntHasher, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{
+    CowUtils, EmbedBuilder, IntHasher, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+};
 use eyre::{Report, Result};
+use futures::future;
 use rosu_v2::{
     prelude::{GameMode, OsuError, Score, Username},
     request::UserId,
@@ -19,17 +24,21 @@ use super::{AT_LEAST_ONE, CompareTop};
 use crate::{
     Context,
     active::{ActiveMessages, impls::CompareTopPagination},
-    commands::osu::{UserExtraction, user_not_found},
+    commands::osu::{UserExtraction, not_linked, user_not_found},
     core::commands::{CommandOrigin, prefix::Args},
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::get_combined_thumbnail},
 };
 
+/// Maximum amount of users whose top scores can be compared
+const MAX_USERS: usize = 4;
+
 #[derive(CommandModel, CreateCommand, Default, SlashCommand)]
 #[command(
     name = "ct",
     desc = "Compare common top scores",
-    help = "Compare common top scores between players and see who did better on them"
+    help = "Compare common top scores between players and see who did better on them.\n\
+    Up to four users can be compared."
 )]
 #[allow(unused)]
 pub struct Ct<'a> {
@@ -39,6 +48,10 @@ pub struct Ct<'a> {
     name1: Option<Cow<'a, str>>,
     #[command(desc = "Specify a username")]
     name2: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name3: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name4: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name1` option, \
@@ -48,6 +61,12 @@ pub struct Ct<'a> {
     discord1: Option<Id<UserMarker>>,
     #[command(desc = "Specify a linked discord user")]
     discord2: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Only show the amount of common maps",
+        help = "Only show the amount of common maps and the top few of them.\n\
+        Faster for users with many common maps since no map data is retrieved."
+    )]
+    count: Option<bool>,
 }
 
 async fn slash_ct(mut command: InteractionCommand) -> Result<()> {
@@ -57,10 +76,14 @@ async fn slash_ct(mut command: InteractionCommand) -> Result<()> {
 }
 
 #[command]
-#[desc("Compare maps of two players' top100s")]
-#[help("Compare the two users' top 100 and check which maps appear in each top list.")]
-#[usage("[name1] [name2]")]
-#[example("badewanne3 \"nathan on osu\"")]
+#[desc("Compare maps of multiple players' top100s")]
+#[help(
+    "Compare the users' top 100 and check which maps appear in each top list.\n\
+    Up to four users can be compared.\n\
+    Add `-count` to only show the amount of common maps."
+)]
+#[usage("[name1] [name2] [name3] [name4] [-count]")]
+#[example("badewanne3 \"nathan on osu\"", "badewanne3 \"nathan on osu\" -count")]
 #[group(Osu)]
 #[alias("comparetop")]
 async fn prefix_common(
@@ -74,9 +97,12 @@ async fn prefix_common(
 }
 
 #[command]
-#[desc("Compare maps of two players' top100s")]
-#[help("Compare the mania users' top 100 and check which maps appear in each top list")]
-#[usage("[name1] [name2]")]
+#[desc("Compare maps of multiple players' top100s")]
+#[help(
+    "Compare the mania users' top 100 and check which maps appear in each top list.\n\
+    Up to four users can be compared."
+)]
+#[usage("[name1] [name2] [name3] [name4]")]
 #[example("badewanne3 \"nathan on osu\"")]
 #[alias("commonm", "comparetopmania")]
 #[group(Mania)]
@@ -91,9 +117,12 @@ async fn prefix_commonmania(
 }
 
 #[command]
-#[desc("Compare maps of two players' top100s")]
-#[help("Compare the taiko users' top 100 and check which maps appear in each top list")]
-#[usage("[name1] [name2]")]
+#[desc("Compare maps of multiple players' top100s")]
+#[help(
+    "Compare the taiko users' top 100 and check which maps appear in each top list.\n\
+    Up to four users can be compared."
+)]
+#[usage("[name1] [name2] [name3] [name4]")]
 #[example("badewanne3 \"nathan on osu\"")]
 #[alias("commont", "comparetoptaiko")]
 #[group(Taiko)]
@@ -108,9 +137,12 @@ async fn prefix_commontaiko(
 }
 
 #[command]
-#[desc("Compare maps of two players' top100s")]
-#[help("Compare the ctb users' top 100 and check which maps appear in each top list")]
-#[usage("[name1] [name2]")]
+#[desc("Compare maps of multiple players' top100s")]
+#[help(
+    "Compare the ctb users' top 100 and check which maps appear in each top list.\n\
+    Up to four users can be compared."
+)]
+#[usage("[name1] [name2] [name3] [name4]")]
 #[example("badewanne3 \"nathan on osu\"")]
 #[alias("commonc", "commoncatch", "comparetopctb", "comparetopcatch")]
 #[group(Catch)]
@@ -125,13 +157,20 @@ async fn prefix_commonctb(
 }
 
 async fn extract_user_id(args: &mut CompareTop<'_>) -> UserExtraction {
-    if let Some(name) = args.name1.take().or_else(|| args.name2.take()) {
+    let name = args
+        .name1
+        .take()
+        .or_else(|| args.name2.take())
+        .or_else(|| args.name3.take())
+        .or_else(|| args.name4.take());
+
+    if let Some(name) = name {
         UserExtraction::Id(UserId::Name(name.as_ref().into()))
     } else if let Some(discord) = args.discord1.take().or_else(|| args.discord2.take()) {
         match Context::user_config().osu_id(discord).await {
             Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
             Ok(None) => {
-                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+                UserExtraction::Content(not_linked(discord))
             }
             Err(err) => UserExtraction::Err(err),
         }
@@ -143,27 +182,25 @@ async fn extract_user_id(args: &mut CompareTop<'_>) -> UserExtraction {
 pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Result<()> {
     let owner = orig.user_id()?;
 
-    let user_id1 = match extract_user_id(&mut args).await {
-        UserExtraction::Id(user_id) => user_id,
-        UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err);
-        }
-        UserExtraction::Content(content) => return orig.error(content).await,
-        UserExtraction::None => return orig.error(AT_LEAST_ONE).await,
-    };
+    let mut user_ids = Vec::with_capacity(MAX_USERS);
 
-    let user_id2 = match extract_user_id(&mut args).await {
-        UserExtraction::Id(user_id) => user_id,
-        UserExtraction::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+    loop {
+        match extract_user_id(&mut args).await {
+            UserExtraction::Id(user_id) => user_ids.push(user_id),
+            UserExtraction::Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
 
-            return Err(err);
+                return Err(err);
+            }
+            UserExtraction::Content(content) => return orig.error(content).await,
+            UserExtraction::None => break,
         }
-        UserExtraction::Content(content) => return orig.error(content).await,
-        UserExtraction::None => match Context::user_config().osu_id(owner).await {
-            Ok(Some(user_id)) => UserId::Id(user_id),
+    }
+
+    match user_ids.len() {
+        0 => return orig.error(AT_LEAST_ONE).await,
+        1 => match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) => user_ids.push(UserId::Id(user_id)),
             Ok(None) => {
                 let content =
                     "Since you're not linked with the `/link` command, you must specify two names.";
@@ -176,10 +213,21 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
                 return Err(err);
             }
         },
-    };
+        len if len > MAX_USERS => {
+            let content = format!("You can compare at most {MAX_USERS} users");
 
-    if user_id1 == user_id2 {
-        return orig.error("Give two different names").await;
+            return orig.error(content).await;
+        }
+        _ => {}
+    }
+
+    let duplicate_ids = user_ids
+        .iter()
+        .enumerate()
+        .any(|(i, user_id)| user_ids[..i].contains(user_id));
+
+    if duplicate_ids {
+        return orig.error("Give different names").await;
     }
 
     let mode = match args.mode {
@@ -194,114 +242,187 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         },
     };
 
-    let fut1 = get_user_and_scores(&user_id1, mode);
-    let fut2 = get_user_and_scores(&user_id2, mode);
+    let user_futs = user_ids
+        .iter()
+        .map(|user_id| get_user_and_scores(user_id, mode));
 
-    let (user1, scores1, user2, scores2) = match tokio::join!(fut1, fut2) {
-        (Ok((user1, scores1)), Ok((user2, scores2))) => (user1, scores1, user2, scores2),
-        (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
-            let content = user_not_found(user_id1).await;
+    let mut users = Vec::with_capacity(user_ids.len());
+    let mut scores = Vec::with_capacity(user_ids.len());
 
-            return orig.error(content).await;
-        }
-        (_, Err(UserArgsError::Osu(OsuError::NotFound))) => {
-            let content = user_not_found(user_id2).await;
+    for (res, user_id) in future::join_all(user_futs).await.into_iter().zip(user_ids) {
+        match res {
+            Ok((user, user_scores)) => {
+                users.push(CommonUser::new(user));
+                scores.push(user_scores);
+            }
+            Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                let content = user_not_found(user_id).await;
 
-            return orig.error(content).await;
-        }
-        (Err(err), _) | (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get scores");
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get scores");
 
-            return Err(err);
+                return Err(err);
+            }
         }
-    };
+    }
 
-    let user1 = CommonUser::new(user1);
-    let user2 = CommonUser::new(user2);
+    let no_scores = users
+        .iter()
+        .zip(scores.iter())
+        .find(|(_, user_scores)| user_scores.is_empty());
 
-    let content = if scores1.is_empty() {
-        Some(format!("No scores data for user `{}`", user1.name))
-    } else if scores2.is_empty() {
-        Some(format!("No scores data for user `{}`", user2.name))
-    } else {
-        None
-    };
+    if let Some((user, _)) = no_scores {
+        let content = format!("No scores data for user `{}`", user.name);
 
-    if let Some(content) = content {
         return orig.error(content).await;
     }
 
-    // Check if different names that both belong to the same user were given
-    if user1.id() == user2.id() {
-        let content = "You must specify two different users";
+    // Check if different names that belong to the same user were given
+    let duplicate_users = users
+        .iter()
+        .enumerate()
+        .any(|(i, user)| users[..i].iter().any(|prev| prev.id() == user.id()));
+
+    if duplicate_users {
+        let content = "You must specify different users";
 
         return orig.error(content).await;
     }
 
-    let indices: HashMap<_, _, IntHasher> = scores2
+    let (first_scores, other_scores) = scores.split_first().expect("at least two users");
+
+    let indices: Vec<HashMap<_, _, IntHasher>> = other_scores
         .iter()
-        .enumerate()
-        .map(|(i, score)| (score.map_id, i))
+        .map(|user_scores| {
+            user_scores
+                .iter()
+                .enumerate()
+                .map(|(i, score)| (score.map_id, i))
+                .collect()
+        })
         .collect();
 
-    let mut wins = [0, 0];
+    // Only consider maps that appear in the top scores of all users
+    let common_scores: Vec<_> = first_scores
+        .iter()
+        .filter_map(|score| {
+            let mut common = Vec::with_capacity(users.len());
+            common.push(score);
 
-    let maps: HashMap<_, _, IntHasher> = scores1
-        .into_iter()
-        .filter_map(|mut score1| {
-            let map = score1.map.take()?;
-            let mapset = score1.mapset.take()?;
+            for (user_scores, indices) in other_scores.iter().zip(indices.iter()) {
+                common.push(&user_scores[*indices.get(&score.map_id)?]);
+            }
 
-            let score1 = CommonScore::from(&score1);
+            Some(common)
+        })
+        .collect();
 
-            let idx = indices.get(&map.map_id)?;
-            let score2 = CommonScore::from(&scores2[*idx]);
+    if args.count == Some(true) {
+        return common_count(orig, &users, common_scores).await;
+    }
 
-            match score1.cmp(&score2) {
-                Ordering::Less => wins[1] += 1,
-                Ordering::Equal => {}
-                Ordering::Greater => wins[0] += 1,
-            }
+    // Retrieve all common maps at once
+    let maps_id_checksum: HashMap<_, _, IntHasher> = common_scores
+        .iter()
+        .map(|common| {
+            let score = common[0];
+            let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+
+            (score.map_id as i32, checksum)
+        })
+        .collect();
 
-            let map_id = map.map_id;
+    let osu_maps = match Context::osu_map().maps(&maps_id_checksum).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
 
-            let map = CompareTopMap {
-                title: mapset.title.into_boxed_str(),
-                version: map.version.into_boxed_str(),
+            return Err(err.wrap_err("Failed to get maps"));
+        }
+    };
+
+    let mut wins = vec![0; users.len()];
+
+    let maps: HashMap<_, _, IntHasher> = common_scores
+        .into_iter()
+        .map(|common| {
+            let score = common[0];
+            let map_id = score.map_id;
+
+            let map = match osu_maps.get(&map_id) {
+                Some(map) => CompareTopMap {
+                    title: map.title().into(),
+                    version: map.version().into(),
+                },
+                None => CompareTopMap {
+                    title: score
+                        .mapset
+                        .as_ref()
+                        .map_or("<unknown title>", |mapset| mapset.title.as_str())
+                        .into(),
+                    version: score
+                        .map
+                        .as_ref()
+                        .map_or("<unknown version>", |map| map.version.as_str())
+                        .into(),
+                },
             };
 
-            Some((map_id, ([score1, score2], map)))
+            let common: Box<[_]> = common.into_iter().map(CommonScore::from).collect();
+
+            if let Some(winner) = unique_winner(&common) {
+                wins[winner] += 1;
+            }
+
+            (map_id, (common, map))
         })
         .collect();
 
     // Sort the maps by their score's avg pp values
     let mut map_pps: Box<[_]> = maps
         .iter()
-        .map(|(map_id, ([a, b], _))| (*map_id, a.pp + b.pp))
+        .map(|(map_id, (scores, _))| (*map_id, scores.iter().map(|score| score.pp).sum::<f32>()))
         .collect();
 
     map_pps.sort_unstable_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
 
     // Accumulate all necessary data
-    let mut content = format!("`{}` and `{}` ", user1.name, user2.name);
+    let mut content = String::with_capacity(128);
+
+    for (user, i) in users.iter().zip(1..) {
+        let separator = match users.len() - i {
+            0 => "",
+            1 if users.len() == 2 => " and ",
+            1 => ", and ",
+            _ => ", ",
+        };
+
+        let _ = write!(content, "`{}`{separator}", user.name);
+    }
 
     let amount_common = maps.len();
 
     if amount_common == 0 {
-        content.push_str("have no common scores");
+        content.push_str(" have no common scores");
     } else {
         let _ = write!(
             content,
-            "have {amount_common} common beatmap{} in their top 100",
+            " have {amount_common} common beatmap{} in their top 100\n🥇 count",
             if amount_common > 1 { "s" } else { "" }
         );
+
+        for (user, wins) in users.iter().zip(wins.iter()) {
+            let _ = write!(content, " • `{}`: {wins}", user.name);
+        }
     }
 
     // Create the combined profile pictures
-    let urls = iter::once(user1.avatar_url()).chain(iter::once(user2.avatar_url()));
+    let urls = users.iter().map(CommonUser::avatar_url);
 
-    let thumbnail = match get_combined_thumbnail(urls, 2, None).await {
+    let thumbnail = match get_combined_thumbnail(urls, users.len() as u32, None).await {
         Ok(thumbnail) => Some(thumbnail),
         Err(err) => {
             warn!(?err, "Failed to combine avatars");
@@ -310,12 +431,13 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         }
     };
 
+    let names = users.into_iter().map(|user| user.name).collect();
+
     let pagination = CompareTopPagination::builder()
-        .name1(user1.name)
-        .name2(user2.name)
+        .names(names)
         .maps(maps)
         .map_pps(map_pps)
-        .wins(wins)
+        .content(content.into_boxed_str())
         .msg_owner(owner)
         .build();
 
@@ -326,6 +448,112 @@ pub(super) async fn top(orig: CommandOrigin<'_>, mut args: CompareTop<'_>) -> Re
         .await
 }
 
+/// Respond with only the amount of common maps and the top few of them.
+///
+/// Skips retrieving the maps so it's fast even for large overlaps.
+async fn common_count(
+    orig: CommandOrigin<'_>,
+    users: &[CommonUser],
+    common_scores: Vec<Vec<&Score>>,
+) -> Result<()> {
+    const TOP_COUNT: usize = 5;
+
+    let mut wins = vec![0; users.len()];
+
+    let mut common_pps: Vec<_> = common_scores
+        .iter()
+        .map(|common| {
+            let scores: Box<[_]> = common.iter().copied().map(CommonScore::from).collect();
+
+            if let Some(winner) = unique_winner(&scores) {
+                wins[winner] += 1;
+            }
+
+            (common[0], scores.iter().map(|score| score.pp).sum::<f32>())
+        })
+        .collect();
+
+    common_pps.sort_unstable_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    let mut description = String::with_capacity(256);
+
+    for (user, i) in users.iter().zip(1..) {
+        let separator = match users.len() - i {
+            0 => "",
+            1 if users.len() == 2 => " and ",
+            1 => ", and ",
+            _ => ", ",
+        };
+
+        let _ = write!(description, "`{}`{separator}", user.name);
+    }
+
+    let amount_common = common_pps.len();
+
+    if amount_common == 0 {
+        description.push_str(" have no common scores");
+    } else {
+        let _ = write!(
+            description,
+            " have **{amount_common}** common beatmap{} in their top 100\n🥇 count",
+            if amount_common > 1 { "s" } else { "" }
+        );
+
+        for (user, wins) in users.iter().zip(wins.iter()) {
+            let _ = write!(description, " • `{}`: {wins}", user.name);
+        }
+
+        description.push('\n');
+
+        for (score, i) in common_pps
+            .iter()
+            .take(TOP_COUNT)
+            .map(|(score, _)| score)
+            .zip(1..)
+        {
+            let title = score
+                .mapset
+                .as_ref()
+                .map_or("<unknown title>", |mapset| mapset.title.as_str());
+
+            let version = score
+                .map
+                .as_ref()
+                .map_or("<unknown version>", |map| map.version.as_str());
+
+            let _ = write!(
+                description,
+                "\n**{i}.** [{title} [{version}]]({OSU_BASE}b/{map_id})",
+                title = title.cow_escape_markdown(),
+                version = version.cow_escape_markdown(),
+                map_id = score.map_id,
+            );
+        }
+    }
+
+    let embed = EmbedBuilder::new().description(description);
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Index of the best score if no other score is equally good.
+fn unique_winner(scores: &[CommonScore]) -> Option<usize> {
+    let (idx, best) = scores
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.cmp(b))?;
+
+    let tied = scores
+        .iter()
+        .filter(|score| score.cmp(best) == Ordering::Equal)
+        .count()
+        > 1;
+
+    (!tied).then_some(idx)
+}
+
 async fn get_user_and_scores(
     user_id: &UserId,
     mode: GameMode,
@@ -410,8 +638,10 @@ impl<'m> CompareTop<'m> {
             ..Default::default()
         };
 
-        for arg in args.take(2) {
-            if let Some(id) = matcher::get_mention_user(arg) {
+        for arg in args.take(MAX_USERS + 1) {
+            if arg == "-count" {
+                args_.count = Some(true);
+            } else if let Some(id) = matcher::get_mention_user(arg) {
                 if args_.discord1.is_none() {
                     args_.discord1 = Some(id);
                 } else {
@@ -419,8 +649,12 @@ impl<'m> CompareTop<'m> {
                 }
             } else if args_.name1.is_none() {
                 args_.name1 = Some(arg.into());
-            } else {
+            } else if args_.name2.is_none() {
                 args_.name2 = Some(arg.into());
+            } else if args_.name3.is_none() {
+                args_.name3 = Some(arg.into());
+            } else {
+                args_.name4 = Some(arg.into());
             }
         }
 
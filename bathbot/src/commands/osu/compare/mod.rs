@@ -179,7 +179,8 @@ pub struct CompareProfile<'a> {
 #[command(
     name = "top",
     desc = "Compare common top scores (same as `/ct`)",
-    help = "Compare common top scores between players and see who did better on them\n\
+    help = "Compare common top scores between players and see who did better on them.\n\
+    Up to four users can be compared.\n\
     Its shorter alias is the `/ct` command."
 )]
 pub struct CompareTop<'a> {
@@ -189,6 +190,10 @@ pub struct CompareTop<'a> {
     name1: Option<Cow<'a, str>>,
     #[command(desc = "Specify a username")]
     name2: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name3: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name4: Option<Cow<'a, str>>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name1` option, \
@@ -198,6 +203,12 @@ pub struct CompareTop<'a> {
     discord1: Option<Id<UserMarker>>,
     #[command(desc = "Specify a linked discord user")]
     discord2: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Only show the amount of common maps",
+        help = "Only show the amount of common maps and the top few of them.\n\
+        Faster for users with many common maps since no map data is retrieved."
+    )]
+    count: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, Default)]
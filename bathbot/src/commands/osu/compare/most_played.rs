@@ -13,7 +13,7 @@ use super::{AT_LEAST_ONE, CompareMostPlayed};
 use crate::{
     Context,
     active::{ActiveMessages, impls::CompareMostPlayedPagination},
-    commands::osu::{UserExtraction, user_not_found},
+    commands::osu::{UserExtraction, not_linked, user_not_found},
     core::commands::CommandOrigin,
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
 };
@@ -55,7 +55,7 @@ async fn extract_user_id(args: &mut CompareMostPlayed<'_>) -> UserExtraction {
         match Context::user_config().osu_id(discord).await {
             Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
             Ok(None) => {
-                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+                UserExtraction::Content(not_linked(discord))
             }
             Err(err) => UserExtraction::Err(err),
         }
@@ -29,7 +29,7 @@ use twilight_model::{
 use super::{AT_LEAST_ONE, CompareProfile};
 use crate::{
     Context,
-    commands::osu::UserExtraction,
+    commands::osu::{UserExtraction, not_linked},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, ProfileCompareEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -77,7 +77,7 @@ async fn extract_user_id(args: &mut CompareProfile<'_>) -> UserExtraction {
         match Context::user_config().osu_id(discord).await {
             Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
             Ok(None) => {
-                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+                UserExtraction::Content(not_linked(discord))
             }
             Err(err) => UserExtraction::Err(err),
         }
@@ -40,7 +40,10 @@ use crate::{
         impls::{CompareScoresPagination, SingleScorePagination},
     },
     commands::{
-        osu::{HasMods, ModsResult, map_strain_graph, require_link, user_not_found},
+        osu::{
+            HasMods, ModsResult, inferred_mode_content, map_strain_graph, require_link,
+            user_not_found,
+        },
         utility::{ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
     core::commands::{
@@ -54,7 +57,7 @@ use crate::{
     util::{
         InteractionCommandExt,
         interaction::InteractionCommand,
-        osu::{IfFc, MapOrScore, PersonalBestIndex},
+        osu::{IfFc, MapOrScore, PersonalBestIndex, if_passed_pp, pp_percentile},
     },
 };
 
@@ -465,7 +468,9 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
     };
 
     let legacy_scores = score_data.is_legacy();
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed_settings(orig.guild_id(), config.score_embed)
+        .await;
 
     let CompareScoreArgs {
         sort,
@@ -559,7 +564,14 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         }
     };
 
+    // Only mention the mode if it wasn't specified explicitly
+    let content = match mode {
+        Some(_) => None,
+        None => inferred_mode_content(map.mode(), config.mode),
+    };
+
     let mode = map.mode();
+
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let (user_res, score_res) = match user_args {
@@ -605,7 +617,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
             return Err(err);
         }
         (_, Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get scores");
 
             return Err(err);
@@ -724,7 +736,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
 
     let graph = match entries.first() {
         Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
-            prepare_graph(entry).await
+            prepare_strain_graph(entry).await
         }
         Some(_) | None => None,
     };
@@ -737,6 +749,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         .pinned(pinned.into_boxed_slice())
         .pp_idx(pp_idx)
         .score_data(score_data)
+        .content(content.map(String::into_boxed_str))
         .msg_owner(owner)
         .build();
 
@@ -781,7 +794,10 @@ async fn process_scores(
         };
 
         let score = ScoreSlim::new(score, pp);
-        let if_fc_pp = IfFc::new(&score, map).await.map(|if_fc| if_fc.pp);
+        let if_fc = IfFc::new(&score, map).await;
+        let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+        let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(score.mode));
+        let if_passed_pp = if_passed_pp(&score, map).await;
 
         let pb_idx = top100.and_then(|top100| {
             let pb_idx = PersonalBestIndex::new(&score, map.map_id(), map.status(), top100);
@@ -796,6 +812,9 @@ async fn process_scores(
                 .map(|idx| idx + 1)
         });
 
+        let pp_percentile =
+            globals.and_then(|globals| pp_percentile(&score, user_id, map.status(), globals));
+
         let entry = ScoreEmbedData {
             score,
             map: map.clone(),
@@ -806,7 +825,12 @@ async fn process_scores(
             miss_analyzer: None,
             pb_idx,
             global_idx,
+            mod_idx: None,
             if_fc_pp,
+            if_fc_acc,
+            if_passed_pp,
+            pp_percentile,
+            map_pb: None,
             #[cfg(feature = "twitch")]
             twitch: None,
         };
@@ -894,7 +918,7 @@ async fn compare_from_score(
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get score");
 
             return Err(err);
@@ -1002,7 +1026,10 @@ async fn compare_from_score(
     };
 
     let score = ScoreSlim::new(score, pp);
-    let if_fc_pp = IfFc::new(&score, &map).await.map(|if_fc| if_fc.pp);
+    let if_fc = IfFc::new(&score, &map).await;
+    let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+    let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(score.mode));
+    let if_passed_pp = if_passed_pp(&score, &map).await;
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
 
     let pb_idx = top100.as_deref().and_then(|top100| {
@@ -1011,13 +1038,17 @@ async fn compare_from_score(
         ScoreEmbedDataPersonalBest::try_new(pb_idx, &origin)
     });
 
-    let global_idx = globals.and_then(|globals| {
+    let global_idx = globals.as_deref().and_then(|globals| {
         globals
             .iter()
             .position(|s| s.user_id == user_id && score.is_eq(s))
             .map(|idx| idx + 1)
     });
 
+    let pp_percentile = globals
+        .as_deref()
+        .and_then(|globals| pp_percentile(&score, user_id, map.status(), globals));
+
     let entry = ScoreEmbedData {
         score,
         map: map.clone(),
@@ -1028,13 +1059,18 @@ async fn compare_from_score(
         miss_analyzer: None,
         pb_idx,
         global_idx,
+        mod_idx: None,
         if_fc_pp,
+        if_fc_acc,
+        if_passed_pp,
+        pp_percentile,
+        map_pb: None,
         #[cfg(feature = "twitch")]
         twitch: None,
     };
 
     let graph = if matches!(settings.image, SettingsImage::ImageWithStrains) {
-        prepare_graph(&entry).await
+        prepare_strain_graph(&entry).await
     } else {
         None
     };
@@ -1098,7 +1134,8 @@ async fn handle_autocomplete(
     Ok(())
 }
 
-async fn prepare_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
+/// Strain graph of the entry's map as attachment for a [`SingleScorePagination`].
+pub async fn prepare_strain_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
     let fut = map_strain_graph(
         &entry.map.pp_map,
         entry.score.mods.clone(),
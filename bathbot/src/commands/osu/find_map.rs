@@ -0,0 +1,140 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::Result;
+use rosu_v2::prelude::{GameMode, RankStatus};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::SearchStatus;
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::FindMapPagination},
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "findmap",
+    desc = "Search for maps that are stored by the bot",
+    help = "Search through maps that the bot already stored instead of asking osu! for them.\n\
+    Every word of the query must appear in the map's artist, title, creator, or difficulty name.\n\
+    Only maps that were previously requested by any command can be found \
+    so the results are not complete but they're quick to retrieve, \
+    e.g. to look up the id of a known map.\n\
+    Maps whose star rating has not been calculated yet are omitted if \
+    `min_stars` or `max_stars` is specified."
+)]
+pub struct FindMap {
+    #[command(desc = "Specify words contained in artist, title, creator, or difficulty name")]
+    query: String,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a ranking status")]
+    status: Option<SearchStatus>,
+    #[command(min_value = 0.0, desc = "Specify a minimum star rating")]
+    min_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Specify a maximum star rating")]
+    max_stars: Option<f32>,
+}
+
+async fn slash_findmap(mut command: InteractionCommand) -> Result<()> {
+    let args = FindMap::from_interaction(command.input_data())?;
+
+    find_map((&mut command).into(), args).await
+}
+
+async fn find_map(orig: CommandOrigin<'_>, args: FindMap) -> Result<()> {
+    let FindMap {
+        query,
+        mode,
+        status,
+        min_stars,
+        max_stars,
+    } = args;
+
+    if let Some((min, max)) = min_stars.zip(max_stars).filter(|(min, max)| min > max) {
+        let content = format!("The minimum stars ({min}) must not exceed the maximum ({max})");
+
+        return orig.error(content).await;
+    }
+
+    let patterns: Vec<_> = query.split_whitespace().map(search_pattern).collect();
+
+    if patterns.is_empty() {
+        return orig.error("The query must not be empty").await;
+    }
+
+    let statuses = match status {
+        None | Some(SearchStatus::Any) => None,
+        Some(SearchStatus::Leaderboard) => Some(
+            &[
+                RankStatus::Ranked,
+                RankStatus::Approved,
+                RankStatus::Qualified,
+                RankStatus::Loved,
+            ][..],
+        ),
+        Some(SearchStatus::Ranked) => Some(&[RankStatus::Ranked, RankStatus::Approved][..]),
+        Some(SearchStatus::Loved) => Some(&[RankStatus::Loved][..]),
+        Some(SearchStatus::Qualified) => Some(&[RankStatus::Qualified][..]),
+        Some(SearchStatus::Pending) => Some(&[RankStatus::Pending, RankStatus::WIP][..]),
+        Some(SearchStatus::Graveyard) => Some(&[RankStatus::Graveyard][..]),
+    };
+
+    let maps_fut = Context::psql().search_osu_maps(
+        &patterns,
+        mode.map(GameMode::from),
+        statuses,
+        min_stars,
+        max_stars,
+    );
+
+    let maps = match maps_fut.await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to search stored maps"));
+        }
+    };
+
+    if maps.is_empty() {
+        let content = format!(
+            "No stored maps found for `{query}`.\n\
+            Consider using `/search` instead which asks osu! directly."
+        );
+
+        return orig.error(content).await;
+    }
+
+    let pagination = FindMapPagination::builder()
+        .maps(maps.into_boxed_slice())
+        .query(query.into_boxed_str())
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Turn a word into a case-insensitive pattern that matches anything
+/// containing the word, escaping its wildcards.
+fn search_pattern(word: &str) -> String {
+    let mut pattern = String::with_capacity(word.len() + 2);
+    pattern.push('%');
+
+    for c in word.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+
+        pattern.push(c);
+    }
+
+    pattern.push('%');
+
+    pattern
+}
@@ -1,12 +1,13 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
-use bathbot_model::ScoreSlim;
+use bathbot_model::{ScoreSlim, command_fields::GameModeOption};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
+    IntHasher, MessageBuilder,
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
-    osu::{MapIdType, ModSelection},
+    osu::{MapIdType, ModSelection, PpListUtil},
 };
 use eyre::{Report, Result};
 use rosu_v2::{
@@ -20,7 +21,7 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-use super::{HasMods, ModsResult, require_link, user_not_found};
+use super::{HasMods, ModsResult, inferred_mode_content, mods_error, require_link, user_not_found};
 use crate::{
     Context,
     core::commands::{CommandOrigin, prefix::Args},
@@ -57,6 +58,12 @@ pub struct Fix<'a> {
         help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a gamemode",
+        help = "Specify a gamemode to convert the map into.\n\
+        If none is specified, the map's own mode will be used."
+    )]
+    mode: Option<GameModeOption>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name` option, \
@@ -64,6 +71,15 @@ pub struct Fix<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        max_value = 100,
+        desc = "Specify how much combo may be missing for an osu!standard FC",
+        help = "Specify how much combo may be missing for an osu!standard score \
+        to still count as FC.\n\
+        Set it to `0` if any sliderbreak should be considered a choke.\n\
+        Defaults to 1 combo per 500 max combo but at least 4."
+    )]
+    tolerance: Option<u32>,
 }
 
 #[derive(HasMods, HasName)]
@@ -71,7 +87,9 @@ struct FixArgs<'a> {
     name: Option<Cow<'a, str>>,
     id: Option<MapOrScore>,
     mods: Option<Cow<'a, str>>,
+    mode: Option<GameMode>,
     discord: Option<Id<UserMarker>>,
+    tolerance: Option<u32>,
 }
 
 impl<'m> FixArgs<'m> {
@@ -79,11 +97,18 @@ impl<'m> FixArgs<'m> {
         let mut name = None;
         let mut discord = None;
         let mut mods = None;
+        let mut tolerance = None;
 
         let mut id_ = MapOrScore::find_in_msg(msg).await;
 
-        for arg in args.take(3) {
-            if let Some(id) = matcher::get_osu_map_id(arg)
+        for arg in args.take(4) {
+            if let Some(value) = arg
+                .strip_prefix("tolerance=")
+                .or_else(|| arg.strip_prefix("t="))
+                .and_then(|value| value.parse().ok())
+            {
+                tolerance = Some(value);
+            } else if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
@@ -104,6 +129,8 @@ impl<'m> FixArgs<'m> {
             discord,
             id: id_,
             mods,
+            mode: None,
+            tolerance,
         }
     }
 }
@@ -134,7 +161,9 @@ impl<'a> TryFrom<Fix<'a>> for FixArgs<'a> {
             name: args.name,
             id,
             mods: args.mods,
+            mode: args.mode.map(GameMode::from),
             discord: args.discord,
+            tolerance: args.tolerance,
         })
     }
 }
@@ -159,13 +188,17 @@ async fn slash_fix(mut command: InteractionCommand) -> Result<()> {
      If no map is given, I will choose the last map \
      I can find in the embeds of this channel.\n\
      Mods can be specified but only if there already is a score \
-     on the map with those mods."
+     on the map with those mods.\n\
+     With `tolerance=` (or `t=`) you can specify how much combo may be \
+     missing for an osu!standard score to still count as FC, e.g. `t=0` \
+     to consider any sliderbreak a choke."
 )]
 #[alias("fixscore")]
-#[usage("[username] [map url / map id] [+mods]")]
+#[usage("[username] [map url / map id] [+mods] [tolerance=number]")]
 #[examples(
     "badewanne3",
     "badewanne3 2240404 +hdhr",
+    "badewanne3 2240404 t=0",
     "https://osu.ppy.sh/beatmapsets/902425#osu/2240404"
 )]
 #[group(AllModes)]
@@ -194,7 +227,7 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
             let content = "Failed to parse mods. Be sure to either specify them directly \
             or through the `+mods` / `+mods!` syntax e.g. `hdhr` or `+hdhr!`";
 
-            return orig.error(content).await;
+            return orig.error(mods_error(content, args.mods.as_deref())).await;
         }
     };
 
@@ -214,12 +247,24 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
         Some(ModSelection::Exact(mods)) | Some(ModSelection::Include(mods)) => Some(mods),
     };
 
+    // Scores come with their own mode so only maps need a mention
+    let inferred_mode = args.mode.is_none() && !matches!(args.id, Some(MapOrScore::Score { .. }));
+
     let data_result = match args.id {
         Some(MapOrScore::Score { id, mode }) => {
-            request_by_score(&orig, id, mode, user_id, legacy_scores).await
+            request_by_score(&orig, id, mode, user_id, legacy_scores, args.tolerance).await
         }
         Some(MapOrScore::Map(MapIdType::Map(id))) => {
-            request_by_map(&orig, id, user_id, mods.as_ref(), legacy_scores).await
+            request_by_map(
+                &orig,
+                id,
+                args.mode,
+                user_id,
+                mods.as_ref(),
+                legacy_scores,
+                args.tolerance,
+            )
+            .await
         }
         Some(MapOrScore::Map(MapIdType::Set(_))) => {
             let content = "Looks like you gave me a mapset id, I need a map id though";
@@ -240,7 +285,16 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
 
             match Context::find_map_id_in_msgs(&msgs, 0).await {
                 Some(MapIdType::Map(id)) => {
-                    request_by_map(&orig, id, user_id, mods.as_ref(), legacy_scores).await
+                    request_by_map(
+                        &orig,
+                        id,
+                        args.mode,
+                        user_id,
+                        mods.as_ref(),
+                        legacy_scores,
+                        args.tolerance,
+                    )
+                    .await
                 }
                 None | Some(MapIdType::Set(_)) => {
                     let content = "No beatmap specified and none found in recent channel history. \
@@ -258,8 +312,19 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
         ScoreResult::Error(err) => return Err(err),
     };
 
+    let content = if inferred_mode {
+        inferred_mode_content(entry.map.mode(), config.mode)
+    } else {
+        None
+    };
+
     let embed_data = FixScoreEmbed::new(&entry, mods);
-    let builder = embed_data.build().into();
+    let mut builder: MessageBuilder<'_> = embed_data.build().into();
+
+    if let Some(content) = content {
+        builder = builder.content(content);
+    }
+
     orig.create_message(builder).await?;
 
     Ok(())
@@ -283,6 +348,8 @@ pub struct FixScore {
     pub score: ScoreSlim,
     pub top: Vec<Score>,
     pub if_fc: Option<IfFc>,
+    /// Total pp if every top100 choke were fixed
+    pub unchoked_total: Option<f32>,
 }
 
 // Retrieve user's score on the map, the user itself, and the map including
@@ -290,11 +357,13 @@ pub struct FixScore {
 async fn request_by_map(
     orig: &CommandOrigin<'_>,
     map_id: u32,
+    mode: Option<GameMode>,
     user_id: UserId,
     mods: Option<&GameModsIntermode>,
     legacy_scores: bool,
+    combo_tolerance: Option<u32>,
 ) -> ScoreResult {
-    let map = match Context::osu_map().map(map_id, None).await {
+    let mut map = match Context::osu_map().map(map_id, None).await {
         Ok(map) => map,
         Err(MapError::NotFound) => {
             let content = format!(
@@ -314,6 +383,10 @@ async fn request_by_map(
         }
     };
 
+    if let Some(mode) = mode {
+        map.convert_mut(mode);
+    }
+
     let (user_res, scores_res) = match UserArgs::rosu_id(&user_id, map.mode()).await {
         UserArgs::Args(args) => {
             let user_fut = Context::redis().osu_user_from_args(args);
@@ -367,7 +440,7 @@ async fn request_by_map(
             }),
         },
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let wrap = "Failed to get scores";
 
             return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -395,7 +468,7 @@ async fn request_by_map(
             let top = match top_res {
                 Ok(scores) => scores,
                 Err(err) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error_retryable(OSU_API_ISSUE).await;
                     let wrap = "failed to get top scores";
 
                     return ScoreResult::Error(Report::new(err).wrap_err(wrap));
@@ -406,9 +479,15 @@ async fn request_by_map(
 
             // Not being done concurrently with the previous two because
             // then the map retrieval might happen twice
-            let if_fc = IfFc::new(&score, &map).await;
-
-            Some(FixScore { score, top, if_fc })
+            let if_fc = IfFc::with_combo_tolerance(&score, &map, combo_tolerance).await;
+            let unchoked_total = top_unchoked_total(&user, &top, combo_tolerance).await;
+
+            Some(FixScore {
+                score,
+                top,
+                if_fc,
+                unchoked_total,
+            })
         }
         None => None,
     };
@@ -422,6 +501,7 @@ async fn request_by_score(
     mode: Option<GameMode>,
     user_id: UserId,
     legacy_scores: bool,
+    combo_tolerance: Option<u32>,
 ) -> ScoreResult {
     let mut score_fut = Context::osu().score(score_id);
 
@@ -449,7 +529,7 @@ async fn request_by_score(
             };
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get score");
 
             return ScoreResult::Error(err);
@@ -499,7 +579,7 @@ async fn request_by_score(
             return ScoreResult::Error(err);
         }
         (.., Err(err)) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get top scores");
 
             return ScoreResult::Error(err);
@@ -512,13 +592,95 @@ async fn request_by_score(
     };
 
     let score = ScoreSlim::new(score, pp);
-    let if_fc = IfFc::new(&score, &map).await;
+    let if_fc = IfFc::with_combo_tolerance(&score, &map, combo_tolerance).await;
+    let unchoked_total = top_unchoked_total(&user, &top, combo_tolerance).await;
 
     let data = FixEntry {
         user,
         map,
-        score: Some(FixScore { score, top, if_fc }),
+        score: Some(FixScore {
+            score,
+            top,
+            if_fc,
+            unchoked_total,
+        }),
     };
 
     ScoreResult::Entry(data)
 }
+
+/// Total pp of the user if every choke in their top scores were fixed.
+///
+/// Returns `None` for mania since combo does not matter there.
+async fn top_unchoked_total(
+    user: &CachedUser,
+    top: &[Score],
+    combo_tolerance: Option<u32>,
+) -> Option<f32> {
+    if top.first()?.mode == GameMode::Mania {
+        return None;
+    }
+
+    let user_pp = user.statistics.as_ref()?.pp.to_native();
+
+    let maps_id_checksum: HashMap<_, _, IntHasher> = top
+        .iter()
+        .filter_map(|score| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let maps = match Context::osu_map().maps(&maps_id_checksum).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            warn!(?err, "Failed to get maps of top scores");
+
+            return None;
+        }
+    };
+
+    Some(unchoked_total_pp(user_pp, top, &maps, combo_tolerance).await)
+}
+
+/// Reweights the given top scores after unchoking each of them and returns
+/// the resulting total pp.
+///
+/// Scores whose map is missing or that can't be unchoked keep their pp.
+/// If no combo tolerance is given, the default one of [`IfFc`] is used.
+pub(super) async fn unchoked_total_pp(
+    user_pp: f32,
+    top: &[Score],
+    maps: &HashMap<u32, OsuMap, IntHasher>,
+    combo_tolerance: Option<u32>,
+) -> f32 {
+    let mut actual = Vec::with_capacity(top.len());
+    let mut unchoked = Vec::with_capacity(top.len());
+
+    for score in top {
+        let pp = score.pp.unwrap_or(0.0);
+        actual.push(pp);
+
+        let Some(map) = maps.get(&score.map_id) else {
+            unchoked.push(pp);
+
+            continue;
+        };
+
+        let score = ScoreSlim::new(score.clone(), pp);
+
+        let if_fc = if map.mode() == score.mode {
+            IfFc::with_combo_tolerance(&score, map, combo_tolerance).await
+        } else {
+            let map = map.clone().convert(score.mode);
+
+            IfFc::with_combo_tolerance(&score, &map, combo_tolerance).await
+        };
+
+        unchoked.push(if_fc.map_or(pp, |if_fc| if_fc.pp));
+    }
+
+    unchoked.sort_unstable_by(|a, b| b.total_cmp(a));
+
+    let bonus_pp = user_pp - actual.accum_weighted();
+
+    unchoked.accum_weighted() + bonus_pp
+}
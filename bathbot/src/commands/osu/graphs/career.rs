@@ -0,0 +1,366 @@
+use std::mem;
+
+use bathbot_model::rosu_v2::user::MonthlyCountRkyv;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, IntoDrawingArea, PathElement, Rectangle, SeriesLabelPosition},
+    series::LineSeries,
+    style::{BLACK, Color, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rkyv::{
+    rancor::{Panic, ResultExt},
+    rend::u32_le,
+    with::{Map, With},
+};
+use rosu_v2::{
+    prelude::{MonthlyCount, OsuError},
+    request::UserId,
+};
+use skia_safe::{EncodedImageFormat, surfaces};
+use time::{Date, Duration, OffsetDateTime, util::days_in_year_month};
+
+use super::{H, W};
+use crate::{
+    commands::osu::user_not_found,
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+const RANK_COLOR: RGBColor = RGBColor(0, 208, 138);
+const PLAYCOUNT_COLOR: RGBColor = RGBColor(0, 116, 193);
+
+pub async fn career_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    user_args: UserArgs,
+) -> Result<Option<(CachedUser, Vec<u8>)>> {
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let bytes = match draw_graph(&user) {
+        Ok(Some(graph)) => graph,
+        Ok(None) => {
+            let content = format!(
+                "`{name}` has no available rank data :(",
+                name = user.username.as_str()
+            );
+
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw career graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes)))
+}
+
+fn draw_graph(user: &CachedUser) -> Result<Option<Vec<u8>>> {
+    let history: Vec<_> = user
+        .rank_history
+        .as_ref()
+        .iter()
+        .copied()
+        .map(u32_le::to_native)
+        .collect();
+
+    let playcounts: Vec<MonthlyCount> = rkyv::api::deserialize_using::<_, _, Panic>(
+        With::<_, Map<MonthlyCountRkyv>>::cast(&user.monthly_playcounts),
+        &mut (),
+    )
+    .always_ok();
+
+    let today = OffsetDateTime::now_utc().date();
+    let data = CareerData::new(&history, &playcounts, today);
+
+    let Some((min_rank, max_rank)) = data.rank_bounds() else {
+        return Ok(None);
+    };
+
+    // Ranks are negated so that better ranks are at the top
+    let pad = ((max_rank - min_rank) / 10).max(1) as i32;
+    let y_min = -(max_rank as i32) - pad;
+    let y_max = (-(min_rank as i32) + pad).min(-1);
+
+    let playcount_max = data
+        .playcounts
+        .iter()
+        .map(|bar| bar.count)
+        .max()
+        .map_or(2, |max| max.max(2));
+
+    let start = data.start;
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(70)
+            .right_y_label_area_size(75)
+            .margin(10)
+            .build_cartesian_2d(0..data.end_day, y_min..y_max)
+            .wrap_err("Failed to build chart")?
+            .set_secondary_coord(0..data.end_day, 0..playcount_max);
+
+        chart
+            .configure_mesh()
+            .light_line_style(BLACK.mix(0.0))
+            .disable_x_mesh()
+            .x_labels(10)
+            .x_label_formatter(&|day| {
+                let date = start + Duration::days(*day as i64);
+
+                format!("{}-{:02}", date.year(), date.month() as u8)
+            })
+            .y_label_formatter(&|rank| format_rank(rank.unsigned_abs()))
+            .y_desc("Rank")
+            .label_style(("sans-serif", 15, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw primary mesh")?;
+
+        chart
+            .configure_secondary_axes()
+            .y_desc("Monthly playcount")
+            .label_style(("sans-serif", 15, &WHITE))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw secondary mesh")?;
+
+        // Playcount bars first so that the rank is drawn on top of them
+        let bars = data.playcounts.iter().map(|bar| {
+            let style = PLAYCOUNT_COLOR.mix(0.6).filled();
+
+            Rectangle::new([(bar.start_day, bar.count), (bar.end_day, 0)], style)
+        });
+
+        chart
+            .draw_secondary_series(bars)
+            .wrap_err("Failed to draw playcount bars")?
+            .label("Monthly playcount")
+            .legend(|(x, y)| {
+                let style = PLAYCOUNT_COLOR.mix(0.6).filled();
+
+                Rectangle::new([(x, y - 5), (x + 20, y + 5)], style)
+            });
+
+        // Inactive days split the rank into multiple lines
+        for (i, segment) in data.ranks.iter().enumerate() {
+            let points = segment.iter().map(|&(day, rank)| (day, -(rank as i32)));
+            let series = LineSeries::new(points, RANK_COLOR.stroke_width(3));
+            let drawn = chart.draw_series(series).wrap_err("Failed to draw rank")?;
+
+            if i == 0 {
+                drawn.label("Rank").legend(|(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], RANK_COLOR.stroke_width(3))
+                });
+            }
+        }
+
+        chart
+            .configure_series_labels()
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(30)
+            .label_font(("sans-serif", 15, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(Some(png_bytes))
+}
+
+/// Rank history and monthly playcounts on a shared x-axis of days since
+/// `start`.
+struct CareerData {
+    start: Date,
+    end_day: i32,
+    /// Consecutive days with a rank as `(day, rank)` pairs
+    ranks: Vec<Vec<(i32, u32)>>,
+    playcounts: Vec<PlaycountBar>,
+}
+
+struct PlaycountBar {
+    start_day: i32,
+    end_day: i32,
+    count: i32,
+}
+
+impl CareerData {
+    /// The last entry of `history` is considered to be the rank of `today`.
+    fn new(history: &[u32], playcounts: &[MonthlyCount], today: Date) -> Self {
+        let history_start = today - Duration::days(history.len().saturating_sub(1) as i64);
+
+        let start = playcounts
+            .first()
+            .map_or(history_start, |count| count.start_date.min(history_start));
+
+        let day_of = |date: Date| (date - start).whole_days() as i32;
+
+        let mut ranks = Vec::new();
+        let mut segment = Vec::new();
+
+        for (day, &rank) in (day_of(history_start)..).zip(history) {
+            if rank > 0 {
+                segment.push((day, rank));
+            } else if !segment.is_empty() {
+                ranks.push(mem::take(&mut segment));
+            }
+        }
+
+        if !segment.is_empty() {
+            ranks.push(segment);
+        }
+
+        let playcounts = playcounts
+            .iter()
+            .map(|MonthlyCount { start_date, count }| {
+                let days = days_in_year_month(start_date.year(), start_date.month());
+                let start_day = day_of(*start_date);
+
+                PlaycountBar {
+                    start_day,
+                    end_day: start_day + days as i32,
+                    count: *count,
+                }
+            })
+            .collect();
+
+        Self {
+            start,
+            end_day: day_of(today).max(1),
+            ranks,
+            playcounts,
+        }
+    }
+
+    /// Best and worst rank or `None` if there are no ranks.
+    fn rank_bounds(&self) -> Option<(u32, u32)> {
+        let mut ranks = self.ranks.iter().flatten().map(|(_, rank)| *rank);
+        let first = ranks.next()?;
+
+        Some(ranks.fold((first, first), |(min, max), rank| {
+            (min.min(rank), max.max(rank))
+        }))
+    }
+}
+
+/// Shortens large ranks, e.g. `100000` to `100k` or `1500` to `1.5k`.
+fn format_rank(rank: u32) -> String {
+    let (value, suffix) = match rank {
+        1_000_000.. => (rank as f32 / 1_000_000.0, "m"),
+        1000.. => (rank as f32 / 1000.0, "k"),
+        _ => return rank.to_string(),
+    };
+
+    let value = (value * 10.0).round() / 10.0;
+
+    if value.fract() == 0.0 {
+        format!("{value:.0}{suffix}")
+    } else {
+        format!("{value:.1}{suffix}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn rank_labels() {
+        assert_eq!(format_rank(950), "950");
+        assert_eq!(format_rank(1500), "1.5k");
+        assert_eq!(format_rank(50_000), "50k");
+        assert_eq!(format_rank(100_000), "100k");
+        assert_eq!(format_rank(2_300_000), "2.3m");
+    }
+
+    #[test]
+    fn inactive_gaps() {
+        let today = date(2025, Month::March, 10);
+        let data = CareerData::new(&[0, 500, 400, 0, 0, 300], &[], today);
+
+        assert_eq!(data.start, date(2025, Month::March, 5));
+        assert_eq!(data.end_day, 5);
+        assert_eq!(data.ranks, [vec![(1, 500), (2, 400)], vec![(5, 300)]]);
+        assert_eq!(data.rank_bounds(), Some((300, 500)));
+    }
+
+    #[test]
+    fn extended_by_playcounts() {
+        let today = date(2025, Month::March, 10);
+
+        let playcounts = [
+            MonthlyCount {
+                start_date: date(2025, Month::January, 1),
+                count: 50,
+            },
+            MonthlyCount {
+                start_date: date(2025, Month::February, 1),
+                count: 20,
+            },
+        ];
+
+        let data = CareerData::new(&[1000, 900], &playcounts, today);
+
+        assert_eq!(data.start, date(2025, Month::January, 1));
+        assert_eq!(data.end_day, 68);
+        assert_eq!(data.ranks, [vec![(67, 1000), (68, 900)]]);
+        assert_eq!(data.playcounts[0].end_day, 31);
+        assert_eq!(data.playcounts[1].start_day, 31);
+        assert_eq!(data.playcounts[1].end_day, 59);
+    }
+
+    #[test]
+    fn no_ranks() {
+        let today = date(2025, Month::March, 10);
+        let data = CareerData::new(&[0, 0], &[], today);
+
+        assert!(data.ranks.is_empty());
+        assert_eq!(data.rank_bounds(), None);
+    }
+}
@@ -22,6 +22,7 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::id::{Id, marker::UserMarker};
 
 use self::{
+    career::career_graph,
     medals::medals_graph,
     playcount_replays::{ProfileGraphFlags, playcount_replays_graph},
     rank::rank_graph,
@@ -39,6 +40,7 @@ use crate::{
     util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod career;
 mod medals;
 mod playcount_replays;
 mod rank;
@@ -52,6 +54,8 @@ mod top_time;
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "graph", desc = "Display graphs about some user data")]
 pub enum Graph {
+    #[command(name = "career")]
+    Career(GraphCareer),
     #[command(name = "medals")]
     Medals(GraphMedals),
     #[command(name = "playcount_replays")]
@@ -68,6 +72,29 @@ pub enum Graph {
     Top(GraphTop),
 }
 
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "career",
+    desc = "Display a user's rank and monthly playcount since registration",
+    help = "Display a user's rank and monthly playcount since registration.\n\
+    Since the osu!api only provides the rank of the last 90 days, the rank \
+    history is shown alongside the monthly playcount of the user's whole career.\n\
+    Gaps in the rank line indicate days on which the user was inactive."
+)]
+pub struct GraphCareer {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<String>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
 #[derive(CommandModel, CreateCommand, HasName)]
 #[command(name = "medals", desc = "Display a user's medal progress over time")]
 pub struct GraphMedals {
@@ -220,6 +247,30 @@ async fn slash_graph(mut command: InteractionCommand) -> Result<()> {
 // `InteractionCommand`
 async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
     let tuple_option = match args {
+        Graph::Career(args) => {
+            let (user_id, mode) = user_id_mode!(orig, args);
+            let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+            let tuple_option = career_graph(&orig, user_id, user_args)
+                .await
+                .wrap_err("Failed to create career graph")?;
+
+            let Some((user, graph)) = tuple_option else {
+                return Ok(());
+            };
+
+            let embed = EmbedBuilder::new()
+                .author(user.author_builder(false))
+                .image(attachment("career_graph.png"));
+
+            let builder = MessageBuilder::new()
+                .embed(embed)
+                .attachment("career_graph.png", graph);
+
+            orig.create_message(builder).await?;
+
+            return Ok(());
+        }
         Graph::Medals(args) => {
             let user_id = match user_id!(orig, args) {
                 Some(user_id) => user_id,
@@ -335,6 +386,7 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
                 .mode
                 .map(GameMode::from)
                 .or(config.mode)
+                .or(Context::guild_config().mode(orig.guild_id()).await)
                 .unwrap_or(GameMode::Osu);
 
             let (user_id, no_user_specified) = match user_id!(orig, args) {
@@ -386,8 +438,8 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph) -> Result<()> {
     Ok(())
 }
 
-const W: u32 = 1350;
-const H: u32 = 711;
+pub(super) const W: u32 = 1350;
+pub(super) const H: u32 = 711;
 
 async fn top_graph(
     orig: &CommandOrigin<'_>,
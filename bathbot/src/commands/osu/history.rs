@@ -0,0 +1,309 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::osu::DbUserSnapshot;
+use bathbot_util::{
+    EmbedBuilder, MessageBuilder,
+    constants::GENERAL_ISSUE,
+    numbers::{WithComma, round},
+};
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, IntoDrawingArea},
+    series::AreaSeries,
+    style::{Color, RGBColor, ShapeStyle, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::prelude::{GameMode, OsuError};
+use skia_safe::{EncodedImageFormat, surfaces};
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{
+    graphs::{H, W},
+    user_not_found,
+};
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    embeds::attachment,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "history",
+    desc = "Display how a user's rank, pp, and accuracy changed over time",
+    help = "Display how a user's rank, pp, and accuracy changed over time.\n\
+    The data is based on daily snapshots that the bot stores whenever a user's profile \
+    is requested, e.g. through commands or tracking. \
+    Users without stored snapshots won't have a history yet."
+)]
+pub struct History<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = "Choose the time window, defaults to the last 7 days")]
+    window: Option<HistoryWindow>,
+    #[command(desc = "Specify whether a pp graph should be included")]
+    graph: Option<bool>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum HistoryWindow {
+    #[default]
+    #[option(name = "Last 7 days", value = "7d")]
+    Week,
+    #[option(name = "Last 30 days", value = "30d")]
+    Month,
+    #[option(name = "All", value = "all")]
+    All,
+}
+
+impl HistoryWindow {
+    fn days(self) -> Option<i64> {
+        match self {
+            Self::Week => Some(7),
+            Self::Month => Some(30),
+            Self::All => None,
+        }
+    }
+}
+
+async fn slash_history(mut command: InteractionCommand) -> Result<()> {
+    let args = History::from_interaction(command.input_data())?;
+
+    history((&mut command).into(), args).await
+}
+
+async fn history(orig: CommandOrigin<'_>, args: History<'_>) -> Result<()> {
+    let (user_id, mode) = user_id_mode!(orig, args);
+
+    // Requesting the user also stores today's snapshot
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let window = args.window.unwrap_or_default();
+
+    let since = window
+        .days()
+        .map(|days| OffsetDateTime::now_utc().date() - Duration::days(days));
+
+    let snapshots_fut = Context::osu_user().snapshots(user.user_id.to_native(), mode, since);
+
+    let snapshots = match snapshots_fut.await {
+        Ok(snapshots) => snapshots,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+        let content = format!(
+            "There is no {mode}history stored for `{name}` yet.\n\
+            Snapshots are stored whenever their profile is requested so consider \
+            tracking them via `/track add` and check back in a few days.",
+            mode = mode_str(mode),
+            name = user.username.as_str(),
+        );
+
+        return orig.error(content).await;
+    };
+
+    let mut description = format!(
+        "Snapshots from <t:{from}:D> to <t:{to}:D> ({count} snapshots)\n\n",
+        from = first.snapshot_date.midnight().assume_utc().unix_timestamp(),
+        to = last.snapshot_date.midnight().assume_utc().unix_timestamp(),
+        count = snapshots.len(),
+    );
+
+    let _ = writeln!(
+        description,
+        "**Rank:** {from} → {to}{delta}",
+        from = RankFormat(first.global_rank),
+        to = RankFormat(last.global_rank),
+        delta = RankDelta(first.global_rank, last.global_rank),
+    );
+
+    let _ = writeln!(
+        description,
+        "**PP:** {from}pp → {to}pp ({delta:+})",
+        from = WithComma::new(first.pp),
+        to = WithComma::new(last.pp),
+        delta = round(last.pp - first.pp),
+    );
+
+    let _ = write!(
+        description,
+        "**Accuracy:** {from}% → {to}% ({delta:+}%)",
+        from = round(first.accuracy),
+        to = round(last.accuracy),
+        delta = round(last.accuracy - first.accuracy),
+    );
+
+    let mut embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .thumbnail(user.avatar_url.as_ref());
+
+    let mut builder = MessageBuilder::new();
+
+    if args.graph.unwrap_or(false) && snapshots.len() > 1 {
+        match draw_graph(&snapshots) {
+            Ok(graph) => {
+                embed = embed.image(attachment("graph.png"));
+                builder = builder.attachment("graph.png", graph);
+            }
+            Err(err) => warn!(?err, "Failed to draw history graph"),
+        }
+    }
+
+    orig.create_message(builder.embed(embed)).await?;
+
+    Ok(())
+}
+
+fn draw_graph(snapshots: &[DbUserSnapshot]) -> Result<Vec<u8>> {
+    let first_date = snapshots[0].snapshot_date;
+    let last_day = (snapshots[snapshots.len() - 1].snapshot_date - first_date).whole_days() as u32;
+
+    let (min, max) = snapshots
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), snapshot| {
+            (min.min(snapshot.pp), max.max(snapshot.pp))
+        });
+
+    // Add some padding so the curve does not touch the borders
+    let padding = ((max - min) * 0.1).max(1.0);
+    let (min, max) = (min - padding, max + padding);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(75)
+            .margin(10)
+            .margin_left(6)
+            .build_cartesian_2d(0_u32..last_day.max(1), min..max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_labels(15)
+            .x_desc("Date")
+            .x_label_formatter(&|x| {
+                let date = first_date + Duration::days(i64::from(*x));
+
+                format!("{:0>2}.{:0>2}.", date.day(), date.month() as u8)
+            })
+            .y_label_formatter(&|y| format!("{}", WithComma::new(*y as u32)))
+            .y_desc("PP")
+            .label_style(("sans-serif", 15, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        let data = snapshots.iter().map(|snapshot| {
+            let day = (snapshot.snapshot_date - first_date).whole_days() as u32;
+
+            (day, snapshot.pp)
+        });
+
+        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
+
+        let border_style = ShapeStyle {
+            color: RGBColor(0, 208, 138).to_rgba(),
+            filled: false,
+            stroke_width: 3,
+        };
+
+        let series = AreaSeries::new(data, min, area_style).border_style(border_style);
+        chart.draw_series(series).wrap_err("Failed to draw area")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+struct RankFormat(i32);
+
+impl Display for RankFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0 == 0 {
+            f.write_str("-")
+        } else {
+            write!(f, "#{}", WithComma::new(self.0 as u32))
+        }
+    }
+}
+
+/// Positive if the rank improved
+struct RankDelta(i32, i32);
+
+impl Display for RankDelta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self(from, to) = *self;
+
+        if from == 0 || to == 0 {
+            return Ok(());
+        }
+
+        write!(f, " ({:+})", from - to)
+    }
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "",
+        GameMode::Taiko => "taiko ",
+        GameMode::Catch => "ctb ",
+        GameMode::Mania => "mania ",
+    }
+}
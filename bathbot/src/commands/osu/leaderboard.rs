@@ -1,4 +1,8 @@
-use std::{borrow::Cow, cmp::Reverse, collections::HashMap};
+use std::{
+    borrow::Cow,
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+};
 
 use bathbot_macros::{HasMods, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
@@ -10,6 +14,7 @@ use bathbot_util::{
     osu::{MapIdType, ModSelection},
 };
 use eyre::{Report, Result};
+use futures::{StreamExt, stream};
 use rosu_v2::prelude::{
     BeatmapUserScore, GameMode, GameMods, GameModsIntermode, Grade, OsuError, Score,
     ScoreStatistics, Username,
@@ -55,11 +60,15 @@ pub struct Leaderboard<'a> {
     #[command(
         desc = "Choose how the scores should be ordered",
         help = "Choose how the scores should be ordered, defaults to `score`.\n\
-        Note that the scores will still be the top pp scores, they'll just be re-ordered."
+        Note that the scores will still be the top pp scores, they'll just be re-ordered.\n\
+        When sorting by pp, scores whose pp could not be calculated are placed last."
     )]
     sort: Option<LeaderboardSort>,
 }
 
+/// How many scores have their pp calculated simultaneously when sorting by pp
+const PP_CONCURRENCY: usize = 8;
+
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
 pub enum LeaderboardSort {
     #[option(name = "Accuracy", value = "acc")]
@@ -85,19 +94,14 @@ impl LeaderboardSort {
             Self::Date => scores.sort_by_key(|score| score.ended_at),
             Self::Misses => scores.sort_by_key(|score| score.statistics.miss),
             Self::Pp => {
-                let mut pps = HashMap::with_capacity_and_hasher(scores.len(), IntHasher);
-
-                for score in scores.iter_mut() {
-                    let pp = score.pp(map).await.pp;
-                    pps.insert(score.pos, pp);
-                }
-
-                scores.sort_by(|a, b| {
-                    let a_pp = pps.get(&a.pos).copied().unwrap_or(0.0);
-                    let b_pp = pps.get(&b.pos).copied().unwrap_or(0.0);
-
-                    b_pp.total_cmp(&a_pp)
-                })
+                // Sort by the same calculated pp that is displayed
+                stream::iter(scores.iter_mut())
+                    .for_each_concurrent(PP_CONCURRENCY, |score| async move {
+                        score.pp(map).await;
+                    })
+                    .await;
+
+                sort_by_pp(scores);
             }
             Self::Score if score_data == ScoreData::LazerWithClassicScoring => {
                 scores.sort_by_key(|score| Reverse(score.classic_score))
@@ -134,6 +138,7 @@ impl<'m> LeaderboardArgs<'m> {
     ) -> Result<LeaderboardArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
+        let mut url_mode = None;
 
         for arg in args.take(2) {
             if let Some(id) = matcher::get_osu_map_id(arg)
@@ -141,6 +146,7 @@ impl<'m> LeaderboardArgs<'m> {
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
                 map = Some(id);
+                url_mode = MapIdType::resolve_mode(arg, mode);
             } else if matcher::get_mods(arg).is_some() {
                 mods = Some(arg.into());
             } else {
@@ -170,7 +176,7 @@ impl<'m> LeaderboardArgs<'m> {
         Ok(Self {
             map,
             mods,
-            mode,
+            mode: url_mode.or(mode),
             sort,
         })
     }
@@ -180,6 +186,13 @@ impl<'a> TryFrom<Leaderboard<'a>> for LeaderboardArgs<'a> {
     type Error = &'static str;
 
     fn try_from(args: Leaderboard<'a>) -> Result<Self, Self::Error> {
+        let mode = args.mode.map(GameMode::from);
+
+        let mode = match args.map {
+            Some(ref map) => MapIdType::resolve_mode(map, mode),
+            None => mode,
+        };
+
         let map = match args.map {
             Some(map) => {
                 if let Some(id) = matcher::get_osu_map_id(&map)
@@ -199,7 +212,7 @@ impl<'a> TryFrom<Leaderboard<'a>> for LeaderboardArgs<'a> {
         Ok(Self {
             map,
             mods: args.mods,
-            mode: args.mode.map(GameMode::from),
+            mode,
             sort: args.sort.unwrap_or_default(),
         })
     }
@@ -326,7 +339,7 @@ async fn slash_leaderboard(mut command: InteractionCommand) -> Result<()> {
     }
 }
 
-async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Result<()> {
+async fn leaderboard(mut orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
         ModsResult::None => None,
@@ -447,7 +460,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
             return Err(err.wrap_err("Failed to get leaderboard"));
         }
@@ -495,6 +508,12 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
 
     let first_place_icon = scores.first().and_then(|s| avatar_urls.remove(&s.score_id));
 
+    let thread_name = format!("Leaderboard of {} [{}]", map.title(), map.version());
+
+    if let Err(err) = orig.redirect_into_thread(&thread_name).await {
+        warn!(?err, "Failed to redirect into thread");
+    }
+
     let pagination = LeaderboardPagination::builder()
         .map(map)
         .scores(scores.into_boxed_slice())
@@ -640,9 +659,69 @@ impl LeaderboardScore {
 
         *self.pps.insert(PpData { pp, max: max_pp })
     }
+
+    /// The calculated pp value to sort by.
+    ///
+    /// `None` if not calculated successfully.
+    fn sort_pp(&self) -> Option<f32> {
+        self.pps.map(|pps| pps.pp).filter(|pp| pp.is_finite())
+    }
+}
+
+/// Sort scores by their calculated pp; scores without pp are placed last.
+fn sort_by_pp(scores: &mut [LeaderboardScore]) {
+    scores.sort_by(|a, b| match (a.sort_pp(), b.sort_pp()) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    })
 }
 
 pub struct LeaderboardUserScore {
     pub discord_id: Id<UserMarker>,
     pub score: LeaderboardScore,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(pos: usize, pp: Option<f32>) -> LeaderboardScore {
+        LeaderboardScore {
+            user_id: pos as u32,
+            username: "Player".into(),
+            pos,
+            grade: Grade::S,
+            accuracy: 98.0,
+            statistics: ScoreStatistics::default(),
+            mode: GameMode::Osu,
+            mods: GameMods::new(),
+            combo: 500,
+            score: 1_000_000 - pos as u32,
+            classic_score: 0,
+            ended_at: OffsetDateTime::UNIX_EPOCH,
+            score_id: pos as u64,
+            is_legacy: false,
+            set_on_lazer: true,
+            pps: pp.map(|pp| PpData { pp, max: 500.0 }),
+        }
+    }
+
+    #[test]
+    fn pp_sort_uses_calculated_pp() {
+        // Scores on e.g. loved maps come without pp from the osu!api so the
+        // calculated pp must be used for sorting
+        let mut scores = vec![
+            score(1, Some(200.0)),
+            score(2, None),
+            score(3, Some(f32::NAN)),
+            score(4, Some(300.0)),
+        ];
+
+        sort_by_pp(&mut scores);
+
+        let order: Vec<_> = scores.iter().map(|score| score.pos).collect();
+        assert_eq!(order, [4, 1, 2, 3]);
+    }
+}
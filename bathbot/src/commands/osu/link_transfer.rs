@@ -0,0 +1,394 @@
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{EmbedBuilder, IntHasher, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, OsuError};
+use tokio::time::{Interval, MissedTickBehavior, interval};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::Attachment,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    Context,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Maximum amount of rows that are processed per import
+const IMPORT_LIMIT: usize = 500;
+
+/// Minimum duration between two osu!api requests of an import
+const IMPORT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Minimum duration between two progress updates of the response
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
+
+const FAILURES_FILENAME: &str = "link_import_failures.csv";
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "linkimport",
+    dm_permission = false,
+    desc = "Link members of this server to osu! profiles in bulk",
+    help = "Link members of this server to osu! profiles in bulk.\n\
+    The attached CSV file must contain one `discord_id,osu_user` row per line \
+    where `osu_user` is either an osu! user id or username.\n\
+    Rows of discord users that are already linked are skipped.\n\
+    Up to 500 rows are processed per invocation. \
+    Rows that could not be processed are sent back in a file which \
+    can be uploaded again once the issues are resolved.\n\
+    Use `/linkexport` to create such a file for the current links."
+)]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+pub struct LinkImport {
+    #[command(desc = "CSV file of `discord_id,osu_user` rows")]
+    file: Attachment,
+}
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "linkexport",
+    dm_permission = false,
+    desc = "Export the osu! links of this server's members",
+    help = "Export the osu! links of this server's members as CSV file.\n\
+    The file can be imported through `/linkimport`."
+)]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+pub struct LinkExport;
+
+async fn slash_linkimport(mut command: InteractionCommand) -> Result<()> {
+    let args = LinkImport::from_interaction(command.input_data())?;
+
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    if !args.file.filename.ends_with(".csv") {
+        command
+            .error("The attached file must be of type .csv")
+            .await?;
+
+        return Ok(());
+    }
+
+    let bytes = match Context::client().get_discord_attachment(&args.file).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download attachment"));
+        }
+    };
+
+    let Ok(content) = std::str::from_utf8(&bytes) else {
+        command
+            .error("The attached file must be UTF-8 encoded")
+            .await?;
+
+        return Ok(());
+    };
+
+    let members: HashSet<_, IntHasher> = match Context::cache().members(guild_id).await {
+        Ok(members) => members.into_iter().collect(),
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get guild members"));
+        }
+    };
+
+    let mut rows = parse_rows(content);
+    let mut failures = Vec::new();
+
+    if rows.len() > IMPORT_LIMIT {
+        let reason = "Exceeded row limit of a single import";
+
+        let exceeding = rows.drain(IMPORT_LIMIT..).map(|row| match row {
+            ImportRow::Valid { discord_id, osu } => {
+                ImportFailure::new(discord_id.to_string(), osu, reason)
+            }
+            ImportRow::Invalid { discord_id, osu } => ImportFailure::new(discord_id, osu, reason),
+        });
+
+        failures.extend(exceeding);
+    }
+
+    let total = rows.len();
+    let mut linked = 0;
+    let mut skipped = 0;
+
+    let mut interval = interval(IMPORT_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut last_update = Instant::now();
+
+    for (row, i) in rows.into_iter().zip(1..) {
+        let (discord_id, osu) = match row {
+            ImportRow::Valid { discord_id, osu } => (discord_id, osu),
+            ImportRow::Invalid { discord_id, osu } => {
+                failures.push(ImportFailure::new(discord_id, osu, "Invalid discord id"));
+
+                continue;
+            }
+        };
+
+        let reason = if members.contains(&discord_id.get()) {
+            match import_row(discord_id, &osu, &mut interval).await {
+                Ok(ImportResult::Linked) => {
+                    linked += 1;
+
+                    None
+                }
+                Ok(ImportResult::AlreadyLinked) => {
+                    skipped += 1;
+
+                    None
+                }
+                Ok(ImportResult::Failed(reason)) => Some(reason),
+                Err(err) => {
+                    warn!(?err, "Failed to import link");
+
+                    Some("Internal error")
+                }
+            }
+        } else {
+            Some("Not a member of this server")
+        };
+
+        if let Some(reason) = reason {
+            failures.push(ImportFailure::new(discord_id.to_string(), osu, reason));
+        }
+
+        if i < total && last_update.elapsed() >= PROGRESS_INTERVAL {
+            last_update = Instant::now();
+
+            let embed = EmbedBuilder::new().description(format!("Processed {i}/{total} rows..."));
+            let builder = MessageBuilder::new().embed(embed);
+
+            if let Err(err) = command.update(builder).await {
+                warn!(?err, "Failed to update link import progress");
+            }
+        }
+    }
+
+    let mut description = format!(
+        "Linked {linked} member{plural}",
+        plural = if linked == 1 { "" } else { "s" }
+    );
+
+    if skipped > 0 {
+        let _ = write!(description, "\nSkipped {skipped} already linked");
+    }
+
+    if !failures.is_empty() {
+        let _ = write!(
+            description,
+            "\nFailed {failed} row{plural}; re-upload the attached file to retry them",
+            failed = failures.len(),
+            plural = if failures.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    let embed = EmbedBuilder::new()
+        .title("Link import")
+        .description(description);
+
+    let mut builder = MessageBuilder::new().embed(embed);
+
+    if !failures.is_empty() {
+        builder = builder.attachment(FAILURES_FILENAME, failures_csv(&failures).into_bytes());
+    }
+
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn slash_linkexport(command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+
+    let members: Vec<_> = match Context::cache().members(guild_id).await {
+        Ok(members) => members.into_iter().map(|id| id as i64).collect(),
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get guild members"));
+        }
+    };
+
+    let links = match Context::psql()
+        .select_osu_links_by_discord_ids(&members)
+        .await
+    {
+        Ok(links) => links,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get osu! links"));
+        }
+    };
+
+    if links.is_empty() {
+        command
+            .error("No member of this server is linked to an osu! profile")
+            .await?;
+
+        return Ok(());
+    }
+
+    let mut csv = String::from("discord_id,osu_id,osu_username\n");
+
+    for link in links.iter() {
+        let _ = writeln!(
+            csv,
+            "{},{},{}",
+            link.discord_id,
+            link.osu_id,
+            link.username.as_deref().unwrap_or_default()
+        );
+    }
+
+    let content = format!(
+        "Exported {len} link{plural}",
+        len = links.len(),
+        plural = if links.len() == 1 { "" } else { "s" }
+    );
+
+    let embed = EmbedBuilder::new().description(content);
+
+    let builder = MessageBuilder::new()
+        .embed(embed)
+        .attachment("links.csv", csv.into_bytes());
+
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+enum ImportRow {
+    Valid {
+        discord_id: Id<UserMarker>,
+        osu: Box<str>,
+    },
+    Invalid {
+        discord_id: Box<str>,
+        osu: Box<str>,
+    },
+}
+
+enum ImportResult {
+    Linked,
+    AlreadyLinked,
+    Failed(&'static str),
+}
+
+struct ImportFailure {
+    discord_id: Box<str>,
+    osu: Box<str>,
+    reason: &'static str,
+}
+
+impl ImportFailure {
+    fn new(discord_id: impl Into<Box<str>>, osu: Box<str>, reason: &'static str) -> Self {
+        Self {
+            discord_id: discord_id.into(),
+            osu,
+            reason,
+        }
+    }
+}
+
+/// Parses `discord_id,osu_user` rows.
+///
+/// Additional columns such as the reason of a failure file are ignored and a
+/// leading header row is skipped.
+fn parse_rows(content: &str) -> Vec<ImportRow> {
+    let mut rows = Vec::new();
+
+    let lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+
+    for (line, i) in lines.zip(0..) {
+        let mut fields = line.split(',').map(|field| field.trim().trim_matches('"'));
+
+        let discord_id = fields.next().unwrap_or_default();
+        let osu = fields.next().unwrap_or_default();
+
+        let parsed_id = discord_id.parse().ok().and_then(Id::new_checked);
+
+        match parsed_id {
+            Some(discord_id) if !osu.is_empty() => rows.push(ImportRow::Valid {
+                discord_id,
+                osu: osu.into(),
+            }),
+            None if i == 0 => {} // header
+            _ => rows.push(ImportRow::Invalid {
+                discord_id: discord_id.into(),
+                osu: osu.into(),
+            }),
+        }
+    }
+
+    rows
+}
+
+async fn import_row(
+    discord_id: Id<UserMarker>,
+    osu: &str,
+    interval: &mut Interval,
+) -> Result<ImportResult> {
+    if Context::user_config().osu_id(discord_id).await?.is_some() {
+        return Ok(ImportResult::AlreadyLinked);
+    }
+
+    // Respect the osu!api when processing many rows
+    interval.tick().await;
+
+    let args = match osu.parse() {
+        Ok(user_id) => UserArgs::user_id(user_id, GameMode::Osu),
+        Err(_) => UserArgs::username(osu, GameMode::Osu).await,
+    };
+
+    let user_id = match Context::redis().osu_user(args).await {
+        Ok(user) => user.user_id.to_native(),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            return Ok(ImportResult::Failed("osu! user not found"));
+        }
+        Err(err) => return Err(Report::new(err).wrap_err("Failed to get user")),
+    };
+
+    if Context::user_config()
+        .discord_from_osu_id(user_id)
+        .await?
+        .is_some()
+    {
+        return Ok(ImportResult::Failed("osu! user is linked to someone else"));
+    }
+
+    let user_config = Context::user_config();
+    let mut config = user_config.with_osu_id(discord_id).await?;
+    config.osu = Some(user_id);
+    user_config.store(discord_id, &config).await?;
+
+    Ok(ImportResult::Linked)
+}
+
+fn failures_csv(failures: &[ImportFailure]) -> String {
+    let mut csv = String::from("discord_id,osu_user,reason\n");
+
+    for failure in failures {
+        let _ = writeln!(
+            csv,
+            "{},{},{}",
+            failure.discord_id, failure.osu, failure.reason
+        );
+    }
+
+    csv
+}
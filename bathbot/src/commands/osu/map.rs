@@ -1,6 +1,7 @@
 use std::{borrow::Cow, cell::RefCell, cmp::Ordering, fmt::Write, mem, rc::Rc, time::Duration};
 
 use bathbot_macros::{HasMods, SlashCommand, command};
+use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     MessageOrigin,
     constants::OSU_API_ISSUE,
@@ -21,7 +22,7 @@ use skia_safe::{BlendMode, EncodedImageFormat, surfaces};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{channel::Message, guild::Permissions};
 
-use super::{BitMapElement, HasMods, ModsResult};
+use super::{BitMapElement, HasMods, ModsResult, mods_error, pp_undefined_content};
 use crate::{
     Context,
     active::{ActiveMessages, impls::MapPagination},
@@ -35,9 +36,8 @@ use crate::{
     desc = "Display a bunch of stats about a map(set)",
     help = "Display a bunch of stats about a map(set).\n\
     The values in the map info will be adjusted to mods.\n\
-    Since discord does not allow images to be adjusted when editing messages, \
-    the strain graph always belongs to the initial map, even after moving to \
-    other maps of the set through the pagination buttons."
+    Other difficulties of the mapset can be selected through the pagination \
+    buttons or directly through the difficulty menu."
 )]
 pub struct Map<'a> {
     #[command(
@@ -60,13 +60,25 @@ pub struct Map<'a> {
     cs: Option<f64>,
     #[command(desc = "Specify an HP value to override the actual one")]
     hp: Option<f64>,
+    #[command(
+        desc = "Only show difficulties of a specific mode",
+        help = "Only show difficulties of a specific mode.\n\
+        If the mapset has no difficulties of that mode, its osu!standard \
+        difficulties will be shown as converts instead."
+    )]
+    mode: Option<GameModeOption>,
 }
 
 #[derive(HasMods)]
 struct MapArgs<'a> {
     map: Option<MapIdType>,
     mods: Option<Cow<'a, str>>,
+    mode: Option<GameMode>,
+    /// Only show difficulties of this mode
+    filter_mode: Option<GameMode>,
     attrs: CustomAttrs,
+    /// Message content if no custom attributes are specified
+    content: Option<String>,
 }
 
 #[derive(Default)]
@@ -123,13 +135,30 @@ impl<'m> MapArgs<'m> {
     async fn args(msg: &Message, args: Args<'m>) -> Result<MapArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
-
-        for arg in args.take(2) {
-            if let Some(id) = matcher::get_osu_map_id(arg)
+        let mut mode = None;
+        let mut filter_mode = None;
+
+        for arg in args.take(3) {
+            if let Some(value) = arg.strip_prefix("mode=") {
+                filter_mode = match value {
+                    "0" | "o" | "osu" | "std" | "standard" => Some(GameMode::Osu),
+                    "1" | "t" | "tko" | "taiko" => Some(GameMode::Taiko),
+                    "2" | "c" | "ctb" | "fruits" | "catch" => Some(GameMode::Catch),
+                    "3" | "m" | "mna" | "mania" => Some(GameMode::Mania),
+                    _ => {
+                        let content = "Failed to parse `mode`. After `mode=` you must \
+                        specify the mode either by its name or by its number i.e. \
+                        0=osu, 1=taiko, 2=ctb, 3=mania.";
+
+                        return Err(content.to_owned());
+                    }
+                };
+            } else if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
                 map = Some(id);
+                mode = MapIdType::resolve_mode(arg, None);
             } else if matcher::get_mods(arg).is_some() {
                 mods = Some(arg.into());
             } else {
@@ -157,7 +186,10 @@ impl<'m> MapArgs<'m> {
         Ok(Self {
             map,
             mods,
+            mode,
+            filter_mode,
             attrs: CustomAttrs::default(),
+            content: None,
         })
     }
 }
@@ -173,8 +205,13 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             od,
             cs,
             hp,
+            mode: filter_mode,
         } = args;
 
+        let mode = map
+            .as_deref()
+            .and_then(|arg| MapIdType::resolve_mode(arg, None));
+
         let map = match map.map(|arg| {
             matcher::get_osu_map_id(&arg)
                 .map(MapIdType::Map)
@@ -192,7 +229,14 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
 
         let attrs = CustomAttrs { ar, cs, hp, od };
 
-        Ok(Self { map, mods, attrs })
+        Ok(Self {
+            map,
+            mods,
+            mode,
+            filter_mode: filter_mode.map(GameMode::from),
+            attrs,
+            content: None,
+        })
     }
 }
 
@@ -203,10 +247,17 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
     If no map(set) is specified by either url or id, I will choose the last map \
     I can find in the embeds of this channel.\n\
     If the mapset is specified by id but there is some map with the same id, \
-    I will choose the latter."
+    I will choose the latter.\n\
+    To only show difficulties of a specific mode, specify `mode=osu`, `mode=taiko`, \
+    `mode=ctb`, or `mode=mania`. If the mapset has no difficulties of that mode, \
+    its osu!standard difficulties will be shown as converts instead."
+)]
+#[usage("[map(set) url / map(set) id] [+mods] [mode=osu/taiko/ctb/mania]")]
+#[examples(
+    "2240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr",
+    "1001507 mode=mania"
 )]
-#[usage("[map(set) url / map(set) id] [+mods]")]
-#[examples("2240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
 #[aliases("m", "beatmap", "maps", "beatmaps", "mapinfo")]
 #[group(AllModes)]
 async fn prefix_map(msg: &Message, args: Args<'_>, permissions: Option<Permissions>) -> Result<()> {
@@ -237,6 +288,24 @@ const W: u32 = 590;
 const H: u32 = 170;
 const LEGEND_H: u32 = 25;
 
+/// Display the map embed for the given map with an additional message content.
+pub(super) async fn map_with_content(
+    orig: CommandOrigin<'_>,
+    map_id: u32,
+    content: String,
+) -> Result<()> {
+    let args = MapArgs {
+        map: Some(MapIdType::Map(map_id)),
+        mods: None,
+        mode: None,
+        filter_mode: None,
+        attrs: CustomAttrs::default(),
+        content: Some(content),
+    };
+
+    map(orig, args).await
+}
+
 async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
@@ -245,11 +314,18 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             let content =
                 "Failed to parse mods. Be sure to specify a valid abbreviation e.g. `hdhr`.";
 
-            return orig.error(content).await;
+            return orig.error(mods_error(content, args.mods.as_deref())).await;
         }
     };
 
-    let MapArgs { map, attrs, .. } = args;
+    let MapArgs {
+        map,
+        mode: mut url_mode,
+        filter_mode,
+        attrs,
+        content,
+        ..
+    } = args;
 
     let map_id = if let Some(id) = map {
         id
@@ -282,6 +358,10 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         None | Some(ModSelection::Exclude { .. }) => GameModsIntermode::new(),
     };
 
+    if let Some(content) = pp_undefined_content(&mods) {
+        return orig.error(content).await;
+    }
+
     let mapset_res = match map_id {
         MapIdType::Map(id) => Context::osu().beatmapset_from_map_id(id).await,
         MapIdType::Set(id) => Context::osu().beatmapset(id).await,
@@ -298,7 +378,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
             return Err(Report::new(err).wrap_err("failed to get mapset"));
         }
@@ -324,6 +404,20 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         })
     });
 
+    if let Some(mode) = filter_mode {
+        if maps.iter().any(|map| map.mode == mode) {
+            maps.retain(|map| map.mode == mode);
+        } else if mode != GameMode::Osu && maps.iter().any(|map| map.mode == GameMode::Osu) {
+            // Show osu!standard difficulties as converts instead
+            maps.retain(|map| map.mode == GameMode::Osu);
+            url_mode = Some(mode);
+        } else {
+            let content = format!("The mapset has no {mode:?} difficulties");
+
+            return orig.error(content).await;
+        }
+    }
+
     let map_idx = match map_id {
         MapIdType::Map(map_id) => maps
             .iter()
@@ -333,7 +427,12 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
     };
 
     let map_id = maps[map_idx].map_id;
-    let mode = maps[map_idx].mode;
+
+    // Only osu!standard maps can be converted to a mode from the url
+    let mode = match url_mode {
+        Some(mode) if maps[map_idx].mode == GameMode::Osu => mode,
+        Some(_) | None => maps[map_idx].mode,
+    };
 
     let mods_with_mode = match mods.clone().try_with_mode(mode) {
         Some(mods) if mods.is_valid() => mods,
@@ -344,24 +443,27 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             return orig.error(content).await;
         }
         None => {
-            let content = format!(
-                "The mods `{mods}` are incompatible with the map's mode {:?}",
-                maps[map_idx].mode
-            );
+            let content = format!("The mods `{mods}` are incompatible with the mode {mode:?}");
 
             return orig.error(content).await;
         }
     };
 
     let graph = match Context::osu_map().pp_map(map_id).await {
-        Ok(map) => match map_strain_graph(&map, mods_with_mode, &mapset.covers.cover).await {
-            Ok(graph) => Some(graph),
-            Err(err) => {
-                warn!(?err, "Failed to create graph");
+        Ok(mut map) => {
+            if mode != maps[map_idx].mode {
+                let _ = map.convert_mut((mode as u8).into(), &Default::default());
+            }
 
-                None
+            match map_strain_graph(&map, mods_with_mode, &mapset.covers.cover).await {
+                Ok(graph) => Some(graph),
+                Err(err) => {
+                    warn!(?err, "Failed to create graph");
+
+                    None
+                }
             }
-        },
+        }
         Err(err) => {
             warn!(?err, "Failed to get pp map");
 
@@ -369,7 +471,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         }
     };
 
-    let content = attrs.content();
+    let content = attrs.content().or(content);
 
     let origin = MessageOrigin::new(orig.guild_id(), orig.channel_id());
 
@@ -377,13 +479,14 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .mapset(mapset)
         .maps(maps.into_boxed_slice())
         .mods(mods)
+        .mode(url_mode)
         .attrs(attrs)
         .origin(origin)
         .content(content.unwrap_or_default().into_boxed_str())
         .msg_owner(orig.user_id()?)
         .build();
 
-    pagination.set_index(map_idx);
+    pagination.set_index(map_idx, graph.is_some());
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{constants::OSU_API_ISSUE, datetime::DATE_FORMAT};
+use eyre::{Report, Result};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rosu_v2::prelude::{BeatmapExtended, BeatmapsetSearchSort, GameMode, RankStatus};
+use time::OffsetDateTime;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::map::map_with_content;
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Amount of mapsets per page of a mapset search
+const SEARCH_PAGE_SIZE: u32 = 50;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mapoftheday",
+    desc = "Display today's map of the day",
+    help = "Display today's map of the day.\n\
+    The map is picked pseudo-randomly among maps that were ranked before that day \
+    and stays the same for the whole day (UTC) for the same options.\n\
+    A mode and star range can be specified to constrain the selection."
+)]
+pub struct MapOfTheDay {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 0.0, desc = "Specify a minimum star rating")]
+    min_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Specify a maximum star rating")]
+    max_stars: Option<f32>,
+}
+
+async fn slash_mapoftheday(mut command: InteractionCommand) -> Result<()> {
+    let args = MapOfTheDay::from_interaction(command.input_data())?;
+
+    map_of_the_day((&mut command).into(), args).await
+}
+
+async fn map_of_the_day(orig: CommandOrigin<'_>, args: MapOfTheDay) -> Result<()> {
+    let MapOfTheDay {
+        mode,
+        min_stars,
+        max_stars,
+    } = args;
+
+    if let Some((min, max)) = min_stars.zip(max_stars).filter(|(min, max)| min > max) {
+        let content = format!("The minimum stars ({min}) must not exceed the maximum ({max})");
+
+        return orig.error(content).await;
+    }
+
+    let mode = mode.map(GameMode::from);
+    let today = OffsetDateTime::now_utc().date();
+    // Seeding with the day keeps the selection stable throughout the day
+    let mut rng = StdRng::seed_from_u64(today.to_julian_day() as u64);
+    let date = today.format(DATE_FORMAT).unwrap_or_default();
+
+    // Only consider mapsets that were ranked before today so that maps being
+    // ranked throughout the day don't change the selection.
+    let mut query = format!("ranked<{date} ");
+
+    if let Some(min) = min_stars {
+        let _ = write!(query, "stars>={min} ");
+    }
+
+    if let Some(max) = max_stars {
+        let _ = write!(query, "stars<={max}");
+    }
+
+    let search = |page: Option<u32>| {
+        let mut search_fut = Context::osu()
+            .beatmapset_search()
+            .status(Some(RankStatus::Ranked))
+            .sort(BeatmapsetSearchSort::RankedDate, false);
+
+        search_fut = search_fut.query(query.trim_end());
+
+        if let Some(mode) = mode {
+            search_fut = search_fut.mode(mode);
+        }
+
+        if let Some(page) = page {
+            search_fut = search_fut.page(page as usize);
+        }
+
+        search_fut
+    };
+
+    let total = match search(None).await {
+        Ok(result) => result.total,
+        Err(err) => {
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to search mapsets"));
+        }
+    };
+
+    if total == 0 {
+        return orig.error("No ranked maps match the given options").await;
+    }
+
+    let idx = rng.gen_range(0..total);
+    let page = idx / SEARCH_PAGE_SIZE + 1;
+
+    let mapsets = match search(Some(page)).await {
+        Ok(result) => result.mapsets,
+        Err(err) => {
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to search mapsets page"));
+        }
+    };
+
+    let mapset_idx = (idx % SEARCH_PAGE_SIZE) as usize;
+
+    let Some(mapset) = mapsets.get(mapset_idx).or_else(|| mapsets.last()) else {
+        return orig.error("No ranked maps match the given options").await;
+    };
+
+    let mut maps: Vec<_> = mapset
+        .maps
+        .iter()
+        .flatten()
+        .filter(|map| mode.is_none_or(|mode| map.mode == mode))
+        .filter(|map| in_star_range(map, min_stars, max_stars))
+        .collect();
+
+    if maps.is_empty() {
+        return orig.error("No ranked maps match the given options").await;
+    }
+
+    maps.sort_unstable_by_key(|map| map.map_id);
+    let map_id = maps[rng.gen_range(0..maps.len())].map_id;
+
+    let content = format!("Map of the day for {date}");
+
+    map_with_content(orig, map_id, content).await
+}
+
+fn in_star_range(map: &BeatmapExtended, min: Option<f32>, max: Option<f32>) -> bool {
+    min.is_none_or(|min| map.stars >= min) && max.is_none_or(|max| map.stars <= max)
+}
@@ -626,7 +626,7 @@ async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
     let mut search_result = match args.request(Context::osu()).await {
         Ok(response) => response,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
             return Err(Report::new(err).wrap_err("Failed to get search results"));
         }
@@ -225,6 +225,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let user_id = match user_id!(orig, args) {
@@ -286,7 +287,9 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
     let mapper_id = mapper.user_id.to_native();
 
     let username = user.username.as_str();
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed_settings(orig.guild_id(), config.score_embed)
+        .await;
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
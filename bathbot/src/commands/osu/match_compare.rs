@@ -113,7 +113,7 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
             let previous_fut_2 = retrieve_previous(&mut match2, Context::osu());
 
             if let Err(err) = tokio::try_join!(previous_fut_1, previous_fut_2) {
-                let _ = command.error(OSU_API_ISSUE).await;
+                let _ = command.error_retryable(OSU_API_ISSUE).await;
                 let report = Report::new(err)
                     .wrap_err("Failed to get history of at least one of the matches");
 
@@ -138,7 +138,7 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
             return Ok(());
         }
         Err(err) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error_retryable(OSU_API_ISSUE).await;
             let report = Report::new(err).wrap_err("failed to get at least one of the matches");
 
             return Err(report);
@@ -20,7 +20,7 @@ use super::{MedalCommon, MedalCommonFilter, MedalCommonOrder};
 use crate::{
     Context,
     active::{ActiveMessages, impls::MedalsCommonPagination},
-    commands::osu::UserExtraction,
+    commands::osu::{UserExtraction, not_linked},
     core::commands::CommandOrigin,
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::osu::get_combined_thumbnail,
@@ -64,7 +64,7 @@ async fn extract_user_id(args: &mut MedalCommon<'_>) -> UserExtraction {
         match Context::user_config().osu_id(discord).await {
             Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
             Ok(None) => {
-                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+                UserExtraction::Content(not_linked(discord))
             }
             Err(err) => UserExtraction::Err(err),
         }
@@ -7,7 +7,7 @@ macro_rules! user_id {
             crate::commands::osu::UserIdResult::Future(fut) => match fut.await {
                 crate::commands::osu::UserIdFutureResult::Id(user_id) => Some(user_id),
                 crate::commands::osu::UserIdFutureResult::NotLinked(user_id) => {
-                    let content = format!("<@{user_id}> is not linked to an osu!profile");
+                    let content = crate::commands::osu::not_linked(user_id);
 
                     return $orig.error(content).await;
                 }
@@ -25,7 +25,8 @@ macro_rules! user_id {
 /// Tries to extract the username and mode from args.
 /// If either fails, it checks the user config.
 /// If the osu user is still not found, return the linking error.
-/// If the mode is still not found, pick GameMode::Osu.
+/// If the mode is still not found, check the guild config and otherwise pick
+/// GameMode::Osu.
 ///
 /// Only use this when the user config is not needed otherwise,
 /// else you'll have to query multiple times from the DB.
@@ -40,6 +41,9 @@ macro_rules! user_id_mode {
                 let mode = crate::core::Context::user_config()
                     .mode($orig.user_id()?)
                     .await?
+                    .or(crate::core::Context::guild_config()
+                        .mode($orig.guild_id())
+                        .await)
                     .unwrap_or(rosu_v2::prelude::GameMode::Osu);
 
                 (user_id, mode)
@@ -51,6 +55,9 @@ macro_rules! user_id_mode {
 
             let mode = mode
                 .or(config.mode)
+                .or(crate::core::Context::guild_config()
+                    .mode($orig.guild_id())
+                    .await)
                 .unwrap_or(rosu_v2::prelude::GameMode::Osu);
 
             match config.osu {
@@ -61,24 +68,32 @@ macro_rules! user_id_mode {
     }};
 }
 
-use std::{future::Future, pin::Pin};
+use std::{borrow::Cow, future::Future, pin::Pin};
 
-use bathbot_util::osu::ModsResult;
+use bathbot_util::{
+    matcher,
+    osu::{ModsResult, pp_undefined_mod},
+};
 use eyre::{Report, Result, WrapErr};
-use rosu_v2::request::UserId;
+use rosu_v2::{
+    prelude::{GameMode, GameModsIntermode},
+    request::UserId,
+};
 use twilight_interactions::command::{CommandOption, CreateOption};
 use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::{
     badges::*, claim_name::*, compare::*, fix::*, graphs::*, leaderboard::*, map::*, map_search::*,
-    match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*, profile::*, recent::*,
-    render::*, simulate::*, snipe::*, top::*, whatif::*,
+    match_compare::*, match_costs::*, medals::*, mod_gain::*, nochoke::*, osustats::*, profile::*,
+    recent::*, render::*, scorepost::*, simulate::*, snipe::*, top::*, whatif::*,
 };
 use crate::{
     Context,
     core::commands::{CommandOrigin, interaction::InteractionCommands},
+    util::locale::{self, Msg},
 };
 
+mod acc_for_pp;
 mod attributes;
 mod avatar;
 mod badges;
@@ -88,17 +103,24 @@ mod cards;
 mod claim_name;
 mod compare;
 mod daily_challenge;
+mod find_map;
 mod fix;
 mod graphs;
+mod history;
 mod leaderboard;
+mod link_transfer;
 mod map;
+mod map_of_the_day;
 mod map_search;
 mod mapper;
 mod match_compare;
 mod match_costs;
 mod medals;
+mod mod_gain;
 mod most_played;
+mod news;
 mod nochoke;
+mod nochoke_leaderboard;
 mod osekai;
 mod osustats;
 mod pinned;
@@ -109,10 +131,13 @@ mod ranking;
 mod ratios;
 mod recent;
 mod render;
+mod replay;
+mod scorepost;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
 mod top;
+mod weekly_best;
 mod whatif;
 
 #[cfg(feature = "server")]
@@ -125,6 +150,44 @@ pub trait HasMods {
     fn mods(&self) -> ModsResult;
 }
 
+/// Extend the error content for mods that failed to parse with a suggestion
+/// in case the given mods are just a typo away from valid ones.
+pub fn mods_error(content: &'static str, mods: Option<&str>) -> Cow<'static, str> {
+    match mods.and_then(matcher::suggest_mods) {
+        Some(suggestion) => format!("{content}\nDid you mean `{suggestion}`?").into(),
+        None => content.into(),
+    }
+}
+
+/// Error content for mods without defined pp such as `RX` so that no
+/// misleading pp values are shown.
+pub fn pp_undefined_content(mods: &GameModsIntermode) -> Option<String> {
+    pp_undefined_mod(mods).map(|gamemod| format!("pp is not defined for {gamemod} scores"))
+}
+
+/// Mention that the map's mode was used in case it differs from the user's
+/// configured mode.
+pub fn inferred_mode_content(map_mode: GameMode, config_mode: Option<GameMode>) -> Option<String> {
+    fn mode_name(mode: GameMode) -> &'static str {
+        match mode {
+            GameMode::Osu => "osu!",
+            GameMode::Taiko => "osu!taiko",
+            GameMode::Catch => "osu!catch",
+            GameMode::Mania => "osu!mania",
+        }
+    }
+
+    let config_mode = config_mode.filter(|&mode| mode != map_mode)?;
+
+    let content = format!(
+        "Using the map's mode `{}` instead of your configured mode `{}`",
+        mode_name(map_mode),
+        mode_name(config_mode),
+    );
+
+    Some(content)
+}
+
 pub trait HasName {
     fn user_id(&self) -> UserIdResult;
 }
@@ -163,20 +226,56 @@ impl UserIdFutureResult {
     }
 }
 
-pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
-    let link = InteractionCommands::get_command("link").map_or_else(
+/// A prefix command argument that's either a discord mention or an osu!
+/// username.
+pub enum NameOrMention<'a> {
+    Name(Cow<'a, str>),
+    Mention(Id<UserMarker>),
+}
+
+impl<'a> NameOrMention<'a> {
+    pub fn parse(arg: impl Into<Cow<'a, str>>) -> Self {
+        let arg = arg.into();
+
+        match matcher::get_mention_user(&arg) {
+            Some(id) => Self::Mention(id),
+            None => Self::Name(arg),
+        }
+    }
+
+    /// Split into the `name` and `discord` fields of command arguments.
+    pub fn into_parts(self) -> (Option<Cow<'a, str>>, Option<Id<UserMarker>>) {
+        match self {
+            Self::Name(name) => (Some(name), None),
+            Self::Mention(id) => (None, Some(id)),
+        }
+    }
+}
+
+fn link_command() -> String {
+    InteractionCommands::get_command("link").map_or_else(
         || "`/link`".to_owned(),
         |cmd| cmd.mention("link").to_string(),
-    );
+    )
+}
 
-    let content =
-        format!("Either specify an osu! username or link yourself to an osu! profile via {link}");
+pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
+    let lang = locale::lang(orig).await;
+    let content = Msg::RequireLink.format(lang, &[("link", &link_command())]);
 
     orig.error(content)
         .await
         .wrap_err("Failed to send require-link message")
 }
 
+/// Content for when a mentioned discord user has no linked osu! profile.
+pub fn not_linked(user_id: Id<UserMarker>) -> String {
+    format!(
+        "<@{user_id}> is not linked to an osu! profile, they can link themselves via {}",
+        link_command()
+    )
+}
+
 pub async fn user_not_found(user_id: UserId) -> String {
     let user_id = match user_id {
         user_id @ UserId::Name(_) => user_id,
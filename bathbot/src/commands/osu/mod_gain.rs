@@ -0,0 +1,384 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    fields,
+    numbers::WithComma,
+    osu::{ExtractablePp, PpListUtil},
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameModIntermode, GameMode, GameModsIntermode, OsuError, Score};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::user_not_found;
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Amount of scores listed with their pp gain
+const LISTED_GAINS: usize = 5;
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "modgain",
+    desc = "How many pp could a user gain by learning a mod?",
+    help = "Estimate how many pp a user could gain by learning a mod.\n\
+    The mod is added to each top score and the score's pp are recalculated. \
+    Whenever the mod raises the pp, the gain is added for the total pp. \
+    Scores that already contain the mod are kept as is.\n\
+    Accuracy and combo of the scores are kept as is so the result is an optimistic \
+    upper bound; actually playing with the mod likely results in worse scores."
+)]
+pub struct ModGain<'a> {
+    #[command(rename = "mod", desc = "Specify the mod to learn")]
+    gamemod: ModGainMod,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum ModGainMod {
+    #[option(name = "Hidden", value = "hd")]
+    Hidden,
+    #[option(name = "HardRock", value = "hr")]
+    HardRock,
+    #[option(name = "DoubleTime", value = "dt")]
+    DoubleTime,
+    #[option(name = "Flashlight", value = "fl")]
+    Flashlight,
+}
+
+impl From<ModGainMod> for GameModIntermode {
+    fn from(gamemod: ModGainMod) -> Self {
+        match gamemod {
+            ModGainMod::Hidden => Self::Hidden,
+            ModGainMod::HardRock => Self::HardRock,
+            ModGainMod::DoubleTime => Self::DoubleTime,
+            ModGainMod::Flashlight => Self::Flashlight,
+        }
+    }
+}
+
+async fn slash_modgain(mut command: InteractionCommand) -> Result<()> {
+    let args = ModGain::from_interaction(command.input_data())?;
+
+    modgain((&mut command).into(), args).await
+}
+
+async fn modgain(orig: CommandOrigin<'_>, args: ModGain<'_>) -> Result<()> {
+    let (user_id, mode) = user_id_mode!(orig, args);
+    let gamemod = GameModIntermode::from(args.gamemod);
+
+    // Retrieve the user and their top scores
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(false)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok((user, scores)) => (user, scores),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    if scores.is_empty() {
+        let content = format!(
+            "`{name}` has no {mode}top scores",
+            name = user.username.as_str(),
+            mode = mode_str(mode),
+        );
+
+        return orig.error(content).await;
+    }
+
+    let old_pps = scores.extract_pp();
+
+    let gains = match score_gains(&scores, gamemod, mode).await {
+        Ok(gains) => gains,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to calculate pp gains"));
+        }
+    };
+
+    let stats = user.statistics.as_ref().expect("missing stats");
+    let pre_pp = stats.pp.to_native();
+    let pre_rank = stats.global_rank.to_native();
+    let bonus_pp = (pre_pp - old_pps.accum_weighted()).max(0.0);
+
+    let mut new_pps: Vec<_> = gains.iter().map(ScoreGain::new_pp).collect();
+    new_pps.sort_unstable_by(|a, b| b.total_cmp(a));
+    let post_pp = bonus_pp + new_pps.accum_weighted();
+
+    let mut improved: Vec<_> = gains.iter().filter(|gain| gain.diff() > 0.0).collect();
+    improved.sort_unstable_by(|a, b| b.diff().total_cmp(&a.diff()));
+
+    let name = user.username.as_str().cow_escape_markdown();
+    let mut description = String::with_capacity(256);
+
+    if improved.is_empty() {
+        let _ = write!(
+            description,
+            "Adding `{gamemod}` does not raise the pp of any of {name}'{genitiv} \
+            {mode}top{len} scores",
+            genitiv = if name.ends_with('s') { "" } else { "s" },
+            mode = mode_str(mode),
+            len = scores.len(),
+        );
+    } else {
+        let rank = match Context::approx().rank(post_pp, mode).await {
+            Ok(rank) => Some(rank),
+            Err(err) => {
+                warn!(?err, "Failed to get rank from pp");
+
+                None
+            }
+        };
+
+        let _ = writeln!(
+            description,
+            "Adding `{gamemod}` raises the pp of **{count}** of {name}'{genitiv} \
+            {mode}top{len} scores",
+            count = improved.len(),
+            genitiv = if name.ends_with('s') { "" } else { "s" },
+            mode = mode_str(mode),
+            len = scores.len(),
+        );
+
+        let _ = write!(
+            description,
+            "**Total pp:** {pre}pp → **{post}pp** (+{diff})",
+            pre = WithComma::new(pre_pp),
+            post = WithComma::new(post_pp),
+            diff = WithComma::new(post_pp - pre_pp),
+        );
+
+        if let Some(rank) = rank {
+            let _ = write!(
+                description,
+                "\n**Rank:** #{pre} → approx. **#{post}**",
+                pre = WithComma::new(pre_rank),
+                post = WithComma::new(rank),
+            );
+        }
+    }
+
+    let mut fields = Vec::with_capacity(2);
+
+    if !improved.is_empty() {
+        let mut value = String::with_capacity(LISTED_GAINS * 128);
+
+        for gain in improved.iter().take(LISTED_GAINS) {
+            let _ = writeln!(
+                value,
+                "**#{idx}** [{title} [{version}]]({OSU_BASE}b/{map_id}): \
+                {old:.2}pp → **{new:.2}pp**",
+                idx = gain.idx,
+                title = gain.title.cow_escape_markdown(),
+                version = gain.version.cow_escape_markdown(),
+                map_id = gain.map_id,
+                old = gain.old_pp,
+                new = gain.new_pp(),
+            );
+        }
+
+        fields![fields { "Biggest gains", value, false }];
+
+        let caveats = format!(
+            "This is an optimistic upper bound. Accuracy and combo are kept as is \
+            while playing with `{gamemod}` likely results in worse scores."
+        );
+
+        fields![fields { ":warning: Caveats", caveats, false }];
+    }
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .fields(fields)
+        .thumbnail(user.avatar_url.as_ref())
+        .title(format!("Potential pp gain with {gamemod}"));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+struct ScoreGain {
+    idx: usize,
+    map_id: u32,
+    title: String,
+    version: String,
+    /// Stored pp of the score
+    old_pp: f32,
+    /// Recalculated pp without the mod
+    base_pp: f32,
+    /// Recalculated pp with the mod
+    mod_pp: f32,
+}
+
+impl ScoreGain {
+    /// Unchanged score e.g. because it already contains the mod
+    fn unchanged(score: &Score, idx: usize) -> Self {
+        let old_pp = score.pp.unwrap_or(0.0);
+
+        Self {
+            idx,
+            map_id: score.map_id,
+            title: score
+                .mapset
+                .as_ref()
+                .map_or_else(String::new, |mapset| mapset.title.clone()),
+            version: score
+                .map
+                .as_ref()
+                .map_or_else(String::new, |map| map.version.clone()),
+            old_pp,
+            base_pp: old_pp,
+            mod_pp: old_pp,
+        }
+    }
+
+    /// How many pp the mod adds, compared on the same calculator so that
+    /// calculator drift doesn't count as gain.
+    fn diff(&self) -> f32 {
+        (self.mod_pp - self.base_pp).max(0.0)
+    }
+
+    /// Stored pp plus the gain if the mod raises them
+    fn new_pp(&self) -> f32 {
+        self.old_pp + self.diff()
+    }
+}
+
+/// Whether the score already contains the mod, including its variants e.g.
+/// `NC` for `DT`.
+fn has_mod(score: &Score, gamemod: GameModIntermode) -> bool {
+    score.mods.contains_intermode(gamemod)
+        || (gamemod == GameModIntermode::DoubleTime
+            && score.mods.contains_intermode(GameModIntermode::Nightcore))
+}
+
+/// Recalculate the pp of all scores with the given mod added.
+///
+/// Scores that already contain the mod or whose map is unavailable are kept
+/// unchanged.
+async fn score_gains(
+    scores: &[Score],
+    gamemod: GameModIntermode,
+    mode: GameMode,
+) -> Result<Vec<ScoreGain>> {
+    let maps_id_checksum = scores
+        .iter()
+        .filter(|score| !has_mod(score, gamemod))
+        .map(|score| {
+            (
+                score.map_id as i32,
+                score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+            )
+        })
+        .collect();
+
+    let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+    let added_mods = [gamemod]
+        .into_iter()
+        .collect::<GameModsIntermode>()
+        .with_mode(mode);
+
+    let mut gains = Vec::with_capacity(scores.len());
+
+    for (score, idx) in scores.iter().zip(1..) {
+        if has_mod(score, gamemod) {
+            gains.push(ScoreGain::unchanged(score, idx));
+
+            continue;
+        }
+
+        let Some(map) = maps.remove(&score.map_id) else {
+            gains.push(ScoreGain::unchanged(score, idx));
+
+            continue;
+        };
+
+        let map = map.convert(score.mode);
+
+        let base_pp = Context::pp(&map)
+            .mode(score.mode)
+            .mods(score.mods.clone())
+            .score(score)
+            .performance()
+            .await
+            .pp() as f32;
+
+        let mut score = score.clone();
+
+        // Replace incompatible mods e.g. `HT` when adding `DT`
+        for m in added_mods.iter() {
+            for &acronym in m.incompatible_mods().iter() {
+                score
+                    .mods
+                    .remove_intermode(GameModIntermode::from_acronym(acronym));
+            }
+        }
+
+        score.mods.extend(added_mods.iter().cloned());
+
+        let mod_pp = Context::pp(&map)
+            .mode(score.mode)
+            .mods(score.mods.clone())
+            .score(&score)
+            .performance()
+            .await
+            .pp() as f32;
+
+        gains.push(ScoreGain {
+            idx,
+            map_id: map.map_id(),
+            title: map.title().to_owned(),
+            version: map.version().to_owned(),
+            old_pp: score.pp.unwrap_or(0.0),
+            base_pp,
+            mod_pp,
+        });
+    }
+
+    Ok(gains)
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "",
+        GameMode::Taiko => "taiko ",
+        GameMode::Catch => "ctb ",
+        GameMode::Mania => "mania ",
+    }
+}
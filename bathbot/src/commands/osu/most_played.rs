@@ -104,7 +104,7 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
     let maps = match maps_fut.await {
         Ok(maps) => maps,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get maps");
 
             return Err(err);
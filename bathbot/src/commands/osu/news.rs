@@ -0,0 +1,220 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_psql::model::osu::DbNewsChannel;
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::NewsPagination},
+    core::commands::checks::check_authority,
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const GUILD_ONLY: &str = "News subscriptions are only available in servers";
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "news", desc = "Latest osu! news posts")]
+pub enum News {
+    #[command(name = "latest")]
+    Latest(NewsLatest),
+    #[command(name = "subscribe")]
+    Subscribe(NewsSubscribe),
+    #[command(name = "unsubscribe")]
+    Unsubscribe(NewsUnsubscribe),
+    #[command(name = "list")]
+    List(NewsList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "latest", desc = "Display the latest osu! news posts")]
+pub struct NewsLatest;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "subscribe",
+    desc = "Post new osu! news in this channel",
+    help = "Post new osu! news in this channel.\n\
+    News are checked once an hour and each new post will be sent as its own message.\n\
+    Requires authority status, see `/serverconfig authorities`."
+)]
+pub struct NewsSubscribe;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "unsubscribe", desc = "Stop posting osu! news in this channel")]
+pub struct NewsUnsubscribe;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List all channels of this server that receive osu! news"
+)]
+pub struct NewsList;
+
+async fn slash_news(mut command: InteractionCommand) -> Result<()> {
+    let args = News::from_interaction(command.input_data())?;
+
+    match args {
+        News::Latest(_) => latest(command).await,
+        News::Subscribe(_) => subscribe(command).await,
+        News::Unsubscribe(_) => unsubscribe(command).await,
+        News::List(_) => list(command).await,
+    }
+}
+
+async fn latest(mut command: InteractionCommand) -> Result<()> {
+    let posts = match Context::redis().news().await {
+        Ok(posts) => posts,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get news"));
+        }
+    };
+
+    if posts.is_empty() {
+        let content = "There are currently no osu! news posts";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let pagination = NewsPagination::builder()
+        .posts(posts)
+        .msg_owner(command.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(&mut command)
+        .await
+}
+
+async fn subscribe(command: InteractionCommand) -> Result<()> {
+    let Some(guild_id) = require_authority(&command).await? else {
+        return Ok(());
+    };
+
+    let news = match Context::redis().news().await {
+        Ok(news) => news,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get news"));
+        }
+    };
+
+    // Only posts published after subscribing will be sent
+    let last_post_id = news.first().map_or(0, |post| post.post_id.to_native());
+
+    let channel = DbNewsChannel {
+        channel_id: command.channel_id.get() as i64,
+        guild_id: guild_id.get() as i64,
+        last_post_id: last_post_id as i32,
+    };
+
+    if let Err(err) = Context::psql().upsert_news_channel(&channel).await {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        return Err(err.wrap_err("Failed to upsert news channel"));
+    }
+
+    respond(
+        &command,
+        "This channel will now receive new osu! news posts",
+    )
+    .await
+}
+
+async fn unsubscribe(command: InteractionCommand) -> Result<()> {
+    if require_authority(&command).await?.is_none() {
+        return Ok(());
+    }
+
+    match Context::psql()
+        .delete_news_channel(command.channel_id)
+        .await
+    {
+        Ok(true) => {
+            let content = "This channel will no longer receive osu! news posts";
+
+            respond(&command, content).await
+        }
+        Ok(false) => {
+            let content = "This channel is not subscribed to osu! news";
+            command.error(content).await?;
+
+            Ok(())
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to delete news channel"))
+        }
+    }
+}
+
+async fn list(command: InteractionCommand) -> Result<()> {
+    let Some(guild_id) = command.guild_id else {
+        command.error(GUILD_ONLY).await?;
+
+        return Ok(());
+    };
+
+    let content = match Context::psql().select_guild_news_channels(guild_id).await {
+        Ok(channels) if channels.is_empty() => {
+            "No channel of this server receives osu! news".to_owned()
+        }
+        Ok(channels) => {
+            let mut content = String::from("Channels that receive osu! news:");
+
+            for channel in channels {
+                let _ = write!(content, "\n- <#{}>", channel.channel_id);
+            }
+
+            content
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get news channels"));
+        }
+    };
+
+    respond(&command, content).await
+}
+
+/// Returns the guild id if the author may manage subscriptions; otherwise
+/// responds with an error and returns `None`.
+async fn require_authority(command: &InteractionCommand) -> Result<Option<Id<GuildMarker>>> {
+    let Some(guild_id) = command.guild_id else {
+        command.error(GUILD_ONLY).await?;
+
+        return Ok(None);
+    };
+
+    match check_authority(command.user_id()?, Some(guild_id)).await {
+        Ok(None) => Ok(Some(guild_id)),
+        Ok(Some(content)) => {
+            command.error(content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to check authority status"))
+        }
+    }
+}
+
+async fn respond(command: &InteractionCommand, content: impl Into<String>) -> Result<()> {
+    let embed = EmbedBuilder::new().description(content);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
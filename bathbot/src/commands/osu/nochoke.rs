@@ -205,7 +205,12 @@ async fn nochoke(orig: CommandOrigin<'_>, args: Nochoke<'_>) -> Result<()> {
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+    {
         None | Some(GameMode::Mania) => GameMode::Osu,
         Some(mode) => mode,
     };
@@ -0,0 +1,315 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::{RankingEntries, RankingEntry, RankingKind};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{EmbedBuilder, IntHasher, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::{Result, WrapErr};
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::{GameMode, Score};
+use tokio::sync::Semaphore;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use super::{NochokeGameMode, fix::unchoked_total_pp};
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::RankingPagination},
+    core::{NochokeLeaderboardEntry, commands::interaction::InteractionCommands},
+    manager::{
+        OsuMap,
+        redis::osu::{CachedUser, UserArgs, UserArgsSlim},
+    },
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// How many users' top scores are requested simultaneously
+const CONCURRENCY: usize = 4;
+
+/// Maximum amount of linked members whose scores are unchoked
+const MAX_MEMBERS: usize = 200;
+
+/// Limits how many leaderboards are computed at the same time across all
+/// servers
+static COMPUTATIONS: Semaphore = Semaphore::const_new(2);
+
+/// Minimum duration between two progress updates of the response
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "nochokeleaderboard",
+    dm_permission = false,
+    desc = "Server leaderboard of how much pp linked members would have without chokes",
+    help = "Unchoke the top100 of all linked members of this server and \
+    rank them by how much pp they would gain or by their total pp without chokes.\n\
+    Since this requires the top scores of every member, it may take a while. \
+    At most 200 linked members are considered.\n\
+    Results are cached for an hour per server and mode."
+)]
+#[flags(ONLY_GUILDS)]
+pub struct NochokeLeaderboard {
+    #[command(
+        desc = "Specify a gamemode",
+        help = "Specify a gamemode. \
+        Since combo does not matter in mania, its scores can't be unchoked."
+    )]
+    mode: Option<NochokeGameMode>,
+    #[command(desc = "Choose how the leaderboard should be sorted")]
+    sort: Option<NochokeLeaderboardSort>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum NochokeLeaderboardSort {
+    #[default]
+    #[option(name = "PP gain", value = "gain")]
+    Gain,
+    #[option(name = "Total pp", value = "total")]
+    Total,
+}
+
+async fn slash_nochokeleaderboard(mut command: InteractionCommand) -> Result<()> {
+    let args = NochokeLeaderboard::from_interaction(command.input_data())?;
+
+    let owner = command.user_id()?;
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+    let mode = args.mode.map_or(GameMode::Osu, GameMode::from);
+    let by_total = matches!(args.sort.unwrap_or_default(), NochokeLeaderboardSort::Total);
+
+    let nochoke_entries = match Context::get().nochoke_leaderboard(guild_id, mode) {
+        Some(entries) => entries,
+        None => match compute_entries(&command, guild_id, mode).await {
+            Ok(entries) => {
+                let entries: Arc<[_]> = entries.into();
+                Context::get().store_nochoke_leaderboard(guild_id, mode, Arc::clone(&entries));
+
+                entries
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    if nochoke_entries.is_empty() {
+        let link = InteractionCommands::get_command("link").map_or_else(
+            || "`/link`".to_owned(),
+            |cmd| cmd.mention("link").to_string(),
+        );
+
+        let content = format!(
+            "No linked members with top scores found in this server.\n\
+            Members can link their osu! profile through the {link} command."
+        );
+
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let mut sorted: Vec<_> = nochoke_entries.iter().collect();
+
+    if by_total {
+        sorted.sort_unstable_by(|a, b| b.unchoked_pp.total_cmp(&a.unchoked_pp));
+    } else {
+        sorted.sort_unstable_by(|a, b| b.gain().total_cmp(&a.gain()));
+    }
+
+    let entries: BTreeMap<_, _> = sorted
+        .into_iter()
+        .map(|entry| RankingEntry {
+            country: Some(entry.country_code.clone()),
+            name: entry.username.clone(),
+            value: if by_total {
+                entry.unchoked_pp
+            } else {
+                entry.gain()
+            },
+        })
+        .enumerate()
+        .collect();
+
+    let entries = RankingEntries::PpF32(entries);
+
+    let author_idx = match Context::user_config().osu_name(owner).await {
+        Ok(name) => name.and_then(|name| entries.name_pos(&name)),
+        Err(err) => {
+            warn!(?err, "Failed to get author name");
+
+            None
+        }
+    };
+
+    let guild_icon = Context::cache()
+        .guild(guild_id)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|guild| Some((guild.id.to_native(), *guild.icon.as_ref()?)));
+
+    let kind = RankingKind::NochokePp {
+        guild_icon,
+        mode,
+        by_total,
+    };
+
+    let total = entries.len();
+
+    let pagination = RankingPagination::builder()
+        .entries(entries)
+        .total(total)
+        .author_idx(author_idx)
+        .kind(kind)
+        .defer(false)
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(&mut command)
+        .await
+}
+
+async fn compute_entries(
+    command: &InteractionCommand,
+    guild_id: Id<GuildMarker>,
+    mode: GameMode,
+) -> Result<Vec<NochokeLeaderboardEntry>> {
+    let _permit = COMPUTATIONS
+        .acquire()
+        .await
+        .wrap_err("Nochoke leaderboard semaphore was closed")?;
+
+    let members: Vec<_> = Context::cache()
+        .members(guild_id)
+        .await?
+        .into_iter()
+        .map(|id| id as i64)
+        .collect();
+
+    let mut osu_ids = Context::psql()
+        .select_osu_ids_by_discord_ids(&members)
+        .await
+        .wrap_err("Failed to get osu ids of members")?;
+
+    osu_ids.truncate(MAX_MEMBERS);
+
+    let legacy_scores = Context::guild_config()
+        .peek(guild_id, |config| config.score_data)
+        .await
+        .is_some_and(ScoreData::is_legacy);
+
+    let total = osu_ids.len();
+    let mut progress = Progress::new(command, total);
+
+    let mut top_scores_stream = stream::iter(osu_ids)
+        .map(|user_id| {
+            let args = UserArgs::Args(UserArgsSlim::user_id(user_id).mode(mode));
+
+            Context::osu_scores()
+                .top(legacy_scores)
+                .limit(100)
+                .exec_with_user(args)
+        })
+        .buffer_unordered(CONCURRENCY);
+
+    let mut users = Vec::with_capacity(total);
+
+    while let Some(res) = top_scores_stream.next().await {
+        match res {
+            Ok((user, scores)) if !scores.is_empty() => users.push((user, scores)),
+            Ok(_) => {}
+            Err(err) => debug!(?err, "Failed to get top scores for nochoke leaderboard"),
+        }
+
+        progress.tick("Requesting top scores").await;
+    }
+
+    // Members share many maps so each map is only retrieved once
+    let maps_id_checksum: HashMap<_, _, IntHasher> = users
+        .iter()
+        .flat_map(|(_, scores)| scores.iter())
+        .filter_map(|score| score.map.as_ref())
+        .map(|map| (map.map_id as i32, map.checksum.as_deref()))
+        .collect();
+
+    let maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+    let mut progress = Progress::new(command, users.len());
+    let mut entries = Vec::with_capacity(users.len());
+
+    for (user, scores) in users {
+        entries.push(unchoke_user(user, scores, &maps).await);
+        progress.tick("Unchoking scores").await;
+    }
+
+    Ok(entries)
+}
+
+async fn unchoke_user(
+    user: CachedUser,
+    scores: Vec<Score>,
+    maps: &HashMap<u32, OsuMap, IntHasher>,
+) -> NochokeLeaderboardEntry {
+    let pp = user
+        .statistics
+        .as_ref()
+        .map_or(0.0, |stats| stats.pp.to_native());
+
+    NochokeLeaderboardEntry {
+        username: user.username.as_str().into(),
+        country_code: user.country_code.as_str().into(),
+        pp,
+        unchoked_pp: unchoked_total_pp(pp, &scores, maps, None).await,
+    }
+}
+
+/// Updates the deferred response with the current progress.
+///
+/// The progress is shown in an embed so that the pagination replaces it.
+struct Progress<'a> {
+    command: &'a InteractionCommand,
+    total: usize,
+    processed: usize,
+    last_update: Instant,
+}
+
+impl<'a> Progress<'a> {
+    fn new(command: &'a InteractionCommand, total: usize) -> Self {
+        Self {
+            command,
+            total,
+            processed: 0,
+            last_update: Instant::now(),
+        }
+    }
+
+    async fn tick(&mut self, action: &str) {
+        self.processed += 1;
+
+        if self.processed == self.total || self.last_update.elapsed() < PROGRESS_INTERVAL {
+            return;
+        }
+
+        self.last_update = Instant::now();
+
+        let content = format!(
+            "{action}... processed {processed}/{total} users",
+            processed = self.processed,
+            total = self.total,
+        );
+
+        let embed = EmbedBuilder::new().description(content);
+        let builder = MessageBuilder::new().embed(embed);
+
+        if let Err(err) = self.command.update(builder).await {
+            warn!(?err, "Failed to update nochoke leaderboard progress");
+        }
+    }
+}
@@ -5,7 +5,6 @@ use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{
     MessageBuilder,
     constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
-    matcher,
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::OsuError;
@@ -15,7 +14,7 @@ use twilight_model::id::{Id, marker::UserMarker};
 use super::OsuStatsCount;
 use crate::{
     Context,
-    commands::osu::user_not_found,
+    commands::osu::{NameOrMention, user_not_found},
     core::commands::{CommandOrigin, prefix::Args},
     embeds::{EmbedData, OsuStatsCountsEmbed},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -53,23 +52,14 @@ impl<'m> From<Osc<'m>> for OsuStatsCount<'m> {
 
 impl<'m> OsuStatsCount<'m> {
     fn args(mode: Option<GameModeOption>, mut args: Args<'m>) -> Self {
-        match args.next() {
-            Some(arg) => match matcher::get_mention_user(arg) {
-                Some(id) => Self {
-                    mode,
-                    discord: Some(id),
-                    name: None,
-                },
-                None => Self {
-                    mode,
-                    name: Some(arg.into()),
-                    discord: None,
-                },
-            },
-            None => Self {
-                mode,
-                ..Default::default()
-            },
+        let (name, discord) = args
+            .next()
+            .map_or((None, None), |arg| NameOrMention::parse(arg).into_parts());
+
+        Self {
+            mode,
+            name,
+            discord,
         }
     }
 }
@@ -6,7 +6,7 @@ use bathbot_model::{
     command_fields::GameModeOption,
 };
 use bathbot_util::{
-    CowUtils,
+    CowUtils, IntermodeFormatter,
     constants::{GENERAL_ISSUE, OSUSTATS_API_ISSUE},
     matcher,
     osu::ModSelection,
@@ -18,13 +18,13 @@ use super::OsuStatsScores;
 use crate::{
     Context,
     active::{ActiveMessages, impls::OsuStatsScoresPagination},
-    commands::osu::{HasMods, ModsResult, user_not_found},
+    commands::osu::{HasMods, ModsResult, NameOrMention, user_not_found},
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
-    util::ChannelExt,
+    util::{ChannelExt, osu::min_pp_cutoff},
 };
 
 #[command]
@@ -38,11 +38,12 @@ use crate::{
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `pp`: only show scores with at least this much pp, orders scores by pp\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(
     "[username] [mods] [acc=[number..]number] [rank=[integer..]integer] \
-    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false]"
+    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false] [pp=number]"
 )]
 #[examples(
     "badewanne3 -dt! acc=97.5..99.5 rank=42 sort=pp reverse=true",
@@ -72,11 +73,12 @@ async fn prefix_osustatsglobals(msg: &Message, args: Args<'_>) -> Result<()> {
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `pp`: only show scores with at least this much pp, orders scores by pp\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(
     "[username] [mods] [acc=[number..]number] [rank=[integer..]integer] \
-    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false]"
+    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false] [pp=number]"
 )]
 #[examples(
     "badewanne3 -dt! acc=97.5..99.5 rank=42 sort=pp reverse=true",
@@ -106,11 +108,12 @@ async fn prefix_osustatsglobalsmania(msg: &Message, args: Args<'_>) -> Result<()
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `pp`: only show scores with at least this much pp, orders scores by pp\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(
     "[username] [mods] [acc=[number..]number] [rank=[integer..]integer] \
-    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false]"
+    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false] [pp=number]"
 )]
 #[examples(
     "badewanne3 -dt! acc=97.5..99.5 rank=42 sort=pp reverse=true",
@@ -140,11 +143,12 @@ async fn prefix_osustatsglobalstaiko(msg: &Message, args: Args<'_>) -> Result<()
     - `rank`: single integer or two integers of the form `a..b` e.g. `rank=2..45`\n\
     - `sort`: `acc`, `combo`, `date` (default), `misses`, `pp`, `rank`, or `score`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `pp`: only show scores with at least this much pp, orders scores by pp\n\
     Check https://osustats.ppy.sh/ for more info."
 )]
 #[usage(
     "[username] [mods] [acc=[number..]number] [rank=[integer..]integer] \
-    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false]"
+    [sort=acc/combo/date/misses/pp/rank/score] [reverse=true/false] [pp=number]"
 )]
 #[examples(
     "badewanne3 -dt! acc=97.5..99.5 rank=42 sort=pp reverse=true",
@@ -163,7 +167,7 @@ async fn prefix_osustatsglobalsctb(msg: &Message, args: Args<'_>) -> Result<()>
     }
 }
 
-pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) -> Result<()> {
+pub(super) async fn scores(mut orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
         ModsResult::None => None,
@@ -189,11 +193,12 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
         }
     };
 
+    let min_pp = args.min_pp;
     let params = args.into_params(user.username.as_str().into(), mode, mods);
     let scores_fut = Context::client().get_global_scores(&params);
 
     // Retrieve their top global scores
-    let (scores, amount) = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
+    let (mut scores, mut amount) = match scores_fut.await.map(OsuStatsScoresRaw::into_scores) {
         Ok(Ok(scores)) => (scores.scores, scores.count),
         Err(err) | Ok(Err(err)) => {
             let _ = orig.error(OSUSTATS_API_ISSUE).await;
@@ -202,6 +207,39 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
         }
     };
 
+    if let Some(min_pp) = min_pp {
+        let params = &params;
+
+        let cutoff_fut = min_pp_cutoff(amount, 24, min_pp, |page| async move {
+            let mut params = params.clone();
+            params.page = page;
+
+            let scores = Context::client()
+                .get_global_scores(&params)
+                .await
+                .and_then(OsuStatsScoresRaw::into_scores)?;
+
+            let pps = scores
+                .scores
+                .iter()
+                .map(|score| score.pp.unwrap_or(0.0))
+                .collect();
+
+            Ok(pps)
+        });
+
+        amount = match cutoff_fut.await {
+            Ok(cutoff) => cutoff,
+            Err(err) => {
+                let _ = orig.error(OSUSTATS_API_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to find min pp cutoff"));
+            }
+        };
+
+        scores.truncate(amount);
+    }
+
     let entries = match process_scores(scores, mode).await {
         Ok(entries) => entries,
         Err(err) => {
@@ -223,6 +261,10 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
         descending = if params.descending { "Desc" } else { "Asc" },
     );
 
+    if let Some(min_pp) = min_pp {
+        let _ = write!(content, " • `Min pp: {min_pp}`");
+    }
+
     if let Some(selection) = params.get_mods() {
         let _ = write!(
             content,
@@ -242,17 +284,25 @@ pub(super) async fn scores(orig: CommandOrigin<'_>, args: OsuStatsScores<'_>) ->
                             mods.remove(GameModIntermode::SuddenDeath);
                         }
 
-                        mods.to_string()
+                        IntermodeFormatter::new(&mods).to_string()
                     } else {
-                        mods.to_string()
+                        IntermodeFormatter::new(mods).to_string()
                     }
                 }
-                ModSelection::Exclude { mods, .. } => format!("Exclude {mods}"),
-                ModSelection::Include(mods) => format!("Include {mods}"),
+                ModSelection::Exclude { mods, .. } => {
+                    format!("Exclude {}", IntermodeFormatter::new(mods))
+                }
+                ModSelection::Include(mods) => format!("Include {}", IntermodeFormatter::new(mods)),
             },
         );
     }
 
+    let thread_name = format!("Global leaderboard scores of {}", user.username);
+
+    if let Err(err) = orig.redirect_into_thread(&thread_name).await {
+        warn!(?err, "Failed to redirect into thread");
+    }
+
     let pagination = OsuStatsScoresPagination::builder()
         .user(user)
         .entries(entries)
@@ -276,6 +326,7 @@ impl<'m> OsuStatsScores<'m> {
     If you want included mods, specify it e.g. as `+hrdt`.\n\
     If you want exact mods, specify it e.g. as `+hdhr!`.\n\
     And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
+    const ERR_PARSE_PP: &'static str = "Failed to parse `pp`. Must be a positive number.";
     const ERR_PARSE_RANK: &'static str = "Failed to parse `rank`.\n\
         Must be either a positive integer \
         or two positive integers of the form `a..b` e.g. `2..45`.";
@@ -290,13 +341,19 @@ impl<'m> OsuStatsScores<'m> {
     ) -> OsuStatsParams {
         let mut params = OsuStatsParams::new(username);
 
-        let order = self.sort.unwrap_or_default();
+        let mut order = self.sort.unwrap_or_default();
         let mut descending = self.reverse.is_none_or(bool::not);
 
         if order == OsuStatsScoresOrder::Rank {
             descending = !descending;
         }
 
+        // The min pp cutoff can only be found if scores are ordered by pp
+        if self.min_pp.is_some() {
+            order = OsuStatsScoresOrder::Pp;
+            descending = true;
+        }
+
         params
             .mode(mode)
             .page(1)
@@ -324,6 +381,7 @@ impl<'m> OsuStatsScores<'m> {
         let mut sort = None;
         let mut mods = None;
         let mut reverse = None;
+        let mut min_pp = None;
 
         for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
@@ -414,6 +472,10 @@ impl<'m> OsuStatsScores<'m> {
                             return Err(content.into());
                         }
                     },
+                    "pp" => match value.parse::<f32>() {
+                        Ok(num) => min_pp = Some(num.max(0.0)),
+                        Err(_) => return Err(Self::ERR_PARSE_PP.into()),
+                    },
                     "mods" => match matcher::get_mods(value) {
                         Some(_) => mods = Some(format!("+{value}!").into()),
                         None => return Err(Self::ERR_PARSE_MODS.into()),
@@ -421,7 +483,7 @@ impl<'m> OsuStatsScores<'m> {
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `rank`, `sort`, or `reverse`."
+                            Available options are: `acc`, `rank`, `sort`, `reverse`, or `pp`."
                         );
 
                         return Err(content.into());
@@ -429,10 +491,8 @@ impl<'m> OsuStatsScores<'m> {
                 }
             } else if matcher::get_mods(&arg).is_some() {
                 mods = Some(arg);
-            } else if let Some(id) = matcher::get_mention_user(&arg) {
-                discord = Some(id);
             } else {
-                name = Some(arg);
+                (name, discord) = NameOrMention::parse(arg).into_parts();
             }
         }
 
@@ -446,6 +506,7 @@ impl<'m> OsuStatsScores<'m> {
             min_acc,
             max_acc,
             reverse,
+            min_pp,
             discord,
         })
     }
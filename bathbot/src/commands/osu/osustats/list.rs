@@ -45,6 +45,11 @@ pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'
         };
     }
 
+    let (min_position, max_position) = match (args.min_position, args.max_position) {
+        (Some(min), Some(max)) if min > max => (Some(max), Some(min)),
+        positions => positions,
+    };
+
     let mut params = OsuStatsPlayersArgs::from(args);
 
     if let Some(country) = params.country.as_mut() {
@@ -91,14 +96,40 @@ pub(super) async fn players(orig: CommandOrigin<'_>, mut args: OsuStatsPlayers<'
 
     let first_place_id = players[&1].first().unwrap().user_id;
 
+    let amount = match max_position {
+        Some(max) => amount.min(max as usize),
+        None => amount,
+    };
+
+    let start_idx = match min_position {
+        Some(min) if min as usize > amount => {
+            let content = format!(
+                "The list only contains {amount} player{plural}, \
+                can't start at position {min}",
+                plural = if amount == 1 { "" } else { "s" },
+            );
+
+            return orig.error(content).await;
+        }
+        Some(min) => min as usize - 1,
+        None => 0,
+    };
+
     let content = format!(
         "Country: `{country}` • `Rank: {rank_min} - {rank_max}`",
         rank_min = params.min_rank,
         rank_max = params.max_rank,
     );
 
-    let pagination =
-        OsuStatsPlayersPagination::new(players, params, first_place_id, amount, content, owner);
+    let pagination = OsuStatsPlayersPagination::new(
+        players,
+        params,
+        first_place_id,
+        amount,
+        start_idx,
+        content,
+        owner,
+    );
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
@@ -235,11 +266,14 @@ fn insert(
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
     The rank range default to 1..100.\n\
+    To start the list at a certain position, specify `pos=` followed by either \
+    a number for the first position or two numbers of the form `a..b` \
+    for the first and last position.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num] [pos=num[..num]] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("osl")]
 #[group(Osu)]
@@ -262,11 +296,14 @@ async fn prefix_osustatslist(msg: &Message, args: Args<'_>) -> Result<()> {
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
     The rank range default to 1..100.\n\
+    To start the list at a certain position, specify `pos=` followed by either \
+    a number for the first position or two numbers of the form `a..b` \
+    for the first and last position.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num] [pos=num[..num]] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslm")]
 #[group(Mania)]
@@ -289,11 +326,14 @@ async fn prefix_osustatslistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
     The rank range default to 1..100.\n\
+    To start the list at a certain position, specify `pos=` followed by either \
+    a number for the first position or two numbers of the form `a..b` \
+    for the first and last position.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num] [pos=num[..num]] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslt")]
 #[group(Taiko)]
@@ -316,11 +356,14 @@ async fn prefix_osustatslisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     The rank range can be specified with `rank=` followed by either a number \
     for max rank, or two numbers of the form `a..b` for min and max rank.\n\
     The rank range default to 1..100.\n\
+    To start the list at a certain position, specify `pos=` followed by either \
+    a number for the first position or two numbers of the form `a..b` \
+    for the first and last position.\n\
     To specify a country, provide its acronym, e.g. `de` for germany.\n\
     If no country is specified, I'll show the global leaderboard.\n\
     Check https://osustats.ppy.sh/r for more info."
 )]
-#[usage("[rank=[num..]num] [country acronym]")]
+#[usage("[rank=[num..]num] [pos=num[..num]] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslc", "osustatslistcatch")]
 #[group(Catch)]
@@ -339,6 +382,9 @@ impl<'m> OsuStatsPlayers<'m> {
     const ERR_PARSE_RANK: &'static str = "Failed to parse `rank`.\n\
         Must be either a positive integer \
         or two positive integers of the form `a..b` e.g. `2..45`.";
+    const ERR_PARSE_POSITION: &'static str = "Failed to parse `pos`.\n\
+        Must be either a positive integer \
+        or two positive integers of the form `a..b` e.g. `100..150`.";
     const MAX_RANK: u32 = 100;
     const MIN_RANK: u32 = 1;
 
@@ -346,8 +392,10 @@ impl<'m> OsuStatsPlayers<'m> {
         let mut country = None;
         let mut min_rank = None;
         let mut max_rank = None;
+        let mut min_position = None;
+        let mut max_position = None;
 
-        for arg in args.take(2).map(|arg| arg.cow_to_ascii_lowercase()) {
+        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -379,9 +427,33 @@ impl<'m> OsuStatsPlayers<'m> {
                         }
                         None => max_rank = Some(value.parse().map_err(|_| Self::ERR_PARSE_RANK)?),
                     },
+                    "position" | "pos" => {
+                        let (min, max) = match value.split_once("..") {
+                            Some((bot, top)) => (bot, Some(top).filter(|top| !top.is_empty())),
+                            None => (value, None),
+                        };
+
+                        let parse = |value: &str| {
+                            value
+                                .parse::<u32>()
+                                .ok()
+                                .filter(|&num| num > 0)
+                                .ok_or(Self::ERR_PARSE_POSITION)
+                        };
+
+                        if !min.is_empty() {
+                            min_position = Some(parse(min)?);
+                        }
+
+                        if let Some(max) = max {
+                            max_position = Some(parse(max)?);
+                        }
+                    }
                     _ => {
-                        let content =
-                            format!("Unrecognized option `{key}`.\nAvailable options are: `rank`.");
+                        let content = format!(
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `rank` or `pos`."
+                        );
 
                         return Err(content.into());
                     }
@@ -406,6 +478,8 @@ impl<'m> OsuStatsPlayers<'m> {
             country,
             min_rank,
             max_rank,
+            min_position,
+            max_position,
         })
     }
 }
@@ -75,6 +75,20 @@ pub struct OsuStatsPlayers<'a> {
         desc = "Specify a max rank between 1 and 100"
     )]
     max_rank: Option<u32>,
+    #[command(
+        min_value = 1,
+        desc = "Specify the first list position to show",
+        help = "Specify the first list position to show.\n\
+        The list will start on the page that contains this position."
+    )]
+    min_position: Option<u32>,
+    #[command(
+        min_value = 1,
+        desc = "Specify the last list position to show",
+        help = "Specify the last list position to show.\n\
+        Pages beyond this position can not be navigated to."
+    )]
+    max_position: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -119,6 +133,13 @@ pub struct OsuStatsScores<'a> {
     max_acc: Option<f32>,
     #[command(desc = "Reverse the resulting score list")]
     reverse: Option<bool>,
+    #[command(
+        min_value = 0.0,
+        desc = "Only show scores with at least this much pp",
+        help = "Only show scores with at least this much pp.\n\
+        The scores will then be ordered by pp, ignoring the `sort` and `reverse` options."
+    )]
+    min_pp: Option<f32>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name` option, \
@@ -7,7 +7,7 @@ use bathbot_macros::{HasMods, HasName, SlashCommand};
 use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
 use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
 use bathbot_util::{
-    MessageOrigin,
+    IntermodeFormatter, MessageOrigin,
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     osu::ModSelection,
 };
@@ -127,6 +127,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let guild_id = orig.guild_id();
@@ -224,7 +225,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
             return orig.error(content).await;
         }
         (Err(err), ..) | (_, Err(err), _) | (.., Err(UserArgsError::Osu(err))) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
 
             return Err(err);
@@ -237,7 +238,9 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned) -> Result<()> {
         }
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed_settings(orig.guild_id(), config.score_embed)
+        .await;
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
@@ -599,12 +602,20 @@ fn content_with_condition(args: &Pinned, amount: usize, mods: Option<&ModSelecti
         content.push_str("`Mods: ");
 
         let _ = match selection {
-            ModSelection::Include(mods) => write!(content, "Include {mods}"),
-            ModSelection::Exclude { mods, nomod: false } => write!(content, "Exclude {mods}"),
+            ModSelection::Include(mods) => {
+                write!(content, "Include {}", IntermodeFormatter::new(mods))
+            }
+            ModSelection::Exclude { mods, nomod: false } => {
+                write!(content, "Exclude {}", IntermodeFormatter::new(mods))
+            }
             ModSelection::Exclude { mods, nomod: true } => {
-                write!(content, "Exclude NM (without {mods})")
+                write!(
+                    content,
+                    "Exclude NM (without {})",
+                    IntermodeFormatter::new(mods)
+                )
             }
-            ModSelection::Exact(mods) => write!(content, "{mods}"),
+            ModSelection::Exact(mods) => write!(content, "{}", IntermodeFormatter::new(mods)),
         };
 
         content.push('`');
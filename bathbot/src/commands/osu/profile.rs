@@ -23,6 +23,7 @@ use crate::{
 
 #[derive(CommandModel, CreateCommand, SlashCommand, HasName)]
 #[command(name = "profile", desc = "Display statistics of a user")]
+#[flags(PERSONAL)]
 pub struct Profile<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
@@ -53,6 +54,10 @@ pub enum ProfileKind {
     Top100Mappers,
     #[option(name = "Mapper statistics", value = "mapper_stats")]
     MapperStats,
+    #[option(name = "PP ceiling", value = "pp_ceiling")]
+    PpCeiling,
+    #[option(name = "Leaderboard counts", value = "lb_counts")]
+    LeaderboardCounts,
 }
 
 impl Default for ProfileKind {
@@ -183,6 +188,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let kind = args.embed.unwrap_or_default();
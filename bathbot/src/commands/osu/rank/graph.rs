@@ -0,0 +1,351 @@
+use std::mem;
+
+use bathbot_util::numbers::WithComma;
+use eyre::{Result, WrapErr};
+use futures::future;
+use plotters::{
+    prelude::{ChartBuilder, IntoDrawingArea, PathElement, SeriesLabelPosition},
+    series::{DashedLineSeries, LineSeries},
+    style::{Color, RGBColor, WHITE},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::prelude::GameMode;
+use skia_safe::{EncodedImageFormat, surfaces};
+
+use crate::{
+    commands::osu::graphs::{H, W},
+    core::Context,
+};
+
+const CURVE_COLOR: RGBColor = RGBColor(0, 208, 138);
+const CURRENT_COLOR: RGBColor = RGBColor(2, 186, 213);
+const TARGET_COLOR: RGBColor = RGBColor(255, 102, 170);
+
+/// Amount of entries on a ranking page.
+const PAGE_SIZE: u32 = 50;
+
+/// Amount of available ranking pages.
+const MAX_PAGE: u32 = 200;
+
+/// Amount of ranks for which pp are approximated.
+const APPROX_SAMPLES: u32 = 10;
+
+/// Draw the pp of ranks around the target rank.
+///
+/// Ranks up to 10,000 use the ranking page of the target and its adjacent
+/// pages, higher ranks use approximated pp values. Both `current` and `target`
+/// are `(rank, pp)` pairs.
+pub(super) async fn rank_curve_graph(
+    mode: GameMode,
+    country: Option<&str>,
+    current: Option<(u32, f32)>,
+    target: (u32, f32),
+) -> Result<Vec<u8>> {
+    let mut curve = if target.0 <= 10_000 {
+        ranking_curve(mode, country, target.0).await
+    } else {
+        approx_curve(mode, current, target.0).await
+    };
+
+    if let Some(current) = current {
+        curve.insert_point(current);
+    }
+
+    curve.insert_point(target);
+
+    draw_graph(&curve, current, target)
+}
+
+async fn ranking_curve(mode: GameMode, country: Option<&str>, rank: u32) -> RankCurve {
+    let page = rank.div_ceil(PAGE_SIZE);
+    let first_page = page.saturating_sub(1).max(1);
+    let last_page = (page + 1).min(MAX_PAGE);
+
+    let mut chunks = Vec::with_capacity(3);
+
+    for page in first_page..=last_page {
+        let chunk = match Context::redis().pp_ranking(mode, page, country).await {
+            Ok(rankings) => {
+                let points = rankings
+                    .ranking
+                    .iter()
+                    .zip((page - 1) * PAGE_SIZE + 1..)
+                    .filter_map(|(user, rank)| {
+                        user.statistics
+                            .as_ref()
+                            .map(|stats| (rank, stats.pp.to_native()))
+                    })
+                    .collect();
+
+                Some(points)
+            }
+            Err(err) => {
+                warn!(page, ?err, "Failed to get ranking page for rank curve");
+
+                None
+            }
+        };
+
+        chunks.push(chunk);
+    }
+
+    RankCurve::new(chunks)
+}
+
+async fn approx_curve(mode: GameMode, current: Option<(u32, f32)>, rank: u32) -> RankCurve {
+    let (min, max) = match current {
+        Some((current, _)) => (current.min(rank), current.max(rank)),
+        None => (rank, rank),
+    };
+
+    let padding = ((max - min) / 4).max(rank / 10);
+    let start = min.saturating_sub(padding).max(1);
+    let step = ((max + padding - start) / (APPROX_SAMPLES - 1)).max(1);
+
+    let sample_futs = (0..APPROX_SAMPLES).map(|i| {
+        let rank = start + i * step;
+
+        async move { (rank, Context::approx().pp(rank, mode).await) }
+    });
+
+    let chunks = future::join_all(sample_futs)
+        .await
+        .into_iter()
+        .map(|(rank, res)| match res {
+            Ok(pp) => Some(vec![(rank, pp)]),
+            Err(err) => {
+                warn!(rank, ?err, "Failed to approximate pp for rank curve");
+
+                None
+            }
+        });
+
+    RankCurve::new(chunks)
+}
+
+/// Known `(rank, pp)` points sorted by rank.
+struct RankCurve {
+    /// Consecutive known points; data between two segments is missing
+    segments: Vec<Vec<(u32, f32)>>,
+}
+
+impl RankCurve {
+    /// Adjacent chunks of points are joined into one segment while `None`
+    /// denotes missing data.
+    fn new(chunks: impl IntoIterator<Item = Option<Vec<(u32, f32)>>>) -> Self {
+        let mut segments = Vec::new();
+        let mut segment = Vec::new();
+
+        for chunk in chunks {
+            match chunk {
+                Some(points) => segment.extend(points),
+                None if segment.is_empty() => {}
+                None => segments.push(mem::take(&mut segment)),
+            }
+        }
+
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+
+        Self { segments }
+    }
+
+    /// Add a point as its own segment unless a segment already covers the
+    /// rank.
+    fn insert_point(&mut self, (rank, pp): (u32, f32)) {
+        let covered = self
+            .segments
+            .iter()
+            .any(|segment| match segment.as_slice() {
+                [(first, _), .., (last, _)] => (*first..=*last).contains(&rank),
+                [(single, _)] => *single == rank,
+                [] => false,
+            });
+
+        if covered {
+            return;
+        }
+
+        let idx = self
+            .segments
+            .iter()
+            .position(|segment| segment.first().is_some_and(|(first, _)| *first > rank))
+            .unwrap_or(self.segments.len());
+
+        self.segments.insert(idx, vec![(rank, pp)]);
+    }
+
+    fn rank_bounds(&self) -> (u32, u32) {
+        self.points().fold((u32::MAX, 0), |(min, max), (rank, _)| {
+            (min.min(rank), max.max(rank))
+        })
+    }
+
+    fn pp_bounds(&self) -> (f32, f32) {
+        self.points()
+            .fold((f32::MAX, 0.0_f32), |(min, max), (_, pp)| {
+                (min.min(pp), max.max(pp))
+            })
+    }
+
+    fn points(&self) -> impl Iterator<Item = (u32, f32)> + '_ {
+        self.segments.iter().flatten().copied()
+    }
+}
+
+fn draw_graph(
+    curve: &RankCurve,
+    current: Option<(u32, f32)>,
+    target: (u32, f32),
+) -> Result<Vec<u8>> {
+    let (rank_min, mut rank_max) = curve.rank_bounds();
+
+    if rank_min == rank_max {
+        rank_max += 1;
+    }
+
+    let (pp_min, pp_max) = curve.pp_bounds();
+    let pp_padding = ((pp_max - pp_min) * 0.05).max(1.0);
+    let (pp_min, pp_max) = ((pp_min - pp_padding).max(0.0), pp_max + pp_padding);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = RGBColor(19, 43, 33);
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(75)
+            .margin(10)
+            .margin_right(20)
+            .build_cartesian_2d(rank_min..rank_max, pp_min..pp_max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(10)
+            .x_desc("Rank")
+            .x_label_formatter(&|rank| format!("#{}", WithComma::new(*rank)))
+            .y_desc("pp")
+            .y_label_formatter(&|pp| WithComma::new(*pp).to_string())
+            .label_style(("sans-serif", 15, &WHITE))
+            .bold_line_style(WHITE.mix(0.3))
+            .axis_style(RGBColor(7, 18, 14))
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        for (i, segment) in curve.segments.iter().enumerate() {
+            let series = LineSeries::new(segment.iter().copied(), CURVE_COLOR.stroke_width(3));
+            let drawn = chart.draw_series(series).wrap_err("Failed to draw curve")?;
+
+            if i == 0 {
+                drawn.label("pp").legend(|(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], CURVE_COLOR.stroke_width(3))
+                });
+            }
+        }
+
+        // Missing data between segments is bridged by dashed lines
+        for window in curve.segments.windows(2) {
+            let (Some(&from), Some(&to)) = (window[0].last(), window[1].first()) else {
+                continue;
+            };
+
+            let series = DashedLineSeries::new([from, to], 10, 6, CURVE_COLOR.stroke_width(2));
+
+            chart
+                .draw_series(series)
+                .wrap_err("Failed to draw missing data")?;
+        }
+
+        let markers = current
+            .map(|(rank, _)| ("Current", rank, CURRENT_COLOR))
+            .into_iter()
+            .chain([("Target", target.0, TARGET_COLOR)]);
+
+        for (name, rank, color) in markers {
+            let line = [(rank, pp_min), (rank, pp_max)];
+
+            chart
+                .draw_series(LineSeries::new(line, color.stroke_width(2)))
+                .wrap_err("Failed to draw marker")?
+                .label(format!("{name}: #{}", WithComma::new(rank)))
+                .legend(move |(x, y)| {
+                    PathElement::new(vec![(x, y), (x + 20, y)], color.stroke_width(2))
+                });
+        }
+
+        chart
+            .configure_series_labels()
+            .background_style(RGBColor(7, 23, 17))
+            .position(SeriesLabelPosition::UpperRight)
+            .legend_area_size(30)
+            .label_font(("sans-serif", 15, &WHITE))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_chunks_split_segments() {
+        let chunks = [
+            None,
+            Some(vec![(1, 500.0), (2, 400.0)]),
+            Some(vec![(3, 300.0)]),
+            None,
+            Some(vec![(5, 100.0)]),
+            None,
+        ];
+
+        let curve = RankCurve::new(chunks);
+
+        assert_eq!(
+            curve.segments,
+            [vec![(1, 500.0), (2, 400.0), (3, 300.0)], vec![(5, 100.0)]]
+        );
+    }
+
+    #[test]
+    fn insert_points() {
+        let mut curve = RankCurve::new([Some(vec![(10, 500.0), (20, 400.0)])]);
+
+        curve.insert_point((15, 450.0));
+        assert_eq!(curve.segments.len(), 1);
+
+        curve.insert_point((30, 300.0));
+        curve.insert_point((5, 600.0));
+
+        assert_eq!(
+            curve.segments,
+            [
+                vec![(5, 600.0)],
+                vec![(10, 500.0), (20, 400.0)],
+                vec![(30, 300.0)]
+            ]
+        );
+
+        assert_eq!(curve.rank_bounds(), (5, 30));
+        assert_eq!(curve.pp_bounds(), (300.0, 600.0));
+    }
+}
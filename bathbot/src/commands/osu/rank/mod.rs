@@ -9,11 +9,13 @@ use twilight_model::id::{Id, marker::UserMarker};
 pub use self::{pp::*, score::*};
 use crate::util::{InteractionCommandExt, interaction::InteractionCommand};
 
+mod graph;
 mod pp;
 mod score;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "rank", desc = "How much is missing to reach the given rank?")]
+#[flags(PERSONAL)]
 pub enum Rank<'a> {
     #[command(name = "pp")]
     Pp(RankPp<'a>),
@@ -48,6 +50,14 @@ pub struct RankPp<'a> {
         If `each` is set, this argument will be ignored"
     )]
     amount: Option<u8>,
+    #[command(
+        desc = "Attach a graph of the pp around the target rank",
+        help = "Attach a graph of the pp around the target rank.\n\
+        For ranks up to 10,000 the graph shows the ranking around the target rank, \
+        for higher ranks it is based on approximated pp values.\n\
+        Dashed lines indicate missing data."
+    )]
+    graph: Option<bool>,
     #[command(desc = "Specify a country (code)")]
     country: Option<Cow<'a, str>>,
     #[command(
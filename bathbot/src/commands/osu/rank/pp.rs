@@ -9,23 +9,24 @@ use std::{
 use bathbot_macros::command;
 use bathbot_model::{Countries, command_fields::GameModeOption};
 use bathbot_util::{
-    CowUtils, EmbedBuilder, MessageBuilder,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
-    matcher,
+    CowUtils, EmbedBuilder, MessageBuilder, matcher,
     numbers::WithComma,
     osu::{ExtractablePp, PpListUtil, approx_more_pp, pp_missing},
 };
 use eyre::{Report, Result};
 use rosu_v2::prelude::{CountryCode, OsuError, Score, UserId, Username};
 
-use super::{RankPp, RankValue};
+use super::{RankPp, RankValue, graph::rank_curve_graph};
 use crate::{
     Context,
     commands::osu::user_not_found,
     core::commands::{CommandOrigin, prefix::Args},
-    embeds::PersonalBestIndexFormatter,
+    embeds::{PersonalBestIndexFormatter, attachment},
     manager::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
-    util::{CachedUserExt, ChannelExt},
+    util::{
+        CachedUserExt, ChannelExt,
+        locale::{self, Msg},
+    },
 };
 
 pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()> {
@@ -36,6 +37,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         rank,
         each,
         amount,
+        graph,
         ..
     } = args;
 
@@ -74,7 +76,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_localized(Msg::GeneralIssue).await;
 
             return Err(Report::new(err).wrap_err("Failed to get user"));
         }
@@ -123,7 +125,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
                     return orig.error(content).await;
                 }
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(Msg::GeneralIssue).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get target user"));
                 }
@@ -154,7 +156,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             let rankings = match rankings_fut.await {
                 Ok(rankings) => rankings,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(Msg::GeneralIssue).await;
 
                     return Err(Report::new(err).wrap_err("Failed to get user"));
                 }
@@ -193,7 +195,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
             let required_pp = match Context::approx().pp(rank, mode).await {
                 Ok(pp) => pp,
                 Err(err) => {
-                    let _ = orig.error(GENERAL_ISSUE).await;
+                    let _ = orig.error_localized(Msg::GeneralIssue).await;
 
                     return Err(err);
                 }
@@ -226,7 +228,8 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         match scores_fut.await {
             Ok(scores) => (!scores.is_empty()).then_some(scores),
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let lang = locale::lang(&orig).await;
+                let _ = orig.error_retryable(Msg::OsuApiIssue.text(lang)).await;
                 let err = Report::new(err).wrap_err("Failed to get scores");
 
                 return Err(err);
@@ -242,18 +245,41 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
         (None, Some(amount)) => RankMultipleScores::Amount(amount),
     };
 
+    let graph = if graph == Some(true) {
+        let (target, country) = rank_data.curve_target();
+        let current = rank_data.curve_current(country.is_some());
+        let country = country.map(|code| code.as_str());
+
+        match rank_curve_graph(mode, country, current, target).await {
+            Ok(graph) => Some(graph),
+            Err(err) => {
+                warn!(?err, "Failed to create rank curve graph");
+
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let title = rank_data.title();
     let user = rank_data.user();
     let description = rank_data.description(scores.as_deref(), multiple);
 
-    let embed = EmbedBuilder::new()
+    let mut embed = EmbedBuilder::new()
         .author(user.author_builder(false))
         .description(description)
         .thumbnail(user.avatar_url.as_ref())
         .title(title);
 
-    let builder = MessageBuilder::new().embed(embed);
-    orig.create_message(builder).await?;
+    let mut builder = MessageBuilder::new();
+
+    if let Some(graph) = graph {
+        embed = embed.image(attachment("rank_curve.png"));
+        builder = builder.attachment("rank_curve.png", graph);
+    }
+
+    orig.create_message(builder.embed(embed)).await?;
 
     Ok(())
 }
@@ -550,6 +576,7 @@ impl<'m> RankPp<'m> {
             name,
             each: None,
             amount: None,
+            graph: None,
             country,
             discord,
         })
@@ -604,6 +631,39 @@ fn idx_suffix(idx: usize) -> &'static str {
     }
 }
 
+/// Note on how much each additional score of the same pp raises the total pp.
+struct EachPpGains<'a> {
+    each: f32,
+    gains: &'a [f32],
+}
+
+impl<'a> EachPpGains<'a> {
+    fn new(each: f32, gains: &'a [f32]) -> Self {
+        Self { each, gains }
+    }
+}
+
+impl Display for EachPpGains<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let [first, .., last] = self.gains else {
+            return Ok(());
+        };
+
+        let n = self.gains.len();
+
+        write!(
+            f,
+            "\nThe first additional {each}pp score adds **{first}pp** to the total \
+            but the {n}{suffix} one only adds **{last}pp** since scores further down \
+            the top plays are weighted less.",
+            each = WithComma::new(self.each),
+            first = WithComma::new(*first),
+            suffix = idx_suffix(n),
+            last = WithComma::new(*last),
+        )
+    }
+}
+
 impl RankData {
     fn with_scores(&self) -> bool {
         let user_pp = |user: &CachedUser| {
@@ -635,6 +695,37 @@ impl RankData {
         }
     }
 
+    /// The target's `(rank, pp)` and the country of the ranking, if any.
+    fn curve_target(&self) -> ((u32, f32), Option<&CountryCode>) {
+        match self {
+            Self::Sub10k {
+                rank,
+                country,
+                rank_holder,
+                ..
+            } => ((*rank, rank_holder.pp), country.as_ref()),
+            Self::Sub10kExact { rank_holder, .. } | Self::Over10kExact { rank_holder, .. } => {
+                ((rank_holder.global_rank, rank_holder.pp), None)
+            }
+            Self::Over10kApprox {
+                rank, required_pp, ..
+            } => ((*rank, *required_pp), None),
+        }
+    }
+
+    /// The user's current `(rank, pp)`, `None` if the user is unranked.
+    fn curve_current(&self, country: bool) -> Option<(u32, f32)> {
+        let stats = self.user().statistics.as_ref().expect("missing stats");
+
+        let rank = if country {
+            stats.country_rank.to_native()
+        } else {
+            stats.global_rank.to_native()
+        };
+
+        (rank > 0).then_some((rank, stats.pp.to_native()))
+    }
+
     fn title(&self) -> String {
         match self {
             RankData::Sub10k {
@@ -899,6 +990,10 @@ impl RankData {
 
                 let mut n_each = len;
 
+                // Total pp gained through each additional score
+                let mut gains = Vec::new();
+                let mut prev_total = top + bot;
+
                 for i in idx..len {
                     let bot = pps[idx..]
                         .iter()
@@ -908,13 +1003,17 @@ impl RankData {
 
                     let factor = 0.95_f32.powi(i as i32);
 
-                    if top + factor * each + bot >= rank_holder_pp {
+                    let total = top + factor * each + bot;
+
+                    if total >= rank_holder_pp {
                         // requires n_each many new scores of `each` many pp and one
                         // additional score
                         n_each = i - idx;
                         break;
                     }
 
+                    gains.push(total - prev_total);
+                    prev_total = total;
                     top += factor * each;
                 }
 
@@ -923,7 +1022,7 @@ impl RankData {
                         "{prefix}, so {username} is missing **{missing}** raw pp.\n\
                         Filling up {username}'{genitiv} top scores with {amount} new \
                         {each}pp score{plural} would only lead to {approx}**{top}pp** which \
-                        is still less than {holder_pp}pp.",
+                        is still less than {holder_pp}pp.{gains}",
                         holder_pp = WithComma::new(rank_holder_pp),
                         amount = len - idx,
                         each = WithComma::new(each),
@@ -932,6 +1031,7 @@ impl RankData {
                         genitiv = if idx != 1 { "s" } else { "" },
                         approx = if idx >= 100 { "roughly " } else { "" },
                         top = WithComma::new(top),
+                        gains = EachPpGains::new(each, &gains),
                     );
                 }
 
@@ -949,12 +1049,13 @@ impl RankData {
                 format!(
                     "{prefix}, so {username} is missing **{missing}** raw pp.\n\
                     To reach {holder_pp}pp, {username} needs to perform **{n_each}** \
-                    more {each}pp score{plural} and one **{required}pp** score.",
+                    more {each}pp score{plural} and one **{required}pp** score.{gains}",
                     holder_pp = WithComma::new(rank_holder_pp),
                     missing = WithComma::new(rank_holder_pp - user_pp),
                     each = WithComma::new(each),
                     plural = if n_each != 1 { "s" } else { "" },
                     required = WithComma::new(required),
+                    gains = EachPpGains::new(each, &gains),
                 )
             }
         }
@@ -1125,6 +1226,10 @@ impl RankData {
 
                 let mut n_each = len;
 
+                // Total pp gained through each additional score
+                let mut gains = Vec::new();
+                let mut prev_total = top + bot;
+
                 for i in idx..len {
                     let bot = pps[idx..]
                         .iter()
@@ -1134,13 +1239,17 @@ impl RankData {
 
                     let factor = 0.95_f32.powi(i as i32);
 
-                    if top + factor * each + bot >= required_pp {
+                    let total = top + factor * each + bot;
+
+                    if total >= required_pp {
                         // requires n_each many new scores of `each` many pp and one
                         // additional score
                         n_each = i - idx;
                         break;
                     }
 
+                    gains.push(total - prev_total);
+                    prev_total = total;
                     top += factor * each;
                 }
 
@@ -1150,7 +1259,7 @@ impl RankData {
                         so {username} is missing **{missing}** raw pp.\n\
                         Filling up {username}'{genitiv} top scores with {amount} new \
                         {each}pp score{plural} would only lead to {approx}**{top}pp** which \
-                        is still less than {required_pp}pp.",
+                        is still less than {required_pp}pp.{gains}",
                         required_pp = WithComma::new(required_pp),
                         amount = len - idx,
                         each = WithComma::new(each),
@@ -1159,6 +1268,7 @@ impl RankData {
                         genitiv = if idx != 1 { "s" } else { "" },
                         approx = if idx >= 100 { "roughly " } else { "" },
                         top = WithComma::new(top),
+                        gains = EachPpGains::new(each, &gains),
                     );
                 }
 
@@ -1177,12 +1287,13 @@ impl RankData {
                     "{prefix} #{rank} currently requires {maybe_approx}**{required_pp}pp**, \
                     so {username} is missing **{missing}** raw pp.\n\
                     To reach {required_pp}pp, {username} needs to perform **{n_each}** \
-                    more {each}pp score{plural} and one **{required}pp** score.",
+                    more {each}pp score{plural} and one **{required}pp** score.{gains}",
                     required_pp = WithComma::new(required_pp),
                     missing = WithComma::new(required_pp - user_pp),
                     each = WithComma::new(each),
                     plural = if n_each != 1 { "s" } else { "" },
                     required = WithComma::new(required),
+                    gains = EachPpGains::new(each, &gains),
                 )
             }
         }
@@ -306,7 +306,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
                 return orig.error(content).await;
             }
             Err(err) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error_retryable(OSU_API_ISSUE).await;
                 let err = Report::new(err).wrap_err("Failed to get user");
 
                 return Err(err);
@@ -63,7 +63,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
     let mut ranking = match Context::osu().country_rankings(mode).await {
         Ok(ranking) => ranking,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get country ranking");
 
             return Err(err);
@@ -36,6 +36,13 @@ pub struct RankingPp<'a> {
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a country (code)")]
     country: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 0,
+        desc = "Only show players with at least this much pp",
+        help = "Only show players with at least this much pp.\n\
+        The list ends at the first player below this value."
+    )]
+    min_pp: Option<u32>,
 }
 
 #[derive(CommandModel, CreateCommand)]
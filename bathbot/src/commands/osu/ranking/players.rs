@@ -7,16 +7,16 @@ use bathbot_model::{
     rosu_v2::ranking::ArchivedRankings,
 };
 use bathbot_util::constants::GENERAL_ISSUE;
-use eyre::{Report, Result};
+use eyre::{Report, Result, WrapErr};
 use rosu_v2::prelude::{CountryCode, GameMode, Rankings};
 
 use super::{RankingPp, RankingScore};
 use crate::{
     Context,
     active::{ActiveMessages, impls::RankingPagination},
-    core::commands::CommandOrigin,
+    core::commands::{CommandOrigin, prefix::Args},
     manager::redis::{RedisError, osu::UserArgs},
-    util::ChannelExt,
+    util::{ChannelExt, osu::min_pp_cutoff},
 };
 
 // TODO: this sucks
@@ -30,8 +30,31 @@ fn check_country(arg: &str) -> Result<CountryCode, &'static str> {
     }
 }
 
+/// Parse the optional country and `pp=number` arguments of prefix pp rankings.
+fn pp_ranking_args(args: Args<'_>) -> Result<(Option<CountryCode>, Option<u32>), &'static str> {
+    let mut country = None;
+    let mut min_pp = None;
+
+    for arg in args {
+        match arg.split_once('=') {
+            Some(("pp", value)) => match value.parse() {
+                Ok(value) => min_pp = Some(value),
+                Err(_) => return Err("Failed to parse `pp`, must be a positive integer"),
+            },
+            Some(_) => return Err("The only key-value argument is `pp=number`"),
+            None => country = Some(check_country(arg)?),
+        }
+    }
+
+    Ok((country, min_pp))
+}
+
 pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<()> {
-    let RankingPp { country, mode } = args;
+    let RankingPp {
+        country,
+        mode,
+        min_pp,
+    } = args;
     let owner = orig.user_id()?;
 
     let (mode, author_id) = match mode {
@@ -44,7 +67,14 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => {
+                let mode = config
+                    .mode
+                    .or(Context::guild_config().mode(orig.guild_id()).await)
+                    .unwrap_or(GameMode::Osu);
+
+                (mode, config.osu)
+            }
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -74,7 +104,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankingPp<'_>) -> Result<(
     let (ranking_res, author_idx) = tokio::join!(ranking_fut, author_idx_fut);
     let ranking_res = ranking_res.map(Ranking::Performance);
 
-    ranking(orig, mode, country, author_idx, ranking_res).await
+    ranking(orig, mode, country, author_idx, min_pp, ranking_res).await
 }
 
 async fn pp_author_idx(
@@ -133,7 +163,14 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
             }
         },
         None => match Context::user_config().with_osu_id(owner).await {
-            Ok(config) => (config.mode.unwrap_or(GameMode::Osu), config.osu),
+            Ok(config) => {
+                let mode = config
+                    .mode
+                    .or(Context::guild_config().mode(orig.guild_id()).await)
+                    .unwrap_or(GameMode::Osu);
+
+                (mode, config.osu)
+            }
             Err(err) => {
                 let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -169,7 +206,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore) -> Result
         .map_err(Report::new)
         .map_err(RedisError::Acquire);
 
-    ranking(orig, mode, None, author_idx, ranking_res).await
+    ranking(orig, mode, None, author_idx, None, ranking_res).await
 }
 
 async fn ranking(
@@ -177,6 +214,7 @@ async fn ranking(
     mode: GameMode,
     country: Option<CountryCode>,
     author_idx: Option<usize>,
+    min_pp: Option<u32>,
     result: Result<Ranking, RedisError>,
 ) -> Result<()> {
     let ranking = match result {
@@ -196,7 +234,8 @@ async fn ranking(
         (name, code)
     });
 
-    let total = ranking.total();
+    let mut total = ranking.total();
+    let country_code = country.as_ref().map(|(_, code)| code.clone());
 
     let ranking_kind = if let Some((name, code)) = country {
         RankingKind::PpCountry {
@@ -208,12 +247,50 @@ async fn ranking(
         ranking.kind(mode)
     };
 
-    let entries = ranking.entries();
+    let mut entries = ranking.entries();
+
+    if let (Some(min_pp), RankingEntries::PpU32(entries)) = (min_pp, &mut entries) {
+        let country = country_code.as_ref().map(CountryCode::as_str);
+
+        let cutoff_fut = min_pp_cutoff(total, 50, min_pp as f32, |page| async move {
+            let ranking = Context::redis()
+                .pp_ranking(mode, page as u32, country)
+                .await
+                .wrap_err("Failed to get ranking page")?;
+
+            let pps = ranking
+                .ranking
+                .iter()
+                .map(|user| {
+                    user.statistics
+                        .as_ref()
+                        .map_or(0.0, |stats| stats.pp.to_native().round())
+                })
+                .collect();
+
+            Ok(pps)
+        });
+
+        total = match cutoff_fut.await {
+            Ok(cutoff) => cutoff,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to find min pp cutoff"));
+            }
+        };
+
+        entries.retain(|&idx, _| idx < total);
+    }
+
+    // The author's position is meaningless if it's not part of the list
+    let author_idx = author_idx.filter(|&idx| idx < total);
 
     let pagination = RankingPagination::builder()
         .entries(entries)
         .total(total)
         .author_idx(author_idx)
+        .min_pp(min_pp)
         .kind(ranking_kind)
         .defer(true)
         .msg_owner(orig.user_id()?)
@@ -309,26 +386,27 @@ impl Ranking {
 #[help(
     "Display the osu! pp ranking.\n\
     For the global ranking, don't give any arguments.\n\
-    For a country specific ranking, provide its name or country code as first argument."
+    For a country specific ranking, provide its name or country code as first argument.\n\
+    To only show players with at least a certain amount of pp, specify `pp=number`."
 )]
-#[usage("[country]")]
-#[examples("", "de", "russia")]
+#[usage("[country] [pp=number]")]
+#[examples("", "de", "russia", "pp=15000", "de pp=10000")]
 #[aliases("ppr", "pplb", "ppleaderboard")]
 #[group(Osu)]
-pub async fn prefix_ppranking(msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let country = match args.next().map(check_country) {
-        Some(Ok(arg)) => Some(arg),
-        Some(Err(content)) => {
+pub async fn prefix_ppranking(msg: &Message, args: Args<'_>) -> Result<()> {
+    let (country, min_pp) = match pp_ranking_args(args) {
+        Ok(tuple) => tuple,
+        Err(content) => {
             msg.error(content).await?;
 
             return Ok(());
         }
-        None => None,
     };
 
     let args = RankingPp {
         mode: None,
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        min_pp,
     };
 
     pp(msg.into(), args).await
@@ -339,26 +417,27 @@ pub async fn prefix_ppranking(msg: &Message, mut args: Args<'_>) -> Result<()> {
 #[help(
     "Display the osu!mania pp ranking.\n\
     For the global ranking, don't give any arguments.\n\
-    For a country specific ranking, provide its name or country code as first argument."
+    For a country specific ranking, provide its name or country code as first argument.\n\
+    To only show players with at least a certain amount of pp, specify `pp=number`."
 )]
-#[usage("[country]")]
-#[examples("", "de", "russia")]
+#[usage("[country] [pp=number]")]
+#[examples("", "de", "russia", "pp=15000", "de pp=10000")]
 #[aliases("pprm", "pplbm", "ppleaderboardmania")]
 #[group(Mania)]
-pub async fn prefix_pprankingmania(msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let country = match args.next().map(check_country) {
-        Some(Ok(arg)) => Some(arg),
-        Some(Err(content)) => {
+pub async fn prefix_pprankingmania(msg: &Message, args: Args<'_>) -> Result<()> {
+    let (country, min_pp) = match pp_ranking_args(args) {
+        Ok(tuple) => tuple,
+        Err(content) => {
             msg.error(content).await?;
 
             return Ok(());
         }
-        None => None,
     };
 
     let args = RankingPp {
         mode: Some(GameModeOption::Mania),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        min_pp,
     };
 
     pp(msg.into(), args).await
@@ -369,26 +448,27 @@ pub async fn prefix_pprankingmania(msg: &Message, mut args: Args<'_>) -> Result<
 #[help(
     "Display the osu!taiko pp ranking.\n\
     For the global ranking, don't give any arguments.\n\
-    For a country specific ranking, provide its name or country code as first argument."
+    For a country specific ranking, provide its name or country code as first argument.\n\
+    To only show players with at least a certain amount of pp, specify `pp=number`."
 )]
-#[usage("[country]")]
-#[examples("", "de", "russia")]
+#[usage("[country] [pp=number]")]
+#[examples("", "de", "russia", "pp=15000", "de pp=10000")]
 #[aliases("pprt", "pplbt", "ppleaderboardtaiko")]
 #[group(Taiko)]
-pub async fn prefix_pprankingtaiko(msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let country = match args.next().map(check_country) {
-        Some(Ok(arg)) => Some(arg),
-        Some(Err(content)) => {
+pub async fn prefix_pprankingtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
+    let (country, min_pp) = match pp_ranking_args(args) {
+        Ok(tuple) => tuple,
+        Err(content) => {
             msg.error(content).await?;
 
             return Ok(());
         }
-        None => None,
     };
 
     let args = RankingPp {
         mode: Some(GameModeOption::Taiko),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        min_pp,
     };
 
     pp(msg.into(), args).await
@@ -399,26 +479,27 @@ pub async fn prefix_pprankingtaiko(msg: &Message, mut args: Args<'_>) -> Result<
 #[help(
     "Display the osu!ctb pp ranking.\n\
     For the global ranking, don't give any arguments.\n\
-    For a country specific ranking, provide its name or country code as first argument."
+    For a country specific ranking, provide its name or country code as first argument.\n\
+    To only show players with at least a certain amount of pp, specify `pp=number`."
 )]
-#[usage("[country]")]
-#[examples("", "de", "russia")]
+#[usage("[country] [pp=number]")]
+#[examples("", "de", "russia", "pp=15000", "de pp=10000")]
 #[aliases("pprc", "pplbc", "ppleaderboardctb", "pprankingcatch")]
 #[group(Catch)]
-pub async fn prefix_pprankingctb(msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let country = match args.next().map(check_country) {
-        Some(Ok(arg)) => Some(arg),
-        Some(Err(content)) => {
+pub async fn prefix_pprankingctb(msg: &Message, args: Args<'_>) -> Result<()> {
+    let (country, min_pp) = match pp_ranking_args(args) {
+        Ok(tuple) => tuple,
+        Err(content) => {
             msg.error(content).await?;
 
             return Ok(());
         }
-        None => None,
     };
 
     let args = RankingPp {
         mode: Some(GameModeOption::Catch),
         country: country.map(CountryCode::into_string).map(Cow::Owned),
+        min_pp,
     };
 
     pp(msg.into(), args).await
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError},
@@ -11,7 +11,7 @@ use rosu_v2::{
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::{require_link, user_not_found};
+use super::{NameOrMention, require_link, user_not_found};
 use crate::{
     Context,
     core::commands::CommandOrigin,
@@ -58,19 +58,11 @@ pub struct Ratios<'a> {
 #[alias("ratio")]
 #[group(Mania)]
 async fn prefix_ratios(msg: &Message, mut args: Args<'_>) -> Result<()> {
-    let args = match args.next() {
-        Some(arg) => match matcher::get_mention_user(arg) {
-            Some(id) => Ratios {
-                name: None,
-                discord: Some(id),
-            },
-            None => Ratios {
-                name: Some(Cow::Borrowed(arg)),
-                discord: None,
-            },
-        },
-        None => Ratios::default(),
-    };
+    let (name, discord) = args
+        .next()
+        .map_or((None, None), |arg| NameOrMention::parse(arg).into_parts());
+
+    let args = Ratios { name, discord };
 
     ratios(msg.into(), args).await
 }
@@ -32,7 +32,12 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+    {
         None => GameMode::Osu,
         Some(GameMode::Mania) => return orig.error("Can't fix mania scores \\:(").await,
         Some(mode) => mode,
@@ -127,7 +132,7 @@ pub(super) async fn fix(orig: CommandOrigin<'_>, args: RecentFix) -> Result<()>
                     return Err(Report::new(err));
                 }
                 (_, Err(err)) => {
-                    let _ = orig.error(OSU_API_ISSUE).await;
+                    let _ = orig.error_retryable(OSU_API_ISSUE).await;
                     let err = Report::new(err).wrap_err("failed to get top scores");
 
                     return Err(err);
@@ -17,7 +17,8 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::LeaderboardPagination},
     commands::osu::{
-        HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult, require_link, user_not_found,
+        HasMods, LeaderboardScore, LeaderboardUserScore, ModsResult, NameOrMention, require_link,
+        user_not_found,
     },
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
@@ -113,10 +114,8 @@ impl<'m> RecentLeaderboard<'m> {
         for arg in args.take(2) {
             if matcher::get_mods(arg).is_some() {
                 mods = Some(arg.into());
-            } else if let Some(id) = matcher::get_mention_user(arg) {
-                discord = Some(id);
             } else {
-                name = Some(arg.into());
+                (name, discord) = NameOrMention::parse(arg).into_parts();
             }
         }
 
@@ -182,6 +181,7 @@ pub(super) async fn leaderboard(
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let user_id = if let Some(user_id) = user_id!(orig, args) {
@@ -317,7 +317,7 @@ pub(super) async fn leaderboard(
             })
             .collect(),
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
             return Err(err.wrap_err("Failed to get scores"));
         }
@@ -11,7 +11,9 @@ use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
 };
 use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{CowUtils, IntHasher, constants::GENERAL_ISSUE, matcher, osu::ModSelection};
+use bathbot_util::{
+    CowUtils, IntHasher, IntermodeFormatter, constants::GENERAL_ISSUE, osu::ModSelection,
+};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, Grade, OsuError, Score},
@@ -22,7 +24,9 @@ use super::{RecentList, RecentListUnique};
 use crate::{
     Context,
     active::{ActiveMessages, impls::RecentListPagination},
-    commands::osu::{HasMods, ModsResult, ScoreOrder, require_link, user_not_found},
+    commands::osu::{
+        HasMods, ModsResult, NameOrMention, ScoreOrder, require_link, user_not_found,
+    },
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
         OsuMap,
@@ -30,6 +34,7 @@ use crate::{
     },
     util::{
         ChannelExt,
+        osu::is_convert,
         query::{IFilterCriteria, RegularCriteria, Searchable},
     },
 };
@@ -40,7 +45,8 @@ use crate::{
     "Display a list of a user's most recent plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -63,7 +69,8 @@ async fn prefix_recentlist(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent mania plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -86,7 +93,8 @@ async fn prefix_recentlistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent taiko plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -109,7 +117,8 @@ async fn prefix_recentlisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent ctb plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -131,7 +140,8 @@ async fn prefix_recentlistctb(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Display a list of a user's most recent passes.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -157,7 +167,8 @@ async fn prefix_recentlistpass(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Display a list of a user's most recent mania passes.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -184,7 +195,8 @@ async fn prefix_recentlistpassmania(msg: &Message, args: Args<'_>) -> Result<()>
     "Display a list of a user's most recent taiko plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -211,7 +223,8 @@ async fn prefix_recentlistpasstaiko(msg: &Message, args: Args<'_>) -> Result<()>
     "Display a list of a user's most recent ctb plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To only list a certain amount of the most recent plays, you can specify `limit=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -244,8 +257,9 @@ impl<'m> RecentList<'m> {
         let mut discord = None;
         let mut grade = None;
         let mut passes = None;
+        let mut limit = None;
 
-        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
+        for arg in args.take(4).map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -275,19 +289,26 @@ impl<'m> RecentList<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "limit" | "amount" => match value.parse() {
+                        Ok(limit_ @ 1..=100) => limit = Some(limit_),
+                        _ => {
+                            let content =
+                                "Failed to parse `limit`. Must be a number between 1 and 100.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, or `limit`."
                         );
 
                         return Err(content.into());
                     }
                 }
-            } else if let Some(id) = matcher::get_mention_user(&arg) {
-                discord = Some(id);
             } else {
-                name = Some(arg);
+                (name, discord) = NameOrMention::parse(arg).into_parts();
             }
         }
 
@@ -304,6 +325,8 @@ impl<'m> RecentList<'m> {
             passes,
             mods: None,
             unique: None,
+            converts: None,
+            limit,
             discord,
         })
     }
@@ -334,7 +357,12 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+    {
         None => GameMode::Osu,
         Some(mode) => mode,
     };
@@ -354,6 +382,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         query,
         grade,
         passes,
+        converts,
         ..
     } = &args;
 
@@ -414,7 +443,8 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         }
     };
 
-    let content = message_content(grade, mods.as_ref(), query.as_deref()).unwrap_or_default();
+    let content =
+        message_content(grade, mods.as_ref(), query.as_deref(), *converts).unwrap_or_default();
 
     let pagination = RecentListPagination::builder()
         .user(user)
@@ -434,6 +464,7 @@ fn message_content(
     grade: Option<Grade>,
     mods: Option<&ModSelection>,
     query: Option<&str>,
+    converts: Option<bool>,
 ) -> Option<String> {
     let mut content = String::new();
 
@@ -449,12 +480,20 @@ fn message_content(
         content.push_str("`Mods: ");
 
         let _ = match selection {
-            ModSelection::Exact(mods) => write!(content, "{mods}"),
-            ModSelection::Exclude { mods, nomod: false } => write!(content, "Exclude {mods}"),
+            ModSelection::Exact(mods) => write!(content, "{}", IntermodeFormatter::new(mods)),
+            ModSelection::Exclude { mods, nomod: false } => {
+                write!(content, "Exclude {}", IntermodeFormatter::new(mods))
+            }
             ModSelection::Exclude { mods, nomod: true } => {
-                write!(content, "Exclude NM (without {mods})")
+                write!(
+                    content,
+                    "Exclude NM (without {})",
+                    IntermodeFormatter::new(mods)
+                )
+            }
+            ModSelection::Include(mods) => {
+                write!(content, "Include {}", IntermodeFormatter::new(mods))
             }
-            ModSelection::Include(mods) => write!(content, "Include {mods}"),
         };
 
         content.push('`');
@@ -468,6 +507,14 @@ fn message_content(
         let _ = write!(content, "`Query: {query}`");
     }
 
+    if let Some(converts) = converts {
+        if !content.is_empty() {
+            content.push_str(" ~ ");
+        }
+
+        let _ = write!(content, "`Converts: {converts}`");
+    }
+
     (!content.is_empty()).then_some(content)
 }
 
@@ -494,6 +541,8 @@ async fn process_scores(
         passes,
         sort,
         unique,
+        limit,
+        converts,
         ..
     } = args;
 
@@ -526,6 +575,10 @@ async fn process_scores(
             return false;
         }
 
+        if *converts == Some(false) && is_convert(score) {
+            return false;
+        }
+
         true
     };
 
@@ -554,17 +607,14 @@ async fn process_scores(
 
         let mods = score.mods.clone();
         let mut calc = Context::pp(map).mode(score.mode).mods(mods);
-        let attrs = calc.difficulty().await;
-        let stars = attrs.stars() as f32;
-        let max_combo = attrs.max_combo();
+        let map_pp = calc.max_pp().await;
+        let stars = map_pp.stars;
+        let max_combo = map_pp.max_combo as u32;
 
-        let max_pp = match score
+        let max_pp = score
             .pp
             .filter(|_| score.grade.eq_letter(Grade::X) && score.mode != GameMode::Mania)
-        {
-            Some(pp) => pp,
-            None => calc.performance().await.pp() as f32,
-        };
+            .unwrap_or(map_pp.max_pp);
 
         let pp = match score.pp {
             Some(pp) => pp,
@@ -586,6 +636,11 @@ async fn process_scores(
         entries.push(entry);
     }
 
+    // Scores are retrieved newest first so the most recent ones remain
+    if let Some(limit) = limit {
+        entries.truncate(*limit as usize);
+    }
+
     match unique {
         None => {}
         Some(RecentListUnique::HighestPp) => {
@@ -10,10 +10,10 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use self::fix::*;
 pub use self::{leaderboard::*, list::*, score::*};
-use super::{HasMods, ModsResult, ScoreOrder, TopArgs, TopScoreOrder};
+use super::{HasMods, ModsResult, ScoreOrder, TopArgs, TopArgsError, TopIndex, TopScoreOrder};
 use crate::{
     commands::osu::{LeaderboardSort, top},
-    util::{InteractionCommandExt, interaction::InteractionCommand},
+    util::{InteractionCommandExt, interaction::InteractionCommand, locale::Msg},
 };
 
 mod fix;
@@ -28,6 +28,7 @@ mod score;
     help = "Retrieve a user's recent plays and display them in various forms.\n\
     The osu!api can provide the last 100 recent plays done within the last 24 hours."
 )]
+#[flags(PERSONAL)]
 pub enum Recent<'a> {
     #[command(name = "score")]
     Score(RecentScore<'a>),
@@ -136,13 +137,21 @@ pub struct RecentBest {
 }
 
 impl TryFrom<RecentBest> for TopArgs<'_> {
-    type Error = &'static str;
+    type Error = TopArgsError;
 
     fn try_from(args: RecentBest) -> Result<Self, Self::Error> {
         let mods = match args.mods() {
             ModsResult::Mods(mods) => Some(mods),
             ModsResult::None => None,
-            ModsResult::Invalid => return Err(Self::ERR_PARSE_MODS),
+            ModsResult::Invalid => return Err(TopArgsError::Mods(args.mods.clone())),
+        };
+
+        let index = match args.index.as_deref() {
+            Some(index) => match TopIndex::parse(index) {
+                Some(index) => Some(index),
+                None => return Err(TopArgsError::Msg(Msg::TopParseIndex)),
+            },
+            None => None,
         };
 
         Ok(Self {
@@ -150,17 +159,21 @@ impl TryFrom<RecentBest> for TopArgs<'_> {
             discord: args.discord,
             mode: args.mode.map(GameMode::from),
             mods,
+            equivalent_mods: false,
             min_acc: None,
             max_acc: None,
             min_combo: None,
             max_combo: None,
             grade: args.grade.map(Grade::from),
+            statuses: None,
             sort_by: TopScoreOrder::Date,
             reverse: args.reverse.unwrap_or(false),
             perfect_combo: args.perfect_combo,
-            index: args.index,
+            converts: None,
+            index,
             query: args.query,
             size: args.size,
+            list: false,
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
@@ -241,6 +254,20 @@ pub struct RecentList<'a> {
     mods: Option<Cow<'a, str>>,
     #[command(desc = "Show each map-mod pair only once")]
     unique: Option<RecentListUnique>,
+    #[command(
+        desc = "Specify whether scores on converted maps should be included",
+        help = "Specify whether scores on maps converted from osu!standard should be included.\n\
+        Only relevant for taiko, catch, and mania. Defaults to `true`."
+    )]
+    converts: Option<bool>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Specify how many of the most recent plays should be listed",
+        help = "Only list this many of the most recent plays.\n\
+        Other filters such as `passes` or `grade` are applied first."
+    )]
+    limit: Option<u32>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name` option, \
@@ -395,11 +422,7 @@ async fn slash_recent(mut command: InteractionCommand) -> Result<()> {
         Recent::Score(args) => score((&mut command).into(), args).await,
         Recent::Best(args) => match TopArgs::try_from(args) {
             Ok(args) => top((&mut command).into(), args).await,
-            Err(content) => {
-                command.error(content).await?;
-
-                Ok(())
-            }
+            Err(err) => err.reply(&(&mut command).into()).await,
         },
         Recent::Leaderboard(args) => leaderboard((&mut command).into(), args).await,
         Recent::List(args) => list((&mut command).into(), args).await,
@@ -412,10 +435,6 @@ async fn slash_rb(mut command: InteractionCommand) -> Result<()> {
 
     match TopArgs::try_from(RecentBest::from(args)) {
         Ok(args) => top((&mut command).into(), args).await,
-        Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&(&mut command).into()).await,
     }
 }
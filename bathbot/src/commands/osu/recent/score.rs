@@ -6,13 +6,14 @@ use bathbot_model::{
     embed_builder::SettingsImage,
 };
 use bathbot_psql::model::configs::{GuildConfig, Retries, ScoreData};
-use bathbot_util::{CowUtils, MessageOrigin, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{CowUtils, MessageOrigin};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rosu_v2::{
     prelude::{GameMod, GameMode, GameMods, Grade, OsuError, Score},
     request::UserId,
 };
+use time::{Duration, OffsetDateTime};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     guild::Permissions,
@@ -27,12 +28,15 @@ use crate::{
         impls::{SingleScoreContent, SingleScorePagination},
     },
     commands::{
-        osu::{map_strain_graph, require_link, user_not_found},
+        osu::{NameOrMention, map_strain_graph, require_link, user_not_found},
         utility::{MissAnalyzerCheck, ScoreEmbedDataWrap},
     },
     core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
     manager::redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
-    util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
+    util::{
+        ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand,
+        locale::Msg,
+    },
 };
 
 #[command]
@@ -310,10 +314,8 @@ impl<'m> RecentScore<'m> {
                         return Err(content.into());
                     }
                 }
-            } else if let Some(id) = matcher::get_mention_user(&arg) {
-                discord = Some(id);
             } else {
-                name = Some(arg);
+                (name, discord) = NameOrMention::parse(arg).into_parts();
             }
         }
 
@@ -343,7 +345,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
     let config = match user_config_res {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_localized(Msg::GeneralIssue).await;
 
             return Err(err.wrap_err("Failed to get user config"));
         }
@@ -359,6 +361,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let user_id = match user_id!(orig, args) {
@@ -426,7 +429,9 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
             return orig.error(content).await;
         }
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig
+                .error_localized_with(Msg::GeneralIssue, config.lang)
+                .await;
             let err = Report::new(err).wrap_err("Failed to get user or scores");
 
             return Err(err);
@@ -534,19 +539,19 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                 })
             }
 
-            Some(
-                1 + prev_scores
-                    .iter()
-                    .take_while(|s| same_mods(&s.mods, mods) && s.map_id == map_id)
-                    .count(),
-            )
+            let prev_scores = prev_scores.iter().map(|s| {
+                let same = s.map_id == map_id && same_mods(&s.mods, mods);
+
+                (s.ended_at, same)
+            });
+
+            Some(count_tries(score.ended_at, prev_scores))
+        }
+        Retries::IgnoreMods => {
+            let prev_scores = prev_scores.iter().map(|s| (s.ended_at, s.map_id == map_id));
+
+            Some(count_tries(score.ended_at, prev_scores))
         }
-        Retries::IgnoreMods => Some(
-            1 + prev_scores
-                .iter()
-                .take_while(|s| s.map_id == map_id)
-                .count(),
-        ),
     };
 
     let user_id = user.user_id.to_native();
@@ -563,10 +568,11 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         (Some(false), _) => false,
     };
 
-    let (settings, missing_settings) = match config.score_embed {
-        Some(settings) => (settings, false),
-        None => (Default::default(), true),
-    };
+    let missing_settings = config.score_embed.is_none();
+
+    let settings = Context::guild_config()
+        .score_embed_settings(orig.guild_id(), config.score_embed)
+        .await;
 
     let top100_fut = async {
         if grade != Grade::F || settings.buttons.pagination {
@@ -630,12 +636,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         miss_analyzer,
     );
 
-    let mut content = tries.map_or(SingleScoreContent::None, |tries| {
-        SingleScoreContent::OnlyForIndex {
-            idx: num,
-            content: format!("Try #{tries}"),
-        }
-    });
+    let mut content = SingleScoreContent::None;
 
     if missing_settings {
         let add_content_notices = {
@@ -662,26 +663,16 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                 |cmd| cmd.mention("builder edit").to_string(),
             );
 
-            let mut new_content =
+            let new_content =
                 format!("✨ NEW: You can now use {builder} to customize your score format! ✨");
 
-            match content {
-                SingleScoreContent::OnlyForIndex {
-                    ref mut content, ..
-                } => {
-                    new_content.push('\n');
-                    new_content.push_str(content);
-                    *content = new_content;
-                }
-                SingleScoreContent::None => content = SingleScoreContent::SameForAll(new_content),
-                SingleScoreContent::SameForAll(_) => unreachable!(),
-            }
+            content = SingleScoreContent::SameForAll(new_content);
         }
     }
 
     let graph = match entries.get_mut(num) {
         Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
-            match entry.get_mut().await {
+            match entry.get_mut(&settings).await {
                 Ok(entry) => {
                     let fut = map_strain_graph(
                         &entry.map.pp_map,
@@ -713,6 +704,10 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
 
     pagination.set_index(num);
 
+    if let Some(tries) = tries {
+        pagination.set_tries(num, tries);
+    }
+
     ActiveMessages::builder(pagination)
         .start_by_update(true)
         .attachment(graph)
@@ -720,6 +715,35 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         .await
 }
 
+/// How far apart two consecutive recent scores may be to still count as the
+/// same session when approximating the attempt count.
+const MAX_TRY_GAP: Duration = Duration::hours(6);
+
+/// Approximate the attempt count of a score.
+///
+/// `prev_scores` yields the end time of each preceding score, most recent
+/// first, and whether it counts as an attempt of the same map. Scores on other
+/// maps are skipped but counting stops at the first gap longer than
+/// [`MAX_TRY_GAP`].
+fn count_tries(
+    ended_at: OffsetDateTime,
+    prev_scores: impl IntoIterator<Item = (OffsetDateTime, bool)>,
+) -> usize {
+    let mut tries = 1;
+    let mut last = ended_at;
+
+    for (ended_at, same_map) in prev_scores {
+        if last - ended_at > MAX_TRY_GAP {
+            break;
+        }
+
+        last = ended_at;
+        tries += usize::from(same_map);
+    }
+
+    tries
+}
+
 fn process_scores(
     scores: Vec<Score>,
     top100: Option<Vec<Score>>,
@@ -884,3 +908,77 @@ async fn get_guild_values(orig: &CommandOrigin<'_>) -> GuildValues {
         None => GuildValues::default(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_740_074_400).unwrap()
+    }
+
+    fn minutes_ago(minutes: i64) -> OffsetDateTime {
+        start() - Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn single_try() {
+        assert_eq!(count_tries(start(), []), 1);
+        assert_eq!(count_tries(start(), [(minutes_ago(3), false)]), 1);
+    }
+
+    #[test]
+    fn consecutive_tries() {
+        let prev = [
+            (minutes_ago(3), true),
+            (minutes_ago(6), true),
+            (minutes_ago(9), true),
+        ];
+
+        assert_eq!(count_tries(start(), prev), 4);
+    }
+
+    #[test]
+    fn interleaved_maps() {
+        let prev = [
+            (minutes_ago(3), false),
+            (minutes_ago(6), true),
+            (minutes_ago(9), false),
+            (minutes_ago(12), false),
+            (minutes_ago(15), true),
+        ];
+
+        assert_eq!(count_tries(start(), prev), 3);
+    }
+
+    #[test]
+    fn stops_at_gap() {
+        let prev = [
+            (minutes_ago(3), true),
+            (minutes_ago(6), true),
+            // more than 6 hours after the previous score
+            (minutes_ago(6 + 6 * 60 + 1), true),
+            (minutes_ago(6 + 6 * 60 + 4), true),
+        ];
+
+        assert_eq!(count_tries(start(), prev), 3);
+    }
+
+    #[test]
+    fn gap_on_other_map_stops() {
+        let prev = [
+            (minutes_ago(3), true),
+            (minutes_ago(7 * 60), false),
+            (minutes_ago(7 * 60 + 3), true),
+        ];
+
+        assert_eq!(count_tries(start(), prev), 2);
+    }
+
+    #[test]
+    fn gap_of_exactly_six_hours_continues() {
+        let prev = [(minutes_ago(6 * 60), true)];
+
+        assert_eq!(count_tries(start(), prev), 2);
+    }
+}
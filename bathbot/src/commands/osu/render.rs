@@ -279,7 +279,7 @@ async fn render_score(mut command: InteractionCommand, score: RenderScore) -> Re
             return Ok(());
         }
         Err(ReplayError::Osu(err)) => {
-            let _ = command.error(OSU_API_ISSUE).await;
+            let _ = command.error_retryable(OSU_API_ISSUE).await;
 
             return Err(Report::new(err).wrap_err("Failed to get replay"));
         }
@@ -0,0 +1,246 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::{ScoreSlim, embed_builder::SettingsImage};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    osu::calculate_legacy_grade,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameModIntermode, GameMode, GameModsIntermode, Grade, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::Attachment;
+
+use self::osr::ReplayHeader;
+use super::{prepare_strain_graph, user_not_found};
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::CompareScoresPagination},
+    commands::utility::ScoreEmbedData,
+    manager::{
+        MapError,
+        redis::osu::{UserArgs, UserArgsError},
+    },
+    util::{
+        InteractionCommandExt,
+        interaction::InteractionCommand,
+        osu::{IfFc, if_passed_pp},
+    },
+};
+
+mod osr;
+
+/// Replay files larger than this are rejected
+const MAX_REPLAY_SIZE: u64 = 10 * 1024 * 1024;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "replay",
+    desc = "Display the score of a replay file",
+    help = "Display the score of an attached .osr replay file.\n\
+    The map is identified through the hash stored in the replay \
+    so it must be submitted and up to date.\n\
+    Replay frames are not analyzed so there is no unstable rate."
+)]
+pub struct Replay {
+    #[command(desc = "Specify a .osr replay file")]
+    replay: Attachment,
+}
+
+async fn slash_replay(mut command: InteractionCommand) -> Result<()> {
+    let Replay { replay } = Replay::from_interaction(command.input_data())?;
+
+    if !replay.filename.ends_with(".osr") {
+        let content = "The attached replay must be a .osr file";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    if replay.size > MAX_REPLAY_SIZE {
+        let content = "The attached replay must not be larger than 10MB";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let bytes = match Context::client().get_discord_attachment(&replay).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download replay"));
+        }
+    };
+
+    let header = match ReplayHeader::parse(&bytes) {
+        Ok(header) => header,
+        Err(_) => {
+            let content = "Failed to parse the replay, the file seems to be corrupt";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let owner = command.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let score_data = match config.score_data {
+        Some(score_data) => score_data,
+        None => match command.guild_id {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .unwrap_or_default(),
+            None => Default::default(),
+        },
+    };
+
+    let settings = Context::guild_config()
+        .score_embed_settings(command.guild_id, config.score_embed)
+        .await;
+    let mode = header.mode;
+
+    let map_id = match Context::osu_map()
+        .map_id_by_checksum(&header.map_checksum)
+        .await
+    {
+        Ok(map_id) => map_id,
+        Err(MapError::NotFound) => {
+            let content = "Couldn't find the replay's map. \
+                It's either not submitted or the replay was set on an outdated version.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(MapError::Report(err)) => {
+            let _ = command.error_retryable(OSU_API_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to resolve replay map"));
+        }
+    };
+
+    let map_fut = Context::osu_map().map(map_id, Some(&header.map_checksum));
+
+    let user_id = UserId::Name(header.player_name.as_str().into());
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let user_fut = Context::redis().osu_user(user_args);
+
+    let (map_res, user_res) = tokio::join!(map_fut, user_fut);
+
+    let map = match map_res {
+        Ok(map) => map.convert(mode),
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err));
+        }
+    };
+
+    let user = match user_res {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let mut mods = GameModsIntermode::from_bits(header.mods);
+
+    // Score v2 only affects the score value but not the pp
+    mods.remove(GameModIntermode::ScoreV2);
+
+    let mods = mods.with_mode(mode);
+    let statistics = header.statistics();
+
+    let mut score = ScoreSlim {
+        accuracy: statistics.legacy_accuracy(mode),
+        ended_at: header.timestamp,
+        grade: calculate_legacy_grade(mode, &mods, &statistics),
+        max_combo: header.max_combo as u32,
+        mode,
+        mods,
+        pp: 0.0,
+        score: header.score,
+        classic_score: header.score as u64,
+        score_id: header.score_id.unwrap_or(0),
+        is_legacy: true,
+        statistics,
+        set_on_lazer: false,
+    };
+
+    let mut calc = Context::pp(&map)
+        .mode(mode)
+        .mods(score.mods.clone())
+        .lazer(false);
+    let attrs = calc.performance().await;
+
+    score.pp = calc.score(&score).performance().await.pp() as f32;
+
+    let max_pp = if score.grade.eq_letter(Grade::X) && mode != GameMode::Mania {
+        score.pp
+    } else {
+        attrs.pp() as f32
+    };
+
+    let if_fc = IfFc::new(&score, &map).await;
+    let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+    let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(score.mode));
+    let if_passed_pp = if_passed_pp(&score, &map).await;
+
+    let entry = ScoreEmbedData {
+        score,
+        map: map.clone(),
+        stars: attrs.stars() as f32,
+        max_combo: attrs.max_combo(),
+        max_pp,
+        replay_score_id: None,
+        miss_analyzer: None,
+        pb_idx: None,
+        global_idx: None,
+        mod_idx: None,
+        if_fc_pp,
+        if_fc_acc,
+        if_passed_pp,
+        pp_percentile: None,
+        map_pb: None,
+        #[cfg(feature = "twitch")]
+        twitch: None,
+    };
+
+    let graph = if matches!(settings.image, SettingsImage::ImageWithStrains) {
+        prepare_strain_graph(&entry).await
+    } else {
+        None
+    };
+
+    let content = "Unstable rate unavailable: replay frames are not analyzed";
+
+    let pagination = CompareScoresPagination::builder()
+        .user(user)
+        .map(map)
+        .settings(settings)
+        .entries(Box::from([entry]))
+        .pinned(Box::default())
+        .pp_idx(0)
+        .score_data(score_data)
+        .content(Some(content.into()))
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .attachment(graph)
+        .begin(&mut command)
+        .await
+}
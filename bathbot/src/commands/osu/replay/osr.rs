@@ -0,0 +1,268 @@
+//! Parser for the header of `.osr` replay files.
+//!
+//! See <https://osu.ppy.sh/wiki/en/Client/File_formats/osr_(file_format)>.
+//! Replay frames are skipped since only the score itself is of interest.
+
+use nom::{
+    Err as NomErr, IResult,
+    bytes::complete as by,
+    combinator::{map, map_res, opt},
+    error::{Error as NomError, ErrorKind as NomErrorKind},
+    number::complete as num,
+};
+use rosu_v2::prelude::{GameMode, ScoreStatistics};
+use thiserror::Error;
+use time::OffsetDateTime;
+
+/// Windows ticks (100ns intervals since 0001-01-01) at the unix epoch
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+#[derive(Debug, PartialEq)]
+pub struct ReplayHeader {
+    pub mode: GameMode,
+    pub version: i32,
+    pub map_checksum: String,
+    pub player_name: String,
+    pub n300: u16,
+    pub n100: u16,
+    pub n50: u16,
+    pub n_geki: u16,
+    pub n_katu: u16,
+    pub n_miss: u16,
+    pub score: u32,
+    pub max_combo: u16,
+    pub perfect: bool,
+    pub mods: u32,
+    pub timestamp: OffsetDateTime,
+    /// Online id of the score, `None` if the score was not submitted.
+    pub score_id: Option<u64>,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("invalid or incomplete replay data")]
+pub struct ReplayParseError;
+
+impl ReplayHeader {
+    pub fn parse(bytes: &[u8]) -> Result<Self, ReplayParseError> {
+        parse_header(bytes)
+            .map(|(_, header)| header)
+            .map_err(|_| ReplayParseError)
+    }
+
+    /// Convert the legacy judgement counts into [`ScoreStatistics`].
+    pub fn statistics(&self) -> ScoreStatistics {
+        let mut stats = ScoreStatistics {
+            great: self.n300 as u32,
+            miss: self.n_miss as u32,
+            ..Default::default()
+        };
+
+        match self.mode {
+            GameMode::Osu => {
+                stats.ok = self.n100 as u32;
+                stats.meh = self.n50 as u32;
+            }
+            GameMode::Taiko => stats.ok = self.n100 as u32,
+            // Same convention as `IfFc`: droplets in `ok`, tiny droplets in `meh`, and
+            // tiny droplet misses in `good`
+            GameMode::Catch => {
+                stats.ok = self.n100 as u32;
+                stats.meh = self.n50 as u32;
+                stats.good = self.n_katu as u32;
+            }
+            GameMode::Mania => {
+                stats.perfect = self.n_geki as u32;
+                stats.good = self.n_katu as u32;
+                stats.ok = self.n100 as u32;
+                stats.meh = self.n50 as u32;
+            }
+        }
+
+        stats
+    }
+}
+
+fn parse_header(input: &[u8]) -> IResult<&[u8], ReplayHeader> {
+    let (input, mode) = map_res(num::le_u8, parse_mode)(input)?;
+    let (input, version) = num::le_i32(input)?;
+    let (input, map_checksum) = parse_string(input)?;
+    let (input, player_name) = parse_string(input)?;
+    let (input, _replay_checksum) = parse_string(input)?;
+    let (input, n300) = num::le_u16(input)?;
+    let (input, n100) = num::le_u16(input)?;
+    let (input, n50) = num::le_u16(input)?;
+    let (input, n_geki) = num::le_u16(input)?;
+    let (input, n_katu) = num::le_u16(input)?;
+    let (input, n_miss) = num::le_u16(input)?;
+    let (input, score) = num::le_u32(input)?;
+    let (input, max_combo) = num::le_u16(input)?;
+    let (input, perfect) = map(num::le_u8, |perfect| perfect != 0)(input)?;
+    let (input, mods) = num::le_u32(input)?;
+    let (input, _life_bar) = parse_string(input)?;
+    let (input, timestamp) = map_res(num::le_i64, parse_timestamp)(input)?;
+    let (input, frames_len) = num::le_u32(input)?;
+    let (input, _frames) = by::take(frames_len)(input)?;
+
+    // Older replays may end before the score id
+    let (input, score_id) = opt(num::le_u64)(input)?;
+
+    let header = ReplayHeader {
+        mode,
+        version,
+        map_checksum,
+        player_name,
+        n300,
+        n100,
+        n50,
+        n_geki,
+        n_katu,
+        n_miss,
+        score,
+        max_combo,
+        perfect,
+        mods,
+        timestamp,
+        score_id: score_id.filter(|&id| id > 0),
+    };
+
+    Ok((input, header))
+}
+
+fn parse_mode(mode: u8) -> Result<GameMode, ReplayParseError> {
+    match mode {
+        0 => Ok(GameMode::Osu),
+        1 => Ok(GameMode::Taiko),
+        2 => Ok(GameMode::Catch),
+        3 => Ok(GameMode::Mania),
+        _ => Err(ReplayParseError),
+    }
+}
+
+fn parse_timestamp(ticks: i64) -> Result<OffsetDateTime, ReplayParseError> {
+    let nanos = (ticks as i128 - UNIX_EPOCH_TICKS as i128) * 100;
+
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| ReplayParseError)
+}
+
+/// Either a single `0x00` byte for an empty string or a `0x0b` byte followed
+/// by the ULEB128 encoded length and the UTF-8 content.
+fn parse_string(input: &[u8]) -> IResult<&[u8], String> {
+    let (input, marker) = num::le_u8(input)?;
+
+    match marker {
+        0x00 => Ok((input, String::new())),
+        0x0b => {
+            let (input, len) = parse_uleb128(input)?;
+
+            map_res(by::take(len), |bytes: &[u8]| {
+                String::from_utf8(bytes.to_owned())
+            })(input)
+        }
+        _ => Err(NomErr::Error(NomError::new(input, NomErrorKind::Tag))),
+    }
+}
+
+fn parse_uleb128(mut input: &[u8]) -> IResult<&[u8], u32> {
+    let mut value = 0;
+
+    for shift in (0..32).step_by(7) {
+        let (rest, byte) = num::le_u8(input)?;
+        input = rest;
+        value |= ((byte & 0x7f) as u32) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok((input, value));
+        }
+    }
+
+    Err(NomErr::Error(NomError::new(input, NomErrorKind::TooLarge)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_string(bytes: &mut Vec<u8>, s: &str) {
+        bytes.push(0x0b);
+        bytes.push(s.len() as u8);
+        bytes.extend_from_slice(s.as_bytes());
+    }
+
+    fn replay_bytes(mode: u8) -> Vec<u8> {
+        let mut bytes = vec![mode];
+        bytes.extend_from_slice(&20250101_i32.to_le_bytes());
+        push_string(&mut bytes, "0123456789abcdef0123456789abcdef");
+        push_string(&mut bytes, "Badewanne3");
+        push_string(&mut bytes, "fedcba9876543210fedcba9876543210");
+
+        for count in [1000_u16, 20, 3, 150, 10, 2] {
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&12_345_678_u32.to_le_bytes());
+        bytes.extend_from_slice(&1234_u16.to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&(8_u32 | 64).to_le_bytes());
+        bytes.push(0x00);
+
+        let ticks = UNIX_EPOCH_TICKS + 1_700_000_000 * 10_000_000;
+        bytes.extend_from_slice(&ticks.to_le_bytes());
+
+        bytes.extend_from_slice(&3_u32.to_le_bytes());
+        bytes.extend_from_slice(&[1, 2, 3]);
+        bytes.extend_from_slice(&4_321_u64.to_le_bytes());
+
+        bytes
+    }
+
+    #[test]
+    fn header() {
+        let header = ReplayHeader::parse(&replay_bytes(0)).unwrap();
+
+        assert_eq!(header.mode, GameMode::Osu);
+        assert_eq!(header.map_checksum, "0123456789abcdef0123456789abcdef");
+        assert_eq!(header.player_name, "Badewanne3");
+        assert_eq!(header.n300, 1000);
+        assert_eq!(header.n_miss, 2);
+        assert_eq!(header.score, 12_345_678);
+        assert_eq!(header.max_combo, 1234);
+        assert!(!header.perfect);
+        assert_eq!(header.mods, 72);
+        assert_eq!(header.timestamp.unix_timestamp(), 1_700_000_000);
+        assert_eq!(header.score_id, Some(4321));
+    }
+
+    #[test]
+    fn without_score_id() {
+        let mut bytes = replay_bytes(3);
+        bytes.truncate(bytes.len() - 8);
+
+        let header = ReplayHeader::parse(&bytes).unwrap();
+
+        assert_eq!(header.mode, GameMode::Mania);
+        assert_eq!(header.score_id, None);
+        assert_eq!(header.statistics().perfect, 150);
+    }
+
+    #[test]
+    fn catch_statistics() {
+        let header = ReplayHeader::parse(&replay_bytes(2)).unwrap();
+        let stats = header.statistics();
+
+        assert_eq!(header.mode, GameMode::Catch);
+        assert_eq!(stats.great, 1000);
+        assert_eq!(stats.ok, 20);
+        assert_eq!(stats.meh, 3);
+        assert_eq!(stats.good, 10);
+        assert_eq!(stats.miss, 2);
+    }
+
+    #[test]
+    fn corrupt() {
+        let bytes = replay_bytes(0);
+
+        assert_eq!(ReplayHeader::parse(&bytes[..50]), Err(ReplayParseError));
+        assert_eq!(ReplayHeader::parse(&replay_bytes(4)), Err(ReplayParseError));
+        assert_eq!(ReplayHeader::parse(&[]), Err(ReplayParseError));
+    }
+}
@@ -0,0 +1,428 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    ModsFormatter,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    matcher,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameModIntermode, GameMode, OsuError, RankStatus, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{require_link, user_not_found};
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::ScorepostActive},
+    core::commands::CommandOrigin,
+    manager::{
+        OsuMap,
+        redis::osu::{UserArgs, UserArgsError},
+    },
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "scorepost",
+    desc = "Generate a title for a score post",
+    help = "Generate a reddit-style title for a score post, e.g.\n\
+    `Player | Artist - Title [Diff] +HDDT 98.32% FC 727pp #1`\n\
+    By default the user's most recent top play is used. \
+    Specify an `index` to pick a different top play or a `map` to use \
+    the user's best score on that map instead."
+)]
+pub struct Scorepost<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Choose the top play at this index"
+    )]
+    index: Option<u32>,
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        The user's best score on that map will be used instead of a top play."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_scorepost(mut command: InteractionCommand) -> Result<()> {
+    let args = Scorepost::from_interaction(command.input_data())?;
+
+    scorepost((&mut command).into(), args).await
+}
+
+async fn scorepost(orig: CommandOrigin<'_>, args: Scorepost<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+        .unwrap_or(GameMode::Osu);
+
+    let legacy_scores = match config.score_data {
+        Some(score_data) => score_data.is_legacy(),
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .is_some_and(ScoreData::is_legacy),
+            None => false,
+        },
+    };
+
+    let map_id = match args.map.as_deref() {
+        Some(map) => match matcher::get_osu_map_id(map) {
+            Some(map_id) => Some(map_id),
+            None => {
+                let content = "Failed to parse map. Be sure to specify a valid map url or map id.";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let (user, score) = if let Some(map_id) = map_id {
+        let user = match Context::redis().osu_user(user_args).await {
+            Ok(user) => user,
+            Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                let content = user_not_found(user_id).await;
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(err);
+            }
+        };
+
+        let score_fut = Context::osu_scores().user_on_map_single(
+            user.user_id.to_native(),
+            map_id,
+            mode,
+            None,
+            legacy_scores,
+        );
+
+        match score_fut.await {
+            Ok(score) => (user, score.score),
+            Err(OsuError::NotFound) => {
+                let content = format!(
+                    "Couldn't find any {mode}score of `{name}` on that map",
+                    mode = mode_str(mode),
+                    name = user.username.as_str(),
+                );
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error_retryable(OSU_API_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get score");
+
+                return Err(err);
+            }
+        }
+    } else {
+        let scores_fut = Context::osu_scores()
+            .top(legacy_scores)
+            .limit(100)
+            .exec_with_user(user_args);
+
+        let (user, mut scores) = match scores_fut.await {
+            Ok((user, scores)) => (user, scores),
+            Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                let content = user_not_found(user_id).await;
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+                return Err(err);
+            }
+        };
+
+        let idx = match args.index {
+            Some(idx) if idx as usize > scores.len() => {
+                let content = format!(
+                    "`{name}` only has {len} top {mode}scores",
+                    name = user.username.as_str(),
+                    len = scores.len(),
+                    mode = mode_str(mode),
+                );
+
+                return orig.error(content).await;
+            }
+            Some(idx) => Some(idx as usize - 1),
+            // Most recent top play
+            None => scores
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, score)| score.ended_at)
+                .map(|(i, _)| i),
+        };
+
+        let Some(idx) = idx else {
+            let content = format!(
+                "`{name}` has no top {mode}scores",
+                name = user.username.as_str(),
+                mode = mode_str(mode),
+            );
+
+            return orig.error(content).await;
+        };
+
+        (user, scores.swap_remove(idx))
+    };
+
+    let checksum = score.map.as_ref().and_then(|map| map.checksum.as_deref());
+
+    let map = match Context::osu_map().map(score.map_id, checksum).await {
+        Ok(map) => map,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err));
+        }
+    };
+
+    let max_combo = Context::pp(&map)
+        .mode(score.mode)
+        .mods(score.mods.clone())
+        .lazer(score.set_on_lazer)
+        .difficulty()
+        .await
+        .max_combo();
+
+    let global_idx = match global_position(map.map_id(), mode, legacy_scores, score.id).await {
+        Ok(global_idx) => global_idx,
+        Err(err) => {
+            warn!(?err, "Failed to get global position for scorepost");
+
+            None
+        }
+    };
+
+    let title = ScorepostTitle::new(&user.username, &map, &score, max_combo, global_idx);
+
+    let active = ScorepostActive::new(title, &user, &map, mode, legacy_scores, score.id, owner);
+
+    ActiveMessages::builder(active).begin(orig).await
+}
+
+/// Retrieve the position of a score on the global leaderboard.
+pub async fn global_position(
+    map_id: u32,
+    mode: GameMode,
+    legacy_scores: bool,
+    score_id: u64,
+) -> Result<Option<usize>> {
+    let scores = Context::osu_scores()
+        .map_leaderboard(map_id, mode, None, 50, legacy_scores)
+        .await?;
+
+    let idx = scores
+        .iter()
+        .position(|s| s.id == score_id)
+        .map(|idx| idx + 1);
+
+    Ok(idx)
+}
+
+/// A reddit-style title for a score post, e.g.
+/// `Player | Artist - Title [Diff] +HDDT 98.32% FC 727pp #1`
+pub struct ScorepostTitle {
+    username: Box<str>,
+    artist: Box<str>,
+    title: Box<str>,
+    version: Box<str>,
+    /// Formatted mods, `None` if nomod
+    mods: Option<Box<str>>,
+    accuracy: f32,
+    misses: u32,
+    combo: u32,
+    max_combo: u32,
+    pp: Option<f32>,
+    pub global_idx: Option<usize>,
+    status: RankStatus,
+}
+
+impl ScorepostTitle {
+    fn new(
+        username: &str,
+        map: &OsuMap,
+        score: &Score,
+        max_combo: u32,
+        global_idx: Option<usize>,
+    ) -> Self {
+        let mut mods = score.mods.clone();
+        mods.remove_intermode(GameModIntermode::Classic);
+
+        let mods =
+            (!mods.is_empty()).then(|| ModsFormatter::new(&mods).to_string().into_boxed_str());
+
+        Self {
+            username: username.into(),
+            artist: map.artist().into(),
+            title: map.title().into(),
+            version: map.version().into(),
+            mods,
+            accuracy: score.accuracy,
+            misses: score.statistics.miss,
+            combo: score.max_combo,
+            max_combo,
+            pp: score.pp,
+            global_idx,
+            status: map.status(),
+        }
+    }
+}
+
+impl Display for ScorepostTitle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} | {} - {} [{}]",
+            self.username, self.artist, self.title, self.version
+        )?;
+
+        if let Some(ref mods) = self.mods {
+            write!(f, " +{mods}")?;
+        }
+
+        write!(f, " {:.2}%", self.accuracy)?;
+
+        if self.misses > 0 {
+            write!(
+                f,
+                " {}xMiss {}/{}x",
+                self.misses, self.combo, self.max_combo
+            )?;
+        } else if self.combo < self.max_combo {
+            write!(f, " {}/{}x", self.combo, self.max_combo)?;
+        } else {
+            f.write_str(" FC")?;
+        }
+
+        if let Some(pp) = self.pp {
+            write!(f, " {}pp", pp.round())?;
+        }
+
+        if let Some(idx) = self.global_idx {
+            write!(f, " #{idx}")?;
+        }
+
+        match self.status {
+            RankStatus::Ranked | RankStatus::Approved => Ok(()),
+            RankStatus::Loved => f.write_str(" Loved"),
+            RankStatus::Qualified => f.write_str(" Qualified"),
+            RankStatus::Pending | RankStatus::WIP | RankStatus::Graveyard => {
+                f.write_str(" Unranked")
+            }
+        }
+    }
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "",
+        GameMode::Taiko => "taiko ",
+        GameMode::Catch => "ctb ",
+        GameMode::Mania => "mania ",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn title(misses: u32, combo: u32) -> ScorepostTitle {
+        ScorepostTitle {
+            username: "Player".into(),
+            artist: "Artist".into(),
+            title: "Title".into(),
+            version: "Diff".into(),
+            mods: Some("HDDT".into()),
+            accuracy: 98.3249,
+            misses,
+            combo,
+            max_combo: 1500,
+            pp: Some(726.5),
+            global_idx: Some(1),
+            status: RankStatus::Ranked,
+        }
+    }
+
+    #[test]
+    fn full_combo() {
+        assert_eq!(
+            title(0, 1500).to_string(),
+            "Player | Artist - Title [Diff] +HDDT 98.32% FC 727pp #1"
+        );
+    }
+
+    #[test]
+    fn choke() {
+        assert_eq!(
+            title(2, 1234).to_string(),
+            "Player | Artist - Title [Diff] +HDDT 98.32% 2xMiss 1234/1500x 727pp #1"
+        );
+
+        assert_eq!(
+            title(0, 1234).to_string(),
+            "Player | Artist - Title [Diff] +HDDT 98.32% 1234/1500x 727pp #1"
+        );
+    }
+
+    #[test]
+    fn loved_nomod() {
+        let mut title = title(0, 1500);
+        title.mods = None;
+        title.pp = None;
+        title.global_idx = None;
+        title.status = RankStatus::Loved;
+
+        assert_eq!(
+            title.to_string(),
+            "Player | Artist - Title [Diff] 98.32% FC Loved"
+        );
+    }
+}
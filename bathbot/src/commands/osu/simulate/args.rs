@@ -35,6 +35,7 @@ pub enum SimulateArg {
     Hp(f32),
     Od(f32),
     Lazer(bool),
+    CompareMods(bool),
 }
 
 impl SimulateArg {
@@ -66,6 +67,9 @@ impl SimulateArg {
             Some("stable") => parse_lazer(rest, ParseError::Stable)
                 .map(<bool as std::ops::Not>::not)
                 .map(SimulateArg::Lazer),
+            Some("compare" | "comparemods") => {
+                parse_lazer(rest, ParseError::CompareMods).map(SimulateArg::CompareMods)
+            }
             Some(key) => {
                 let (sub_n, _) = opt::<_, _, NomError<_>, _>(ch::char('n'))(key)
                     .map_err(|_| ParseError::nom(&input))?;
@@ -373,6 +377,7 @@ pub enum ParseError {
     Od,
     Lazer,
     Stable,
+    CompareMods,
     Nom(String),
     Unknown(String),
 }
@@ -386,7 +391,7 @@ impl ParseError {
         Self::Unknown(format!(
             "Unknown key `{input}`. Must be `mods`, `lazer`, `stable`, `acc`, `bpm`, \
             `combo`, `clockrate`, `n300`, `n100`, `n50`, `miss`, `geki`, `katu`, \
-            `sliderends`, `largeticks`, `smallticks`, `ar`, `cs`, `hp`, or `od`"
+            `sliderends`, `largeticks`, `smallticks`, `ar`, `cs`, `hp`, `od`, or `compare`"
         ))
     }
 
@@ -412,6 +417,7 @@ impl ParseError {
             Self::SmallTicks => "Failed to parse small ticks, must be a number".into(),
             Self::Lazer => "Failed to parse lazer, must be a boolean".into(),
             Self::Stable => "Failed to parse stable, must be a boolean".into(),
+            Self::CompareMods => "Failed to parse compare, must be a boolean".into(),
             Self::Nom(err) | Self::Unknown(err) => err.into(),
         }
     }
@@ -631,6 +637,22 @@ mod tests {
         assert_eq!(SimulateArg::parse("stable=123"), Err(ParseError::Stable));
     }
 
+    #[test]
+    fn compare_mods() {
+        assert_eq!(
+            SimulateArg::parse("compare=true"),
+            Ok(SimulateArg::CompareMods(true))
+        );
+        assert_eq!(
+            SimulateArg::parse("comparemods=0"),
+            Ok(SimulateArg::CompareMods(false))
+        );
+        assert_eq!(
+            SimulateArg::parse("compare=hr"),
+            Err(ParseError::CompareMods)
+        );
+    }
+
     #[test]
     fn mods() {
         let hdhr = mods!(HD HR);
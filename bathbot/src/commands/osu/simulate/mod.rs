@@ -22,7 +22,7 @@ use twilight_model::{
 use self::args::{ParseError, SimulateArg};
 use super::{
     HasMods, ModsResult, TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion,
-    TopOldTaikoVersion,
+    TopOldTaikoVersion, mods_error, pp_undefined_content,
 };
 use crate::{
     active::{
@@ -88,6 +88,13 @@ pub struct Simulate<'m> {
     od: Option<f32>,
     #[command(desc = "Specify a .osu file")]
     file: Option<Attachment>,
+    #[command(
+        desc = "Show the pp of the same hits with other mods",
+        help = "Show the pp of the same hits with HR, DT, and HDDT, \
+        or with DT and HT for mania.\n\
+        Only available for the current pp version."
+    )]
+    compare_mods: Option<bool>,
 }
 
 pub async fn slash_simulate(mut command: InteractionCommand) -> Result<()> {
@@ -105,7 +112,10 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     let config = Context::user_config().with_osu_id(owner).await?;
 
     let map = args.map.take();
-    let mode = args.mode.or(config.mode);
+    let mode = args
+        .mode
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await);
 
     let Some(map) = prepare_map(&orig, map, mode).await? else {
         return Ok(());
@@ -173,6 +183,7 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
         score: None,
         version,
         max_combo,
+        compare_mods: args.compare_mods,
     };
 
     let active = SimulateComponents::new(map, simulate_data, owner);
@@ -208,7 +219,8 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
     - od: `od=[number]` or `od[number]`\n\
-    - lazer: `lazer=[bool]` or `stable=[bool]`"
+    - lazer: `lazer=[bool]` or `stable=[bool]`\n\
+    - compare mods: `compare=[bool]`"
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
@@ -248,7 +260,8 @@ async fn prefix_simulate(
     - ar: `ar=[number]` or `ar[number]`\n\
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
-    - od: `od=[number]` or `od[number]`"
+    - od: `od=[number]` or `od[number]`\n\
+    - compare mods: `compare=[bool]`"
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
@@ -290,7 +303,8 @@ async fn prefix_simulatetaiko(
     - ar: `ar=[number]` or `ar[number]`\n\
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
-    - od: `od=[number]` or `od[number]`"
+    - od: `od=[number]` or `od[number]`\n\
+    - compare mods: `compare=[bool]`"
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
@@ -334,7 +348,8 @@ async fn prefix_simulatectb(
     - cs: `cs=[number]` or `cs[number]`\n\
     - hp: `hp=[number]` or `hp[number]`\n\
     - od: `od=[number]` or `od[number]`\n\
-    - lazer: `lazer=[bool]` or `stable=[bool]`"
+    - lazer: `lazer=[bool]` or `stable=[bool]`\n\
+    - compare mods: `compare=[bool]`"
 )]
 #[usage(
     "[map url / map id] [+mods] [acc%] [combox] [clockrate*] \
@@ -447,6 +462,7 @@ struct SimulateArgs {
     cs: Option<f32>,
     hp: Option<f32>,
     od: Option<f32>,
+    compare_mods: bool,
 }
 
 impl SimulateArgs {
@@ -478,6 +494,7 @@ impl SimulateArgs {
 
             if let Some(id) = id_opt {
                 simulate.map = Some(SimulateMapArg::Id(id));
+                simulate.mode = MapIdType::resolve_mode(arg, mode);
 
                 continue;
             }
@@ -503,13 +520,14 @@ impl SimulateArgs {
                 SimulateArg::Hp(val) => simulate.hp = Some(val),
                 SimulateArg::Od(val) => simulate.od = Some(val),
                 SimulateArg::Lazer(val) => simulate.set_on_lazer = Some(val),
+                SimulateArg::CompareMods(val) => simulate.compare_mods = val,
             }
         }
 
         Ok(simulate)
     }
 
-    fn from_simulate(simulate: Simulate<'_>) -> Result<Self, &'static str> {
+    fn from_simulate(simulate: Simulate<'_>) -> Result<Self, Cow<'static, str>> {
         let mods = match simulate.mods() {
             ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => Some(mods),
             ModsResult::None => None,
@@ -517,16 +535,25 @@ impl SimulateArgs {
                 let content = "Failed to parse mods. Be sure to either specify them directly \
                 or through the `+mods` / `+mods!` syntax e.g. `hdhr` or `+hdhr!`";
 
-                return Err(content);
+                return Err(mods_error(content, simulate.mods.as_deref()));
             }
             ModsResult::Mods(ModSelection::Exclude { .. }) => {
                 let content = "Excluding mods does not work for this command";
 
-                return Err(content);
+                return Err(content.into());
             }
         };
 
-        let mode = simulate.mode.map(GameMode::from);
+        if let Some(content) = mods.as_ref().and_then(pp_undefined_content) {
+            return Err(content.into());
+        }
+
+        let mode = match simulate.map {
+            Some(ref map) if simulate.file.is_none() => {
+                MapIdType::resolve_mode(map, simulate.mode.map(GameMode::from))
+            }
+            Some(_) | None => simulate.mode.map(GameMode::from),
+        };
 
         let map = match simulate.file {
             Some(attachment) => Some(SimulateMapArg::Attachment(Box::new(attachment))),
@@ -565,6 +592,7 @@ impl SimulateArgs {
             cs: simulate.cs,
             hp: simulate.hp,
             od: simulate.od,
+            compare_mods: simulate.compare_mods.unwrap_or(false),
         })
     }
 }
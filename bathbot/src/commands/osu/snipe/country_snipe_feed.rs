@@ -0,0 +1,152 @@
+use std::cmp::Reverse;
+
+use bathbot_model::Countries;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::{Report, Result};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{CountryCode, OsuError},
+    request::UserId,
+};
+use time::OffsetDateTime;
+
+use super::{SnipeCountryFeed, SnipeGameMode, SnipeWindow};
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::SnipeCountryFeedPagination},
+    commands::osu::user_not_found,
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+};
+
+pub(super) async fn country_feed(
+    orig: CommandOrigin<'_>,
+    args: SnipeCountryFeed<'_>,
+) -> Result<()> {
+    let Some(window) = SnipeWindow::parse_opt(args.since.as_deref()) else {
+        return orig.error(SnipeWindow::ERR_PARSE).await;
+    };
+
+    let owner = orig.user_id()?;
+    let now = OffsetDateTime::now_utc();
+    let since = window.since(now);
+
+    if since >= now {
+        return orig.error("The date must be in the past").await;
+    }
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get user config"));
+        }
+    };
+
+    let guild_mode = Context::guild_config().mode(orig.guild_id()).await;
+
+    // Taiko is not supported so configured modes are ignored in that case
+    let mode = args
+        .mode
+        .or_else(|| config.mode.and_then(SnipeGameMode::try_from_mode))
+        .or_else(|| guild_mode.and_then(SnipeGameMode::try_from_mode))
+        .map_or(GameMode::Osu, GameMode::from);
+
+    let country_code = match args.country {
+        Some(ref country) => match Countries::name(country).to_code() {
+            Some(code) => CountryCode::from(code),
+            None if country.len() == 2 => CountryCode::from(country.as_ref()),
+            None => {
+                let content =
+                    format!("Looks like `{country}` is neither a country name nor a country code");
+
+                return orig.error(content).await;
+            }
+        },
+        None => match config.osu {
+            Some(user_id) => {
+                let user_args = UserArgs::user_id(user_id, mode);
+
+                let user = match Context::redis().osu_user(user_args).await {
+                    Ok(user) => user,
+                    Err(UserArgsError::Osu(OsuError::NotFound)) => {
+                        let content = user_not_found(UserId::Id(user_id)).await;
+
+                        return orig.error(content).await;
+                    }
+                    Err(err) => {
+                        let _ = orig.error(GENERAL_ISSUE).await;
+                        let err = Report::new(err).wrap_err("Failed to get user");
+
+                        return Err(err);
+                    }
+                };
+
+                user.country_code.as_str().into()
+            }
+            None => {
+                let content = "Since you're not linked, you must specify a country (code)";
+
+                return orig.error(content).await;
+            }
+        },
+    };
+
+    // Check if huisemetbenen supports the country
+    if !Context::huismetbenen()
+        .is_supported(country_code.as_str(), mode)
+        .await
+    {
+        let content = format!("The country code `{country_code}` is not supported :(",);
+
+        return orig.error(content).await;
+    }
+
+    let scores_fut = Context::client().get_country_snipes(&country_code, since, mode);
+
+    let mut scores = match scores_fut.await {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get country snipes"));
+        }
+    };
+
+    let country = Countries::code(&country_code)
+        .to_name()
+        .map_or_else(|| country_code.to_string(), |name| name.to_string());
+
+    if scores.is_empty() {
+        let content = format!(
+            "No national {mode} #1s were claimed in {country} {window}.",
+            mode = match mode {
+                GameMode::Osu => "osu!standard",
+                GameMode::Taiko => "osu!taiko",
+                GameMode::Catch => "osu!catch",
+                GameMode::Mania => "osu!mania",
+            }
+        );
+
+        let builder = MessageBuilder::new().embed(content);
+        orig.create_message(builder).await?;
+
+        return Ok(());
+    }
+
+    scores.sort_unstable_by_key(|s| Reverse(s.date));
+
+    let pagination = SnipeCountryFeedPagination::builder()
+        .country(country)
+        .country_code(country_code)
+        .window(window)
+        .scores(scores.into_boxed_slice())
+        .msg_owner(owner)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
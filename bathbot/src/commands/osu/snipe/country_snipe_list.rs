@@ -132,7 +132,10 @@ pub(super) async fn country_list(
         Ok(config) => {
             let mode = match mode {
                 Some(mode) => mode.into(),
-                None => config.mode.unwrap_or(GameMode::Osu),
+                None => config
+                    .mode
+                    .or(Context::guild_config().mode(orig.guild_id()).await)
+                    .unwrap_or(GameMode::Osu),
             };
 
             match config.osu {
@@ -118,6 +118,7 @@ pub(super) async fn country_stats(
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let country_code = match args.country {
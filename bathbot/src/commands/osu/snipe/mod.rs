@@ -8,11 +8,12 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::id::{Id, marker::UserMarker};
 
 pub use self::{
-    country_snipe_list::*, country_snipe_stats::*, player_snipe_list::*, player_snipe_stats::*,
-    sniped::*, sniped_difference::*,
+    country_snipe_feed::*, country_snipe_list::*, country_snipe_stats::*, player_snipe_list::*,
+    player_snipe_stats::*, sniped::*, sniped_difference::*,
 };
 use crate::util::{InteractionCommandExt, interaction::InteractionCommand};
 
+mod country_snipe_feed;
 mod country_snipe_list;
 mod country_snipe_stats;
 mod player_snipe_list;
@@ -44,6 +45,8 @@ pub enum SnipeCountry<'a> {
     List(SnipeCountryList<'a>),
     #[command(name = "stats")]
     Stats(SnipeCountryStats<'a>),
+    #[command(name = "feed")]
+    Feed(SnipeCountryFeed<'a>),
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption, Default)]
@@ -108,6 +111,29 @@ pub struct SnipeCountryStats<'a> {
     country: Option<Cow<'a, str>>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "feed",
+    desc = "Recently claimed national #1s in a country",
+    help = "List all national #1 scores that were claimed in a country within the last week \
+    or within the time window specified by the `since` option.\n\
+    The newest #1s are shown first."
+)]
+pub struct SnipeCountryFeed<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<SnipeGameMode>,
+    #[command(desc = "Specify a country (code)")]
+    country: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a time window e.g. 30d, 4w, or 2024-01-01",
+        help = "Specify the time window in which snipes should be considered.\n\
+        Can be either an amount of days or weeks e.g. `30d` or `4w`, \
+        or a date of the form `yyyy-mm-dd` e.g. `2024-01-01`.\n\
+        Defaults to the last week."
+    )]
+    since: Option<String>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "player", desc = "Player related snipe stats")]
 pub enum SnipePlayer<'a> {
@@ -127,7 +153,8 @@ pub enum SnipePlayer<'a> {
 #[command(
     name = "gain",
     desc = "Display a user's recent national #1 scores",
-    help = "Display all national #1 scores that a user acquired within the last week"
+    help = "Display all national #1 scores that a user acquired within the last week \
+    or within the time window specified by the `since` option"
 )]
 pub struct SnipePlayerGain<'a> {
     #[command(desc = "Specify a gamemode")]
@@ -141,6 +168,14 @@ pub struct SnipePlayerGain<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Specify a time window e.g. 30d, 4w, or 2024-01-01",
+        help = "Specify the time window in which snipes should be considered.\n\
+        Can be either an amount of days or weeks e.g. `30d` or `4w`, \
+        or a date of the form `yyyy-mm-dd` e.g. `2024-01-01`.\n\
+        Defaults to the last week."
+    )]
+    since: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -173,7 +208,8 @@ pub struct SnipePlayerList<'a> {
 #[command(
     name = "loss",
     desc = "Display a user's recently lost national #1 scores",
-    help = "Display all national #1 scores that a user lost within the last week"
+    help = "Display all national #1 scores that a user lost within the last week \
+    or within the time window specified by the `since` option"
 )]
 pub struct SnipePlayerLoss<'a> {
     #[command(desc = "Specify a gamemode")]
@@ -187,6 +223,14 @@ pub struct SnipePlayerLoss<'a> {
         Only works on users who have used the `/link` command."
     )]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Specify a time window e.g. 30d, 4w, or 2024-01-01",
+        help = "Specify the time window in which snipes should be considered.\n\
+        Can be either an amount of days or weeks e.g. `30d` or `4w`, \
+        or a date of the form `yyyy-mm-dd` e.g. `2024-01-01`.\n\
+        Defaults to the last week."
+    )]
+    since: Option<String>,
 }
 
 #[derive(CommandModel, CreateCommand, Default, HasName)]
@@ -231,6 +275,7 @@ async fn slash_snipe(mut command: InteractionCommand) -> Result<()> {
         Snipe::Country(SnipeCountry::Stats(args)) => {
             country_stats((&mut command).into(), args).await
         }
+        Snipe::Country(SnipeCountry::Feed(args)) => country_feed((&mut command).into(), args).await,
         Snipe::Player(SnipePlayer::Gain(args)) => player_gain((&mut command).into(), args).await,
         Snipe::Player(SnipePlayer::List(args)) => player_list((&mut command).into(), args).await,
         Snipe::Player(SnipePlayer::Loss(args)) => player_loss((&mut command).into(), args).await,
@@ -107,6 +107,7 @@ pub(super) async fn player_stats(
         .mode
         .map(GameMode::from)
         .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
         .unwrap_or(GameMode::Osu);
 
     let legacy_scores = match config.score_data {
@@ -204,7 +205,7 @@ pub(super) async fn player_stats(
         match tokio::join!(score_fut, map_fut) {
             (Ok(score), Ok(map)) => Some((score.score, map)),
             (Err(err), _) => {
-                let _ = orig.error(OSU_API_ISSUE).await;
+                let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
                 return Err(Report::new(err).wrap_err("Failed to get oldest score"));
             }
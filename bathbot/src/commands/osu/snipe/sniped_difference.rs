@@ -1,10 +1,18 @@
-use std::{cmp::Reverse, collections::HashMap};
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 use bathbot_macros::command;
-use bathbot_util::{IntHasher, MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{
+    IntHasher, MessageBuilder, constants::GENERAL_ISSUE, datetime::DATE_FORMAT, matcher,
+};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
-use time::{Duration, OffsetDateTime};
+use time::{Date, Duration, OffsetDateTime};
+use twilight_model::id::{Id, marker::UserMarker};
 
 use super::{SnipeGameMode, SnipePlayerGain, SnipePlayerLoss};
 use crate::{
@@ -12,34 +20,44 @@ use crate::{
     active::{ActiveMessages, impls::SnipeDifferencePagination},
     core::commands::{CommandOrigin, prefix::Args},
     manager::redis::osu::{UserArgs, UserArgsError},
+    util::ChannelExt,
 };
 
 #[command]
 #[desc("Display a user's recently acquired national #1 scores")]
 #[help(
     "Display a user's national #1 scores that they acquired within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!standard originates from [Mr Helix](https://osu.ppy.sh/users/2330619)'s \
     [huismetbenen](https://snipe.huismetbenen.nl/)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases("sg", "snipegain", "snipesgain")]
 #[group(Osu)]
 async fn prefix_snipedgain(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerGain::args(args, None);
+    match SnipePlayerGain::args(args, None) {
+        Ok(args) => player_gain(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_gain(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
 #[desc("Display a user's recently acquired national #1 ctb scores")]
 #[help(
     "Display a user's national #1 ctb scores that they acquired within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!catch originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases(
     "sgc",
     "snipedgaincatch",
@@ -50,37 +68,51 @@ async fn prefix_snipedgain(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Catch)]
 async fn prefix_snipedgainctb(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerGain::args(args, Some(GameMode::Catch));
+    match SnipePlayerGain::args(args, Some(GameMode::Catch)) {
+        Ok(args) => player_gain(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_gain(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
 #[desc("Display a user's recently acquired national #1 mania scores")]
 #[help(
     "Display a user's national #1 mania scores that they acquired within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!mania originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases("sgm", "snipegainmania", "snipesgainmania")]
 #[group(Mania)]
 async fn prefix_snipedgainmania(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerGain::args(args, Some(GameMode::Mania));
+    match SnipePlayerGain::args(args, Some(GameMode::Mania)) {
+        Ok(args) => player_gain(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_gain(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
 #[desc("Display a user's recently lost national #1 scores")]
 #[help(
     "Display a user's national #1 scores that they lost within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!standard originates from [Mr Helix](https://osu.ppy.sh/users/2330619)'s \
     [huismetbenen](https://snipe.huismetbenen.nl/)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases(
     "sl",
     "snipeloss",
@@ -91,20 +123,27 @@ async fn prefix_snipedgainmania(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Osu)]
 async fn prefix_snipedloss(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerLoss::args(args, None);
+    match SnipePlayerLoss::args(args, None) {
+        Ok(args) => player_loss(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_loss(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
 #[desc("Display a user's recently lost national #1 ctb scores")]
 #[help(
     "Display a user's national #1 ctb scores that they lost within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!catch originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases(
     "slc",
     "snipelossctb",
@@ -120,20 +159,27 @@ async fn prefix_snipedloss(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Catch)]
 async fn prefix_snipedlossctb(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerLoss::args(args, Some(GameMode::Catch));
+    match SnipePlayerLoss::args(args, Some(GameMode::Catch)) {
+        Ok(args) => player_loss(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_loss(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 #[command]
 #[desc("Display a user's recently lost national #1 mania scores")]
 #[help(
     "Display a user's national #1 mania scores that they lost within the last week.\n\
+    To check a different time window, specify `since=` with an amount of days or weeks \
+    e.g. `since=30d` or `since=4w`, or with a date e.g. `since=2024-01-01`.\n\
     Data for osu!mania originates from [molneya](https://osu.ppy.sh/users/8945180)'s \
     [kittenroleplay](https://snipes.kittenroleplay.com)."
 )]
-#[usage("[username]")]
-#[example("badewanne3")]
+#[usage("[username] [since=7d/4w/yyyy-mm-dd]")]
+#[examples("badewanne3", "badewanne3 since=30d")]
 #[aliases(
     "slm",
     "snipelossmania",
@@ -144,30 +190,50 @@ async fn prefix_snipedlossctb(msg: &Message, args: Args<'_>) -> Result<()> {
 )]
 #[group(Mania)]
 async fn prefix_snipedlossmania(msg: &Message, args: Args<'_>) -> Result<()> {
-    let args = SnipePlayerLoss::args(args, Some(GameMode::Mania));
+    match SnipePlayerLoss::args(args, Some(GameMode::Mania)) {
+        Ok(args) => player_loss(msg.into(), args).await,
+        Err(content) => {
+            msg.error(content).await?;
 
-    player_loss(msg.into(), args).await
+            Ok(())
+        }
+    }
 }
 
 pub(super) async fn player_gain(orig: CommandOrigin<'_>, args: SnipePlayerGain<'_>) -> Result<()> {
+    let Some(window) = SnipeWindow::parse_opt(args.since.as_deref()) else {
+        return orig.error(SnipeWindow::ERR_PARSE).await;
+    };
+
     let (user_id, mode) = user_id_mode!(orig, args);
 
-    sniped_diff(orig, Difference::Gain, user_id, mode).await
+    sniped_diff(orig, Difference::Gain, window, user_id, mode).await
 }
 
 pub(super) async fn player_loss(orig: CommandOrigin<'_>, args: SnipePlayerLoss<'_>) -> Result<()> {
+    let Some(window) = SnipeWindow::parse_opt(args.since.as_deref()) else {
+        return orig.error(SnipeWindow::ERR_PARSE).await;
+    };
+
     let (user_id, mode) = user_id_mode!(orig, args);
 
-    sniped_diff(orig, Difference::Loss, user_id, mode).await
+    sniped_diff(orig, Difference::Loss, window, user_id, mode).await
 }
 
 async fn sniped_diff(
     orig: CommandOrigin<'_>,
     diff: Difference,
+    window: SnipeWindow,
     user_id: UserId,
     mode: GameMode,
 ) -> Result<()> {
     let owner = orig.user_id()?;
+    let now = OffsetDateTime::now_utc();
+    let since = window.since(now);
+
+    if since >= now {
+        return orig.error("The date must be in the past").await;
+    }
 
     // Request the user
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
@@ -204,13 +270,11 @@ async fn sniped_diff(
     }
 
     let client = Context::client();
-    let now = OffsetDateTime::now_utc();
-    let week_ago = now - Duration::weeks(1);
 
     // Request the scores
     let scores_fut = match diff {
-        Difference::Gain => client.get_national_snipes(user_id, true, week_ago, mode),
-        Difference::Loss => client.get_national_snipes(user_id, false, week_ago, mode),
+        Difference::Gain => client.get_national_snipes(user_id, true, since, mode),
+        Difference::Loss => client.get_national_snipes(user_id, false, since, mode),
     };
 
     let mut scores = match scores_fut.await {
@@ -224,7 +288,7 @@ async fn sniped_diff(
 
     if scores.is_empty() {
         let content = format!(
-            "`{username}` didn't {diff} national {mode} #1s in the last week.",
+            "`{username}` didn't {diff} national {mode} #1s {window}.",
             diff = match diff {
                 Difference::Gain => "gain any new",
                 Difference::Loss => "lose any",
@@ -248,6 +312,7 @@ async fn sniped_diff(
     let pagination = SnipeDifferencePagination::builder()
         .user(user)
         .diff(diff)
+        .window(window)
         .scores(scores.into_boxed_slice())
         .star_map(HashMap::with_hasher(IntHasher))
         .msg_owner(owner)
@@ -265,42 +330,152 @@ pub enum Difference {
     Loss,
 }
 
-impl<'m> SnipePlayerGain<'m> {
-    fn args(mut args: Args<'m>, mode: Option<GameMode>) -> Self {
-        let mut name = None;
-        let mut discord = None;
-
-        if let Some(arg) = args.next() {
-            match matcher::get_mention_user(arg) {
-                Some(id) => discord = Some(id),
-                None => name = Some(arg.into()),
-            }
+/// Time window in which snipes should be considered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnipeWindow {
+    Days(u32),
+    Weeks(u32),
+    Date(Date),
+}
+
+impl SnipeWindow {
+    pub(super) const ERR_PARSE: &'static str = "Failed to parse `since`.\n\
+        Must be either an amount of days or weeks e.g. `7d` or `4w`, \
+        or a date of the form `yyyy-mm-dd` e.g. `2024-01-01`.";
+
+    /// Parse `7d`, `4w`, or `2024-01-01`.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+
+        if let Ok(date) = Date::parse(s, &DATE_FORMAT) {
+            return Some(Self::Date(date));
+        }
+
+        let (amount, unit) = s.split_at_checked(s.len().checked_sub(1)?)?;
+        let amount = amount
+            .trim_end()
+            .parse::<u32>()
+            .ok()
+            .filter(|&amount| amount > 0)?;
+
+        match unit {
+            "d" | "D" => Some(Self::Days(amount)),
+            "w" | "W" => Some(Self::Weeks(amount)),
+            _ => None,
+        }
+    }
+
+    /// Defaults to one week if no value is given and returns `None` if the
+    /// value could not be parsed.
+    pub(super) fn parse_opt(s: Option<&str>) -> Option<Self> {
+        s.map_or(Some(Self::default()), Self::parse)
+    }
+
+    pub(super) fn since(self, now: OffsetDateTime) -> OffsetDateTime {
+        match self {
+            Self::Days(days) => now.saturating_sub(Duration::days(i64::from(days))),
+            Self::Weeks(weeks) => now.saturating_sub(Duration::weeks(i64::from(weeks))),
+            Self::Date(date) => date.midnight().assume_utc(),
+        }
+    }
+}
+
+impl Default for SnipeWindow {
+    fn default() -> Self {
+        Self::Weeks(1)
+    }
+}
+
+impl Display for SnipeWindow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Days(1) => f.write_str("in the last day"),
+            Self::Days(days) => write!(f, "in the last {days} days"),
+            Self::Weeks(1) => f.write_str("in the last week"),
+            Self::Weeks(weeks) => write!(f, "in the last {weeks} weeks"),
+            Self::Date(date) => write!(f, "since {date}"),
         }
+    }
+}
 
-        Self {
+impl<'m> SnipePlayerGain<'m> {
+    fn args(args: Args<'m>, mode: Option<GameMode>) -> Result<Self, &'static str> {
+        let (name, discord, since) = parse_diff_args(args)?;
+
+        Ok(Self {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
-        }
+            since,
+        })
     }
 }
 
 impl<'m> SnipePlayerLoss<'m> {
-    fn args(mut args: Args<'m>, mode: Option<GameMode>) -> Self {
-        let mut name = None;
-        let mut discord = None;
-
-        if let Some(arg) = args.next() {
-            match matcher::get_mention_user(arg) {
-                Some(id) => discord = Some(id),
-                None => name = Some(arg.into()),
-            }
-        }
+    fn args(args: Args<'m>, mode: Option<GameMode>) -> Result<Self, &'static str> {
+        let (name, discord, since) = parse_diff_args(args)?;
 
-        Self {
+        Ok(Self {
             mode: mode.and_then(SnipeGameMode::try_from_mode),
             name,
             discord,
+            since,
+        })
+    }
+}
+
+type DiffArgs<'m> = (Option<Cow<'m, str>>, Option<Id<UserMarker>>, Option<String>);
+
+fn parse_diff_args(args: Args<'_>) -> Result<DiffArgs<'_>, &'static str> {
+    let mut name = None;
+    let mut discord = None;
+    let mut since = None;
+
+    for arg in args.take(2) {
+        if let Some(value) = arg.strip_prefix("since=") {
+            if SnipeWindow::parse(value).is_none() {
+                return Err(SnipeWindow::ERR_PARSE);
+            }
+
+            since = Some(value.to_owned());
+        } else if let Some(id) = matcher::get_mention_user(arg) {
+            discord = Some(id);
+        } else {
+            name = Some(arg.into());
         }
     }
+
+    Ok((name, discord, since))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    #[test]
+    fn parse_window() {
+        assert_eq!(SnipeWindow::parse("7d"), Some(SnipeWindow::Days(7)));
+        assert_eq!(SnipeWindow::parse("4W"), Some(SnipeWindow::Weeks(4)));
+        assert_eq!(
+            SnipeWindow::parse("2024-01-01"),
+            Some(SnipeWindow::Date(
+                Date::from_calendar_date(2024, Month::January, 1).unwrap()
+            ))
+        );
+
+        assert_eq!(SnipeWindow::parse("0d"), None);
+        assert_eq!(SnipeWindow::parse("d"), None);
+        assert_eq!(SnipeWindow::parse("7m"), None);
+        assert_eq!(SnipeWindow::parse("2024-13-01"), None);
+        assert_eq!(SnipeWindow::parse(""), None);
+    }
+
+    #[test]
+    fn default_window() {
+        assert_eq!(SnipeWindow::parse_opt(None), Some(SnipeWindow::Weeks(1)));
+        assert_eq!(SnipeWindow::parse_opt(Some("x")), None);
+        assert_eq!(SnipeWindow::default().to_string(), "in the last week");
+    }
 }
@@ -17,7 +17,7 @@ use twilight_model::id::{Id, marker::UserMarker};
 use crate::{
     Context,
     active::{ActiveMessages, impls::TopIfPagination},
-    commands::osu::{require_link, user_not_found},
+    commands::osu::{pp_undefined_content, require_link, user_not_found},
     core::commands::{CommandOrigin, prefix::Args},
     manager::{
         OsuMap,
@@ -65,6 +65,15 @@ pub struct TopIf<'a> {
         help = "Choose how the scores should be ordered, defaults to `pp`."
     )]
     sort: Option<TopIfScoreOrder>,
+    #[command(
+        min_value = 1,
+        max_value = 100,
+        desc = "Only apply the mods to the top score at this position",
+        help = "Instead of adjusting all top scores, only adjust the score at this position.\n\
+        Accuracy and combo of the score are kept as they are so the resulting pp delta \
+        shows what the score would have been worth with the given mods."
+    )]
+    index: Option<usize>,
     #[command(
         desc = "Specify a linked discord user",
         help = "Instead of specifying an osu! username with the `name` option, \
@@ -122,6 +131,7 @@ impl<'m> TopIf<'m> {
             name,
             query: None,
             sort: None,
+            index: None,
             discord,
         })
     }
@@ -205,6 +215,17 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         None => return orig.error(TopIf::ERR_PARSE_MODS).await,
     };
 
+    let pp_undefined = match mods {
+        ModSelection::Include(ref selected) | ModSelection::Exact(ref selected) => {
+            pp_undefined_content(selected)
+        }
+        ModSelection::Exclude { .. } => None,
+    };
+
+    if let Some(content) = pp_undefined {
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
@@ -216,7 +237,12 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         },
     };
 
-    let mode = match args.mode.map(GameMode::from).or(config.mode) {
+    let mode = match args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+    {
         None | Some(GameMode::Mania) => GameMode::Osu,
         Some(mode) => mode,
     };
@@ -271,8 +297,20 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         .pp
         .to_native()
         - actual_pp;
+
+    if let Some(idx) = args.index.filter(|&idx| idx > scores.len()) {
+        let content = format!(
+            "`{name}` only has {len} top score{plural}, can't pick #{idx}",
+            name = user.username.as_str(),
+            len = scores.len(),
+            plural = if scores.len() == 1 { "" } else { "s" },
+        );
+
+        return orig.error(content).await;
+    }
+
     let sort = args.sort.unwrap_or_default();
-    let content = get_content(
+    let mut content = get_content(
         user.username.as_str(),
         mode,
         &mods,
@@ -280,7 +318,9 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         sort,
     );
 
-    let mut entries = match process_scores(scores, mods, mode, sort, legacy_scores).await {
+    let process_fut = process_scores(scores, mods, mode, sort, legacy_scores, args.index);
+
+    let mut entries = match process_fut.await {
         Ok(scores) => scores,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -294,6 +334,21 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
         sum + entry.score.pp * 0.95_f32.powi(i)
     });
 
+    // Only show the adjusted score but keep all scores for the total pp
+    if let Some(idx) = args.index {
+        entries.retain(|entry| entry.original_idx == idx);
+
+        if let Some(entry) = entries.first() {
+            let _ = write!(
+                content,
+                "\nPP delta of score #{idx}: **{delta:+.2}pp**\n\
+                :warning: Accuracy and combo are kept as is; some mods (e.g. `EZ` or `HR`) \
+                change which accuracy is realistically achievable.",
+                delta = entry.pp_diff(),
+            );
+        }
+    }
+
     // Process query afterwards so that total pp is calculated with *all* scores
     if let Some(query) = args.query.as_deref() {
         let criteria = TopCriteria::create(query);
@@ -457,6 +512,7 @@ async fn process_scores(
     mode: GameMode,
     sort: TopIfScoreOrder,
     legacy_scores: bool,
+    index: Option<usize>,
 ) -> Result<Vec<TopIfEntry>> {
     let mut entries = Vec::with_capacity(scores.len());
 
@@ -503,6 +559,8 @@ async fn process_scores(
         map = map.convert(score.mode);
 
         let changed = match &arg_mods {
+            // Only the score at the given index is adjusted
+            _ if index.is_some_and(|idx| idx != i) => false,
             ModSelection::Include(mods) if mods.is_empty() => {
                 let changed = !score.mods.is_empty();
                 score.mods = GameMods::new();
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use bathbot_macros::{HasMods, HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::Result;
+use rosu_v2::{prelude::GameMode, request::UserId};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{TopArgs, TopArgsError, TopScoreOrder, process_scores, user_and_bundle};
+use crate::{
+    Context,
+    active::{
+        ActiveMessages,
+        impls::{TopMapperEntry, TopMapperPagination},
+    },
+    commands::{
+        osu::{HasMods, ModsResult, require_link},
+        utility::ScoreEmbedDataWrap,
+    },
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// How many mappers are shown
+const MAPPER_COUNT: usize = 15;
+
+#[derive(CommandModel, CreateCommand, HasMods, HasName, SlashCommand)]
+#[command(
+    name = "topmapper",
+    desc = "Display which mappers dominate a user's top100",
+    help = "Group the user's top100 by mapset creator and show the mappers \
+    with the most scores alongside their summed weighted pp.\n\
+    The scores of a specific mapper can be inspected through the menu."
+)]
+#[flags(PERSONAL)]
+pub struct TopMapper {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<String>,
+    #[command(
+        desc = "Filter mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
+        help = "Filter out all scores that don't match the specified mods.\n\
+        Mods must be given as `+mods` for included mods, `+mods!` for exact mods, \
+        or `-mods!` for excluded mods."
+    )]
+    mods: Option<String>,
+    #[command(
+        desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
+        help = "Filter out scores similarly as you filter maps in osu! itself.\n\
+        You can specify the artist, difficulty, title, or limit values such as \
+        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 acc>99 acc<=99.5`."
+    )]
+    query: Option<String>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_topmapper(mut command: InteractionCommand) -> Result<()> {
+    let args = TopMapper::from_interaction(command.input_data())?;
+
+    top_mapper((&mut command).into(), args).await
+}
+
+async fn top_mapper(orig: CommandOrigin<'_>, args: TopMapper) -> Result<()> {
+    let mods = match args.mods() {
+        ModsResult::Mods(mods) => Some(mods),
+        ModsResult::None => None,
+        ModsResult::Invalid => return TopArgsError::Mods(args.mods.clone()).reply(&orig).await,
+    };
+
+    let msg_owner = orig.user_id()?;
+
+    let mut config = match Context::user_config().with_osu_id(msg_owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => config
+            .mode
+            .or(Context::guild_config().mode(orig.guild_id()).await)
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu.take() {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+        }
+        None => None,
+    };
+
+    let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
+    let legacy_scores = score_data.is_legacy();
+
+    let Some((user, bundle)) = user_and_bundle(&orig, user_id, mode, legacy_scores).await? else {
+        return Ok(());
+    };
+
+    let top_args = TopArgs {
+        name: None,
+        discord: None,
+        mode: Some(mode),
+        mods,
+        equivalent_mods: false,
+        min_acc: None,
+        max_acc: None,
+        min_combo: None,
+        max_combo: None,
+        grade: None,
+        statuses: None,
+        sort_by: TopScoreOrder::Pp,
+        reverse: false,
+        perfect_combo: None,
+        converts: None,
+        index: None,
+        query: args.query,
+        size: None,
+        list: false,
+        has_dash_r: false,
+        has_dash_p_or_i: false,
+    };
+
+    let entries = process_scores(&bundle, &top_args, false, score_data);
+    let total = entries.len();
+    let mappers = group_by_mapper(entries);
+
+    let username = user.username.as_str();
+
+    if mappers.is_empty() {
+        let content = format!("No top scores found for `{username}` with the specified properties");
+
+        return orig.error(content).await;
+    }
+
+    let genitive = if username.ends_with('s') { "" } else { "s" };
+
+    let content = if top_args.mods.is_some() || top_args.query.is_some() {
+        format!(
+            "Mappers of {total} score{plural} in `{username}`'{genitive} top100 \
+            with the specified properties:",
+            plural = if total == 1 { "" } else { "s" },
+        )
+    } else {
+        format!("Top mappers in `{username}`'{genitive} top100:")
+    };
+
+    let pagination = TopMapperPagination::new(
+        user,
+        mappers,
+        score_data,
+        content.into_boxed_str(),
+        msg_owner,
+    );
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
+
+/// Group the entries by mapset creator and keep the mappers with the most
+/// scores.
+fn group_by_mapper(entries: Vec<ScoreEmbedDataWrap>) -> Box<[TopMapperEntry]> {
+    let mut mappers: HashMap<Box<str>, (f32, Vec<ScoreEmbedDataWrap>)> = HashMap::new();
+
+    for entry in entries {
+        let half = entry.get_half();
+
+        // Weighted by the position within the unfiltered top100
+        let weighted_pp = half
+            .pb_idx
+            .as_ref()
+            .and_then(|pb_idx| pb_idx.idx)
+            .map_or(0.0, |idx| half.score.pp * 0.95_f32.powi(idx as i32));
+
+        let (pp, scores) = mappers.entry(Box::from(half.map.creator())).or_default();
+
+        *pp += weighted_pp;
+        scores.push(entry);
+    }
+
+    let mut mappers: Vec<_> = mappers
+        .into_iter()
+        .map(|(name, (weighted_pp, scores))| TopMapperEntry {
+            name,
+            weighted_pp,
+            scores: scores.into_boxed_slice(),
+        })
+        .collect();
+
+    mappers.sort_unstable_by(|a, b| {
+        b.scores
+            .len()
+            .cmp(&a.scores.len())
+            .then_with(|| b.weighted_pp.total_cmp(&a.weighted_pp))
+    });
+
+    mappers.truncate(MAPPER_COUNT);
+
+    mappers.into_boxed_slice()
+}
@@ -1,18 +1,16 @@
-use std::{borrow::Cow, cmp::Reverse, fmt::Write, mem};
+use std::{borrow::Cow, cmp::Ordering, fmt::Write, mem, ops::RangeInclusive, sync::Arc};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::{
     command_fields::{GameModeOption, GradeOption},
     embed_builder::SettingsImage,
 };
-use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{
-    CowUtils, constants::GENERAL_ISSUE, matcher, numbers::round, osu::ModSelection,
-};
+use bathbot_psql::model::configs::{GuildConfig, Lang, ListSize, ScoreData};
+use bathbot_util::{CowUtils, IntermodeFormatter, matcher, numbers::round, osu::ModSelection};
 use eyre::{Report, Result};
 use rand::{Rng, thread_rng};
 use rosu_v2::{
-    prelude::{GameMode, Grade, OsuError, Score},
+    prelude::{GameMode, Grade, OsuError, RankStatus, Score},
     request::UserId,
 };
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -21,7 +19,7 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-pub use self::{if_::*, old::*};
+pub use self::{if_::*, mapper::*, old::*};
 use super::{HasMods, ModsResult, ScoreOrder, map_strain_graph, require_link, user_not_found};
 use crate::{
     Context,
@@ -32,20 +30,30 @@ use crate::{
     commands::utility::{
         MissAnalyzerCheck, ScoreEmbedDataHalf, ScoreEmbedDataPersonalBest, ScoreEmbedDataWrap,
     },
-    core::commands::{CommandOrigin, prefix::Args},
-    manager::redis::osu::{UserArgs, UserArgsError},
+    core::{
+        TopScoresBundle,
+        commands::{
+            CommandOrigin,
+            prefix::{Args, ArgsNum},
+        },
+    },
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
     util::{
         ChannelExt, CheckPermissions, InteractionCommandExt,
         interaction::InteractionCommand,
+        locale::{self, Msg},
+        osu::is_convert,
         query::{IFilterCriteria, Searchable, TopCriteria},
     },
 };
 
 mod if_;
+mod mapper;
 mod old;
 
 #[derive(CommandModel, CreateCommand, HasMods, SlashCommand)]
 #[command(name = "top", desc = "Display the user's current top100")]
+#[flags(PERSONAL)]
 pub struct Top {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
@@ -68,7 +76,20 @@ pub struct Top {
         - `-nm!`: Scores can not be nomod so there must be any other mod"
     )]
     mods: Option<String>,
-    #[command(desc = "Choose a specific score index or `random`")]
+    #[command(
+        desc = "Treat DT/NC, HT/DC, and SD/PF as the same mod for exact mods",
+        help = "When filtering for exact mods e.g. `+hddt!`, \
+        scores with `NC` instead of `DT` are usually filtered out.\n\
+        With this option enabled, `DT` and `NC`, `HT` and `DC`, as well as `SD` and `PF` \
+        are considered equivalent so `+hddt!` also matches `HDNC` scores."
+    )]
+    equivalent_mods: Option<bool>,
+    #[command(
+        desc = "Choose a specific score index, a range like `20..30`, or `random`",
+        help = "Choose a specific score index, a range of indices, or `random`.\n\
+        A single index shows that score in a single embed while a range e.g. `20..30` \
+        shows only the scores within that range in a list."
+    )]
     index: Option<String>,
     #[command(
         desc = "Specify a linked discord user",
@@ -83,14 +104,29 @@ pub struct Top {
         desc = "Specify a search query containing artist, difficulty, AR, BPM, ...",
         help = "Filter out scores similarly as you filter maps in osu! itself.\n\
         You can specify the artist, creator, difficulty, title, or limit values such as \
-        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date or ranked_date \
-        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`."
+        ar, cs, hp, od, bpm, length, stars, pp, acc, score, misses, date, ranked_date or year \
+        e.g. `ar>10 od>=9 ranked<2017-01-01 creator=monstrata acc>99 acc<=99.5`.\n\
+        Prefix a key with `-` to negate it e.g. `-creator=sotarks` and wrap values \
+        with spaces in quotes e.g. `title=\"blue zenith\"`."
     )]
     query: Option<String>,
     #[command(desc = "Consider only scores with this grade")]
     grade: Option<GradeOption>,
+    #[command(
+        desc = "Only keep scores on maps with these statuses e.g. `loved` or `ranked,approved`",
+        help = "Only keep scores on maps with the given ranked statuses.\n\
+        Available statuses are `ranked`, `loved`, `qualified`, and `approved`. \
+        Multiple statuses can be separated by commas e.g. `ranked,loved`."
+    )]
+    status: Option<String>,
     #[command(desc = "Filter out all scores that don't have a perfect combo")]
     perfect_combo: Option<bool>,
+    #[command(
+        desc = "Specify whether scores on converted maps should be included",
+        help = "Specify whether scores on maps converted from osu!standard should be included.\n\
+        Only relevant for taiko, catch, and mania. Defaults to `true`."
+    )]
+    converts: Option<bool>,
     #[command(
         desc = "Size of the embed",
         help = "Size of the embed.\n\
@@ -98,6 +134,13 @@ pub struct Top {
         The default can be set with the `/config` command."
     )]
     size: Option<ListSize>,
+    #[command(
+        desc = "Show the list on the page of the chosen index instead of a single score",
+        help = "When an `index` is specified, the score is usually shown in a single embed.\n\
+        With this option enabled, the list is shown instead, starting on the page \
+        that contains the chosen score which will be highlighted."
+    )]
+    list: Option<bool>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -166,13 +209,17 @@ impl From<ScoreOrder> for TopScoreOrder {
      - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
      - `sort`: `acc`, `combo`, `date` (= `rb` command), `length`, or `position` (default)\n\
      - `reverse`: `true` or `false` (default)\n\
+     - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+     - `index`: single integer or two integers of the form `a..b` e.g. `index=20..30`\n\
+     - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+     Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
      \n\
      Instead of showing the scores in a list, you can also __show a single score__ by \
      specifying a number right after the command, e.g. `<top2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -184,11 +231,7 @@ impl From<ScoreOrder> for TopScoreOrder {
 async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(None, args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -204,13 +247,17 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbm` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `converts`: `true` (default) or `false` to exclude maps converted from osu!standard\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topm2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -222,11 +269,7 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Mania), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -242,13 +285,17 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbt` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `converts`: `true` (default) or `false` to exclude maps converted from osu!standard\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topt2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+    [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -260,11 +307,7 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Taiko), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -280,13 +323,17 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `sort`: `acc`, `combo`, `date` (= `rbc` command), `length`, or `position` (default)\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `converts`: `true` (default) or `false` to exclude maps converted from osu!standard\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<topc2 badewanne3`."
 )]
 #[usage(
     "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] \
-   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false]"
+   [grade=SS/S/A/B/C/D] [sort=acc/combo/date/length/position] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr sort=combo",
@@ -298,11 +345,7 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Catch), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -317,12 +360,15 @@ async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rb2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -345,11 +391,7 @@ async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -364,12 +406,15 @@ async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbm2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -385,11 +430,7 @@ async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -404,12 +445,15 @@ async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbt2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -425,11 +469,7 @@ async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -444,12 +484,15 @@ async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     - `combo`: single integer or two integers of the form `a..b` e.g. `combo=500..1234`\n\
     - `grade`: `SS`, `S`, `A`, `B`, `C`, or `D`\n\
     - `reverse`: `true` or `false` (default)\n\
+    - `equivalent`: `true` or `false` (default) whether exact mods treat DT/NC, HT/DC, and SD/PF as the same\n\
+    - `query`: a search query like the one of the `/top` command, e.g. `query=\"ar>9 length<120\"`. \
+    Wrap it in double quotes if it contains spaces; quotes inside the query can be escaped as `\\\"`\n\
     \n\
     Instead of showing the scores in a list, you can also __show a single score__ by \
     specifying a number right after the command, e.g. `<rbc2 badewanne3`."
 )]
 #[usage(
-    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false]"
+    "[username] [mods] [acc=number[..number]] [combo=integer[..integer]] [grade=SS/S/A/B/C/D] [reverse=true/false] [query=\"search query\"]"
 )]
 #[examples(
     "badewanne3 acc=97.34..99.5 grade=A +hdhr",
@@ -465,11 +508,7 @@ async fn prefix_recentbestctb(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&msg.into()).await,
     }
 }
 
@@ -478,11 +517,7 @@ async fn slash_top(mut command: InteractionCommand) -> Result<()> {
 
     match TopArgs::try_from(args) {
         Ok(args) => top((&mut command).into(), args).await,
-        Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
-        }
+        Err(err) => err.reply(&(&mut command).into()).await,
     }
 }
 
@@ -492,46 +527,71 @@ pub struct TopArgs<'a> {
     pub discord: Option<Id<UserMarker>>,
     pub mode: Option<GameMode>,
     pub mods: Option<ModSelection>,
+    /// Whether exact mods should consider equivalent mods such as DT and NC
+    pub equivalent_mods: bool,
     pub min_acc: Option<f32>,
     pub max_acc: Option<f32>,
     pub min_combo: Option<u32>,
     pub max_combo: Option<u32>,
     pub grade: Option<Grade>,
+    pub statuses: Option<Vec<RankStatus>>,
     pub sort_by: TopScoreOrder,
     pub reverse: bool,
     pub perfect_combo: Option<bool>,
-    pub index: Option<String>,
+    pub converts: Option<bool>,
+    pub index: Option<TopIndex>,
     pub query: Option<String>,
     pub size: Option<ListSize>,
+    pub list: bool,
     pub has_dash_r: bool,
     pub has_dash_p_or_i: bool,
 }
 
 impl<'m> TopArgs<'m> {
-    const ERR_PARSE_ACC: &'static str = "Failed to parse `accuracy`.\n\
-        Must be either decimal number \
-        or two decimal numbers of the form `a..b` e.g. `97.5..98.5`.";
-    const ERR_PARSE_COMBO: &'static str = "Failed to parse `combo`.\n\
-        Must be either a positive integer \
-        or two positive integers of the form `a..b` e.g. `501..1234`.";
-    pub const ERR_PARSE_MODS: &'static str = "Failed to parse mods.\n\
-        If you want included mods, specify it e.g. as `+hrdt`.\n\
-        If you want exact mods, specify it e.g. as `+hdhr!`.\n\
-        And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
-
-    fn args(mode: Option<GameMode>, args: Args<'m>) -> Result<Self, Cow<'static, str>> {
+    fn into_owned(self) -> TopArgs<'static> {
+        TopArgs {
+            name: self.name.map(|name| Cow::Owned(name.into_owned())),
+            discord: self.discord,
+            mode: self.mode,
+            mods: self.mods,
+            equivalent_mods: self.equivalent_mods,
+            min_acc: self.min_acc,
+            max_acc: self.max_acc,
+            min_combo: self.min_combo,
+            max_combo: self.max_combo,
+            grade: self.grade,
+            statuses: self.statuses,
+            sort_by: self.sort_by,
+            reverse: self.reverse,
+            perfect_combo: self.perfect_combo,
+            converts: self.converts,
+            index: self.index,
+            query: self.query,
+            size: self.size,
+            list: self.list,
+            has_dash_r: self.has_dash_r,
+            has_dash_p_or_i: self.has_dash_p_or_i,
+        }
+    }
+
+    fn args(mode: Option<GameMode>, args: Args<'m>) -> Result<Self, TopArgsError> {
         let mut name = None;
         let mut discord = None;
         let mut mods = None;
+        let mut equivalent_mods = None;
         let mut acc_min = None;
         let mut acc_max = None;
         let mut combo_min = None;
         let mut combo_max = None;
         let mut grade = None;
+        let mut statuses = None;
         let mut sort_by = None;
         let mut reverse = None;
         let mut has_dash_r = None;
         let mut has_dash_p_or_i = None;
+        let mut query = None;
+        let mut converts = None;
+        let mut index = None;
         let num = args.num;
 
         for arg in args.map(|arg| arg.cow_to_ascii_lowercase()) {
@@ -554,7 +614,7 @@ impl<'m> TopArgs<'m> {
                             } else if let Ok(num) = bot.parse::<f32>() {
                                 num.clamp(0.0, 100.0)
                             } else {
-                                return Err(Self::ERR_PARSE_ACC.into());
+                                return Err(TopArgsError::Msg(Msg::TopParseAcc));
                             };
 
                             let mut max = if top.is_empty() {
@@ -562,7 +622,7 @@ impl<'m> TopArgs<'m> {
                             } else if let Ok(num) = top.parse::<f32>() {
                                 num.clamp(0.0, 100.0)
                             } else {
-                                return Err(Self::ERR_PARSE_ACC.into());
+                                return Err(TopArgsError::Msg(Msg::TopParseAcc));
                             };
 
                             if min > max {
@@ -574,7 +634,7 @@ impl<'m> TopArgs<'m> {
                         }
                         None => match value.parse() {
                             Ok(num) => acc_min = Some(num),
-                            Err(_) => return Err(Self::ERR_PARSE_ACC.into()),
+                            Err(_) => return Err(TopArgsError::Msg(Msg::TopParseAcc)),
                         },
                     },
                     "combo" | "c" => match value.find("..") {
@@ -587,7 +647,7 @@ impl<'m> TopArgs<'m> {
                             } else if let Ok(num) = bot.parse() {
                                 num
                             } else {
-                                return Err(Self::ERR_PARSE_COMBO.into());
+                                return Err(TopArgsError::Msg(Msg::TopParseCombo));
                             };
 
                             let mut max = top.parse().ok();
@@ -603,12 +663,16 @@ impl<'m> TopArgs<'m> {
                         }
                         None => match value.parse() {
                             Ok(num) => combo_min = Some(num),
-                            Err(_) => return Err(Self::ERR_PARSE_COMBO.into()),
+                            Err(_) => return Err(TopArgsError::Msg(Msg::TopParseCombo)),
                         },
                     },
                     "grade" | "g" => match value.parse::<GradeOption>() {
                         Ok(grade_) => grade = Some(grade_.into()),
-                        Err(content) => return Err(content.into()),
+                        Err(_) => return Err(TopArgsError::Msg(Msg::TopParseGrade)),
+                    },
+                    "status" | "st" => match parse_statuses(value) {
+                        Some(statuses_) => statuses = Some(statuses_),
+                        None => return Err(TopArgsError::Msg(Msg::TopParseStatus)),
                     },
                     "sort" | "s" | "order" | "ordering" => match value {
                         "acc" | "a" | "accuracy" => sort_by = Some(ScoreOrder::Acc),
@@ -616,38 +680,42 @@ impl<'m> TopArgs<'m> {
                         "date" | "d" | "recent" | "r" => sort_by = Some(ScoreOrder::Date),
                         "length" | "len" | "l" => sort_by = Some(ScoreOrder::Length),
                         "pp" | "p" => sort_by = Some(ScoreOrder::Pp),
-                        _ => {
-                            let content = "Failed to parse `sort`.\n\
-                            Must be either `acc`, `combo`, `date`, `length`, or `pp`";
-
-                            return Err(content.into());
-                        }
+                        _ => return Err(TopArgsError::Msg(Msg::TopParseSort)),
                     },
                     "mods" => match matcher::get_mods(value) {
                         Some(mods_) => mods = Some(mods_),
-                        None => return Err(Self::ERR_PARSE_MODS.into()),
+                        None => return Err(TopArgsError::Mods(Some(value.to_owned()))),
+                    },
+                    "equivalent" | "equiv" => match value {
+                        "true" | "t" | "1" => equivalent_mods = Some(true),
+                        "false" | "f" | "0" => equivalent_mods = Some(false),
+                        _ => return Err(TopArgsError::Bool("equivalent")),
                     },
                     "reverse" | "r" => match value {
                         "true" | "t" | "1" => reverse = Some(true),
                         "false" | "f" | "0" => reverse = Some(false),
-                        _ => {
-                            let content =
-                                "Failed to parse `reverse`. Must be either `true` or `false`.";
-
-                            return Err(content.into());
-                        }
+                        _ => return Err(TopArgsError::Bool("reverse")),
                     },
-                    _ => {
-                        let content = format!(
-                            "Unrecognized option `{key}`.\n\
-                            Available options are: `acc`, `combo`, `sort`, `grade`, or `reverse`."
-                        );
-
-                        return Err(content.into());
-                    }
+                    "query" | "q" => match Args::unquote(value) {
+                        Some(value) => query = Some(value.into_owned()),
+                        None => return Err(TopArgsError::Msg(Msg::TopUnterminatedQuery)),
+                    },
+                    "index" | "i" => match TopIndex::parse(value) {
+                        Some(index_) => index = Some(index_),
+                        None => return Err(TopArgsError::Msg(Msg::TopParseIndex)),
+                    },
+                    "converts" | "convert" => match value {
+                        "true" | "t" | "1" => converts = Some(true),
+                        "false" | "f" | "0" => converts = Some(false),
+                        _ => return Err(TopArgsError::Bool("converts")),
+                    },
+                    _ => return Err(TopArgsError::UnknownOption(key.to_owned())),
                 }
             } else if let Some(mods_) = matcher::get_mods(arg.as_ref()) {
                 mods = Some(mods_);
+            } else if arg.starts_with('+') {
+                // Usernames can't contain `+` so it must have been invalid mods
+                return Err(TopArgsError::Mods(Some(arg.into_owned())));
             } else {
                 match matcher::get_mention_user(arg.as_ref()) {
                     Some(id) => discord = Some(id),
@@ -661,17 +729,25 @@ impl<'m> TopArgs<'m> {
             discord,
             mode,
             mods,
+            equivalent_mods: equivalent_mods.unwrap_or(false),
             min_acc: acc_min,
             max_acc: acc_max,
             min_combo: combo_min,
             max_combo: combo_max,
             grade,
+            statuses,
             sort_by: sort_by.unwrap_or_default().into(),
             reverse: reverse.unwrap_or(false),
             perfect_combo: None,
-            index: num.to_string_opt(),
-            query: None,
+            converts,
+            index: index.or(match num {
+                ArgsNum::Value(n) => Some(TopIndex::Single(n as usize)),
+                ArgsNum::Random => Some(TopIndex::Random),
+                ArgsNum::None => None,
+            }),
+            query,
             size: None,
+            list: false,
             has_dash_r: has_dash_r.unwrap_or(false),
             has_dash_p_or_i: has_dash_p_or_i.unwrap_or(false),
         };
@@ -680,14 +756,74 @@ impl<'m> TopArgs<'m> {
     }
 }
 
+/// Failed to parse [`TopArgs`].
+///
+/// The content is only created once the author's language is known.
+pub enum TopArgsError {
+    Msg(Msg),
+    /// Failed to parse the `true` / `false` value of the given option
+    Bool(&'static str),
+    UnknownOption(String),
+    /// Failed to parse the given mods
+    Mods(Option<String>),
+}
+
+impl TopArgsError {
+    pub fn content(&self, lang: Lang) -> Cow<'static, str> {
+        match self {
+            Self::Msg(msg) => msg.text(lang).into(),
+            Self::Bool(option) => Msg::TopParseBool.format(lang, &[("option", option)]).into(),
+            Self::UnknownOption(key) => Msg::TopUnknownOption.format(lang, &[("key", key)]).into(),
+            Self::Mods(mods) => {
+                let content = Msg::TopParseMods.text(lang);
+
+                match mods.as_deref().and_then(matcher::suggest_mods) {
+                    Some(suggestion) => {
+                        let suggestion =
+                            Msg::ModsSuggestion.format(lang, &[("suggestion", &suggestion)]);
+
+                        format!("{content}\n{suggestion}").into()
+                    }
+                    None => content.into(),
+                }
+            }
+        }
+    }
+
+    /// Respond with the error in the author's language.
+    pub async fn reply(&self, orig: &CommandOrigin<'_>) -> Result<()> {
+        let lang = locale::lang(orig).await;
+
+        orig.error(self.content(lang)).await
+    }
+}
+
 impl TryFrom<Top> for TopArgs<'static> {
-    type Error = &'static str;
+    type Error = TopArgsError;
 
     fn try_from(args: Top) -> Result<Self, Self::Error> {
         let mods = match args.mods() {
             ModsResult::Mods(mods) => Some(mods),
             ModsResult::None => None,
-            ModsResult::Invalid => return Err(Self::ERR_PARSE_MODS),
+            ModsResult::Invalid => {
+                return Err(TopArgsError::Mods(args.mods.clone()));
+            }
+        };
+
+        let statuses = match args.status.as_deref() {
+            Some(status) => match parse_statuses(status) {
+                Some(statuses) => Some(statuses),
+                None => return Err(TopArgsError::Msg(Msg::TopParseStatus)),
+            },
+            None => None,
+        };
+
+        let index = match args.index.as_deref() {
+            Some(index) => match TopIndex::parse(index) {
+                Some(index) => Some(index),
+                None => return Err(TopArgsError::Msg(Msg::TopParseIndex)),
+            },
+            None => None,
         };
 
         Ok(Self {
@@ -695,36 +831,106 @@ impl TryFrom<Top> for TopArgs<'static> {
             discord: args.discord,
             mode: args.mode.map(GameMode::from),
             mods,
+            equivalent_mods: args.equivalent_mods.unwrap_or(false),
             min_acc: None,
             max_acc: None,
             min_combo: None,
             max_combo: None,
             grade: args.grade.map(Grade::from),
+            statuses,
             sort_by: args.sort.unwrap_or_default(),
             reverse: args.reverse.unwrap_or(false),
             perfect_combo: args.perfect_combo,
-            index: args.index,
+            converts: args.converts,
+            index,
             query: args.query,
             size: args.size,
+            list: args.list.unwrap_or(false),
             has_dash_r: false,
             has_dash_p_or_i: false,
         })
     }
 }
 
-pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()> {
+/// Which scores of the top100 to show.
+///
+/// Indices are 1-based.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TopIndex {
+    Single(usize),
+    Range(RangeInclusive<usize>),
+    Random,
+}
+
+impl TopIndex {
+    pub fn parse(s: &str) -> Option<Self> {
+        if matches!(s, "random" | "?") {
+            return Some(Self::Random);
+        }
+
+        let Some((start, end)) = s.split_once("..") else {
+            return s.trim().parse().ok().map(Self::Single);
+        };
+
+        let start: usize = start.trim().parse().ok().filter(|&n| n > 0)?;
+        let end: usize = end.trim().parse().ok()?;
+
+        (start <= end).then_some(Self::Range(start..=end))
+    }
+}
+
+/// Keep only the entries within the given range.
+///
+/// The end of the range is clamped to the amount of entries. Returns the
+/// resulting range or `None` if the range starts after the last entry.
+fn slice_entries<T>(
+    entries: &mut Vec<T>,
+    range: &RangeInclusive<usize>,
+) -> Option<RangeInclusive<usize>> {
+    let start = *range.start();
+
+    if start > entries.len() {
+        return None;
+    }
+
+    let end = (*range.end()).min(entries.len());
+    entries.truncate(end);
+    entries.drain(..start - 1);
+
+    Some(start..=end)
+}
+
+/// Append a note about the shown range to the content.
+fn range_content(content: Option<String>, range: &RangeInclusive<usize>, total: usize) -> String {
+    let mut content = content.map_or_else(String::new, |content| content + "\n");
+
+    let _ = write!(
+        content,
+        "Showing scores {start} to {end} out of {total}",
+        start = range.start(),
+        end = range.end(),
+    );
+
+    content
+}
+
+pub(super) async fn top(mut orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()> {
     let msg_owner = orig.user_id()?;
 
     let mut config = match Context::user_config().with_osu_id(msg_owner).await {
         Ok(config) => config,
         Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+            let _ = orig.error_localized(Msg::GeneralIssue).await;
 
             return Err(err);
         }
     };
 
-    let mode = args.mode.or(config.mode).unwrap_or(GameMode::Osu);
+    let mode = args
+        .mode
+        .or(config.mode)
+        .or(Context::guild_config().mode(orig.guild_id()).await)
+        .unwrap_or(GameMode::Osu);
 
     if args.sort_by == TopScoreOrder::Pp && args.has_dash_r {
         let mode_long = mode_long(mode);
@@ -786,29 +992,13 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let score_data = config.score_data.or(guild_score_data).unwrap_or_default();
     let legacy_scores = score_data.is_legacy();
 
-    // Retrieve the user and their top scores
-    let user_args = UserArgs::rosu_id(&user_id, mode).await;
-    let scores_fut = Context::osu_scores()
-        .top(legacy_scores)
-        .limit(100)
-        .exec_with_user(user_args);
-
-    let (user, scores) = match scores_fut.await {
-        Ok((user, scores)) => (user, scores),
-        Err(UserArgsError::Osu(OsuError::NotFound)) => {
-            let content = user_not_found(user_id).await;
-
-            return orig.error(content).await;
-        }
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user or scores");
-
-            return Err(err);
-        }
+    let Some((user, bundle)) = user_and_bundle(&orig, user_id, mode, legacy_scores).await? else {
+        return Ok(());
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed_settings(orig.guild_id(), config.score_embed)
+        .await;
 
     let mut with_render = match (guild_render_button, config.render_button) {
         (None | Some(true), None) => true,
@@ -817,53 +1007,55 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     };
 
     with_render &= settings.buttons.render
-        && mode == GameMode::Osu
         && orig.has_permission_to(Permissions::SEND_MESSAGES)
         && Context::ordr_available();
 
-    let pre_len = scores.len();
+    // Regardless of mode so that it can be re-used when switching modes
+    let render_allowed = with_render;
+    with_render &= mode == GameMode::Osu;
 
-    let entries = match process_scores(scores, &args, with_render, score_data).await {
-        Ok(entries) => entries,
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-
-            return Err(err.wrap_err("failed to process scores"));
-        }
-    };
+    let pre_len = bundle.scores.len();
+    let mut entries = process_scores(&bundle, &args, with_render, score_data);
 
     let post_len = entries.len();
     let username = user.username.as_str();
 
-    let index = match args.index.as_deref() {
-        Some("random" | "?") => (post_len > 0).then(|| thread_rng().gen_range(1..=post_len)),
-        Some(n) => match n.parse::<usize>() {
-            Ok(n) if n > post_len => {
-                let mut content = format!("`{username}` only has {post_len} top scores");
+    let (index, range) = match args.index.as_ref() {
+        Some(TopIndex::Random) => {
+            let index = (post_len > 0).then(|| thread_rng().gen_range(1..=post_len));
 
-                if pre_len > post_len {
-                    let _ = write!(content, " with the specified properties");
-                }
+            (index, None)
+        }
+        Some(TopIndex::Single(n)) if *n <= post_len => (Some(*n), None),
+        Some(TopIndex::Range(range)) if *range.start() <= post_len => {
+            (None, slice_entries(&mut entries, range))
+        }
+        Some(TopIndex::Single(_) | TopIndex::Range(_)) => {
+            let lang = locale::lang_with_user(config.lang, orig.guild_id()).await;
 
-                return orig.error(content).await;
-            }
-            Ok(n) => Some(n),
-            Err(_) => {
-                let content = "Failed to parse index. \
-                Must be an integer between 1 and 100 or `random` / `?`.";
+            let msg = if pre_len > post_len {
+                Msg::TopScoresOnlyFiltered
+            } else {
+                Msg::TopScoresOnly
+            };
 
-                return orig.error(content).await;
-            }
-        },
-        None => None,
+            let content = msg.format(lang, &[("name", &username), ("n", &post_len)]);
+
+            return orig.error(content).await;
+        }
+        None => (None, None),
     };
 
     let single_idx = index
         .map(|num| num.saturating_sub(1))
-        .or_else(|| (post_len == 1).then_some(0));
+        .or_else(|| (entries.len() == 1).then_some(0));
 
     let entries = entries.into_boxed_slice();
-    let content = write_content(username, &args, entries.len(), index);
+    let mut content = write_content(username, &args, post_len, index);
+
+    if let Some(ref range) = range {
+        content = Some(range_content(content, range, post_len));
+    }
 
     let list_size = args
         .size
@@ -871,7 +1063,13 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .or(guild_list_size)
         .unwrap_or_default();
 
+    // Index with `list` enabled opens the list on the page of the index
+    let start_idx = single_idx.filter(|_| args.list);
+
     let condensed_list = match (single_idx, list_size) {
+        (Some(_), ListSize::Condensed | ListSize::Single) if args.list => true,
+        (Some(_), ListSize::Detailed) if args.list => false,
+        (None, ListSize::Single) if range.is_some() => true,
         (Some(_), _) | (None, ListSize::Single) => {
             let content = match (single_idx, content) {
                 (Some(idx), Some(content)) => SingleScoreContent::OnlyForIndex { idx, content },
@@ -919,16 +1117,40 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         (None, ListSize::Detailed) => false,
     };
 
-    let pagination = TopPagination::builder()
+    let thread_name = format!("Top scores of {}", user.username);
+
+    if let Err(err) = orig.redirect_into_thread(&thread_name).await {
+        warn!(?err, "Failed to redirect into thread");
+    }
+
+    let sort_by = args.sort_by;
+
+    let mode_switch = TopModeSwitch {
+        user_id: user.user_id.to_native(),
+        args: args.into_owned(),
+        legacy_scores,
+        score_data,
+        with_render: render_allowed,
+    };
+
+    let mut builder = TopPagination::builder();
+
+    builder
         .user(user)
         .mode(mode)
         .entries(entries)
-        .sort_by(args.sort_by)
+        .sort_by(sort_by)
         .condensed_list(condensed_list)
         .score_data(score_data)
         .content(content.unwrap_or_default().into_boxed_str())
-        .msg_owner(msg_owner)
-        .build();
+        .mode_switch(mode_switch)
+        .msg_owner(msg_owner);
+
+    if let Some(idx) = start_idx {
+        builder.start_index(idx);
+    }
+
+    let pagination = builder.build();
 
     ActiveMessages::builder(pagination)
         .start_by_update(true)
@@ -936,49 +1158,161 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         .await
 }
 
-async fn process_scores(
-    scores: Vec<Score>,
-    args: &TopArgs<'_>,
-    with_render: bool,
-    score_data: ScoreData,
-) -> Result<Vec<ScoreEmbedDataWrap>> {
-    let legacy_scores = score_data.is_legacy();
-    let mut entries = Vec::<ScoreEmbedDataWrap>::with_capacity(scores.len());
+/// Retrieve the user and their prepared top scores.
+///
+/// If the scores are still cached, only the user is requested. Returns `None`
+/// if the error has already been responded with.
+async fn user_and_bundle(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    mode: GameMode,
+    legacy_scores: bool,
+) -> Result<Option<(CachedUser, Arc<TopScoresBundle>)>> {
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
-    let acc_range = match (args.min_acc, args.max_acc) {
-        (None, None) => None,
-        (None, Some(max)) => Some(0.0..=max),
-        (Some(min), None) => Some(min..=100.0),
-        (Some(min), Some(max)) => Some(min..=max),
+    match request_user_and_bundle(user_args, mode, legacy_scores).await {
+        Ok(tuple) => Ok(Some(tuple)),
+        Err(BundleError::User(UserArgsError::Osu(OsuError::NotFound))) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            Ok(None)
+        }
+        Err(BundleError::User(err)) => {
+            let _ = orig.error_localized(Msg::GeneralIssue).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            Err(err)
+        }
+        Err(BundleError::Prepare(err)) => {
+            let _ = orig.error_localized(Msg::GeneralIssue).await;
+
+            Err(err.wrap_err("failed to prepare scores"))
+        }
+    }
+}
+
+enum BundleError {
+    User(UserArgsError),
+    Prepare(Report),
+}
+
+async fn request_user_and_bundle(
+    user_args: UserArgs,
+    mode: GameMode,
+    legacy_scores: bool,
+) -> Result<(CachedUser, Arc<TopScoresBundle>), BundleError> {
+    let cache_user_id = match user_args {
+        UserArgs::Args(args) => Some(args.user_id),
+        UserArgs::User { ref user, .. } => Some(user.user_id.to_native()),
+        UserArgs::Err(_) => None,
     };
 
-    let combo_range = match (args.min_combo, args.max_combo) {
-        (None, None) => None,
-        (None, Some(max)) => Some(0..=max),
-        (Some(min), None) => Some(min..=u32::MAX),
-        (Some(min), Some(max)) => Some(min..=max),
+    let cached =
+        cache_user_id.and_then(|user_id| Context::get().top_scores(user_id, mode, legacy_scores));
+
+    let user_res = match cached {
+        // Processed scores are still cached so only the user is required
+        Some(_) => Context::redis()
+            .osu_user(user_args)
+            .await
+            .map(|user| (user, Vec::new())),
+        None => {
+            Context::osu_scores()
+                .top(legacy_scores)
+                .limit(100)
+                .exec_with_user(user_args)
+                .await
+        }
     };
 
-    let filter_criteria = args.query.as_deref().map(TopCriteria::create);
+    let (user, scores) = user_res.map_err(BundleError::User)?;
+
+    let bundle = match cached {
+        Some(bundle) => {
+            debug!(user = user.user_id.to_native(), %mode, "Using cached top scores");
 
+            bundle
+        }
+        None => {
+            let bundle = prepare_scores(scores, legacy_scores)
+                .await
+                .map(Arc::new)
+                .map_err(BundleError::Prepare)?;
+
+            Context::get().store_top_scores(
+                user.user_id.to_native(),
+                mode,
+                legacy_scores,
+                Arc::clone(&bundle),
+            );
+
+            bundle
+        }
+    };
+
+    Ok((user, bundle))
+}
+
+/// Everything that's required to re-run the top command for another mode.
+pub struct TopModeSwitch {
+    user_id: u32,
+    args: TopArgs<'static>,
+    legacy_scores: bool,
+    score_data: ScoreData,
+    /// Whether the render button is allowed, disregarding the mode
+    with_render: bool,
+}
+
+impl TopModeSwitch {
+    /// Request the user and their top scores for the given mode and process
+    /// them with the original arguments.
+    pub async fn fetch(&self, mode: GameMode) -> Result<TopModeSwitchData> {
+        let user_args = UserArgs::user_id(self.user_id, mode);
+
+        let (user, bundle) = match request_user_and_bundle(user_args, mode, self.legacy_scores)
+            .await
+        {
+            Ok(tuple) => tuple,
+            Err(BundleError::User(err)) => {
+                return Err(Report::new(err).wrap_err("Failed to get user or scores"));
+            }
+            Err(BundleError::Prepare(err)) => return Err(err.wrap_err("failed to prepare scores")),
+        };
+
+        let with_render = self.with_render && mode == GameMode::Osu;
+        let mut entries = process_scores(&bundle, &self.args, with_render, self.score_data);
+        let total = entries.len();
+        let mut content = write_content(user.username.as_str(), &self.args, total, None);
+
+        if let Some(TopIndex::Range(ref range)) = self.args.index {
+            match slice_entries(&mut entries, range) {
+                Some(range) => content = Some(range_content(content, &range, total)),
+                None => entries.clear(),
+            }
+        }
+
+        Ok(TopModeSwitchData {
+            user,
+            entries: entries.into_boxed_slice(),
+            content: content.unwrap_or_default().into_boxed_str(),
+        })
+    }
+}
+
+pub struct TopModeSwitchData {
+    pub user: CachedUser,
+    pub entries: Box<[ScoreEmbedDataWrap]>,
+    pub content: Box<str>,
+}
+
+/// Retrieve the maps of all scores and calculate their attributes.
+///
+/// The resulting bundle is independent of any command arguments so that it can
+/// be cached and re-used by subsequent invocations.
+async fn prepare_scores(scores: Vec<Score>, legacy_scores: bool) -> Result<TopScoresBundle> {
     let maps_id_checksum = scores
         .iter()
-        .filter(|score| match acc_range {
-            Some(ref range) => range.contains(&score.accuracy),
-            None => true,
-        })
-        .filter(|score| match combo_range {
-            Some(ref range) => range.contains(&score.max_combo),
-            None => true,
-        })
-        .filter(|score| match args.grade {
-            Some(grade) => score.grade.eq_letter(grade),
-            None => true,
-        })
-        .filter(|score| match args.mods {
-            None => true,
-            Some(ref selection) => selection.filter_score(score),
-        })
         .map(|score| {
             (
                 score.map_id as i32,
@@ -988,6 +1322,8 @@ async fn process_scores(
         .collect();
 
     let mut maps = Context::osu_map().maps(&maps_id_checksum).await?;
+    let mut kept_scores = Vec::with_capacity(scores.len());
+    let mut halves = Vec::with_capacity(scores.len());
 
     for (i, score) in scores.into_iter().enumerate() {
         let Some(mut map) = maps.remove(&score.map_id) else {
@@ -999,24 +1335,84 @@ async fn process_scores(
         let pb_idx = Some(ScoreEmbedDataPersonalBest::from_index(i));
 
         let half = ScoreEmbedDataHalf::new(
-            score,
+            score.clone(),
             map,
             pb_idx,
             legacy_scores,
-            with_render,
+            false,
             MissAnalyzerCheck::without(),
         )
         .await;
 
-        if let Some(ref criteria) = filter_criteria {
-            if half.matches(criteria) {
-                entries.push(half.into());
-            }
-        } else {
-            entries.push(half.into());
-        }
+        kept_scores.push(score);
+        halves.push(half);
     }
 
+    Ok(TopScoresBundle {
+        scores: kept_scores.into(),
+        halves: halves.into_boxed_slice(),
+    })
+}
+
+/// Filter and sort the prepared scores according to the arguments.
+fn process_scores(
+    bundle: &TopScoresBundle,
+    args: &TopArgs<'_>,
+    with_render: bool,
+    score_data: ScoreData,
+) -> Vec<ScoreEmbedDataWrap> {
+    let acc_range = match (args.min_acc, args.max_acc) {
+        (None, None) => None,
+        (None, Some(max)) => Some(0.0..=max),
+        (Some(min), None) => Some(min..=100.0),
+        (Some(min), Some(max)) => Some(min..=max),
+    };
+
+    let combo_range = match (args.min_combo, args.max_combo) {
+        (None, None) => None,
+        (None, Some(max)) => Some(0..=max),
+        (Some(min), None) => Some(min..=u32::MAX),
+        (Some(min), Some(max)) => Some(min..=max),
+    };
+
+    let filter_criteria = args.query.as_deref().map(TopCriteria::create);
+
+    let mut entries: Vec<ScoreEmbedDataWrap> = bundle
+        .iter()
+        .filter(|(score, _)| match acc_range {
+            Some(ref range) => range.contains(&score.accuracy),
+            None => true,
+        })
+        .filter(|(score, _)| match combo_range {
+            Some(ref range) => range.contains(&score.max_combo),
+            None => true,
+        })
+        .filter(|(score, _)| match args.grade {
+            Some(grade) => score.grade.eq_letter(grade),
+            None => true,
+        })
+        .filter(|(score, _)| match args.mods {
+            None => true,
+            Some(ref selection) if args.equivalent_mods => selection.filter_score_equivalent(score),
+            Some(ref selection) => selection.filter_score(score),
+        })
+        .filter(|(_, half)| match args.statuses {
+            Some(ref statuses) => statuses.contains(&half.map.status()),
+            None => true,
+        })
+        .filter(|(score, _)| args.converts != Some(false) || !is_convert(score))
+        .filter(|(_, half)| match filter_criteria {
+            Some(ref criteria) => half.matches(criteria),
+            None => true,
+        })
+        .map(|(_, half)| {
+            let mut half = half.clone();
+            half.with_render = with_render;
+
+            half.into()
+        })
+        .collect();
+
     if let Some(perfect_combo) = args.perfect_combo {
         entries.retain(|entry| {
             perfect_combo == (entry.get_half().max_combo == entry.get_half().score.max_combo)
@@ -1024,16 +1420,16 @@ async fn process_scores(
     }
 
     match args.sort_by {
-        TopScoreOrder::Acc => entries.sort_by(|a, b| {
+        TopScoreOrder::Acc => sort_entries(&mut entries, top_idx, |a, b| {
             b.get_half()
                 .score
                 .accuracy
                 .total_cmp(&a.get_half().score.accuracy)
         }),
-        TopScoreOrder::Ar => {
-            entries.sort_by(|a, b| b.get_half().ar().total_cmp(&a.get_half().ar()))
-        }
-        TopScoreOrder::Bpm => entries.sort_by(|a, b| {
+        TopScoreOrder::Ar => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().ar().total_cmp(&a.get_half().ar())
+        }),
+        TopScoreOrder::Bpm => sort_entries(&mut entries, top_idx, |a, b| {
             let a_bpm =
                 a.get_half().map.bpm() as f64 * a.get_half().score.mods.clock_rate().unwrap_or(1.0);
             let b_bpm =
@@ -1041,29 +1437,33 @@ async fn process_scores(
 
             b_bpm.total_cmp(&a_bpm)
         }),
-        TopScoreOrder::Combo => {
-            entries.sort_by_key(|entry| Reverse(entry.get_half().score.max_combo))
-        }
-        TopScoreOrder::Cs => {
-            entries.sort_by(|a, b| b.get_half().cs().total_cmp(&a.get_half().cs()))
-        }
-        TopScoreOrder::Date => {
-            entries.sort_by_key(|entry| Reverse(entry.get_half().score.ended_at))
-        }
-        TopScoreOrder::Hp => {
-            entries.sort_by(|a, b| b.get_half().hp().total_cmp(&a.get_half().hp()))
-        }
-        TopScoreOrder::Length => {
-            entries.sort_by(|a, b| {
-                let a_len = a.get_half().map.seconds_drain() as f64
-                    / a.get_half().score.mods.clock_rate().unwrap_or(1.0);
-                let b_len = b.get_half().map.seconds_drain() as f64
-                    / b.get_half().score.mods.clock_rate().unwrap_or(1.0);
-
-                b_len.total_cmp(&a_len)
-            });
-        }
-        TopScoreOrder::Misses => entries.sort_by(|a, b| {
+        TopScoreOrder::Combo => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half()
+                .score
+                .max_combo
+                .cmp(&a.get_half().score.max_combo)
+        }),
+        TopScoreOrder::Cs => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().cs().total_cmp(&a.get_half().cs())
+        }),
+        TopScoreOrder::Date => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half()
+                .score
+                .ended_at
+                .cmp(&a.get_half().score.ended_at)
+        }),
+        TopScoreOrder::Hp => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().hp().total_cmp(&a.get_half().hp())
+        }),
+        TopScoreOrder::Length => sort_entries(&mut entries, top_idx, |a, b| {
+            let a_len = a.get_half().map.seconds_drain() as f64
+                / a.get_half().score.mods.clock_rate().unwrap_or(1.0);
+            let b_len = b.get_half().map.seconds_drain() as f64
+                / b.get_half().score.mods.clock_rate().unwrap_or(1.0);
+
+            b_len.total_cmp(&a_len)
+        }),
+        TopScoreOrder::Misses => sort_entries(&mut entries, top_idx, |a, b| {
             let a = a.get_half();
             let b = b.get_half();
 
@@ -1083,32 +1483,61 @@ async fn process_scores(
                         .then_with(|| hits_b.cmp(&hits_a))
                 })
         }),
-        TopScoreOrder::ModsCount => {
-            entries.sort_by_key(|entry| Reverse(entry.get_half().score.mods.len()))
-        }
-        TopScoreOrder::Od => {
-            entries.sort_by(|a, b| b.get_half().od().total_cmp(&a.get_half().od()))
-        }
-        TopScoreOrder::Pp => {
-            entries.sort_by(|a, b| b.get_half().score.pp.total_cmp(&a.get_half().score.pp))
-        }
-        TopScoreOrder::RankedDate => {
-            entries.sort_by_key(|entry| Reverse(entry.get_half().map.ranked_date()))
-        }
+        TopScoreOrder::ModsCount => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half()
+                .score
+                .mods
+                .len()
+                .cmp(&a.get_half().score.mods.len())
+        }),
+        TopScoreOrder::Od => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().od().total_cmp(&a.get_half().od())
+        }),
+        TopScoreOrder::Pp => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().score.pp.total_cmp(&a.get_half().score.pp)
+        }),
+        TopScoreOrder::RankedDate => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half()
+                .map
+                .ranked_date()
+                .cmp(&a.get_half().map.ranked_date())
+        }),
         TopScoreOrder::Score if score_data == ScoreData::LazerWithClassicScoring => {
-            entries.sort_by_key(|entry| Reverse(entry.get_half().score.classic_score))
-        }
-        TopScoreOrder::Score => entries.sort_by_key(|entry| Reverse(entry.get_half().score.score)),
-        TopScoreOrder::Stars => {
-            entries.sort_by(|a, b| b.get_half().stars.total_cmp(&a.get_half().stars))
+            sort_entries(&mut entries, top_idx, |a, b| {
+                b.get_half()
+                    .score
+                    .classic_score
+                    .cmp(&a.get_half().score.classic_score)
+            })
         }
+        TopScoreOrder::Score => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().score.score.cmp(&a.get_half().score.score)
+        }),
+        TopScoreOrder::Stars => sort_entries(&mut entries, top_idx, |a, b| {
+            b.get_half().stars.total_cmp(&a.get_half().stars)
+        }),
     }
 
     if args.reverse {
         entries.reverse();
     }
 
-    Ok(entries)
+    entries
+}
+
+/// Sort the entries and break ties through their index so that the resulting
+/// order does not depend on the order of the input.
+fn sort_entries<T>(entries: &mut [T], idx: impl Fn(&T) -> usize, cmp: impl Fn(&T, &T) -> Ordering) {
+    entries.sort_by(|a, b| cmp(a, b).then_with(|| idx(a).cmp(&idx(b))));
+}
+
+/// The 0-based index of the entry within the user's top100.
+fn top_idx(entry: &ScoreEmbedDataWrap) -> usize {
+    let half = entry.get_half();
+
+    half.original_idx
+        .or(half.pb_idx.as_ref().and_then(|pb_idx| pb_idx.idx))
+        .unwrap_or(usize::MAX)
 }
 
 fn mode_long(mode: GameMode) -> &'static str {
@@ -1131,8 +1560,10 @@ fn write_content(
         || args.min_combo.is_some()
         || args.max_combo.is_some()
         || args.grade.is_some()
+        || args.statuses.is_some()
         || args.mods.is_some()
         || args.perfect_combo.is_some()
+        || args.converts.is_some()
         || args.query.is_some();
 
     if condition {
@@ -1279,16 +1710,38 @@ fn content_with_condition(args: &TopArgs<'_>, amount: usize) -> String {
         let _ = write!(content, " • `Grade: {grade}`");
     }
 
+    if let Some(ref statuses) = args.statuses {
+        content.push_str(" • `Status: ");
+
+        for (status, i) in statuses.iter().zip(0..) {
+            if i > 0 {
+                content.push_str(", ");
+            }
+
+            content.push_str(status_name(*status));
+        }
+
+        content.push('`');
+    }
+
     if let Some(ref selection) = args.mods {
         content.push_str(" • `Mods: ");
 
         let _ = match selection {
-            ModSelection::Include(mods) => write!(content, "Include {mods}"),
-            ModSelection::Exclude { mods, nomod: false } => write!(content, "Exclude {mods}"),
+            ModSelection::Include(mods) => {
+                write!(content, "Include {}", IntermodeFormatter::new(mods))
+            }
+            ModSelection::Exclude { mods, nomod: false } => {
+                write!(content, "Exclude {}", IntermodeFormatter::new(mods))
+            }
             ModSelection::Exclude { mods, nomod: true } => {
-                write!(content, "Exclude NM (without {mods})")
+                write!(
+                    content,
+                    "Exclude NM (without {})",
+                    IntermodeFormatter::new(mods)
+                )
             }
-            ModSelection::Exact(mods) => write!(content, "{mods}"),
+            ModSelection::Exact(mods) => write!(content, "{}", IntermodeFormatter::new(mods)),
         };
 
         content.push('`');
@@ -1298,6 +1751,10 @@ fn content_with_condition(args: &TopArgs<'_>, amount: usize) -> String {
         let _ = write!(content, " • `Perfect combo: {perfect_combo}`");
     }
 
+    if let Some(converts) = args.converts {
+        let _ = write!(content, " • `Converts: {converts}`");
+    }
+
     if let Some(query) = args.query.as_deref() {
         TopCriteria::create(query).display(&mut content);
     }
@@ -1308,6 +1765,41 @@ fn content_with_condition(args: &TopArgs<'_>, amount: usize) -> String {
     content
 }
 
+/// Parse a comma-separated list of ranked statuses.
+///
+/// Returns `None` if any of the statuses is unknown.
+fn parse_statuses(value: &str) -> Option<Vec<RankStatus>> {
+    let mut statuses = Vec::new();
+
+    for status in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let status = match status.cow_to_ascii_lowercase().as_ref() {
+            "ranked" | "r" => RankStatus::Ranked,
+            "loved" | "l" => RankStatus::Loved,
+            "qualified" | "q" => RankStatus::Qualified,
+            "approved" | "a" => RankStatus::Approved,
+            _ => return None,
+        };
+
+        if !statuses.contains(&status) {
+            statuses.push(status);
+        }
+    }
+
+    (!statuses.is_empty()).then_some(statuses)
+}
+
+fn status_name(status: RankStatus) -> &'static str {
+    match status {
+        RankStatus::Graveyard => "Graveyard",
+        RankStatus::WIP => "WIP",
+        RankStatus::Pending => "Pending",
+        RankStatus::Ranked => "Ranked",
+        RankStatus::Approved => "Approved",
+        RankStatus::Qualified => "Qualified",
+        RankStatus::Loved => "Loved",
+    }
+}
+
 #[derive(Default)]
 struct GuildValues {
     list_size: Option<ListSize>,
@@ -1324,3 +1816,41 @@ impl From<&GuildConfig> for GuildValues {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Accuracy and index within the top100
+    type Entry = (f32, usize);
+
+    fn sort(mut entries: Vec<Entry>) -> Vec<Entry> {
+        sort_entries(&mut entries, |entry| entry.1, |a, b| b.0.total_cmp(&a.0));
+
+        entries
+    }
+
+    #[test]
+    fn equal_keys_are_deterministic() {
+        let entries = vec![(98.5, 3), (99.1, 0), (98.5, 1), (97.0, 4), (98.5, 2)];
+
+        let mut shuffled = entries.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 3);
+
+        let sorted = sort(entries);
+
+        assert_eq!(sorted, sort(shuffled));
+        assert_eq!(
+            sorted,
+            [(99.1, 0), (98.5, 1), (98.5, 2), (98.5, 3), (97.0, 4)]
+        );
+    }
+
+    #[test]
+    fn repeated_invocations() {
+        let entries: Vec<_> = (0..100).map(|i| ((i % 3) as f32, 99 - i)).collect();
+
+        assert_eq!(sort(entries.clone()), sort(entries));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, cmp::Ordering};
+use std::{borrow::Cow, cmp::Ordering, fmt::Write};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::ScoreSlim;
@@ -7,7 +7,7 @@ use bathbot_util::{constants::GENERAL_ISSUE, matcher, numbers::round, osu::ModSe
 use eyre::{Report, Result};
 use rosu_pp_older::*;
 use rosu_v2::{
-    prelude::{GameMode, OsuError, Score},
+    prelude::{GameModIntermode, GameMode, OsuError, Score},
     request::UserId,
 };
 use time::OffsetDateTime;
@@ -142,21 +142,40 @@ pub enum TopOldOsuVersion {
     March25Now,
 }
 
+/// Lists the years that can be specified for the prefix commands of a mode.
+macro_rules! supported_years {
+    (Osu) => {
+        "Supported years: `2014`, `2015`, `2016`-`2017`, `2018`, `2019`-`2020`, \
+        `2021`, `2022`, `2023`, `2024`, and `2025` onwards.\n\
+        For more specific versions, use the `/topold osu` command."
+    };
+    (Taiko) => {
+        "Supported years: `2014`-`2019`, `2020`-`2022`, `2023`, `2024`, \
+        and `2025` onwards."
+    };
+    (Catch) => {
+        "Supported years: `2014`-`2019`, `2020`-`2023`, and `2024` onwards."
+    };
+    (Mania) => {
+        "Supported years: `2014`-`2018`, `2019`-`2022`, `2023`, and `2024` onwards."
+    };
+}
+
 impl TryFrom<i32> for TopOldOsuVersion {
     type Error = &'static str;
 
     fn try_from(year: i32) -> Result<Self, Self::Error> {
         match year {
-            2007..=2011 | 7..=11 => {
-                Err("Up until april 2012, ranked score was the skill metric.\n\
-                The first available pp system is from 2014.")
-            }
-            2012..=2013 | 12..=13 => Err(
+            2007..=2011 | 7..=11 => Err(concat!(
+                "Up until april 2012, ranked score was the skill metric.\n",
+                supported_years!(Osu)
+            )),
+            2012..=2013 | 12..=13 => Err(concat!(
                 "April 2012 till january 2014 the ppv1 system was in place, \
                 which is unfortunately impossible to implement nowadays \
-                because of lacking data \\:(\n\
-                The first available pp system is from 2014.",
-            ),
+                because of lacking data \\:(\n",
+                supported_years!(Osu)
+            )),
             2014 | 14 => Ok(Self::May14July14),
             2015 | 15 => Ok(Self::February15April15),
             2016..=2017 | 16..=17 => Ok(Self::April15May18),
@@ -166,8 +185,10 @@ impl TryFrom<i32> for TopOldOsuVersion {
             2022 | 22 => Ok(Self::November21September22),
             2023 | 23 => Ok(Self::September22October24),
             2024 | 24 => Ok(Self::October24March25),
-            i32::MIN..=2006 => Err("osu! was not a thing until september 2007.\n\
-                The first available pp system is from 2014."),
+            i32::MIN..=2006 => Err(concat!(
+                "osu! was not a thing until september 2007.\n",
+                supported_years!(Osu)
+            )),
             _ => Ok(Self::March25Now),
         }
     }
@@ -254,8 +275,11 @@ impl TryFrom<i32> for TopOldTaikoVersion {
             2020..=2022 | 20..=22 => Ok(Self::September20September22),
             2023 | 23 => Ok(Self::September22October24),
             2024 | 24 => Ok(Self::October24March25),
-            i32::MIN..=2013 => Err("taiko pp were not a thing until march 2014. \
-                I think? Don't quote me on that :^)"),
+            i32::MIN..=2013 => Err(concat!(
+                "taiko pp were not a thing until march 2014. \
+                I think? Don't quote me on that :^)\n",
+                supported_years!(Taiko)
+            )),
             _ => Ok(Self::March25Now),
         }
     }
@@ -327,9 +351,12 @@ impl TryFrom<i32> for TopOldCatchVersion {
     fn try_from(year: i32) -> Result<Self, Self::Error> {
         match year {
             2014..=2019 | 14..=19 => Ok(Self::March14May20),
-            2023 | 23 => Ok(Self::May20October24),
-            i32::MIN..=2013 => Err("ctb pp were not a thing until march 2014. \
-                I think? Don't quote me on that :^)"),
+            2020..=2023 | 20..=23 => Ok(Self::May20October24),
+            i32::MIN..=2013 => Err(concat!(
+                "ctb pp were not a thing until march 2014. \
+                I think? Don't quote me on that :^)\n",
+                supported_years!(Catch)
+            )),
             _ => Ok(Self::October24Now),
         }
     }
@@ -406,8 +433,11 @@ impl TryFrom<i32> for TopOldManiaVersion {
             2014..=2018 | 14..=18 => Ok(Self::March14May18),
             2019..=2022 | 19..=22 => Ok(Self::May18October22),
             2023 | 23 => Ok(Self::October22October24),
-            i32::MIN..=2013 => Err("mania pp were not a thing until march 2014. \
-                I think? Don't quote me on that :^)"),
+            i32::MIN..=2013 => Err(concat!(
+                "mania pp were not a thing until march 2014. \
+                I think? Don't quote me on that :^)\n",
+                supported_years!(Mania)
+            )),
             _ => Ok(Self::October24Now),
         }
     }
@@ -620,6 +650,23 @@ impl<'m> TopOld<'m> {
         Ok(args)
     }
 
+    /// Whether the pp version only considers legacy mods i.e. mod settings
+    /// such as custom clock rates or lazer-only mods can not be handled.
+    fn uses_legacy_mods(&self) -> bool {
+        match self {
+            TopOld::Osu(o) => !matches!(
+                o.version,
+                TopOldOsuVersion::October24March25 | TopOldOsuVersion::March25Now
+            ),
+            TopOld::Taiko(t) => !matches!(
+                t.version,
+                TopOldTaikoVersion::October24March25 | TopOldTaikoVersion::March25Now
+            ),
+            TopOld::Catch(c) => c.version != TopOldCatchVersion::October24Now,
+            TopOld::Mania(m) => m.version != TopOldManiaVersion::October24Now,
+        }
+    }
+
     fn date_range(&self) -> &'static str {
         match self {
             TopOld::Osu(o) => match o.version {
@@ -904,7 +951,7 @@ macro_rules! user_id_ref {
             crate::commands::osu::UserIdResult::Future(fut) => match fut.await {
                 crate::commands::osu::UserIdFutureResult::Id(user_id) => Some(user_id),
                 crate::commands::osu::UserIdFutureResult::NotLinked(user_id) => {
-                    let content = format!("<@{user_id}> is not linked to an osu!profile");
+                    let content = crate::commands::osu::not_linked(user_id);
 
                     return $orig.error(content).await;
                 }
@@ -996,8 +1043,8 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         .to_native();
     let bonus_pp = pre_pp - actual_pp;
 
-    let mut entries = match process_scores(scores, &args).await {
-        Ok(scores) => scores,
+    let (mut entries, unchanged) = match process_scores(scores, &args).await {
+        Ok(tuple) => tuple,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
 
@@ -1053,6 +1100,16 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         content.push(':');
     }
 
+    if unchanged > 0 {
+        let _ = write!(
+            content,
+            "\n:warning: {unchanged} score{plural} with lazer-only mods or custom \
+            speed rates kept {pronoun} current pp because this pp version can't handle them",
+            plural = if unchanged == 1 { "" } else { "s" },
+            pronoun = if unchanged == 1 { "its" } else { "their" },
+        );
+    }
+
     let pagination = TopIfPagination::builder()
         .user(user)
         .entries(entries.into_boxed_slice())
@@ -1069,8 +1126,12 @@ async fn topold(orig: CommandOrigin<'_>, args: TopOld<'_>) -> Result<()> {
         .await
 }
 
-async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<TopIfEntry>> {
+/// Returns the processed entries and the amount of scores whose pp were kept
+/// because the pp version is not able to handle their mods.
+async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<(Vec<TopIfEntry>, usize)> {
     let mut entries = Vec::with_capacity(scores.len());
+    let mut unchanged = 0;
+    let uses_legacy_mods = args.uses_legacy_mods();
 
     let maps_id_checksum = scores
         .iter()
@@ -1108,110 +1169,116 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
 
         let rosu_map = &map.pp_map;
 
-        let (new_pp, max_pp, stars, max_combo) = match args {
-            TopOld::Osu(o) => match o.version {
-                TopOldOsuVersion::May14July14 => {
-                    pp_std!(osu_2014_may, rosu_map, score)
-                }
-                TopOldOsuVersion::July14February15 => {
-                    pp_std!(osu_2014_july, rosu_map, score)
-                }
-                TopOldOsuVersion::February15April15 => {
-                    pp_std!(osu_2015_february, rosu_map, score)
-                }
-                TopOldOsuVersion::April15May18 => {
-                    pp_std!(osu_2015_april, rosu_map, score)
-                }
-                TopOldOsuVersion::May18February19 => {
-                    pp_std!(osu_2018, rosu_map, score)
-                }
-                TopOldOsuVersion::February19January21 => {
-                    pp_std!(osu_2019, rosu_map, score)
-                }
-                TopOldOsuVersion::January21July21 => {
-                    pp_std!(osu_2021_january, rosu_map, score)
-                }
-                TopOldOsuVersion::July21November21 => {
-                    pp_std!(osu_2021_july, rosu_map, score)
-                }
-                TopOldOsuVersion::November21September22 => {
-                    pp_std!(osu_2021_november, rosu_map, score)
-                }
-                TopOldOsuVersion::September22October24 => {
-                    pp_std!(osu_2022, rosu_map, score)
-                }
-                TopOldOsuVersion::October24March25 => {
-                    pp_std!(osu_2024, rosu_map, score, lazer)
-                }
-                TopOldOsuVersion::March25Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Taiko(t) => match t.version {
-                TopOldTaikoVersion::March14September20 => {
-                    pp_tko!(taiko_ppv1, rosu_map, score)
-                }
-                TopOldTaikoVersion::September20September22 => {
-                    pp_tko!(taiko_2020, rosu_map, score)
-                }
-                TopOldTaikoVersion::September22October24 => {
-                    pp_tko!(taiko_2022, rosu_map, score)
-                }
-                TopOldTaikoVersion::October24March25 => {
-                    pp_tko!(taiko_2024, rosu_map, score, lazer)
-                }
-                TopOldTaikoVersion::March25Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Catch(c) => match c.version {
-                TopOldCatchVersion::March14May20 => pp_ctb!(fruits_ppv1, rosu_map, score),
-                TopOldCatchVersion::May20October24 => {
-                    pp_ctb!(fruits_2022, rosu_map, score)
-                }
-                TopOldCatchVersion::October24Now => use_current_system(&score, &map).await,
-            },
-            TopOld::Mania(m) => match m.version {
-                TopOldManiaVersion::March14May18 => {
-                    let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_ppv1::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .accuracy(score.accuracy)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-                    let max_combo = Context::pp(&map).difficulty().await.max_combo();
-
-                    (pp, max_pp, stars, max_combo)
-                }
-                TopOldManiaVersion::May18October22 => {
-                    let max_pp_res = mania_2018::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .calculate();
-
-                    let max_pp = max_pp_res.pp as f32;
-                    let stars = max_pp_res.difficulty.stars as f32;
-
-                    let attrs = mania_2018::ManiaPP::new(rosu_map)
-                        .mods(score.mods.bits())
-                        .attributes(max_pp_res)
-                        .score(score.score)
-                        .calculate();
-
-                    let pp = attrs.pp as f32;
-                    let max_combo = Context::pp(&map).difficulty().await.max_combo();
-
-                    (pp, max_pp, stars, max_combo)
-                }
-                TopOldManiaVersion::October22October24 => {
-                    pp_mna!(mania_2022, rosu_map, score)
-                }
-                TopOldManiaVersion::October24Now => use_current_system(&score, &map).await,
-            },
+        let (new_pp, max_pp, stars, max_combo) = if uses_legacy_mods && !has_legacy_mods(&score) {
+            unchanged += 1;
+
+            use_current_system(&score, &map).await
+        } else {
+            match args {
+                TopOld::Osu(o) => match o.version {
+                    TopOldOsuVersion::May14July14 => {
+                        pp_std!(osu_2014_may, rosu_map, score)
+                    }
+                    TopOldOsuVersion::July14February15 => {
+                        pp_std!(osu_2014_july, rosu_map, score)
+                    }
+                    TopOldOsuVersion::February15April15 => {
+                        pp_std!(osu_2015_february, rosu_map, score)
+                    }
+                    TopOldOsuVersion::April15May18 => {
+                        pp_std!(osu_2015_april, rosu_map, score)
+                    }
+                    TopOldOsuVersion::May18February19 => {
+                        pp_std!(osu_2018, rosu_map, score)
+                    }
+                    TopOldOsuVersion::February19January21 => {
+                        pp_std!(osu_2019, rosu_map, score)
+                    }
+                    TopOldOsuVersion::January21July21 => {
+                        pp_std!(osu_2021_january, rosu_map, score)
+                    }
+                    TopOldOsuVersion::July21November21 => {
+                        pp_std!(osu_2021_july, rosu_map, score)
+                    }
+                    TopOldOsuVersion::November21September22 => {
+                        pp_std!(osu_2021_november, rosu_map, score)
+                    }
+                    TopOldOsuVersion::September22October24 => {
+                        pp_std!(osu_2022, rosu_map, score)
+                    }
+                    TopOldOsuVersion::October24March25 => {
+                        pp_std!(osu_2024, rosu_map, score, lazer)
+                    }
+                    TopOldOsuVersion::March25Now => use_current_system(&score, &map).await,
+                },
+                TopOld::Taiko(t) => match t.version {
+                    TopOldTaikoVersion::March14September20 => {
+                        pp_tko!(taiko_ppv1, rosu_map, score)
+                    }
+                    TopOldTaikoVersion::September20September22 => {
+                        pp_tko!(taiko_2020, rosu_map, score)
+                    }
+                    TopOldTaikoVersion::September22October24 => {
+                        pp_tko!(taiko_2022, rosu_map, score)
+                    }
+                    TopOldTaikoVersion::October24March25 => {
+                        pp_tko!(taiko_2024, rosu_map, score, lazer)
+                    }
+                    TopOldTaikoVersion::March25Now => use_current_system(&score, &map).await,
+                },
+                TopOld::Catch(c) => match c.version {
+                    TopOldCatchVersion::March14May20 => pp_ctb!(fruits_ppv1, rosu_map, score),
+                    TopOldCatchVersion::May20October24 => {
+                        pp_ctb!(fruits_2022, rosu_map, score)
+                    }
+                    TopOldCatchVersion::October24Now => use_current_system(&score, &map).await,
+                },
+                TopOld::Mania(m) => match m.version {
+                    TopOldManiaVersion::March14May18 => {
+                        let max_pp_res = mania_ppv1::ManiaPP::new(rosu_map)
+                            .mods(score.mods.bits())
+                            .calculate();
+
+                        let max_pp = max_pp_res.pp as f32;
+                        let stars = max_pp_res.difficulty.stars as f32;
+
+                        let attrs = mania_ppv1::ManiaPP::new(rosu_map)
+                            .mods(score.mods.bits())
+                            .attributes(max_pp_res)
+                            .score(score.score)
+                            .accuracy(score.accuracy)
+                            .calculate();
+
+                        let pp = attrs.pp as f32;
+                        let max_combo = Context::pp(&map).difficulty().await.max_combo();
+
+                        (pp, max_pp, stars, max_combo)
+                    }
+                    TopOldManiaVersion::May18October22 => {
+                        let max_pp_res = mania_2018::ManiaPP::new(rosu_map)
+                            .mods(score.mods.bits())
+                            .calculate();
+
+                        let max_pp = max_pp_res.pp as f32;
+                        let stars = max_pp_res.difficulty.stars as f32;
+
+                        let attrs = mania_2018::ManiaPP::new(rosu_map)
+                            .mods(score.mods.bits())
+                            .attributes(max_pp_res)
+                            .score(score.score)
+                            .calculate();
+
+                        let pp = attrs.pp as f32;
+                        let max_combo = Context::pp(&map).difficulty().await.max_combo();
+
+                        (pp, max_pp, stars, max_combo)
+                    }
+                    TopOldManiaVersion::October22October24 => {
+                        pp_mna!(mania_2022, rosu_map, score)
+                    }
+                    TopOldManiaVersion::October24Now => use_current_system(&score, &map).await,
+                },
+            }
         };
 
         let old_pp = score.pp.expect("missing pp");
@@ -1229,7 +1296,23 @@ async fn process_scores(scores: Vec<Score>, args: &TopOld<'_>) -> Result<Vec<Top
         entries.push(entry);
     }
 
-    Ok(entries)
+    Ok((entries, unchanged))
+}
+
+/// Whether the score's mods can be fully represented through legacy mods.
+fn has_legacy_mods(score: &Score) -> bool {
+    let custom_rate = score
+        .mods
+        .clock_rate()
+        .is_some_and(|rate| ![1.0, 1.5, 0.75].contains(&rate));
+
+    let lazer_only = score.mods.iter().any(|gamemod| {
+        let intermode = gamemod.intermode();
+
+        intermode != GameModIntermode::Classic && intermode.bits().is_none()
+    });
+
+    !(custom_rate || lazer_only)
 }
 
 fn plural(name: &str) -> &'static str {
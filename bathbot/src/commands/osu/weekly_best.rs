@@ -0,0 +1,72 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use time::{Duration, OffsetDateTime};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    Context,
+    active::{ActiveMessages, impls::WeeklyBestPagination},
+    core::commands::CommandOrigin,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "weeklybest",
+    desc = "The best scores of the past week",
+    help = "Highlight reel of the highest pp scores that were set in the past week.\n\
+    Only new top scores of users that are tracked in any channel are considered \
+    so the list is not exhaustive.\n\
+    Scores are only included if they're worth at least 300pp or are within the user's top 10."
+)]
+pub struct WeeklyBest {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(min_value = 0.0, desc = "Only show scores with at least this much pp")]
+    min_pp: Option<f32>,
+}
+
+async fn slash_weeklybest(mut command: InteractionCommand) -> Result<()> {
+    let args = WeeklyBest::from_interaction(command.input_data())?;
+
+    weekly_best((&mut command).into(), args).await
+}
+
+async fn weekly_best(orig: CommandOrigin<'_>, args: WeeklyBest) -> Result<()> {
+    let WeeklyBest { mode, min_pp } = args;
+    let mode = mode.map(GameMode::from);
+    let since = OffsetDateTime::now_utc() - Duration::WEEK;
+
+    let scores = match Context::psql()
+        .select_notable_scores(since, mode, min_pp)
+        .await
+    {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get notable scores"));
+        }
+    };
+
+    if scores.is_empty() {
+        let content = "No notable scores were set in the past week for these options";
+
+        return orig.error(content).await;
+    }
+
+    let pagination = WeeklyBestPagination::builder()
+        .scores(scores.into_boxed_slice())
+        .mode(mode)
+        .min_pp(min_pp)
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .begin(orig)
+        .await
+}
@@ -5,7 +5,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{add_bg::*, cache::*, request_members::*, sync_commands::*};
 use crate::{
     commands::owner::reshard::reshard,
     util::{InteractionCommandExt, interaction::InteractionCommand},
@@ -15,6 +15,7 @@ mod add_bg;
 mod cache;
 mod request_members;
 mod reshard;
+mod sync_commands;
 mod tracking_stats;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -30,6 +31,8 @@ pub enum Owner {
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
     Reshard(OwnerReshard),
+    #[command(name = "synccommands")]
+    SyncCommands(OwnerSyncCommands),
     #[command(name = "tracking")]
     Tracking(OwnerTracking),
 }
@@ -61,6 +64,16 @@ pub struct OwnerRequestMembers {
 #[command(name = "reshard", desc = "Reshard the gateway")]
 pub struct OwnerReshard;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "synccommands",
+    desc = "Compare registered slash commands with local ones and apply changes"
+)]
+pub struct OwnerSyncCommands {
+    #[command(desc = "Only show the changes without applying them")]
+    dry_run: Option<bool>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "tracking", desc = "Stuff about osu!tracking")]
 pub enum OwnerTracking {
@@ -78,6 +91,7 @@ async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
         Owner::Cache(_) => cache(command).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
+        Owner::SyncCommands(args) => sync_commands(command, args.dry_run.unwrap_or(false)).await,
         Owner::Tracking(OwnerTracking::Stats(_)) => tracking_stats::trackingstats(command).await,
     }
 }
@@ -0,0 +1,193 @@
+use std::fmt::Write;
+
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use twilight_model::application::command::{
+    Command, CommandOption, CommandOptionChoice, CommandOptionType,
+};
+
+use crate::{
+    core::{Context, commands::interaction::InteractionCommands},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const DESCRIPTION_LIMIT: usize = 4000;
+
+pub async fn sync_commands(command: InteractionCommand, dry_run: bool) -> Result<()> {
+    command.defer(false).await?;
+
+    let local = InteractionCommands::get().collect();
+
+    let registered = match Context::registered_commands().await {
+        Ok(registered) => registered,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get registered commands"));
+        }
+    };
+
+    let changes = diff_commands(&local, &registered);
+
+    if changes.is_empty() {
+        let content = format!("All {} commands are up to date", local.len());
+        let embed = EmbedBuilder::new().description(content);
+        let builder = MessageBuilder::new().embed(embed);
+        command.update(builder).await?;
+
+        return Ok(());
+    }
+
+    let title = if dry_run {
+        format!("{} command changes (dry run)", changes.len())
+    } else {
+        // Discord only updates the commands that differ so ids of unchanged
+        // commands remain the same.
+        match Context::register_commands(local).await {
+            Ok(cmds) => InteractionCommands::set_missing_ids(&cmds),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to register commands"));
+            }
+        }
+
+        format!("Applied {} command changes", changes.len())
+    };
+
+    let mut description = String::with_capacity(DESCRIPTION_LIMIT);
+
+    for (change, i) in changes.iter().zip(0..) {
+        if description.len() + change.len() > DESCRIPTION_LIMIT {
+            let _ = write!(description, "... and {} more", changes.len() - i);
+
+            break;
+        }
+
+        let _ = writeln!(description, "- {change}");
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(if dry_run {
+            "Nothing has been applied"
+        } else {
+            "Registrations have been updated"
+        }))
+        .title(title);
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+fn diff_commands(local: &[Command], registered: &[Command]) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    for cmd in local {
+        let registered_cmd = registered
+            .iter()
+            .find(|reg| reg.name == cmd.name && reg.kind == cmd.kind);
+
+        let Some(registered_cmd) = registered_cmd else {
+            changes.push(format!("{}: command added", cmd.name));
+
+            continue;
+        };
+
+        if cmd.description != registered_cmd.description {
+            changes.push(format!("{}: description changed", cmd.name));
+        }
+
+        diff_options(
+            &cmd.name,
+            &cmd.options,
+            &registered_cmd.options,
+            &mut changes,
+        );
+    }
+
+    for reg in registered {
+        if !local
+            .iter()
+            .any(|cmd| cmd.name == reg.name && cmd.kind == reg.kind)
+        {
+            changes.push(format!("{}: command removed", reg.name));
+        }
+    }
+
+    changes
+}
+
+fn diff_options(
+    path: &str,
+    local: &[CommandOption],
+    registered: &[CommandOption],
+    changes: &mut Vec<String>,
+) {
+    for option in local {
+        let name = &option.name;
+
+        let Some(reg) = registered.iter().find(|reg| reg.name == *name) else {
+            changes.push(format!("{path}: option `{name}` added"));
+
+            continue;
+        };
+
+        if option.kind != reg.kind {
+            changes.push(format!(
+                "{path}: option `{name}` type changed from {:?} to {:?}",
+                reg.kind, option.kind
+            ));
+
+            continue;
+        }
+
+        if option.description != reg.description {
+            changes.push(format!("{path}: option `{name}` description changed"));
+        }
+
+        if option.required.unwrap_or(false) != reg.required.unwrap_or(false) {
+            let required = if option.required.unwrap_or(false) {
+                "required"
+            } else {
+                "optional"
+            };
+
+            changes.push(format!("{path}: option `{name}` is now {required}"));
+        }
+
+        let choices = option.choices.as_deref().unwrap_or_default();
+        let reg_choices = reg.choices.as_deref().unwrap_or_default();
+
+        if !choices_eq(choices, reg_choices) {
+            changes.push(format!("{path}: option `{name}` choices changed"));
+        }
+
+        if matches!(
+            option.kind,
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        ) {
+            diff_options(
+                &format!("{path} {name}"),
+                option.options.as_deref().unwrap_or_default(),
+                reg.options.as_deref().unwrap_or_default(),
+                changes,
+            );
+        }
+    }
+
+    for reg in registered {
+        if !local.iter().any(|option| option.name == reg.name) {
+            changes.push(format!("{path}: option `{}` removed", reg.name));
+        }
+    }
+}
+
+fn choices_eq(a: &[CommandOptionChoice], b: &[CommandOptionChoice]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| a.name == b.name && a.value == b.value)
+}
@@ -0,0 +1,165 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::osu::DbDailyRecap;
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use rosu_v2::prelude::GameMode;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+
+use crate::{
+    Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "dailyrecap",
+    dm_permission = false,
+    desc = "Post a daily recap of the best scores of this server's members"
+)]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+pub enum DailyRecap {
+    #[command(name = "enable")]
+    Enable(DailyRecapEnable),
+    #[command(name = "disable")]
+    Disable(DailyRecapDisable),
+    #[command(name = "list")]
+    List(DailyRecapList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "enable",
+    desc = "Enable the daily recap for this channel",
+    help = "Once a day, post the best recent score of the past 24 hours \
+    for each linked member of this server.\n\
+    The top 10 scores by pp will be shown in a single embed."
+)]
+pub struct DailyRecapEnable {
+    #[command(desc = "Specify a gamemode, defaults to osu!standard")]
+    mode: Option<GameModeOption>,
+    #[command(
+        min_value = 0,
+        max_value = 23,
+        desc = "Specify the UTC hour at which the recap is posted, defaults to 0"
+    )]
+    hour: Option<i64>,
+    #[command(desc = "What to do on days without new scores, defaults to posting a message")]
+    quiet: Option<DailyRecapQuiet>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default, Eq, PartialEq)]
+pub enum DailyRecapQuiet {
+    #[default]
+    #[option(name = "Post a short message", value = "post")]
+    Post,
+    #[option(name = "Skip", value = "skip")]
+    Skip,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "disable", desc = "Disable the daily recap for this channel")]
+pub struct DailyRecapDisable;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "list",
+    desc = "List all channels of this server with a daily recap"
+)]
+pub struct DailyRecapList;
+
+async fn slash_dailyrecap(mut command: InteractionCommand) -> Result<()> {
+    let args = DailyRecap::from_interaction(command.input_data())?;
+
+    let guild_id = command.guild_id.unwrap(); // command is only processed in guilds
+    let channel_id = command.channel_id;
+
+    let content = match args {
+        DailyRecap::Enable(args) => {
+            let mode = args.mode.map_or(GameMode::Osu, GameMode::from);
+            let hour = args.hour.unwrap_or(0).clamp(0, 23) as i16;
+            let skip_quiet = args.quiet.unwrap_or_default() == DailyRecapQuiet::Skip;
+
+            let recap = DbDailyRecap {
+                channel_id: channel_id.get() as i64,
+                guild_id: guild_id.get() as i64,
+                gamemode: mode as i16,
+                hour,
+                skip_quiet,
+            };
+
+            if let Err(err) = Context::psql().upsert_daily_recap(&recap).await {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to upsert daily recap"));
+            }
+
+            format!(
+                "This channel will now receive a daily {mode} recap at {hour:0>2}:00 UTC",
+                mode = mode_str(mode),
+            )
+        }
+        DailyRecap::Disable(_) => match Context::psql().delete_daily_recap(channel_id).await {
+            Ok(true) => "Disabled the daily recap for this channel".to_owned(),
+            Ok(false) => {
+                let content = "There is no daily recap enabled for this channel";
+
+                command.error(content).await?;
+
+                return Ok(());
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to delete daily recap"));
+            }
+        },
+        DailyRecap::List(_) => match Context::psql().select_guild_daily_recaps(guild_id).await {
+            Ok(recaps) if recaps.is_empty() => {
+                "No channel of this server has a daily recap enabled".to_owned()
+            }
+            Ok(recaps) => {
+                let mut content = String::from("Channels with a daily recap:");
+
+                for recap in recaps {
+                    let _ = write!(
+                        content,
+                        "\n- <#{channel}>: {mode} at {hour:0>2}:00 UTC{quiet}",
+                        channel = recap.channel_id,
+                        mode = mode_str(GameMode::from(recap.gamemode as u8)),
+                        hour = recap.hour,
+                        quiet = if recap.skip_quiet {
+                            " (skipping quiet days)"
+                        } else {
+                            ""
+                        },
+                    );
+                }
+
+                content
+            }
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to get daily recaps"));
+            }
+        },
+    };
+
+    let embed = EmbedBuilder::new().description(content);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!ctb",
+        GameMode::Mania => "osu!mania",
+    }
+}
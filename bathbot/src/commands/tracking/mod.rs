@@ -2,12 +2,16 @@ use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_macros::SlashCommand;
 use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::osu::TrackEmbedStyle;
 use bathbot_util::CowUtils;
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, Username};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
-pub use self::{track::*, track_list::*, untrack::*, untrack_all::*};
+pub use self::{
+    daily_recap::*, track::*, track_first_place::*, track_list::*, track_milestones::*,
+    track_startup_summary::*, track_style::*, untrack::*, untrack_all::*,
+};
 use crate::{
     Context,
     core::commands::prefix::{Args, ArgsNum},
@@ -15,8 +19,13 @@ use crate::{
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod daily_recap;
 mod track;
+mod track_first_place;
 mod track_list;
+mod track_milestones;
+mod track_startup_summary;
+mod track_style;
 mod untrack;
 mod untrack_all;
 
@@ -30,6 +39,14 @@ pub enum Track {
     Remove(TrackRemove),
     #[command(name = "list")]
     List(TrackList),
+    #[command(name = "style")]
+    Style(TrackStyle),
+    #[command(name = "milestones")]
+    Milestones(TrackMilestones),
+    #[command(name = "firstplace")]
+    FirstPlace(TrackFirstPlace),
+    #[command(name = "startupsummary")]
+    StartupSummary(TrackStartupSummary),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -37,7 +54,8 @@ pub enum Track {
     name = "add",
     desc = "Track top scores of a player",
     help = "Add users to the tracking list for this channel.\n\
-    If a tracked user gets a new top score, this channel will be notified about it."
+    If a tracked user gets a new top score, this channel will be notified about it.\n\
+    With `preview` enabled, the users are only resolved and shown without tracking them."
 )]
 pub struct TrackAdd {
     #[command(desc = "Choose a username to be tracked")]
@@ -80,6 +98,8 @@ pub struct TrackAdd {
     name4: Option<String>,
     #[command(desc = "Specify a fifth username")]
     name5: Option<String>,
+    #[command(desc = "Only show what would be tracked without tracking anyone")]
+    preview: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -118,6 +138,66 @@ pub struct TrackRemoveAll {
 )]
 pub struct TrackList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "style",
+    desc = "Choose how top score notifications look in this channel",
+    help = "Choose how top score notifications look in this channel.\n\
+    - `Full embed`: The detailed score embed (default)\n\
+    - `Compact embed`: A single line with user, map, mods, pp, and personal best index\n\
+    - `Text without embed`: Same as compact but as plain message"
+)]
+pub struct TrackStyle {
+    #[command(desc = "Specify the notification style")]
+    style: TrackEmbedStyle,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "milestones",
+    desc = "Notify this channel when tracked players reach milestones",
+    help = "Notify this channel when tracked players reach milestones.\n\
+    Milestones are every 10,000 plays, every 10 billion ranked score, \
+    and every 1 million total hits. \
+    Multiple milestones reached at once are combined into a single message."
+)]
+pub struct TrackMilestones {
+    #[command(desc = "Whether milestone notifications should be sent")]
+    enable: bool,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "firstplace",
+    desc = "Highlight top scores that take #1 on their map",
+    help = "Highlight top scores that take #1 on their map.\n\
+    New top scores with enough pp are checked against the map's global leaderboard. \
+    If the score is #1, the notification gets a crown and names the runner-up \
+    alongside the score margin.\n\
+    The check is only done above a pp threshold to save requests, the default is 300pp."
+)]
+pub struct TrackFirstPlace {
+    #[command(
+        min_value = 0.0,
+        desc = "Minimum pp for the leaderboard check, leave empty to reset to 300pp"
+    )]
+    min_pp: Option<f32>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "startupsummary",
+    desc = "Send a summary of tracked players when the bot starts up",
+    help = "Send a summary of tracked players to this channel when the bot starts up.\n\
+    The summary lists each tracked player alongside the time of their last update. \
+    If more than 20 players are tracked, only their amount is shown.\n\
+    Summaries are spread over several minutes after startup so they may arrive with a delay."
+)]
+pub struct TrackStartupSummary {
+    #[command(desc = "Whether the startup summary should be sent")]
+    enable: bool,
+}
+
 async fn slash_track(mut command: InteractionCommand) -> Result<()> {
     match Track::from_interaction(command.input_data())? {
         Track::Add(add) => track((&mut command).into(), add.into()).await,
@@ -126,6 +206,12 @@ async fn slash_track(mut command: InteractionCommand) -> Result<()> {
             untrackall((&mut command).into(), all.mode.map(GameMode::from)).await
         }
         Track::List(_) => tracklist((&mut command).into()).await,
+        Track::Style(args) => trackstyle((&mut command).into(), args.style).await,
+        Track::Milestones(args) => trackmilestones((&mut command).into(), args.enable).await,
+        Track::FirstPlace(args) => trackfirstplace((&mut command).into(), args.min_pp).await,
+        Track::StartupSummary(args) => {
+            trackstartupsummary((&mut command).into(), args.enable).await
+        }
     }
 }
 
@@ -172,12 +258,14 @@ struct TrackArgs {
     min_combo_percent: Option<f32>,
     max_combo_percent: Option<f32>,
     more_names: Vec<String>,
+    preview: bool,
 }
 
 impl TrackArgs {
     async fn args(mode: Option<GameMode>, args: Args<'_>) -> Result<Self, Cow<'static, str>> {
         let mut name = None;
         let mut more_names = Vec::new();
+        let mut preview = false;
 
         let mut min_index = match args.num {
             ArgsNum::Value(n) => Some(n.min(100) as u8),
@@ -198,9 +286,20 @@ impl TrackArgs {
                             return Err(content.into());
                         }
                     },
+                    "preview" | "p" => match value {
+                        "true" | "t" | "1" => preview = true,
+                        "false" | "f" | "0" => preview = false,
+                        _ => {
+                            let content =
+                                "Failed to parse `preview`. Must be either `true` or `false`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
-                            "Unrecognized option `{key}`.\nAvailable options are: `limit`."
+                            "Unrecognized option `{key}`.\n\
+                            Available options are: `limit` or `preview`."
                         );
 
                         return Err(content.into());
@@ -228,6 +327,7 @@ impl TrackArgs {
             max_combo_percent: None,
             more_names,
             mode,
+            preview,
         };
 
         Ok(args)
@@ -249,6 +349,7 @@ impl From<TrackAdd> for TrackArgs {
             name3,
             name4,
             name5,
+            preview,
         } = add;
 
         let mut more_names = Vec::new();
@@ -279,6 +380,7 @@ impl From<TrackAdd> for TrackArgs {
             max_pp,
             min_combo_percent,
             max_combo_percent,
+            preview: preview.unwrap_or(false),
         }
     }
 }
@@ -297,6 +399,7 @@ impl From<TrackRemoveUser> for TrackArgs {
             max_pp: None,
             min_combo_percent: None,
             max_combo_percent: None,
+            preview: false,
         }
     }
 }
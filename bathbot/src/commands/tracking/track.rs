@@ -1,9 +1,9 @@
-use std::fmt::Write;
+use std::{collections::HashMap, fmt::Write};
 
 use bathbot_macros::command;
 use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, fields};
 use eyre::{Report, Result};
-use rosu_v2::prelude::{GameMode, OsuError};
+use rosu_v2::prelude::{GameMode, OsuError, Username};
 
 use super::TrackArgs;
 use crate::{
@@ -25,6 +25,7 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         max_pp,
         min_combo_percent,
         max_combo_percent,
+        preview,
     } = args;
 
     more_names.push(name);
@@ -57,6 +58,10 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
         .with_pp(min_pp, max_pp)
         .with_combo_percent(min_combo_percent, max_combo_percent);
 
+    if preview {
+        return track_preview(orig, users, mode, params).await;
+    }
+
     let channel = orig.channel_id();
     let mut success = Vec::with_capacity(users.len());
     let mut failure = Vec::new();
@@ -144,6 +149,66 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     Ok(())
 }
 
+/// Show the resolved users alongside their latest top score without tracking
+/// anyone.
+async fn track_preview(
+    orig: CommandOrigin<'_>,
+    users: HashMap<Username, u32>,
+    mode: GameMode,
+    params: TrackEntryParams,
+) -> Result<()> {
+    let mut description = String::with_capacity(users.len() * 64);
+
+    for (username, user_id) in users {
+        let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+        let scores_fut = Context::osu_scores().top(false).limit(100).exec(user_args);
+
+        let _ = write!(description, "- `{username}` (`{user_id}`): ");
+
+        match scores_fut.await {
+            Ok(scores) => match scores.iter().map(|score| score.ended_at).max() {
+                Some(latest) => {
+                    let _ = writeln!(
+                        description,
+                        "{count} top scores, latest one <t:{timestamp}:R>",
+                        count = scores.len(),
+                        timestamp = latest.unix_timestamp(),
+                    );
+                }
+                None => description.push_str("no top scores yet\n"),
+            },
+            Err(err) => {
+                warn!(?err, "Failed to request top scores for tracking preview");
+                description.push_str("failed to retrieve top scores\n");
+            }
+        }
+    }
+
+    description.push_str("\nNothing was tracked. Run it again without `preview` to confirm.");
+
+    let value = format!(
+        "`Index: {index}` | `PP: {pp}pp` | `Combo percent: {combo_percent}%`",
+        index = params.index(),
+        pp = params.pp(),
+        combo_percent = params.combo_percent(),
+    );
+
+    let fields = fields!["Parameters:".to_owned(), value, false];
+
+    let footer = FooterBuilder::new("").icon_url(Emote::from(mode).url());
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .fields(fields)
+        .footer(footer)
+        .title("Top score tracking preview");
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
 #[command]
 #[desc("Track osu!standard user top scores")]
 #[help(
@@ -155,9 +220,10 @@ pub(super) async fn track(orig: CommandOrigin<'_>, args: TrackArgs) -> Result<()
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `track limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To only see what would be tracked without tracking anyone, specify `preview=true`."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -187,9 +253,10 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackmania limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To only see what would be tracked without tracking anyone, specify `preview=true`."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -219,9 +286,10 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `tracktaiko limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To only see what would be tracked without tracking anyone, specify `preview=true`."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -251,9 +319,10 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     a new score in his top 42.\n\
     Alternatively, you can provide a limit by specifying `limit=number`, \
     e.g. `trackctb limit=42 badewanne3`.\n\
-    The limit must be between 1 and 100, **defaults to 50** if none is given."
+    The limit must be between 1 and 100, **defaults to 50** if none is given.\n\
+    To only see what would be tracked without tracking anyone, specify `preview=true`."
 )]
-#[usage("[limit=number] [username1] [username2] ...")]
+#[usage("[limit=number] [preview=true/false] [username1] [username2] ...")]
 #[examples(
     "badewanne3 \"freddie benson\" peppy limit=23",
     "limit=45 cookiezi whitecat",
@@ -0,0 +1,28 @@
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::{core::commands::CommandOrigin, tracking::OsuTracking};
+
+pub async fn trackfirstplace(orig: CommandOrigin<'_>, min_pp: Option<f32>) -> Result<()> {
+    if let Err(err) = OsuTracking::set_first_place_min_pp(orig.channel_id(), min_pp).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let min_pp = min_pp.unwrap_or(OsuTracking::DEFAULT_FIRST_PLACE_MIN_PP);
+
+    let description = format!(
+        "New top scores with at least {min_pp}pp will now be checked for \
+        being #1 on the map's leaderboard"
+    );
+
+    let embed = EmbedBuilder::new()
+        .title("First place tracking")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
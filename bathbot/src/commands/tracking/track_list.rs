@@ -45,7 +45,7 @@ pub async fn tracklist(orig: CommandOrigin<'_>) -> Result<()> {
     let mut users = match get_users(orig.channel_id(), entries).await {
         Ok(entries) => entries,
         Err(err) => {
-            let _ = orig.error(OSU_API_ISSUE).await;
+            let _ = orig.error_retryable(OSU_API_ISSUE).await;
 
             return Err(Report::new(err).wrap_err("failed to get users"));
         }
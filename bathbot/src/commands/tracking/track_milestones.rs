@@ -0,0 +1,28 @@
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::{core::commands::CommandOrigin, tracking::OsuTracking};
+
+pub async fn trackmilestones(orig: CommandOrigin<'_>, enable: bool) -> Result<()> {
+    if let Err(err) = OsuTracking::set_milestones(orig.channel_id(), enable).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = if enable {
+        "This channel will now be notified when tracked users reach milestones \
+        such as every 10,000 plays, 10 billion ranked score, or 1 million total hits"
+    } else {
+        "This channel will no longer be notified about milestones of tracked users"
+    };
+
+    let embed = EmbedBuilder::new()
+        .title("Milestone tracking")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
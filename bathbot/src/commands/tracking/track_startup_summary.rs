@@ -0,0 +1,28 @@
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::{core::commands::CommandOrigin, tracking::OsuTracking};
+
+pub async fn trackstartupsummary(orig: CommandOrigin<'_>, enable: bool) -> Result<()> {
+    if let Err(err) = OsuTracking::set_startup_summary(orig.channel_id(), enable).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = if enable {
+        "This channel will now receive a summary of its tracked users \
+        whenever the bot starts up"
+    } else {
+        "This channel will no longer receive a summary of its tracked users on startup"
+    };
+
+    let embed = EmbedBuilder::new()
+        .title("Tracking startup summary")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use bathbot_psql::model::osu::TrackEmbedStyle;
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::{core::commands::CommandOrigin, tracking::OsuTracking};
+
+pub async fn trackstyle(orig: CommandOrigin<'_>, style: TrackEmbedStyle) -> Result<()> {
+    if let Err(err) = OsuTracking::set_embed_style(orig.channel_id(), style).await {
+        let _ = orig.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let description = match style {
+        TrackEmbedStyle::Full => "Notifications in this channel will now use the full embed",
+        TrackEmbedStyle::Compact => "Notifications in this channel will now use a compact embed",
+        TrackEmbedStyle::Text => "Notifications in this channel will now be sent as plain text",
+    };
+
+    let embed = EmbedBuilder::new()
+        .title("Top score tracking")
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -7,7 +7,10 @@ use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
 use eyre::{Report, Result};
 use twilight_model::{
     guild::Permissions,
-    id::{Id, marker::RoleMarker},
+    id::{
+        Id,
+        marker::{RoleMarker, UserMarker},
+    },
 };
 
 use crate::{
@@ -72,9 +75,107 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                 return Err(err.wrap_err("failed to update guild config"));
             }
 
-            "Successfully added authority role. Authority roles now are: ".to_owned()
+            "Successfully added authority role.".to_owned()
+        }
+        AuthorityCommandKind::List => "Current authorities for this server:".to_owned(),
+        AuthorityCommandKind::AddUser(user_id) => {
+            let users = Context::guild_config()
+                .peek(guild_id, |config| config.authority_users.clone())
+                .await;
+
+            if users.len() >= 10 {
+                let content = "You can have at most 10 users per server setup as authorities.";
+
+                return orig.error_callback(content).await;
+            }
+
+            let f = |config: &mut GuildConfig| {
+                if !config.authority_users.contains(&user_id) {
+                    config.authority_users.push(user_id);
+                }
+            };
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            "Successfully added authority user.".to_owned()
+        }
+        AuthorityCommandKind::RemoveUser(user_id) => {
+            let author_id = orig.user_id()?;
+
+            let (users, roles) = Context::guild_config()
+                .peek(guild_id, |config| {
+                    (config.authority_users.clone(), config.authorities.clone())
+                })
+                .await;
+
+            if !users.contains(&user_id) {
+                let content = "The user was no authority user anyway";
+                let builder = MessageBuilder::new().embed(content);
+                orig.callback(builder).await?;
+
+                return Ok(());
+            }
+
+            // Make sure the author is still an authority after removing themselves
+            if user_id == author_id
+                && !(author_id == BotConfig::get().owner
+                    || cache
+                        .guild(guild_id)
+                        .await?
+                        .is_some_and(|guild| guild.owner_id == author_id))
+            {
+                let member_fut = cache.member(guild_id, author_id);
+
+                let member_roles = match member_fut.await {
+                    Ok(Some(member)) => member
+                        .roles
+                        .iter()
+                        .copied()
+                        .map(ArchivedId::to_native)
+                        .collect(),
+                    Ok(None) => Vec::new(),
+                    Err(err) => {
+                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                        return Err(Report::new(err));
+                    }
+                };
+
+                let still_authority = match cache.roles(guild_id, member_roles).await {
+                    Ok(cached_roles) => cached_roles.into_iter().any(|role| {
+                        Permissions::from_bits_truncate(role.permissions.to_native())
+                            .contains(Permissions::ADMINISTRATOR)
+                            || roles.iter().any(|&id| id == role.id)
+                    }),
+                    Err(err) => {
+                        let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                        return Err(Report::new(err));
+                    }
+                };
+
+                if !still_authority {
+                    let content = "You cannot remove yourself as authority user \
+                        if that would make you lose authority status.";
+
+                    return orig.error_callback(content).await;
+                }
+            }
+
+            let f = |config: &mut GuildConfig| config.authority_users.retain(|id| *id != user_id);
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            "Successfully removed authority user.".to_owned()
         }
-        AuthorityCommandKind::List => "Current authority roles for this server: ".to_owned(),
         AuthorityCommandKind::Remove(role_id) => {
             let author_id = orig.user_id()?;
             let roles = Context::guild_config()
@@ -142,7 +243,7 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                 return Err(err.wrap_err("failed to update guild config"));
             }
 
-            "Successfully removed authority role. Authority roles now are: ".to_owned()
+            "Successfully removed authority role.".to_owned()
         }
         AuthorityCommandKind::RemoveAll => {
             let author_id = orig.user_id()?;
@@ -261,15 +362,22 @@ pub async fn authorities(orig: CommandOrigin<'_>, args: AuthorityCommandKind) ->
                 return Err(err.wrap_err("failed to update guild config"));
             }
 
-            "Successfully changed the authority roles to: ".to_owned()
+            "Successfully changed the authority roles.".to_owned()
         }
     };
 
     // Send the message
-    let roles = Context::guild_config()
-        .peek(guild_id, |config| config.authorities.clone())
+    let (roles, users) = Context::guild_config()
+        .peek(guild_id, |config| {
+            (config.authorities.clone(), config.authority_users.clone())
+        })
         .await;
+
+    content.push_str("\n**Roles:** ");
     role_string(&roles, &mut content);
+    content.push_str("\n**Users:** ");
+    user_string(&users, &mut content);
+
     let builder = MessageBuilder::new().embed(content);
     orig.callback(builder).await?;
 
@@ -291,11 +399,28 @@ fn role_string(roles: &Authorities, content: &mut String) {
     }
 }
 
+fn user_string(users: &[Id<UserMarker>], content: &mut String) {
+    let mut iter = users.iter();
+
+    if let Some(first) = iter.next() {
+        content.reserve(users.len() * 22);
+        let _ = write!(content, "<@{first}>");
+
+        for user in iter {
+            let _ = write!(content, ", <@{user}>");
+        }
+    } else {
+        content.push_str("None");
+    }
+}
+
 pub enum AuthorityCommandKind {
     Add(Id<RoleMarker>),
+    AddUser(Id<UserMarker>),
     List,
     Remove(Id<RoleMarker>),
     RemoveAll,
+    RemoveUser(Id<UserMarker>),
     Replace(Vec<Id<RoleMarker>>),
 }
 
@@ -1,27 +1,41 @@
+use std::fmt::Write;
+
 use ::time::UtcOffset;
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::{ShowHideOption, TimezoneOption};
 use bathbot_psql::model::configs::{
-    ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
+    Lang, ListSize, NumberFormat, OsuUserId, OsuUsername, ResponseVisibility, Retries, ScoreData,
+    UserConfig,
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
 use bathbot_util::constants::GENERAL_ISSUE;
-#[cfg(feature = "server")]
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result};
 use rosu_v2::prelude::GameMode;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
-use twilight_model::id::{Id, marker::UserMarker};
+#[cfg(feature = "server")]
+use twilight_model::id::marker::GuildMarker;
+use twilight_model::{
+    application::interaction::application_command::{
+        CommandData, CommandDataOption, CommandOptionValue,
+    },
+    id::{Id, marker::UserMarker},
+};
 
 use super::{SkinValidation, ValidationStatus};
 use crate::{
     Context,
     embeds::{ConfigEmbed, EmbedData},
-    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand, retry_components},
 };
 #[cfg(feature = "server")]
-use crate::{core::BotConfig, util::Emote};
+use crate::{
+    core::BotConfig,
+    manager::redis::osu::UserArgsSlim,
+    tracking::{OsuTracking, TrackEntryParams},
+    util::Emote,
+};
 
 #[cfg(feature = "server")]
 #[derive(CommandModel, CreateCommand, Default, SlashCommand)]
@@ -93,6 +107,33 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Should responses to commands like top, profile, or recent only be visible to you?",
+        help = "Should responses to `/top`, `/profile`, `/recent`, and `/rank` \
+        only be visible to you?\n\
+        If a server forces public responses through `/serverconfig`, that takes \
+        precedence over this setting."
+    )]
+    response_visibility: Option<ResponseVisibility>,
+    #[command(
+        desc = "Specify how large numbers should be formatted",
+        help = "Specify the thousands and decimal separators for large numbers.\n\
+        If not specified, the server's number format is used."
+    )]
+    number_format: Option<NumberFormat>,
+    #[command(
+        desc = "Specify the language of error messages",
+        help = "Specify the language of error messages such as missing links or failed requests.\n\
+        If not specified, the server's language is used, falling back to English."
+    )]
+    lang: Option<Lang>,
+    #[command(
+        desc = "Show your last command that failed due to the osu!api to retry it",
+        help = "Show your last command that failed due to the osu!api within the last five \
+        minutes alongside a button to retry it.\n\
+        If this is set to `True`, all other options are ignored."
+    )]
+    retry_failed: Option<bool>,
 }
 
 // FIXME: Some attribute command does not register the #[cfg(feature = "")]
@@ -147,6 +188,33 @@ pub struct Config {
         They have a different score and grade calculation and only lazer adds the new mods."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Should responses to commands like top, profile, or recent only be visible to you?",
+        help = "Should responses to `/top`, `/profile`, `/recent`, and `/rank` \
+        only be visible to you?\n\
+        If a server forces public responses through `/serverconfig`, that takes \
+        precedence over this setting."
+    )]
+    response_visibility: Option<ResponseVisibility>,
+    #[command(
+        desc = "Specify how large numbers should be formatted",
+        help = "Specify the thousands and decimal separators for large numbers.\n\
+        If not specified, the server's number format is used."
+    )]
+    number_format: Option<NumberFormat>,
+    #[command(
+        desc = "Specify the language of error messages",
+        help = "Specify the language of error messages such as missing links or failed requests.\n\
+        If not specified, the server's language is used, falling back to English."
+    )]
+    lang: Option<Lang>,
+    #[command(
+        desc = "Show your last command that failed due to the osu!api to retry it",
+        help = "Show your last command that failed due to the osu!api within the last five \
+        minutes alongside a button to retry it.\n\
+        If this is set to `True`, all other options are ignored."
+    )]
+    retry_failed: Option<bool>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -202,8 +270,16 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        response_visibility,
+        number_format,
+        lang,
+        retry_failed,
     } = config;
 
+    if retry_failed == Some(true) {
+        return failed_command(&command).await;
+    }
+
     if let Some(ref skin_url) = skin_url {
         match SkinValidation::check(&command, skin_url).await? {
             ValidationStatus::Continue => {}
@@ -251,6 +327,18 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(response_visibility) = response_visibility {
+        config.response_visibility = Some(response_visibility);
+    }
+
+    if let Some(number_format) = number_format {
+        config.number_format = Some(number_format);
+    }
+
+    if let Some(lang) = lang {
+        config.lang = Some(lang);
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -362,10 +450,13 @@ async fn handle_both_links(
     let builder = MessageBuilder::new().embed(embed);
     let fut = async { tokio::try_join!(osu_fut, twitch_fut) };
 
+    let mut linked = None;
+
     let twitch_name = match handle_ephemeral(command, builder, fut).await {
         Some(Ok((osu, twitch))) => {
             config.osu = Some(osu.user_id);
             config.twitch_id = Some(twitch.user_id);
+            linked = Some((osu.user_id, osu.mode));
 
             tokio::spawn(async move {
                 Context::osu_user().store(&osu, osu.mode).await;
@@ -388,6 +479,10 @@ async fn handle_both_links(
         return HandleResult::Err(err);
     }
 
+    if let Some((user_id, mode)) = linked {
+        tokio::spawn(auto_track(command.guild_id, user_id, mode));
+    }
+
     HandleResult::TwitchName(twitch_name)
 }
 
@@ -441,15 +536,16 @@ async fn handle_osu_link(
 
     let builder = MessageBuilder::new().embed(embed);
 
-    config.osu = match handle_ephemeral(command, builder, fut).await {
+    let mode = match handle_ephemeral(command, builder, fut).await {
         Some(Ok(user)) => {
-            let user_id = user.user_id;
+            let mode = user.mode;
+            config.osu = Some(user.user_id);
 
             tokio::spawn(async move {
                 Context::osu_user().store(&user, user.mode).await;
             });
 
-            Some(user_id)
+            mode
         }
         Some(Err(err)) => return HandleResult::Err(err),
         None => return HandleResult::Done,
@@ -485,9 +581,65 @@ async fn handle_osu_link(
         return HandleResult::Err(err);
     }
 
+    if let Some(user_id) = config.osu {
+        tokio::spawn(auto_track(command.guild_id, user_id, mode));
+    }
+
     HandleResult::TwitchName(twitch_name)
 }
 
+/// Track a freshly linked user in the guild's auto-track channel if the guild
+/// enabled it and the user is not tracked there already.
+#[cfg(feature = "server")]
+async fn auto_track(guild_id: Option<Id<GuildMarker>>, user_id: u32, mode: GameMode) {
+    let Some(guild_id) = guild_id else { return };
+
+    let channel_fut = Context::guild_config().peek(guild_id, |config| config.auto_track_channel);
+
+    let Some(channel) = channel_fut.await else {
+        return;
+    };
+
+    match OsuTracking::tracked_users_in_channel(channel).await {
+        Ok(entries)
+            if entries
+                .iter()
+                .any(|(id, m, _)| *id == user_id && *m == mode) =>
+        {
+            return;
+        }
+        Ok(_) => {}
+        Err(err) => {
+            warn!(?err, "Failed to get tracked users for auto-track");
+
+            return;
+        }
+    }
+
+    let require = match OsuTracking::add_user(user_id, mode, channel, TrackEntryParams::new()).await
+    {
+        Ok(Some(require)) => require,
+        Ok(None) => return,
+        Err(err) => {
+            warn!(?err, "Failed to auto-track linked user");
+
+            return;
+        }
+    };
+
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+    let scores_fut = Context::osu_scores().top(false).limit(100).exec(user_args);
+
+    match scores_fut.await {
+        Ok(scores) => {
+            if let Err(err) = require.callback(&scores).await {
+                warn!(?err, "Failed to auto-track linked user");
+            }
+        }
+        Err(err) => warn!(?err, "Failed to request top scores for auto-track"),
+    }
+}
+
 #[cfg(feature = "server")]
 async fn handle_ephemeral<T>(
     command: &InteractionCommand,
@@ -582,6 +734,9 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        response_visibility,
+        number_format,
+        lang,
     } = config;
 
     UserConfig {
@@ -594,6 +749,9 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        response_visibility,
+        number_format,
+        lang,
     }
 }
 
@@ -603,3 +761,65 @@ enum HandleResult {
     Done,
     Err(Report),
 }
+
+/// Show the author's last command that failed due to the osu!api alongside a
+/// button to retry it.
+async fn failed_command(command: &InteractionCommand) -> Result<()> {
+    let user_id = command.user_id()?;
+
+    let Some((interaction_id, failed)) = Context::get().last_failed_command(user_id) else {
+        let content = "You have no command that failed within the last five minutes";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let description = format!(
+        "Your last command that failed due to the osu!api:\n`{}`",
+        command_string(&failed.data)
+    );
+
+    let embed = EmbedBuilder::new().description(description);
+
+    let builder = MessageBuilder::new()
+        .embed(embed)
+        .components(retry_components(interaction_id));
+
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Display a command the way it was invoked e.g. `/rs name:badewanne3`
+fn command_string(data: &CommandData) -> String {
+    fn push_options(content: &mut String, options: &[CommandDataOption]) {
+        for option in options {
+            let name = &option.name;
+
+            let _ = match option.value {
+                CommandOptionValue::SubCommand(ref options)
+                | CommandOptionValue::SubCommandGroup(ref options) => {
+                    let _ = write!(content, " {name}");
+                    push_options(content, options);
+
+                    continue;
+                }
+                CommandOptionValue::Attachment(_) => write!(content, " {name}:<attachment>"),
+                CommandOptionValue::Boolean(value) => write!(content, " {name}:{value}"),
+                CommandOptionValue::Channel(id) => write!(content, " {name}:{id}"),
+                CommandOptionValue::Focused(ref value, _) => write!(content, " {name}:{value}"),
+                CommandOptionValue::Integer(value) => write!(content, " {name}:{value}"),
+                CommandOptionValue::Mentionable(id) => write!(content, " {name}:{id}"),
+                CommandOptionValue::Number(value) => write!(content, " {name}:{value}"),
+                CommandOptionValue::Role(id) => write!(content, " {name}:{id}"),
+                CommandOptionValue::String(ref value) => write!(content, " {name}:{value}"),
+                CommandOptionValue::User(id) => write!(content, " {name}:{id}"),
+            };
+        }
+    }
+
+    let mut content = format!("/{}", data.name);
+    push_options(&mut content, &data.options);
+
+    content
+}
@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::configs::GuildConfig;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::core::{
+    Context,
+    commands::{CommandOrigin, checks::canonical_command_name, prefix::PrefixCommands},
+};
+
+/// Commands that can't be disabled so the configuration can always be undone.
+const PROTECTED_COMMANDS: &[&str] = &["serverconfig", "help"];
+
+pub async fn disabled_commands(orig: CommandOrigin<'_>, args: DisabledCommandsKind) -> Result<()> {
+    let guild_id = orig.guild_id().unwrap();
+
+    let mut content = match args {
+        DisabledCommandsKind::Disable(name) => {
+            let name = normalize_name(&name);
+
+            let Some(name) = canonical_command_name(&name) else {
+                let content = format!("There is no command called `{name}`");
+
+                return orig.error_callback(content).await;
+            };
+
+            if is_protected(name) {
+                let content = format!("The `{name}` command cannot be disabled");
+
+                return orig.error_callback(content).await;
+            }
+
+            // Checking the limit within the update prevents concurrent
+            // invocations from exceeding it
+            let f = |config: &mut GuildConfig| {
+                let disabled = &mut config.disabled_commands;
+
+                if disabled.iter().any(|entry| entry == name) {
+                    return true;
+                }
+
+                if disabled.len() >= GuildConfig::MAX_DISABLED_COMMANDS {
+                    return false;
+                }
+
+                disabled.push(name.to_owned());
+
+                true
+            };
+
+            match Context::guild_config().update(guild_id, f).await {
+                Ok(true) => format!("Successfully disabled `{name}` in this server."),
+                Ok(false) => {
+                    let content = format!(
+                        "You can disable at most {} commands per server",
+                        GuildConfig::MAX_DISABLED_COMMANDS
+                    );
+
+                    return orig.error_callback(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to update guild config"));
+                }
+            }
+        }
+        DisabledCommandsKind::Enable(name) => {
+            let name = normalize_name(&name);
+
+            // Unknown names may still be stored if the command was removed since
+            let name = canonical_command_name(&name).map_or(name, str::to_owned);
+
+            let f = |config: &mut GuildConfig| {
+                let len = config.disabled_commands.len();
+                config
+                    .disabled_commands
+                    .retain(|disabled| *disabled != name);
+
+                len != config.disabled_commands.len()
+            };
+
+            match Context::guild_config().update(guild_id, f).await {
+                Ok(true) => format!("Successfully enabled `{name}` in this server."),
+                Ok(false) => format!("The `{name}` command was not disabled anyway."),
+                Err(err) => {
+                    let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to update guild config"));
+                }
+            }
+        }
+        DisabledCommandsKind::List => "Currently disabled commands in this server:".to_owned(),
+    };
+
+    let disabled = Context::guild_config()
+        .peek(guild_id, |config| config.disabled_commands.clone())
+        .await;
+
+    content.push_str("\n**Disabled:** ");
+    let mut iter = disabled.iter();
+
+    if let Some(first) = iter.next() {
+        let _ = write!(content, "`{first}`");
+
+        for name in iter {
+            let _ = write!(content, ", `{name}`");
+        }
+    } else {
+        content.push_str("None");
+    }
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.callback(builder).await?;
+
+    Ok(())
+}
+
+/// Lowercase the name and strip a leading slash.
+fn normalize_name(name: &str) -> String {
+    let name = name.trim();
+
+    name.strip_prefix('/').unwrap_or(name).to_ascii_lowercase()
+}
+
+/// Whether the name or any alias of its prefix command is protected.
+fn is_protected(name: &str) -> bool {
+    PROTECTED_COMMANDS.contains(&name)
+        || PrefixCommands::get().command(name).is_some_and(|cmd| {
+            cmd.names
+                .iter()
+                .any(|alias| PROTECTED_COMMANDS.contains(alias))
+        })
+}
+
+pub enum DisabledCommandsKind {
+    Disable(String),
+    Enable(String),
+    List,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_name;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_name(" /BG "), "bg");
+        assert_eq!(normalize_name("recent"), "recent");
+    }
+}
@@ -8,7 +8,10 @@ use eyre::{Report, Result};
 use rosu_pp::model::beatmap::BeatmapAttributes;
 use rosu_v2::{
     model::{GameMode, Grade},
-    prelude::{GameModIntermode, GameMods, RankStatus, Score, ScoreStatistics},
+    prelude::{
+        GameMod, GameModIntermode, GameMods, GameModsIntermode, OsuError, RankStatus, Score,
+        ScoreStatistics,
+    },
 };
 use time::OffsetDateTime;
 use twilight_interactions::command::{CommandModel, CreateCommand};
@@ -24,7 +27,7 @@ use crate::{
     util::{
         Authored, InteractionCommandExt,
         interaction::InteractionCommand,
-        osu::{IfFc, PersonalBestIndex},
+        osu::{IfFc, PersonalBestIndex, if_passed_pp, pp_percentile},
         query::{FilterCriteria, Searchable, TopCriteria},
     },
 };
@@ -96,7 +99,9 @@ async fn edit(command: &mut InteractionCommand) -> Result<()> {
         },
     };
 
-    let settings = config.score_embed.unwrap_or_default();
+    let settings = Context::guild_config()
+        .score_embed_settings(command.guild_id(), config.score_embed)
+        .await;
 
     exec(command, settings, score_data).await
 }
@@ -304,7 +309,10 @@ impl ScoreEmbedDataWrap {
 
         let score = ScoreSlim::new(score, pp);
 
-        let if_fc_pp = IfFc::new(&score, &map).await.map(|if_fc| if_fc.pp);
+        let if_fc = IfFc::new(&score, &map).await;
+        let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+        let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(score.mode));
+        let if_passed_pp = if_passed_pp(&score, &map).await;
 
         Self {
             inner: ScoreEmbedDataStatus::Full(ScoreEmbedData {
@@ -317,7 +325,12 @@ impl ScoreEmbedDataWrap {
                 miss_analyzer: None,
                 pb_idx: Some(ScoreEmbedDataPersonalBest::from_index(pb_idx)),
                 global_idx,
+                mod_idx: None,
                 if_fc_pp,
+                if_fc_acc,
+                if_passed_pp,
+                pp_percentile: None,
+                map_pb: None,
                 #[cfg(feature = "twitch")]
                 twitch: None,
             }),
@@ -326,8 +339,9 @@ impl ScoreEmbedDataWrap {
 
     /// Returns the inner [`ScoreEmbedData`].
     ///
-    /// If the data has not yet been calculated, it will do so first.
-    pub async fn get_mut(&mut self) -> Result<&mut ScoreEmbedData> {
+    /// If the data has not yet been calculated, it will do so first while
+    /// skipping values that the given settings don't show.
+    pub async fn get_mut(&mut self, settings: &ScoreEmbedSettings) -> Result<&mut ScoreEmbedData> {
         let data = match self.inner {
             ScoreEmbedDataStatus::Raw(ref mut raw) => {
                 let raw = raw
@@ -336,7 +350,7 @@ impl ScoreEmbedDataWrap {
 
                 self.inner = ScoreEmbedDataStatus::Empty;
 
-                raw.into_full().await?
+                raw.into_full(settings).await?
             }
             ScoreEmbedDataStatus::Half(ref mut half) => {
                 let half = half
@@ -345,7 +359,7 @@ impl ScoreEmbedDataWrap {
 
                 self.inner = ScoreEmbedDataStatus::Empty;
 
-                half.into_full().await
+                half.into_full(settings).await
             }
             ScoreEmbedDataStatus::Full(ref mut data) => return Ok(data),
             ScoreEmbedDataStatus::Empty => bail!("Empty data"),
@@ -405,6 +419,7 @@ impl ScoreEmbedDataStatus {
     }
 }
 
+#[derive(Clone)]
 pub struct ScoreEmbedDataHalf {
     pub user_id: u32,
     pub score: ScoreSlim,
@@ -470,7 +485,7 @@ impl ScoreEmbedDataHalf {
         }
     }
 
-    async fn into_full(self) -> ScoreEmbedData {
+    async fn into_full(self, settings: &ScoreEmbedSettings) -> ScoreEmbedData {
         let global_idx_fut = async {
             if !matches!(
                 self.map.status(),
@@ -480,7 +495,7 @@ impl ScoreEmbedDataHalf {
                     | RankStatus::Approved
             ) || self.score.grade == Grade::F
             {
-                return None;
+                return (None, None);
             }
 
             let map_lb_fut = Context::osu_scores().map_leaderboard(
@@ -496,14 +511,18 @@ impl ScoreEmbedDataHalf {
                 Err(err) => {
                     warn!(?err, "Failed to get global scores");
 
-                    return None;
+                    return (None, None);
                 }
             };
 
-            scores
+            let global_idx = scores
                 .iter()
                 .position(|s| s.user_id == self.user_id && self.score.is_eq(s))
-                .map(|idx| idx + 1)
+                .map(|idx| idx + 1);
+
+            let percentile = pp_percentile(&self.score, self.user_id, self.map.status(), &scores);
+
+            (global_idx, percentile)
         };
 
         let miss_analyzer_fut = async {
@@ -534,12 +553,34 @@ impl ScoreEmbedDataHalf {
             }
         };
 
+        let mod_idx_fut = mod_leaderboard_idx(
+            &self.score,
+            &self.map,
+            self.user_id,
+            self.legacy_scores,
+            settings,
+        );
+        let map_pb_fut = MapPersonalBest::new(
+            &self.score,
+            &self.map,
+            self.user_id,
+            self.legacy_scores,
+            settings,
+        );
         let if_fc_fut = IfFc::new(&self.score, &self.map);
+        let if_passed_fut = if_passed_pp(&self.score, &self.map);
+
+        let ((global_idx, pp_percentile), mod_idx, map_pb, if_fc, if_passed_pp, miss_analyzer) = tokio::join!(
+            global_idx_fut,
+            mod_idx_fut,
+            map_pb_fut,
+            if_fc_fut,
+            if_passed_fut,
+            miss_analyzer_fut
+        );
 
-        let (global_idx, if_fc, miss_analyzer) =
-            tokio::join!(global_idx_fut, if_fc_fut, miss_analyzer_fut);
-
-        let if_fc_pp = if_fc.map(|if_fc| if_fc.pp);
+        let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+        let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(self.score.mode));
 
         let replay_score_id = (self.with_render && self.has_replay && !self.score.is_legacy)
             .then_some(self.score.score_id);
@@ -554,7 +595,12 @@ impl ScoreEmbedDataHalf {
             miss_analyzer,
             pb_idx: self.pb_idx,
             global_idx,
+            mod_idx,
             if_fc_pp,
+            if_fc_acc,
+            if_passed_pp,
+            pp_percentile,
+            map_pb,
             #[cfg(feature = "twitch")]
             twitch: None,
         }
@@ -591,11 +637,78 @@ pub struct ScoreEmbedData {
     pub miss_analyzer: Option<MissAnalyzerData>,
     pub pb_idx: Option<ScoreEmbedDataPersonalBest>,
     pub global_idx: Option<usize>,
+    /// Position on the leaderboard filtered by the score's mods
+    pub mod_idx: Option<usize>,
     pub if_fc_pp: Option<f32>,
+    /// Accuracy of a choked score if it had been an FC
+    pub if_fc_acc: Option<f32>,
+    /// Estimated pp of a failed score if it had been finished
+    pub if_passed_pp: Option<f32>,
+    /// Percentage of the map's top scores with less pp
+    pub pp_percentile: Option<f32>,
+    /// How the score compares to the user's best score on the map
+    pub map_pb: Option<MapPersonalBest>,
     #[cfg(feature = "twitch")]
     pub twitch: Option<Arc<TwitchData>>,
 }
 
+/// How a score compares to the user's best score on its map
+pub enum MapPersonalBest {
+    /// The score is the user's best score on the map
+    Current,
+    /// The user has a better score on the map
+    Previous { pp: Option<f32>, accuracy: f32 },
+}
+
+impl MapPersonalBest {
+    /// Compare the score with the user's best score on the map.
+    ///
+    /// Only available for passes on maps with a leaderboard and only requested
+    /// if the settings show it.
+    async fn new(
+        score: &ScoreSlim,
+        map: &OsuMap,
+        user_id: u32,
+        legacy_scores: bool,
+        settings: &ScoreEmbedSettings,
+    ) -> Option<Self> {
+        if !settings.show_map_pb
+            || score.grade == Grade::F
+            || !matches!(
+                map.status(),
+                RankStatus::Ranked
+                    | RankStatus::Loved
+                    | RankStatus::Qualified
+                    | RankStatus::Approved
+            )
+        {
+            return None;
+        }
+
+        let best_fut = Context::osu_scores().user_on_map_single(
+            user_id,
+            map.map_id(),
+            score.mode,
+            None,
+            legacy_scores,
+        );
+
+        match best_fut.await {
+            Ok(best) if score.is_eq(&best.score) => Some(Self::Current),
+            Ok(best) => Some(Self::Previous {
+                pp: best.score.pp,
+                accuracy: best.score.accuracy,
+            }),
+            Err(OsuError::NotFound) => None,
+            Err(err) => {
+                warn!(?err, "Failed to get best score on map");
+
+                None
+            }
+        }
+    }
+}
+
 #[cfg(feature = "twitch")]
 pub enum TwitchData {
     Vod {
@@ -770,7 +883,7 @@ impl ScoreEmbedDataRaw {
         }
     }
 
-    async fn into_full(self) -> Result<ScoreEmbedData> {
+    async fn into_full(self, settings: &ScoreEmbedSettings) -> Result<ScoreEmbedData> {
         let map_id = self.map_id;
         let checksum = self.checksum.as_deref();
 
@@ -827,7 +940,7 @@ impl ScoreEmbedDataRaw {
                     | RankStatus::Approved
             ) || score.grade == Grade::F
             {
-                return None;
+                return (None, None);
             }
 
             let map_lb_fut = Context::osu_scores().map_leaderboard(
@@ -843,14 +956,18 @@ impl ScoreEmbedDataRaw {
                 Err(err) => {
                     warn!(?err, "Failed to get global scores");
 
-                    return None;
+                    return (None, None);
                 }
             };
 
-            scores
+            let global_idx = scores
                 .iter()
                 .position(|s| s.user_id == self.user_id && score.is_eq(s))
-                .map(|idx| idx + 1)
+                .map(|idx| idx + 1);
+
+            let percentile = pp_percentile(&score, self.user_id, map.status(), &scores);
+
+            (global_idx, percentile)
         };
 
         let miss_analyzer_fut = async {
@@ -881,12 +998,24 @@ impl ScoreEmbedDataRaw {
             }
         };
 
+        let mod_idx_fut =
+            mod_leaderboard_idx(&score, &map, self.user_id, self.legacy_scores, settings);
+        let map_pb_fut =
+            MapPersonalBest::new(&score, &map, self.user_id, self.legacy_scores, settings);
         let if_fc_fut = IfFc::new(&score, &map);
+        let if_passed_fut = if_passed_pp(&score, &map);
+
+        let ((global_idx, pp_percentile), mod_idx, map_pb, if_fc, if_passed_pp, miss_analyzer) = tokio::join!(
+            global_idx_fut,
+            mod_idx_fut,
+            map_pb_fut,
+            if_fc_fut,
+            if_passed_fut,
+            miss_analyzer_fut
+        );
 
-        let (global_idx, if_fc, miss_analyzer) =
-            tokio::join!(global_idx_fut, if_fc_fut, miss_analyzer_fut);
-
-        let if_fc_pp = if_fc.map(|if_fc| if_fc.pp);
+        let if_fc_pp = if_fc.as_ref().map(|if_fc| if_fc.pp);
+        let if_fc_acc = if_fc.and_then(|if_fc| if_fc.accuracy(score.mode));
 
         let replay_score_id =
             (self.with_render && self.has_replay && !self.is_legacy).then_some(score.score_id);
@@ -907,7 +1036,12 @@ impl ScoreEmbedDataRaw {
             miss_analyzer,
             pb_idx,
             global_idx,
+            mod_idx,
             if_fc_pp,
+            if_fc_acc,
+            if_passed_pp,
+            pp_percentile,
+            map_pb,
             #[cfg(feature = "twitch")]
             twitch: self.twitch,
         })
@@ -935,24 +1069,78 @@ impl<'m> PpAttrs<'m> {
             .mods(mods.to_owned())
             .lazer(lazer);
 
-        let attrs = calc.performance().await;
+        let map_pp = calc.max_pp().await;
 
         let max_pp = pp
             .filter(|_| grade.eq_letter(Grade::X) && mode != GameMode::Mania)
-            .unwrap_or(attrs.pp() as f32);
-
-        let stars = attrs.stars() as f32;
-        let max_combo = attrs.max_combo();
+            .unwrap_or(map_pp.max_pp);
 
         Self {
             calc,
-            stars,
-            max_combo,
+            stars: map_pp.stars,
+            max_combo: map_pp.max_combo as u32,
             max_pp,
         }
     }
 }
 
+/// Position of the score on the map leaderboard filtered by the score's mods.
+///
+/// Only available for modded passes on maps with a leaderboard and only
+/// requested if the settings show it.
+async fn mod_leaderboard_idx(
+    score: &ScoreSlim,
+    map: &OsuMap,
+    user_id: u32,
+    legacy_scores: bool,
+    settings: &ScoreEmbedSettings,
+) -> Option<usize> {
+    if !settings.show_mod_idx
+        || score.mods.is_empty()
+        || score.grade == Grade::F
+        || !matches!(
+            map.status(),
+            RankStatus::Ranked | RankStatus::Loved | RankStatus::Qualified | RankStatus::Approved
+        )
+    {
+        return None;
+    }
+
+    let ctx = Context::get();
+
+    if let Some(idx) = ctx.mod_leaderboard_idx(score.score_id, legacy_scores) {
+        return idx;
+    }
+
+    let mods: GameModsIntermode = score.mods.iter().map(GameMod::intermode).collect();
+
+    let map_lb_fut = Context::osu_scores().map_leaderboard(
+        map.map_id(),
+        score.mode,
+        Some(mods),
+        50,
+        legacy_scores,
+    );
+
+    let scores = match map_lb_fut.await {
+        Ok(scores) => scores,
+        Err(err) => {
+            warn!(?err, "Failed to get mod leaderboard");
+
+            return None;
+        }
+    };
+
+    let idx = scores
+        .iter()
+        .position(|s| s.user_id == user_id && score.is_eq(s))
+        .map(|idx| idx + 1);
+
+    ctx.store_mod_leaderboard_idx(score.score_id, legacy_scores, idx);
+
+    idx
+}
+
 #[derive(Copy, Clone)]
 pub struct MissAnalyzerCheck {
     guild_id: Option<Id<GuildMarker>>,
@@ -970,6 +1158,7 @@ impl MissAnalyzerCheck {
     }
 }
 
+#[derive(Clone)]
 pub struct ScoreEmbedDataPersonalBest {
     /// Note that `idx` is 0-indexed.
     pub idx: Option<usize>,
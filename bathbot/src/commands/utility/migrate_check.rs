@@ -0,0 +1,223 @@
+use std::fmt::Write;
+
+use bathbot_macros::command;
+use bathbot_psql::model::configs::GuildConfig;
+use bathbot_util::{EmbedBuilder, MessageBuilder, fields};
+use eyre::Result;
+use twilight_model::guild::Permissions;
+
+use crate::{Context, util::ChannelExt};
+
+#[command]
+#[desc("Check which server configurations are still at their defaults")]
+#[help(
+    "Check which server configurations are still at their defaults.\n\
+    Configurations that can be adjusted through prefix commands are listed \
+    alongside the respective command, all others require slash commands."
+)]
+#[alias("migrationcheck")]
+#[flags(AUTHORITY, ONLY_GUILDS, SKIP_DEFER)]
+#[group(Utility)]
+async fn prefix_migratecheck(msg: &Message, permissions: Option<Permissions>) -> Result<()> {
+    let guild_id = msg.guild_id.unwrap();
+
+    let defaults = Context::guild_config()
+        .peek(guild_id, default_settings)
+        .await;
+
+    let mut prefix_value = String::new();
+    let mut slash_value = String::new();
+
+    for setting in defaults {
+        match setting.prefix {
+            Some(command) => {
+                let _ = writeln!(prefix_value, "`{}`: `{command}`", setting.name);
+            }
+            None => {
+                let _ = writeln!(
+                    slash_value,
+                    "`{name}`: `/serverconfig edit {name}`",
+                    name = setting.name
+                );
+            }
+        }
+    }
+
+    let description = if prefix_value.is_empty() && slash_value.is_empty() {
+        "All configurations of this server have been adjusted"
+    } else {
+        "The following configurations of this server are still at their defaults"
+    };
+
+    let mut fields = Vec::with_capacity(2);
+
+    if !prefix_value.is_empty() {
+        fields![fields { "Configurable via prefix commands", prefix_value, false }];
+    }
+
+    if !slash_value.is_empty() {
+        fields![fields { "Requires slash commands", slash_value, false }];
+    }
+
+    let embed = EmbedBuilder::new()
+        .title("Server configuration check")
+        .description(description)
+        .fields(fields);
+
+    let builder = MessageBuilder::new().embed(embed);
+    msg.create_message(builder, permissions).await?;
+
+    Ok(())
+}
+
+struct Setting {
+    name: &'static str,
+    /// Prefix command to adjust the setting, `None` if it's slash-only.
+    prefix: Option<&'static str>,
+    is_default: fn(&GuildConfig) -> bool,
+}
+
+const SETTINGS: &[Setting] = &[
+    Setting {
+        name: "prefixes",
+        prefix: Some("<prefix add"),
+        is_default: |config| config.prefixes == [GuildConfig::DEFAULT_PREFIX],
+    },
+    Setting {
+        name: "authorities",
+        prefix: Some("<authorities"),
+        is_default: |config| config.authorities.is_empty() && config.authority_users.is_empty(),
+    },
+    Setting {
+        name: "list_embeds",
+        prefix: Some("<serverconfig listsize"),
+        is_default: |config| config.list_size.is_none(),
+    },
+    Setting {
+        name: "song_commands",
+        prefix: None,
+        is_default: |config| config.allow_songs.is_none(),
+    },
+    Setting {
+        name: "retries",
+        prefix: None,
+        is_default: |config| config.retries.is_none(),
+    },
+    Setting {
+        name: "render_button",
+        prefix: None,
+        is_default: |config| config.render_button.is_none(),
+    },
+    Setting {
+        name: "allow_custom_skins",
+        prefix: None,
+        is_default: |config| config.allow_custom_skins.is_none(),
+    },
+    Setting {
+        name: "hide_medal_solutions",
+        prefix: None,
+        is_default: |config| config.hide_medal_solution.is_none(),
+    },
+    Setting {
+        name: "score_data",
+        prefix: None,
+        is_default: |config| config.score_data.is_none(),
+    },
+    Setting {
+        name: "pagination_lifetime",
+        prefix: None,
+        is_default: |config| config.pagination_lifetime.is_none(),
+    },
+    Setting {
+        name: "use_threads",
+        prefix: None,
+        is_default: |config| config.use_threads.is_none(),
+    },
+    Setting {
+        name: "force_public_responses",
+        prefix: None,
+        is_default: |config| config.force_public_responses.is_none(),
+    },
+    Setting {
+        name: "default_mode",
+        prefix: None,
+        is_default: |config| config.default_mode.is_none(),
+    },
+    Setting {
+        name: "number_format",
+        prefix: None,
+        is_default: |config| config.number_format.is_none(),
+    },
+    Setting {
+        name: "lang",
+        prefix: None,
+        is_default: |config| config.lang.is_none(),
+    },
+    Setting {
+        name: "score_embed_image",
+        prefix: None,
+        is_default: |config| config.score_embed_image.is_none(),
+    },
+    Setting {
+        name: "error_message_ttl",
+        prefix: None,
+        is_default: |config| config.error_message_ttl.is_none(),
+    },
+    Setting {
+        name: "error_delete_invoke",
+        prefix: None,
+        is_default: |config| config.error_delete_invoke.is_none(),
+    },
+    Setting {
+        name: "embed_color",
+        prefix: None,
+        is_default: |config| config.embed_color.is_none(),
+    },
+    Setting {
+        name: "auto_track",
+        prefix: None,
+        is_default: |config| config.auto_track_channel.is_none(),
+    },
+];
+
+fn default_settings(config: &GuildConfig) -> Vec<&'static Setting> {
+    SETTINGS
+        .iter()
+        .filter(|setting| (setting.is_default)(config))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bathbot_psql::model::configs::ListSize;
+
+    use super::*;
+
+    fn names(config: &GuildConfig) -> Vec<&'static str> {
+        default_settings(config)
+            .into_iter()
+            .map(|setting| setting.name)
+            .collect()
+    }
+
+    #[test]
+    fn all_defaults() {
+        assert_eq!(names(&GuildConfig::default()).len(), SETTINGS.len());
+    }
+
+    #[test]
+    fn configured_settings() {
+        let mut config = GuildConfig::default();
+        config.prefixes.push("!".to_owned());
+        config.list_size = Some(ListSize::Single);
+        config.embed_color = Some(0x1F8B4C);
+
+        let names = names(&config);
+
+        assert_eq!(names.len(), SETTINGS.len() - 3);
+        assert!(!names.contains(&"prefixes"));
+        assert!(!names.contains(&"list_embeds"));
+        assert!(!names.contains(&"embed_color"));
+        assert!(names.contains(&"auto_track"));
+    }
+}
@@ -2,8 +2,10 @@ mod authorities;
 mod changelog;
 mod commands;
 mod config;
+mod disabled_commands;
 mod embed_builder;
 mod invite;
+mod migrate_check;
 mod ping;
 mod prefix;
 mod roll;
@@ -11,4 +13,6 @@ mod server_config;
 mod skin;
 
 #[allow(unused_imports)]
-pub use self::{authorities::*, changelog::*, config::*, embed_builder::*, skin::*};
+pub use self::{
+    authorities::*, changelog::*, config::*, disabled_commands::*, embed_builder::*, skin::*,
+};
@@ -6,7 +6,11 @@ use eyre::Result;
 use twilight_interactions::command::CreateCommand;
 use twilight_model::guild::Permissions;
 
-use crate::{core::commands::CommandOrigin, util::interaction::InteractionCommand};
+use crate::{
+    active::{ActiveMessages, impls::ShardsPagination},
+    core::{Context, commands::CommandOrigin},
+    util::interaction::InteractionCommand,
+};
 
 #[derive(CreateCommand, SlashCommand)]
 #[command(
@@ -14,7 +18,9 @@ use crate::{core::commands::CommandOrigin, util::interaction::InteractionCommand
     desc = "Check if the bot is online",
     help = "Most basic command, generally used to check if the bot is online.\n\
     The displayed latency is the time it takes for the bot \
-    to receive a response from discord after sending a message."
+    to receive a response from discord after sending a message.\n\
+    Additionally, the gateway state, heartbeat latency, and event count of each shard \
+    are shown, highlighting the shard of the current server."
 )]
 #[flags(SKIP_DEFER)]
 pub struct Ping;
@@ -28,7 +34,9 @@ async fn slash_ping(mut command: InteractionCommand) -> Result<()> {
 #[help(
     "Most basic command, generally used to check if the bot is online.\n\
     The displayed latency is the time it takes for the bot \
-    to receive a response from discord after sending a message."
+    to receive a response from discord after sending a message.\n\
+    Additionally, the gateway state, heartbeat latency, and event count of each shard \
+    are shown, highlighting the shard of the current server."
 )]
 #[alias("p")]
 #[flags(SKIP_DEFER)]
@@ -38,6 +46,9 @@ async fn prefix_ping(msg: &Message, permissions: Option<Permissions>) -> Result<
 }
 
 async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let guild_id = orig.guild_id();
+
     let builder = MessageBuilder::new().content("Pong");
     let start = Instant::now();
     let response_raw = orig.callback_with_response(builder).await?;
@@ -53,8 +64,23 @@ async fn ping(orig: CommandOrigin<'_>) -> Result<()> {
     };
 
     let content = format!(":ping_pong: Pong! ({elapsed}ms)");
-    let builder = MessageBuilder::new().content(content);
-    response.update(builder).await?;
 
-    Ok(())
+    let ctx = Context::get();
+    let shards = ctx.shard_stats().into_boxed_slice();
+
+    let guild_shard =
+        guild_id.and_then(|guild_id| ctx.guild_shards().pin().get(&guild_id).copied());
+
+    let mut pagination = ShardsPagination::builder()
+        .shards(shards)
+        .guild_shard(guild_shard)
+        .content(content.into_boxed_str())
+        .msg_owner(owner)
+        .build();
+
+    pagination.jump_to_guild_shard();
+
+    ActiveMessages::builder(pagination)
+        .begin_by_edit(response)
+        .await
 }
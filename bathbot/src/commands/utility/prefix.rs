@@ -6,9 +6,13 @@ use bathbot_psql::model::configs::GuildConfig;
 use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
 use eyre::Result;
 use once_cell::sync::OnceCell;
-use twilight_model::guild::Permissions;
+use twilight_model::{channel::Message, guild::Permissions};
 
-use crate::{Context, core::commands::checks::check_authority, util::ChannelExt};
+use crate::{
+    Context,
+    core::commands::{checks::check_authority, prefix::Args},
+    util::ChannelExt,
+};
 
 #[command]
 #[desc("Change my prefixes for a server")]
@@ -27,6 +31,17 @@ use crate::{Context, core::commands::checks::check_authority, util::ChannelExt};
 #[flags(ONLY_GUILDS, SKIP_DEFER)] // authority check is done manually
 #[group(Utility)]
 async fn prefix_prefix(
+    msg: &Message,
+    args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    prefixes(msg, args, permissions).await
+}
+
+/// Display or adjust the prefixes of the message's guild.
+///
+/// Shared with `<serverconfig prefix`.
+pub(super) async fn prefixes(
     msg: &Message,
     mut args: Args<'_>,
     permissions: Option<Permissions>,
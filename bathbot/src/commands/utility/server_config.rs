@@ -1,16 +1,29 @@
+use bathbot_cache::model::CachedArchive;
 use bathbot_macros::{SlashCommand, command};
-use bathbot_model::command_fields::{EnableDisable, ShowHideOption};
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_model::{
+    command_fields::{EnableDisable, ScoreEmbedImage, ShowHideOption},
+    twilight::guild::ArchivedCachedGuild,
+};
+use bathbot_psql::model::configs::{
+    ErrorMessageTtl, GuildConfig, HideSolutions, Lang, ListSize, NumberFormat, Retries, ScoreData,
+};
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
 use eyre::{Report, Result};
 use twilight_interactions::command::{CommandModel, CreateCommand};
-use twilight_model::id::{Id, marker::RoleMarker};
+use twilight_model::{
+    guild::Permissions,
+    id::{
+        Id,
+        marker::{GuildMarker, RoleMarker, UserMarker},
+    },
+};
 
-use super::AuthorityCommandKind;
+use super::{AuthorityCommandKind, ConfigGameMode, DisabledCommandsKind, prefix::prefixes};
 use crate::{
     Context,
+    core::commands::prefix::Args,
     embeds::{EmbedData, ServerConfigEmbed},
-    util::{InteractionCommandExt, interaction::InteractionCommand},
+    util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -25,6 +38,8 @@ pub enum ServerConfig {
     Authorities(ServerConfigAuthorities),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
+    #[command(name = "commands")]
+    Commands(ServerConfigCommands),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -32,8 +47,8 @@ pub enum ServerConfig {
     name = "authorities",
     desc = "Adjust authority roles for a server",
     help = "To use certain commands, users require a special status.\n\
-    This command adjusts the authority status of roles.\n\
-    Any member with an authority role can use these higher commands.\n\n\
+    This command adjusts the authority status of roles and users.\n\
+    Any member with an authority role and any authority user can use these higher commands.\n\n\
     Authority commands: `matchlive`, `prune`, `roleassign`, \
     `serverconfig`, `track`, `trackstream`."
 )]
@@ -44,6 +59,10 @@ pub enum ServerConfigAuthorities {
     Remove(ServerConfigAuthoritiesRemove),
     #[command(name = "remove_all")]
     RemoveAll(ServerConfigAuthoritiesRemoveAll),
+    #[command(name = "add_user")]
+    AddUser(ServerConfigAuthoritiesAddUser),
+    #[command(name = "remove_user")]
+    RemoveUser(ServerConfigAuthoritiesRemoveUser),
     #[command(name = "list")]
     List(ServerConfigAuthoritiesList),
 }
@@ -55,6 +74,8 @@ impl From<ServerConfigAuthorities> for AuthorityCommandKind {
             ServerConfigAuthorities::Add(args) => Self::Add(args.role),
             ServerConfigAuthorities::Remove(args) => Self::Remove(args.role),
             ServerConfigAuthorities::RemoveAll(_) => Self::RemoveAll,
+            ServerConfigAuthorities::AddUser(args) => Self::AddUser(args.user),
+            ServerConfigAuthorities::RemoveUser(args) => Self::RemoveUser(args.user),
             ServerConfigAuthorities::List(_) => Self::List,
         }
     }
@@ -94,9 +115,86 @@ pub struct ServerConfigAuthoritiesRemove {
 pub struct ServerConfigAuthoritiesRemoveAll;
 
 #[derive(CommandModel, CreateCommand)]
-#[command(name = "list", desc = "Display all current authority roles")]
+#[command(
+    name = "add_user",
+    desc = "Add authority status to a user",
+    help = "Add authority status to a single user regardless of their roles.\n\
+    Servers can have at most 10 authority users."
+)]
+pub struct ServerConfigAuthoritiesAddUser {
+    #[command(desc = "Specify the user that should gain authority status")]
+    user: Id<UserMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "remove_user",
+    desc = "Remove authority status from a user",
+    help = "Remove authority status from a user.\n\
+    You can only remove yourself if you would __not__ lose authority status by doing so."
+)]
+pub struct ServerConfigAuthoritiesRemoveUser {
+    #[command(desc = "Specify the user that should lose authority status")]
+    user: Id<UserMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all current authority roles and users")]
 pub struct ServerConfigAuthoritiesList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "commands",
+    desc = "Disable or enable commands for a server",
+    help = "Disable or enable commands for the whole server.\n\
+    Disabling a command affects both its slash and prefix variant \
+    and members will be told that the command is disabled in this server.\n\
+    The `serverconfig` and `help` commands cannot be disabled."
+)]
+pub enum ServerConfigCommands {
+    #[command(name = "disable")]
+    Disable(ServerConfigCommandsDisable),
+    #[command(name = "enable")]
+    Enable(ServerConfigCommandsEnable),
+    #[command(name = "list")]
+    List(ServerConfigCommandsList),
+}
+
+impl From<ServerConfigCommands> for DisabledCommandsKind {
+    #[inline]
+    fn from(args: ServerConfigCommands) -> Self {
+        match args {
+            ServerConfigCommands::Disable(args) => Self::Disable(args.command),
+            ServerConfigCommands::Enable(args) => Self::Enable(args.command),
+            ServerConfigCommands::List(_) => Self::List,
+        }
+    }
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "disable",
+    desc = "Disable a command in this server",
+    help = "Disable a command in this server e.g. `bg`.\n\
+    Aliases and the slash command of the same name are disabled along with it.\n\
+    Servers can disable at most 50 commands."
+)]
+pub struct ServerConfigCommandsDisable {
+    #[command(desc = "Specify the name of the command e.g. bg")]
+    command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "enable", desc = "Enable a previously disabled command")]
+pub struct ServerConfigCommandsEnable {
+    #[command(desc = "Specify the name of the command e.g. bg")]
+    command: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "Display all disabled commands of this server")]
+pub struct ServerConfigCommandsList;
+
 #[derive(CommandModel, CreateCommand)]
 #[command(name = "edit", desc = "Adjust configurations for a server")]
 pub struct ServerConfigEdit {
@@ -138,6 +236,86 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        min_value = 30,
+        max_value = 900,
+        desc = "For how many seconds should paginations stay interactive?",
+        help = "For how many seconds should paginations stay interactive?\n\
+        Every interaction resets the duration. Once it runs out, the buttons are removed.\n\
+        Can be increased for slower channels, defaults to 60 seconds."
+    )]
+    pagination_lifetime: Option<i64>,
+    #[command(
+        desc = "Should prefix list commands respond in a thread?",
+        help = "Should prefix list commands such as `top`, `leaderboard`, or `osustats` \
+        respond in a thread?\n\
+        If enabled, a thread will be created off of the command message so that \
+        paginations don't get buried in busy channels.\n\
+        Requires the bot to have the permission to create public threads."
+    )]
+    use_threads: Option<EnableDisable>,
+    #[command(
+        desc = "Should responses to commands like top, profile, or recent always be public?",
+        help = "Should responses to `/top`, `/profile`, `/recent`, and `/rank` always be public?\n\
+        Members can configure these responses to only be visible to themselves via `/config`. \
+        If enabled, this server setting overrides their preference."
+    )]
+    force_public_responses: Option<bool>,
+    #[command(
+        desc = "Specify a default gamemode for commands",
+        help = "Specify a default gamemode for commands such as `top` or `recent`.\n\
+        Applies only if neither the command nor the member's config specify a mode."
+    )]
+    default_mode: Option<ConfigGameMode>,
+    #[command(
+        desc = "Specify how large numbers should be formatted",
+        help = "Specify the thousands and decimal separators for large numbers.\n\
+        Members can override this through their own `/config`."
+    )]
+    number_format: Option<NumberFormat>,
+    #[command(
+        desc = "Specify the language of error messages",
+        help = "Specify the language of error messages such as missing links or failed requests.\n\
+        Members can override this through their own `/config`."
+    )]
+    lang: Option<Lang>,
+    #[command(
+        desc = "Should score embeds show the map cover as thumbnail or full-width image?",
+        help = "Should score embeds such as `/rs` or `/cs` show the map cover as thumbnail \
+        or as full-width image?\n\
+        Applies only if the member has not customized their score embed through `/builder`."
+    )]
+    score_embed_image: Option<ScoreEmbedImage>,
+    #[command(
+        desc = "Should error replies to prefix commands be deleted after a while?",
+        help = "Should error replies to prefix commands be deleted after a while?\n\
+        Errors such as `Failed to parse mods` otherwise remain in the channel indefinitely."
+    )]
+    error_message_ttl: Option<ErrorMessageTtl>,
+    #[command(
+        desc = "Should the invoking message be deleted alongside an error reply?",
+        help = "Should the invoking message be deleted alongside an error reply?\n\
+        Only applies if `error_message_ttl` is set and requires the bot to have the \
+        `Manage Messages` permission."
+    )]
+    error_delete_invoke: Option<bool>,
+    #[command(
+        desc = "Specify a hex color for embeds e.g. #1F8B4C",
+        help = "Specify a hex color such as `#1F8B4C` that will be used for the bot's embeds \
+        in this server.\n\
+        Embeds that indicate success or failure through their color remain unaffected.\n\
+        Specify `default` to reset the color."
+    )]
+    embed_color: Option<String>,
+    #[command(
+        desc = "Automatically track members in this channel when they link",
+        help = "Automatically track members when they use `/link` in this server.\n\
+        Enabling it uses the channel in which this command is used \
+        so I must be allowed to send messages there. \
+        Members are tracked in their osu! profile's mode with default parameters \
+        unless they're already tracked in that channel."
+    )]
+    auto_track: Option<EnableDisable>,
 }
 
 impl ServerConfigEdit {
@@ -150,6 +328,17 @@ impl ServerConfigEdit {
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            pagination_lifetime,
+            use_threads,
+            force_public_responses,
+            default_mode,
+            number_format,
+            lang,
+            score_embed_image,
+            error_message_ttl,
+            error_delete_invoke,
+            embed_color,
+            auto_track,
         } = self;
 
         song_commands.is_some()
@@ -159,6 +348,40 @@ impl ServerConfigEdit {
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || pagination_lifetime.is_some()
+            || use_threads.is_some()
+            || force_public_responses.is_some()
+            || default_mode.is_some()
+            || number_format.is_some()
+            || lang.is_some()
+            || score_embed_image.is_some()
+            || error_message_ttl.is_some()
+            || error_delete_invoke.is_some()
+            || embed_color.is_some()
+            || auto_track.is_some()
+    }
+}
+
+/// Parse a hex color like `#1F8B4C`.
+///
+/// Returns `Ok(None)` if the color should be reset to the default.
+fn parse_embed_color(input: &str) -> Result<Option<u32>, &'static str> {
+    let input = input.trim();
+
+    if input.eq_ignore_ascii_case("default") || input.eq_ignore_ascii_case("reset") {
+        return Ok(None);
+    }
+
+    let hex = input.strip_prefix('#').unwrap_or(input);
+
+    if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err("The embed color must be a hex color of the form `#RRGGBB`, e.g. `#1F8B4C`");
+    }
+
+    match u32::from_str_radix(hex, 16) {
+        Ok(0) => Err("Discord does not support pure black `#000000` as embed color, try `#000001`"),
+        Ok(color) => Ok(Some(color)),
+        Err(_) => Err("Failed to parse the embed color"),
     }
 }
 
@@ -187,8 +410,33 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
             return super::authorities((&mut command).into(), args.into()).await;
         }
         ServerConfig::Edit(edit) => edit,
+        ServerConfig::Commands(args) => {
+            return super::disabled_commands((&mut command).into(), args.into()).await;
+        }
     };
 
+    let embed_color = match args.embed_color.as_deref().map(parse_embed_color) {
+        Some(Ok(color)) => Some(color),
+        Some(Err(content)) => {
+            command.error_callback(content).await?;
+
+            return Ok(());
+        }
+        None => None,
+    };
+
+    let channel_id = command.channel_id;
+
+    if matches!(args.auto_track, Some(EnableDisable::Enable))
+        && !command.has_permission_to(Permissions::SEND_MESSAGES)
+    {
+        let content = "I'm lacking the permission to send messages in this channel \
+            so I can't auto-track members here.";
+        command.error_callback(content).await?;
+
+        return Ok(());
+    }
+
     if args.any() {
         let f = |config: &mut GuildConfig| {
             let ServerConfigEdit {
@@ -199,6 +447,17 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                pagination_lifetime,
+                use_threads,
+                force_public_responses,
+                default_mode,
+                number_format,
+                lang,
+                score_embed_image,
+                error_message_ttl,
+                error_delete_invoke,
+                embed_color: _,
+                auto_track,
             } = args;
 
             if let Some(list_embeds) = list_embeds {
@@ -228,6 +487,57 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
             if let Some(score_data) = score_data {
                 config.score_data = Some(score_data);
             }
+
+            if let Some(secs) = pagination_lifetime {
+                let secs = secs.clamp(
+                    GuildConfig::MIN_PAGINATION_LIFETIME as i64,
+                    GuildConfig::MAX_PAGINATION_LIFETIME as i64,
+                );
+
+                config.pagination_lifetime = Some(secs as u16);
+            }
+
+            if let Some(use_threads) = use_threads {
+                config.use_threads = Some(use_threads == EnableDisable::Enable);
+            }
+
+            if let Some(force_public_responses) = force_public_responses {
+                config.force_public_responses = Some(force_public_responses);
+            }
+
+            if let Some(default_mode) = default_mode {
+                config.default_mode = default_mode.into();
+            }
+
+            if let Some(number_format) = number_format {
+                config.number_format = Some(number_format);
+            }
+
+            if let Some(lang) = lang {
+                config.lang = Some(lang);
+            }
+
+            if let Some(score_embed_image) = score_embed_image {
+                config.score_embed_image = Some(score_embed_image.into());
+            }
+
+            if let Some(error_message_ttl) = error_message_ttl {
+                config.error_message_ttl = Some(error_message_ttl);
+            }
+
+            if let Some(error_delete_invoke) = error_delete_invoke {
+                config.error_delete_invoke = Some(error_delete_invoke);
+            }
+
+            if let Some(embed_color) = embed_color {
+                config.embed_color = embed_color;
+            }
+
+            match auto_track {
+                Some(EnableDisable::Enable) => config.auto_track_channel = Some(channel_id),
+                Some(EnableDisable::Disable) => config.auto_track_channel = None,
+                None => {}
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
@@ -237,6 +547,103 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         }
     }
 
+    let embed = config_embed(guild, guild_id).await;
+    let builder = embed.build().into();
+    command.callback(builder, false).await?;
+
+    Ok(())
+}
+
+#[command]
+#[desc("Adjust configurations for this server")]
+#[help(
+    "Adjust configurations for this server.\n\
+    Without arguments, the current configuration will be shown.\n\
+    To change the prefixes, the first argument must be `prefix` followed by \
+    the same arguments as for the `prefix` command.\n\
+    To change the amount of scores shown per page in top, rb, pinned, and mapper, \
+    the first argument must be `listsize` followed by `condensed`, `detailed`, or `single`.\n\
+    All other configurations require `/serverconfig edit`."
+)]
+#[usage("[prefix [add / remove] [prefix] / listsize [condensed / detailed / single]]")]
+#[example("prefix add $", "listsize detailed")]
+#[flags(AUTHORITY, ONLY_GUILDS, SKIP_DEFER)]
+#[group(Utility)]
+async fn prefix_serverconfig(
+    msg: &Message,
+    mut args: Args<'_>,
+    permissions: Option<Permissions>,
+) -> Result<()> {
+    let guild_id = msg.guild_id.unwrap();
+
+    match args.next() {
+        None => {}
+        Some("prefix" | "prefixes") => return prefixes(msg, args, permissions).await,
+        Some("listsize" | "list_size") => {
+            let Some(list_size) = args.next().and_then(parse_list_size) else {
+                let content = "After `listsize` you must specify either \
+                    `condensed`, `detailed`, or `single`";
+                msg.error(content).await?;
+
+                return Ok(());
+            };
+
+            let f = |config: &mut GuildConfig| config.list_size = Some(list_size);
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = msg.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+        }
+        Some(other) => {
+            let content = format!(
+                "If any arguments are provided, the first one \
+                must be either `prefix` or `listsize`, not `{other}`.\n\
+                All other configurations require `/serverconfig edit`."
+            );
+
+            msg.error(content).await?;
+
+            return Ok(());
+        }
+    }
+
+    let guild = match Context::cache().guild(guild_id).await {
+        Ok(Some(guild)) => guild,
+        Ok(None) => {
+            warn!("Missing guild {guild_id} in cache");
+            msg.error(GENERAL_ISSUE).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = msg.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err));
+        }
+    };
+
+    let embed = config_embed(guild, guild_id).await;
+    let builder = MessageBuilder::new().embed(embed.build());
+    msg.create_message(builder, permissions).await?;
+
+    Ok(())
+}
+
+fn parse_list_size(input: &str) -> Option<ListSize> {
+    match input.to_ascii_lowercase().as_str() {
+        "condensed" => Some(ListSize::Condensed),
+        "detailed" => Some(ListSize::Detailed),
+        "single" => Some(ListSize::Single),
+        _ => None,
+    }
+}
+
+async fn config_embed(
+    guild: CachedArchive<ArchivedCachedGuild>,
+    guild_id: Id<GuildMarker>,
+) -> ServerConfigEmbed {
     let config = Context::guild_config()
         .peek(guild_id, GuildConfig::to_owned)
         .await;
@@ -249,9 +656,32 @@ async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
         }
     }
 
-    let embed = ServerConfigEmbed::new(guild, config, &authorities);
-    let builder = embed.build().into();
-    command.callback(builder, false).await?;
+    ServerConfigEmbed::new(guild, config, &authorities)
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use bathbot_psql::model::configs::ListSize;
+
+    use super::{parse_embed_color, parse_list_size};
+
+    #[test]
+    fn embed_color() {
+        assert_eq!(parse_embed_color("#1F8B4C"), Ok(Some(0x1F8B4C)));
+        assert_eq!(parse_embed_color("ff00aa"), Ok(Some(0xFF00AA)));
+        assert_eq!(parse_embed_color(" Default "), Ok(None));
+        assert!(parse_embed_color("#000000").is_err());
+        assert!(parse_embed_color("#12345").is_err());
+        assert!(parse_embed_color("#1234567").is_err());
+        assert!(parse_embed_color("#12G456").is_err());
+        assert!(parse_embed_color("+12345").is_err());
+    }
+
+    #[test]
+    fn list_size() {
+        assert_eq!(parse_list_size("condensed"), Some(ListSize::Condensed));
+        assert_eq!(parse_list_size("Detailed"), Some(ListSize::Detailed));
+        assert_eq!(parse_list_size("SINGLE"), Some(ListSize::Single));
+        assert_eq!(parse_list_size("compact"), None);
+    }
 }
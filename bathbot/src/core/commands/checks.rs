@@ -17,6 +17,10 @@ use twilight_model::{
     },
 };
 
+use super::{
+    interaction::{InteractionCommandKind, InteractionCommands},
+    prefix::PrefixCommands,
+};
 use crate::core::{BotConfig, Context};
 
 /// Is authority -> Ok(None)
@@ -35,11 +39,21 @@ pub async fn check_authority(
         return Ok(None);
     }
 
-    let auth_roles = Context::guild_config()
-        .peek(guild_id, |config| config.authorities.clone())
+    let (auth_roles, is_auth_user, no_auth_users) = Context::guild_config()
+        .peek(guild_id, |config| {
+            (
+                config.authorities.clone(),
+                config.authority_users.contains(&author),
+                config.authority_users.is_empty(),
+            )
+        })
         .await;
 
-    if auth_roles.is_empty() {
+    if is_auth_user {
+        return Ok(None);
+    }
+
+    if auth_roles.is_empty() && no_auth_users {
         let content = "You need admin permissions to use this command.\n\
             (`/serverconfig` to adjust authority status for this server)";
 
@@ -62,10 +76,16 @@ pub async fn check_authority(
         .iter()
         .any(|role| auth_roles.contains(&Id::from(*role)))
     {
-        let mut content = String::from(
-            "You need either admin permissions or \
-            any of these roles to use this command:\n",
-        );
+        let mut content = if auth_roles.is_empty() {
+            String::from(
+                "You need either admin permissions or authority status to use this command.",
+            )
+        } else {
+            String::from(
+                "You need either admin permissions or \
+                any of these roles to use this command:\n",
+            )
+        };
 
         content.reserve(auth_roles.len() * 5);
         let mut roles = auth_roles.iter();
@@ -86,6 +106,40 @@ pub async fn check_authority(
     Ok(None)
 }
 
+/// The name under which a command is disabled or `None` if there is no such
+/// command.
+///
+/// Prefix commands resolve to their first name so that a single entry covers
+/// all aliases as well as the slash command of the same name.
+pub fn canonical_command_name(name: &str) -> Option<&'static str> {
+    if let Some(cmd) = PrefixCommands::get().command(name) {
+        return Some(cmd.names[0]);
+    }
+
+    match InteractionCommands::get().command(name) {
+        Some(InteractionCommandKind::Chat(cmd)) => Some(cmd.name),
+        _ => None,
+    }
+}
+
+/// Whether the guild disabled the command.
+///
+/// The name must be resolved through [`canonical_command_name`] first.
+pub async fn check_disabled(guild: Option<Id<GuildMarker>>, name: &str) -> bool {
+    let Some(guild_id) = guild else {
+        return false;
+    };
+
+    Context::guild_config()
+        .peek(guild_id, |config| {
+            config
+                .disabled_commands
+                .iter()
+                .any(|disabled| disabled == name)
+        })
+        .await
+}
+
 pub async fn check_guild_permissions(
     user: Id<UserMarker>,
     guild: Id<GuildMarker>,
@@ -6,6 +6,7 @@ bitflags::bitflags! {
         const ONLY_GUILDS = 1 << 2;
         const ONLY_OWNER  = 1 << 3;
         const SKIP_DEFER  = 1 << 4;
+        const PERSONAL    = 1 << 5;
     }
 }
 
@@ -29,4 +30,10 @@ impl CommandFlags {
     pub fn only_owner(self) -> bool {
         self.contains(CommandFlags::ONLY_OWNER)
     }
+
+    /// Whether the command displays personal data so that its response
+    /// respects the author's response visibility.
+    pub fn personal(self) -> bool {
+        self.contains(CommandFlags::PERSONAL)
+    }
 }
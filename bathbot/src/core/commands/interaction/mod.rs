@@ -88,4 +88,24 @@ impl InteractionCommands {
             }
         }
     }
+
+    /// Like [`InteractionCommands::set_ids`] but skips commands whose id has
+    /// already been set.
+    pub fn set_missing_ids(commands: &[Command]) {
+        let this = Self::get();
+
+        for cmd in commands {
+            let Some(id) = cmd.id else { continue };
+
+            let set_res = match this.command(&cmd.name) {
+                Some(InteractionCommandKind::Chat(cmd)) => cmd.id.set(id),
+                Some(InteractionCommandKind::Message(cmd)) => cmd.id.set(id),
+                None => continue,
+            };
+
+            if set_res.is_err() {
+                debug!(name = cmd.name, "Command id has already been set");
+            }
+        }
+    }
 }
@@ -1,8 +1,9 @@
+use bathbot_psql::model::configs::Lang;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use twilight_http::Response;
 use twilight_model::{
-    channel::Message,
+    channel::{Message, thread::AutoArchiveDuration},
     guild::Permissions,
     id::{
         Id,
@@ -15,6 +16,7 @@ use crate::{
     util::{
         Authored, ChannelExt, InteractionCommandExt, MessageExt,
         interaction::{InteractionCommand, InteractionComponent},
+        locale::{self, Msg},
     },
 };
 
@@ -22,6 +24,8 @@ pub enum CommandOrigin<'d> {
     Message {
         msg: &'d Message,
         permissions: Option<Permissions>,
+        /// Thread into which responses are redirected
+        thread: Option<Id<ChannelMarker>>,
     },
     Interaction {
         command: &'d mut InteractionCommand,
@@ -57,8 +61,13 @@ impl CommandOrigin<'_> {
     /// In case of an interaction, the response will **not** be ephemeral.
     pub async fn callback(&self, builder: MessageBuilder<'_>) -> Result<()> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
+            Self::Message {
+                msg,
+                permissions,
+                thread,
+            } => thread
+                .unwrap_or(msg.channel_id)
+                .create_message(with_embed_color(builder, msg.guild_id), *permissions)
                 .await
                 .map(|_| ())
                 .wrap_err("failed to create message to callback"),
@@ -78,8 +87,13 @@ impl CommandOrigin<'_> {
         builder: MessageBuilder<'_>,
     ) -> Result<Response<Message>> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
+            Self::Message {
+                msg,
+                permissions,
+                thread,
+            } => thread
+                .unwrap_or(msg.channel_id)
+                .create_message(with_embed_color(builder, msg.guild_id), *permissions)
                 .await
                 .wrap_err("failed to create message for response callback"),
             Self::Interaction { command } => {
@@ -107,8 +121,13 @@ impl CommandOrigin<'_> {
         ephemeral: bool,
     ) -> Result<()> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
+            Self::Message {
+                msg,
+                permissions,
+                thread,
+            } => thread
+                .unwrap_or(msg.channel_id)
+                .create_message(with_embed_color(builder, msg.guild_id), *permissions)
                 .await
                 .map(|_| ())
                 .wrap_err("failed to create message for flagged callback"),
@@ -128,8 +147,13 @@ impl CommandOrigin<'_> {
     /// Afterwards, use the resulting response message instead.
     pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
         match self {
-            Self::Message { msg, permissions } => msg
-                .create_message(builder, *permissions)
+            Self::Message {
+                msg,
+                permissions,
+                thread,
+            } => thread
+                .unwrap_or(msg.channel_id)
+                .create_message(with_embed_color(builder, msg.guild_id), *permissions)
                 .await
                 .wrap_err("failed to create message as response"),
             Self::Interaction { command } => command
@@ -142,7 +166,9 @@ impl CommandOrigin<'_> {
     /// Update a response and return the resulting response message.
     pub async fn update(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
         match self {
-            Self::Message { msg, permissions } => msg
+            Self::Message {
+                msg, permissions, ..
+            } => msg
                 .update(builder, *permissions)
                 .wrap_err("lacking permission to update message")?
                 .await
@@ -154,6 +180,56 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// In case of a message within a guild that enabled threads, create a
+    /// thread off of the message and redirect all further created responses
+    /// into it.
+    ///
+    /// Interactions are unaffected since their responses are tied to the
+    /// interaction itself.
+    pub async fn redirect_into_thread(&mut self, name: &str) -> Result<()> {
+        let Self::Message {
+            msg,
+            permissions,
+            thread,
+        } = self
+        else {
+            return Ok(());
+        };
+
+        let Some(guild_id) = msg.guild_id else {
+            return Ok(());
+        };
+
+        let use_threads = Context::guild_config()
+            .peek(guild_id, |config| config.use_threads.unwrap_or(false))
+            .await;
+
+        let required = Permissions::CREATE_PUBLIC_THREADS | Permissions::SEND_MESSAGES_IN_THREADS;
+
+        if !use_threads || permissions.is_some_and(|p| !p.contains(required)) {
+            return Ok(());
+        }
+
+        // Thread names can be at most 100 characters long
+        let name = match name.char_indices().nth(100) {
+            Some((idx, _)) => &name[..idx],
+            None => name,
+        };
+
+        let channel = Context::http()
+            .create_thread_from_message(msg.channel_id, msg.id, name)
+            .auto_archive_duration(AutoArchiveDuration::Hour)
+            .await
+            .wrap_err("Failed to create thread")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize thread")?;
+
+        *thread = Some(channel.id);
+
+        Ok(())
+    }
+
     /// Respond with a red embed.
     ///
     /// In case of an interaction, be sure you already called back beforehand.
@@ -172,6 +248,43 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// Respond with a red embed containing the message in the author's
+    /// language.
+    ///
+    /// In case of an interaction, be sure you already called back beforehand.
+    pub async fn error_localized(&self, msg: Msg) -> Result<()> {
+        let lang = locale::lang(self).await;
+
+        self.error(msg.text(lang)).await
+    }
+
+    /// Same as [`CommandOrigin::error_localized`] but reuses the language of
+    /// the author's already loaded config.
+    pub async fn error_localized_with(&self, msg: Msg, user_lang: Option<Lang>) -> Result<()> {
+        let lang = locale::lang_with_user(user_lang, self.guild_id()).await;
+
+        self.error(msg.text(lang)).await
+    }
+
+    /// Same as [`CommandOrigin::error`] but interactions get a button so
+    /// that the author can retry the command.
+    ///
+    /// Intended for failures that are likely temporary such as osu!api issues.
+    pub async fn error_retryable(&self, content: impl Into<String>) -> Result<()> {
+        match self {
+            Self::Message { msg, .. } => msg
+                .error(content)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to respond with error"),
+            Self::Interaction { command } => command
+                .error_retryable(content)
+                .await
+                .map(|_| ())
+                .wrap_err("failed to respond with error"),
+        }
+    }
+
     /// Respond with a red embed.
     ///
     /// In case of an interaction, be sure this is the first and only time you
@@ -194,7 +307,11 @@ impl CommandOrigin<'_> {
 
 impl<'d> CommandOrigin<'d> {
     pub fn from_msg(msg: &'d Message, permissions: Option<Permissions>) -> Self {
-        Self::Message { msg, permissions }
+        Self::Message {
+            msg,
+            permissions,
+            thread: None,
+        }
     }
 
     pub fn from_interaction(command: &'d mut InteractionCommand) -> Self {
@@ -219,6 +336,7 @@ pub enum OwnedCommandOrigin {
     Message {
         msg: Id<MessageMarker>,
         channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
         permissions: Option<Permissions>,
     },
     Interaction {
@@ -236,9 +354,10 @@ impl OwnedCommandOrigin {
             Self::Message {
                 msg,
                 channel,
+                guild,
                 permissions,
             } => (*msg, *channel)
-                .update(builder, *permissions)
+                .update(with_embed_color(builder, *guild), *permissions)
                 .wrap_err("Lacking permission to update message")?
                 .await
                 .wrap_err("Failed to update message"),
@@ -258,6 +377,7 @@ impl OwnedCommandOrigin {
                 msg,
                 channel,
                 permissions,
+                ..
             } => {
                 let embed = EmbedBuilder::new().color_red().description(content);
                 let builder = MessageBuilder::new().embed(embed);
@@ -283,6 +403,7 @@ impl From<(Message, Option<Permissions>)> for OwnedCommandOrigin {
         Self::Message {
             msg: msg.id,
             channel: msg.channel_id,
+            guild: msg.guild_id,
             permissions,
         }
     }
@@ -299,7 +420,21 @@ impl From<&InteractionComponent> for OwnedCommandOrigin {
         Self::Message {
             msg: component.message.id,
             channel: component.message.channel_id,
+            guild: component.guild_id,
             permissions: component.permissions,
         }
     }
 }
+
+/// Apply the guild's embed color for responses that are sent through a plain
+/// channel or message id.
+fn with_embed_color(
+    mut builder: MessageBuilder<'_>,
+    guild_id: Option<Id<GuildMarker>>,
+) -> MessageBuilder<'_> {
+    builder
+        .embed
+        .default_color(Context::guild_config().embed_color(guild_id));
+
+    builder
+}
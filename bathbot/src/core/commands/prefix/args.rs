@@ -1,10 +1,12 @@
+use std::borrow::Cow;
+
 use nom::{
     Err as NomErr, IResult,
     branch::alt,
     bytes::complete as by,
     character::complete as ch,
     combinator::{ParserIterator, iterator, map_opt},
-    error::Error as NomError,
+    error::{Error as NomError, ErrorKind},
     sequence::{delimited, terminated},
 };
 
@@ -52,6 +54,7 @@ impl<'m> Args<'m> {
         });
 
         let options = (
+            Self::key_quoted,
             quote_delimited('"', '"'),
             quote_delimited('\'', '\''),
             quote_delimited('“', '“'),
@@ -63,6 +66,61 @@ impl<'m> Args<'m> {
 
         terminated(alt(options), ch::space0)(input)
     }
+
+    /// Parses items of the form `key="some value"` so that the value may
+    /// contain whitespace. Quotes inside the value can be escaped via `\"`.
+    ///
+    /// The item is returned as is, i.e. including the quotes. Use
+    /// [`Args::unquote`] to retrieve the actual value.
+    fn key_quoted(input: &'m str) -> IResult<&'m str, &'m str, ItemError<'m>> {
+        let err = || NomErr::Error(NomError::new(input, ErrorKind::Char));
+
+        let value_start = input
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .filter(|&idx| idx > 0 && input[idx..].starts_with("=\""))
+            .map(|idx| idx + 2)
+            .ok_or_else(err)?;
+
+        let mut escaped = false;
+
+        for (i, c) in input[value_start..].char_indices() {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => {
+                    let end = value_start + i + 1;
+
+                    return Ok((&input[end..], &input[..end]));
+                }
+                _ => escaped = false,
+            }
+        }
+
+        Err(err())
+    }
+
+    /// Strips the surrounding quotes of a value and unescapes inner quotes
+    /// and backslashes.
+    ///
+    /// Values without a leading quote are returned unchanged. Returns `None`
+    /// if the closing quote is missing.
+    pub fn unquote(value: &str) -> Option<Cow<'_, str>> {
+        let Some(inner) = value.strip_prefix('"') else {
+            return Some(Cow::Borrowed(value));
+        };
+
+        let mut unquoted = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => unquoted.push(chars.next()?),
+                '"' => return chars.as_str().is_empty().then_some(Cow::Owned(unquoted)),
+                _ => unquoted.push(c),
+            }
+        }
+
+        None
+    }
 }
 
 impl<'m> Iterator for Args<'m> {
@@ -90,3 +148,53 @@ impl ArgsNum {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(content: &str) -> Vec<&str> {
+        Args::new(content, ArgsNum::None).collect()
+    }
+
+    #[test]
+    fn key_with_quoted_value() {
+        assert_eq!(
+            items(r#"badewanne3 query="ar>9 length<120" +hd"#),
+            ["badewanne3", r#"query="ar>9 length<120""#, "+hd"]
+        );
+    }
+
+    #[test]
+    fn key_with_escaped_quotes() {
+        let content = r#"query="title=\"the big black\" stars>6" sort=acc"#;
+
+        assert_eq!(
+            items(content),
+            [r#"query="title=\"the big black\" stars>6""#, "sort=acc"]
+        );
+
+        assert_eq!(
+            Args::unquote(r#""title=\"the big black\" stars>6""#).as_deref(),
+            Some(r#"title="the big black" stars>6"#)
+        );
+    }
+
+    #[test]
+    fn key_with_unterminated_quote() {
+        assert_eq!(
+            items(r#"query="ar>9 length<120"#),
+            [r#"query="ar>9"#, "length<120"]
+        );
+
+        assert_eq!(Args::unquote(r#""ar>9"#), None);
+        assert_eq!(Args::unquote(r#""ar>9\""#), None);
+        assert_eq!(Args::unquote(r#""ar>9" length"#), None);
+    }
+
+    #[test]
+    fn unquoted_value() {
+        assert_eq!(Args::unquote("ar>9").as_deref(), Some("ar>9"));
+        assert_eq!(Args::unquote(r#""""#).as_deref(), Some(""));
+    }
+}
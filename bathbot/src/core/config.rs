@@ -1,4 +1,4 @@
-use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
+use std::{env, fmt::Debug, mem::MaybeUninit, net::IpAddr, path::PathBuf, str::FromStr};
 
 use eyre::Result;
 use once_cell::sync::OnceCell;
@@ -28,6 +28,19 @@ pub struct BotConfig {
     pub owner: Id<UserMarker>,
     pub dev_guild: Id<GuildMarker>,
     pub hl_channel: Id<ChannelMarker>,
+    /// Beatmap mirrors to download map files from if osu! fails to provide
+    /// them, in order of priority.
+    pub map_mirrors: Box<[Box<str>]>,
+    pub map_cache: MapCache,
+}
+
+/// Limits for the `.osu` files stored in [`Paths::maps`].
+#[derive(Debug)]
+pub struct MapCache {
+    /// Total size in megabytes
+    pub max_size_mb: Option<u64>,
+    /// Days since a file was last used
+    pub max_age_days: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -42,6 +55,8 @@ pub struct Paths {
 #[cfg(feature = "server")]
 #[derive(Debug)]
 pub struct Server {
+    /// Interface to bind the server to
+    pub interface: IpAddr,
     pub port: u16,
     pub public_url: Box<str>,
 }
@@ -115,6 +130,7 @@ impl BotConfig {
             },
             #[cfg(feature = "server")]
             server: Server {
+                interface: env_var_opt("SERVER_INTERFACE")?.unwrap_or(IpAddr::from([0, 0, 0, 0])),
                 port: env_var("SERVER_PORT")?,
                 public_url: env_var("PUBLIC_URL")?,
             },
@@ -127,6 +143,11 @@ impl BotConfig {
             owner: env_var("OWNER_USER_ID")?,
             dev_guild: env_var("DEV_GUILD_ID")?,
             hl_channel: env_var("HL_IMAGE_CHANNEL")?,
+            map_mirrors: env_var_opt("MAP_MIRRORS")?.unwrap_or_default(),
+            map_cache: MapCache {
+                max_size_mb: env_var_opt("MAP_CACHE_MAX_SIZE_MB")?,
+                max_age_days: env_var_opt("MAP_CACHE_MAX_AGE_DAYS")?,
+            },
         };
 
         if CONFIG.set(config).is_err() {
@@ -191,9 +212,13 @@ env_kind! {
     u16: |s| { s.parse().map_err(|_| s) },
     u64: |s| { s.parse().map_err(|_| s) },
     PathBuf: |s| { s.parse().map_err(|_| s) },
+    IpAddr: |s| { s.parse().map_err(|_| s) },
     Id<UserMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<GuildMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
     Id<ChannelMarker>: |s| { s.parse().map(Id::new).map_err(|_| s) },
+    Box<[Box<str>]>: |s| {
+        Ok(s.split(',').map(str::trim).filter(|s| !s.is_empty()).map(Box::from).collect())
+    },
 }
 
 impl EnvKind for CustomEmote {
@@ -222,6 +247,14 @@ fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     })
 }
 
+fn env_var_opt<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    if env::var_os(name).is_none() {
+        return Ok(None);
+    }
+
+    env_var(name).map(Some)
+}
+
 trait AsUsize {
     fn to_usize(self) -> usize;
 }
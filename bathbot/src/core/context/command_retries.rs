@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use papaya::HashMap as PapayaMap;
+use twilight_model::{
+    application::interaction::application_command::CommandData,
+    id::{
+        Id,
+        marker::{InteractionMarker, UserMarker},
+    },
+};
+
+use super::Context;
+
+/// How long a failed command can be retried.
+const RETRY_LIFETIME: Duration = Duration::from_secs(300);
+
+/// Mapping the interaction ids of commands that failed due to the osu!api to
+/// the data required to retry them.
+pub type FailedCommands = PapayaMap<Id<InteractionMarker>, FailedCommand>;
+
+#[derive(Clone)]
+pub struct FailedCommand {
+    pub failed_at: Instant,
+    pub user_id: Id<UserMarker>,
+    pub data: CommandData,
+}
+
+impl Context {
+    /// Store a failed command so that its author can retry it.
+    ///
+    /// Only the most recent failure of a user is kept.
+    pub fn store_failed_command(
+        &self,
+        interaction_id: Id<InteractionMarker>,
+        user_id: Id<UserMarker>,
+        data: CommandData,
+    ) {
+        let failed = self.data.failed_commands.pin();
+
+        failed.retain(|_, command| {
+            command.failed_at.elapsed() < RETRY_LIFETIME && command.user_id != user_id
+        });
+
+        let command = FailedCommand {
+            failed_at: Instant::now(),
+            user_id,
+            data,
+        };
+
+        failed.insert(interaction_id, command);
+    }
+
+    pub fn failed_command(&self, interaction_id: Id<InteractionMarker>) -> Option<FailedCommand> {
+        self.data
+            .failed_commands
+            .pin()
+            .get(&interaction_id)
+            .filter(|command| command.failed_at.elapsed() < RETRY_LIFETIME)
+            .cloned()
+    }
+
+    /// The most recent failed command of a user that can still be retried.
+    pub fn last_failed_command(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Option<(Id<InteractionMarker>, FailedCommand)> {
+        self.data
+            .failed_commands
+            .pin()
+            .iter()
+            .find(|(_, command)| {
+                command.user_id == user_id && command.failed_at.elapsed() < RETRY_LIFETIME
+            })
+            .map(|(interaction_id, command)| (*interaction_id, command.clone()))
+    }
+
+    /// Remove a failed command so that it can't be retried again.
+    ///
+    /// Returns `false` if the command was already removed.
+    pub fn remove_failed_command(&self, interaction_id: Id<InteractionMarker>) -> bool {
+        self.data
+            .failed_commands
+            .pin()
+            .remove(&interaction_id)
+            .is_some()
+    }
+}
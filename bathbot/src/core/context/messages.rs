@@ -5,7 +5,10 @@ use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use time::OffsetDateTime;
 use twilight_model::{
-    channel::{Message, message::Embed},
+    channel::{
+        Message,
+        message::{Component, Embed},
+    },
     id::{Id, marker::ChannelMarker},
 };
 
@@ -23,6 +26,10 @@ impl Context {
             .wrap_err("Failed to receive channel messages")
     }
 
+    /// Find the `idx`-th map id in the given messages.
+    ///
+    /// The messages are expected to be ordered from newest to oldest, as
+    /// returned by discord, so the most recent map is preferred.
     pub async fn find_map_id_in_msgs(msgs: &[Message], idx: usize) -> Option<MapIdType> {
         const SKIP_DELAY: Duration = Duration::from_millis(500);
 
@@ -45,8 +52,10 @@ impl Context {
     pub async fn find_map_id_in_msg(msg: &Message) -> Option<MapIdType> {
         if let id @ Some(_) = Self::find_map_id_in_content(&msg.content) {
             id
+        } else if let id @ Some(_) = Self::find_map_id_in_embeds(&msg.embeds).await {
+            id
         } else {
-            Self::find_map_id_in_embeds(&msg.embeds).await
+            Self::find_map_id_in_components(&msg.components)
         }
     }
 
@@ -60,38 +69,53 @@ impl Context {
             .or_else(|| matcher::get_osu_mapset_id(content).map(MapIdType::Set))
     }
 
+    /// Checks the urls of link buttons
+    fn find_map_id_in_components(components: &[Component]) -> Option<MapIdType> {
+        components.iter().find_map(|component| match component {
+            Component::ActionRow(row) => Self::find_map_id_in_components(&row.components),
+            Component::Button(button) => button
+                .url
+                .as_deref()
+                .and_then(matcher::get_osu_map_id)
+                .map(MapIdType::Map),
+            _ => None,
+        })
+    }
+
+    /// Checks the urls of an embed's author and title, as well as the texts
+    /// of its description, fields, and footer.
+    ///
+    /// Map ids are preferred over mapset ids regardless of where they're
+    /// found. Texts only count if they contain a single map url so that e.g.
+    /// top score lists don't match.
+    fn find_map_id_in_embed(embed: &Embed) -> Option<MapIdType> {
+        let author_url = embed
+            .author
+            .as_ref()
+            .and_then(|author| author.url.as_deref());
+
+        let urls = || author_url.into_iter().chain(embed.url.as_deref());
+
+        let texts = embed
+            .description
+            .as_deref()
+            .into_iter()
+            .chain(embed.fields.iter().map(|field| field.value.as_str()))
+            .chain(embed.footer.as_ref().map(|footer| footer.text.as_str()));
+
+        urls()
+            .find_map(matcher::get_osu_map_id)
+            .or_else(|| texts.into_iter().find_map(matcher::get_single_osu_map_id))
+            .map(MapIdType::Map)
+            .or_else(|| {
+                urls()
+                    .find_map(matcher::get_osu_mapset_id)
+                    .map(MapIdType::Set)
+            })
+    }
+
     pub async fn find_map_id_in_embeds(embeds: &[Embed]) -> Option<MapIdType> {
-        let opt = embeds.iter().find_map(|embed| {
-            let url = embed
-                .author
-                .as_ref()
-                .and_then(|author| author.url.as_deref());
-
-            url.and_then(matcher::get_osu_map_id)
-                .map(MapIdType::Map)
-                .or_else(|| url.and_then(matcher::get_osu_mapset_id).map(MapIdType::Set))
-                .or_else(|| {
-                    embed
-                        .url
-                        .as_deref()
-                        .and_then(matcher::get_osu_map_id)
-                        .map(MapIdType::Map)
-                })
-                .or_else(|| {
-                    embed
-                        .url
-                        .as_deref()
-                        .and_then(matcher::get_osu_mapset_id)
-                        .map(MapIdType::Set)
-                })
-                .or_else(|| {
-                    embed
-                        .description
-                        .as_deref()
-                        .and_then(matcher::get_single_osu_map_id)
-                        .map(MapIdType::Map)
-                })
-        });
+        let opt = embeds.iter().find_map(Self::find_map_id_in_embed);
 
         if opt.is_some() {
             return opt;
@@ -150,3 +174,183 @@ impl Context {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bathbot_util::{
+        AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE, fields,
+    };
+    use twilight_model::channel::message::component::{ActionRow, Button, ButtonStyle};
+
+    use super::*;
+    use crate::util::retry_components;
+
+    /// Same order as [`Context::find_map_id_in_msg`] without the content.
+    fn find(builder: MessageBuilder<'_>) -> Option<MapIdType> {
+        let MessageBuilder {
+            embed, components, ..
+        } = builder;
+
+        let embeds: Option<Vec<Embed>> = embed.into();
+
+        embeds
+            .iter()
+            .flatten()
+            .find_map(Context::find_map_id_in_embed)
+            .or_else(|| Context::find_map_id_in_components(components.as_deref().unwrap_or(&[])))
+    }
+
+    fn link_button(url: String) -> Vec<Component> {
+        let button = Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: Some("Map".to_owned()),
+            style: ButtonStyle::Link,
+            url: Some(url),
+            sku_id: None,
+        };
+
+        vec![Component::ActionRow(ActionRow {
+            components: vec![Component::Button(button)],
+        })]
+    }
+
+    #[test]
+    fn single_score_embed() {
+        // user as author, map as url
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("user: 12,345pp").url(format!("{OSU_BASE}u/2")))
+            .title("Artist - Title [Version]")
+            .url(format!("{OSU_BASE}b/123"))
+            .footer(FooterBuilder::new("Try #3 • Ranked mapset of mapper"));
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(123))));
+    }
+
+    #[test]
+    fn map_embed() {
+        // mapper as author, map url from the osu!api as url
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("Mapped by mapper").url(format!("{OSU_BASE}u/3")))
+            .url(format!("{OSU_BASE}beatmaps/456"));
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(456))));
+    }
+
+    #[test]
+    fn leaderboard_embed() {
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("Artist - Title [Version]").url(format!("{OSU_BASE}b/789")))
+            .description("**#1 user** +HDDT");
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(789))));
+    }
+
+    #[test]
+    fn prefer_map_over_mapset() {
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("Mapset").url(format!("{OSU_BASE}beatmapsets/1")))
+            .url(format!("{OSU_BASE}beatmapsets/1#osu/2"));
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(2))));
+    }
+
+    #[test]
+    fn mapset_only() {
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("Mapset").url(format!("{OSU_BASE}beatmapsets/1")));
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Set(1))));
+    }
+
+    #[test]
+    fn map_in_field() {
+        let fields = fields![
+            "Score", "1,234,567".to_owned(), true;
+            "Map", format!("[Artist - Title [Version]]({OSU_BASE}beatmapsets/10#taiko/20)"), false;
+        ];
+
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("user").url(format!("{OSU_BASE}u/2")))
+            .fields(fields);
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(20))));
+    }
+
+    #[test]
+    fn map_in_footer() {
+        let embed = EmbedBuilder::new().footer(FooterBuilder::new(format!("{OSU_BASE}b/42")));
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(42))));
+    }
+
+    #[test]
+    fn map_in_link_button() {
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("user").url(format!("{OSU_BASE}u/2")))
+            .description("Accuracy: 98.76%");
+
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .components(link_button(format!("{OSU_BASE}b/64")));
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(64))));
+    }
+
+    #[test]
+    fn embed_before_link_button() {
+        let embed = EmbedBuilder::new().url(format!("{OSU_BASE}b/1"));
+
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .components(link_button(format!("{OSU_BASE}b/2")));
+
+        assert!(matches!(find(builder), Some(MapIdType::Map(1))));
+    }
+
+    #[test]
+    fn top_list_embed() {
+        // multiple maps in the description are ambiguous
+        let description = format!(
+            "**#1 [Title [Version]]({OSU_BASE}b/1)** [7.2★]\n\
+            **#2 [Title [Version]]({OSU_BASE}b/2)** [6.9★]"
+        );
+
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("user").url(format!("{OSU_BASE}u/2")))
+            .description(description);
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        assert!(find(builder).is_none());
+    }
+
+    #[test]
+    fn no_map() {
+        let embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new("user").url(format!("{OSU_BASE}u/2")))
+            .description("Accuracy: 98.76%")
+            .footer(FooterBuilder::new("Page 1/2"));
+
+        // buttons without url such as the retry button are ignored
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .components(retry_components(Id::new(1)));
+
+        assert!(find(builder).is_none());
+    }
+}
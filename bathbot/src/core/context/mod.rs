@@ -35,9 +35,21 @@ use twilight_model::{
 };
 use twilight_standby::Standby;
 
-use self::osutrack::OsuTrackUserNotifTimestamps;
+use self::{
+    command_retries::FailedCommands, mod_leaderboard::CachedModLeaderboardIndices,
+    nochoke_leaderboard::CachedNochokeLeaderboards, number_formats::CachedNumberFormats,
+    osutrack::OsuTrackUserNotifTimestamps, pp_ceiling::CachedPpCeilings,
+    prefix_dedupe::RecentPrefixCommands, shard_stats::ShardStats, top_counts::CachedTopCounts,
+    top_scores::CachedTopScores,
+};
+pub use self::{
+    nochoke_leaderboard::NochokeLeaderboardEntry,
+    pp_ceiling::{PpCeilingEntry, PpCeilings},
+    shard_stats::ShardStatsEntry,
+    top_scores::TopScoresBundle,
+};
 use super::{
-    BotConfig, BotMetrics,
+    BotConfig, BotMetrics, OsuApiRecorder,
     buckets::{BucketName, Buckets},
 };
 use crate::{
@@ -45,12 +57,22 @@ use crate::{
     tracking::{Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
+mod command_retries;
 mod games;
 mod manager;
 mod messages;
+mod mod_leaderboard;
+mod nochoke_leaderboard;
+mod number_formats;
 mod osutrack;
+mod pp_ceiling;
+mod prefix_dedupe;
 mod set_commands;
+mod shard_stats;
 mod shutdown;
+mod top_counts;
+mod top_scores;
+mod ttl_cache;
 
 #[cfg(feature = "matchlive")]
 mod matchlive;
@@ -181,6 +203,7 @@ impl Context {
             let fanout = FanoutBuilder::default()
                 .add_recorder(prometheus)
                 .add_recorder(reader.clone())
+                .add_recorder(OsuApiRecorder)
                 .build();
 
             let prefix = PrefixLayer::new("bathbot").layer(fanout);
@@ -378,6 +401,9 @@ impl Context {
         // Storing shard senders
         *Context::get().shard_senders.write().unwrap() = senders;
 
+        // Stats of the previous shards are no longer meaningful
+        Context::get().clear_shard_stats();
+
         info!("Finished resharding");
 
         Ok(())
@@ -430,6 +456,15 @@ struct ContextData {
     guild_shards: GuildShards,
     miss_analyzer_guilds: MissAnalyzerGuilds,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
+    top_scores: CachedTopScores,
+    nochoke_leaderboards: CachedNochokeLeaderboards,
+    mod_leaderboard_indices: CachedModLeaderboardIndices,
+    pp_ceilings: CachedPpCeilings,
+    top_counts: CachedTopCounts,
+    number_formats: CachedNumberFormats,
+    failed_commands: FailedCommands,
+    recent_prefix_commands: RecentPrefixCommands,
+    shard_stats: ShardStats,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
 }
@@ -482,6 +517,19 @@ impl ContextData {
             matchlive: crate::matchlive::MatchLiveChannels::new(),
             miss_analyzer_guilds,
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
+            top_scores: CachedTopScores::new(top_scores::TOP_SCORES_LIFETIME),
+            nochoke_leaderboards: CachedNochokeLeaderboards::new(
+                nochoke_leaderboard::NOCHOKE_LEADERBOARD_LIFETIME,
+            ),
+            mod_leaderboard_indices: CachedModLeaderboardIndices::new(
+                mod_leaderboard::MOD_LEADERBOARD_IDX_LIFETIME,
+            ),
+            pp_ceilings: CachedPpCeilings::new(pp_ceiling::PP_CEILING_LIFETIME),
+            top_counts: CachedTopCounts::new(top_counts::TOP_COUNTS_LIFETIME),
+            number_formats: CachedNumberFormats::new(number_formats::NUMBER_FORMAT_LIFETIME),
+            failed_commands: FailedCommands::default(),
+            recent_prefix_commands: RecentPrefixCommands::default(),
+            shard_stats: ShardStats::default(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
         })
@@ -632,6 +680,7 @@ async fn bathbot_server(
         website_path: config.paths.website.clone(),
         prometheus,
         metrics_reader,
+        health: server_health,
         osu_client_id: config.tokens.osu_client_id,
         osu_client_secret: config.tokens.osu_client_secret.to_string(),
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
@@ -641,7 +690,50 @@ async fn bathbot_server(
 
     let (server, standby, tx) = bathbot_server::Server::new(builder)?;
 
-    tokio::spawn(server.run(config.server.port));
+    tokio::spawn(server.run(config.server.interface, config.server.port));
 
     Ok((standby, tx))
 }
+
+#[cfg(feature = "server")]
+fn server_health() -> Option<bathbot_server::Health> {
+    use std::slice;
+
+    use metrics::{Key, Label};
+    use twilight_gateway::ShardState;
+
+    static GUILDS_LABEL: Label = Label::from_static_parts("kind", "Guilds");
+
+    let ctx = CONTEXT.get()?;
+
+    let shards = ctx
+        .shard_stats()
+        .into_iter()
+        .map(|(id, entry)| bathbot_server::ShardHealth {
+            id,
+            state: match entry.state {
+                ShardState::Active => "Active",
+                ShardState::Identifying => "Identifying",
+                ShardState::Resuming => "Resuming",
+                ShardState::Disconnected { .. } => "Disconnected",
+                ShardState::FatallyClosed => "Fatally closed",
+            },
+            latency_ms: entry.latency.map(|latency| latency.as_millis() as u64),
+        })
+        .collect();
+
+    let guilds_key =
+        Key::from_static_parts("bathbot.cache_entries", slice::from_ref(&GUILDS_LABEL));
+    let queue_key = Key::from_name("bathbot.osu_tracking_queue");
+    let last_osu_key = Key::from_name("bathbot.osu_api_last_success");
+
+    let last_osu_request = ctx.metrics.gauge_value(&last_osu_key) as u64;
+
+    Some(bathbot_server::Health {
+        uptime_secs: (OffsetDateTime::now_utc() - ctx.start_time).whole_seconds(),
+        shards,
+        guild_count: ctx.metrics.gauge_value(&guilds_key) as u64,
+        tracking_queue: ctx.metrics.gauge_value(&queue_key) as u64,
+        last_osu_request: (last_osu_request > 0).then_some(last_osu_request),
+    })
+}
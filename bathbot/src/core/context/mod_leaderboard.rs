@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use super::{Context, ttl_cache::TtlCache};
+
+/// How long a score's position on its mod-filtered leaderboard remains cached.
+pub(super) const MOD_LEADERBOARD_IDX_LIFETIME: Duration = Duration::from_secs(600);
+
+/// Mapping score ids and whether legacy scores were requested to the
+/// score's position on the leaderboard filtered by its mods.
+pub type CachedModLeaderboardIndices = TtlCache<(u64, bool), Option<usize>>;
+
+impl Context {
+    /// Returns `Some(None)` if the score is cached but not on the leaderboard.
+    pub fn mod_leaderboard_idx(&self, score_id: u64, legacy_scores: bool) -> Option<Option<usize>> {
+        self.data
+            .mod_leaderboard_indices
+            .get(&(score_id, legacy_scores))
+    }
+
+    pub fn store_mod_leaderboard_idx(
+        &self,
+        score_id: u64,
+        legacy_scores: bool,
+        idx: Option<usize>,
+    ) {
+        self.data
+            .mod_leaderboard_indices
+            .insert((score_id, legacy_scores), idx);
+    }
+}
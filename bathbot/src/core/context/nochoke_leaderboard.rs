@@ -0,0 +1,49 @@
+use std::{sync::Arc, time::Duration};
+
+use rosu_v2::prelude::{CountryCode, GameMode, Username};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use super::{Context, ttl_cache::TtlCache};
+
+/// How long no-choke leaderboards of a guild remain cached.
+pub(super) const NOCHOKE_LEADERBOARD_LIFETIME: Duration = Duration::from_secs(3600);
+
+/// Mapping guild ids and modes to the no-choke leaderboard of their members.
+pub type CachedNochokeLeaderboards =
+    TtlCache<(Id<GuildMarker>, GameMode), Arc<[NochokeLeaderboardEntry]>>;
+
+pub struct NochokeLeaderboardEntry {
+    pub username: Username,
+    pub country_code: CountryCode,
+    /// Current total pp
+    pub pp: f32,
+    /// Total pp if all top scores were full combos
+    pub unchoked_pp: f32,
+}
+
+impl NochokeLeaderboardEntry {
+    pub fn gain(&self) -> f32 {
+        self.unchoked_pp - self.pp
+    }
+}
+
+impl Context {
+    pub fn nochoke_leaderboard(
+        &self,
+        guild_id: Id<GuildMarker>,
+        mode: GameMode,
+    ) -> Option<Arc<[NochokeLeaderboardEntry]>> {
+        self.data.nochoke_leaderboards.get(&(guild_id, mode))
+    }
+
+    pub fn store_nochoke_leaderboard(
+        &self,
+        guild_id: Id<GuildMarker>,
+        mode: GameMode,
+        entries: Arc<[NochokeLeaderboardEntry]>,
+    ) {
+        self.data
+            .nochoke_leaderboards
+            .insert((guild_id, mode), entries);
+    }
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use bathbot_psql::model::configs::NumberFormat;
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{Context, ttl_cache::TtlCache};
+
+/// How long the number format of a user remains cached.
+pub(super) const NUMBER_FORMAT_LIFETIME: Duration = Duration::from_secs(600);
+
+/// Mapping discord user ids to their configured number format.
+pub type CachedNumberFormats = TtlCache<Id<UserMarker>, Option<NumberFormat>>;
+
+impl Context {
+    /// The cached number format of a user; `None` if it's not cached.
+    pub fn number_format(&self, user_id: Id<UserMarker>) -> Option<Option<NumberFormat>> {
+        self.data.number_formats.get(&user_id)
+    }
+
+    pub fn store_number_format(&self, user_id: Id<UserMarker>, format: Option<NumberFormat>) {
+        self.data.number_formats.insert(user_id, format);
+    }
+}
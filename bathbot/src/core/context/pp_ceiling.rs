@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use rosu_v2::prelude::GameMods;
+
+use super::{Context, ttl_cache::TtlCache};
+
+/// How long the highest pp plays of a user remain cached.
+pub(super) const PP_CEILING_LIFETIME: Duration = Duration::from_secs(300);
+
+/// Mapping user ids and whether legacy scores were requested to the user's
+/// highest pp play for each mode.
+pub type CachedPpCeilings = TtlCache<(u32, bool), PpCeilings>;
+
+/// Highest pp play for each mode, ordered as osu!, taiko, catch, and mania.
+///
+/// `None` if the user has no top scores in that mode.
+pub type PpCeilings = [Option<PpCeilingEntry>; 4];
+
+#[derive(Clone)]
+pub struct PpCeilingEntry {
+    pub map_id: u32,
+    pub map: Box<str>,
+    pub mods: GameMods,
+    pub pp: f32,
+}
+
+impl Context {
+    pub fn pp_ceilings(&self, user_id: u32, legacy_scores: bool) -> Option<PpCeilings> {
+        self.data.pp_ceilings.get(&(user_id, legacy_scores))
+    }
+
+    pub fn store_pp_ceilings(&self, user_id: u32, legacy_scores: bool, ceilings: PpCeilings) {
+        self.data
+            .pp_ceilings
+            .insert((user_id, legacy_scores), ceilings);
+    }
+}
@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+use papaya::{Compute, HashMap as PapayaMap, Operation};
+use tokio::time::{MissedTickBehavior, interval};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
+
+use super::Context;
+
+/// Identical prefix commands within this window are considered duplicates.
+const DEDUPE_WINDOW: Duration = Duration::from_secs(3);
+
+/// How often outdated entries are removed.
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Mapping the author, channel, and normalized content of recent prefix
+/// commands to the time they were received.
+pub type RecentPrefixCommands = PapayaMap<(Id<UserMarker>, Id<ChannelMarker>, Box<str>), Instant>;
+
+impl Context {
+    /// Remember a prefix command and check whether an identical one was
+    /// received shortly before.
+    ///
+    /// The check and the insertion happen atomically so that concurrent
+    /// identical commands are only processed once.
+    ///
+    /// Returns `true` if the command is a duplicate and should be ignored.
+    pub fn is_duplicate_prefix_command(
+        &self,
+        author: Id<UserMarker>,
+        channel: Id<ChannelMarker>,
+        content: &str,
+    ) -> bool {
+        let key = (author, channel, normalize_command(content));
+        let now = Instant::now();
+        let recent = self.data.recent_prefix_commands.pin();
+
+        let compute = recent.compute(key, |entry| match entry {
+            Some((_, received)) if now.duration_since(*received) < DEDUPE_WINDOW => {
+                Operation::Abort(())
+            }
+            Some(_) | None => Operation::Insert(now),
+        });
+
+        matches!(compute, Compute::Aborted(()))
+    }
+
+    /// Periodically remove prefix commands that are past the dedupe window.
+    pub async fn prefix_dedupe_cleanup_loop() {
+        let mut interval = interval(CLEANUP_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let ctx = Context::get();
+
+        loop {
+            interval.tick().await;
+
+            ctx.data
+                .recent_prefix_commands
+                .pin()
+                .retain(|_, received| received.elapsed() < DEDUPE_WINDOW);
+        }
+    }
+}
+
+/// Lowercase the content and collapse consecutive whitespace.
+fn normalize_command(content: &str) -> Box<str> {
+    let mut normalized = String::with_capacity(content.len());
+
+    for word in content.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+
+        normalized.extend(word.chars().flat_map(char::to_lowercase));
+    }
+
+    normalized.into_boxed_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_command;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(&*normalize_command("top"), "top");
+        assert_eq!(
+            &*normalize_command("  TOP   badewanne3 \n+HD "),
+            "top badewanne3 +hd"
+        );
+        assert_eq!(&*normalize_command(""), "");
+    }
+}
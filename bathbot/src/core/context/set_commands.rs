@@ -49,6 +49,34 @@ impl Context {
             .wrap_err("Failed to deserialize commands")
     }
 
+    /// Fetch the commands that are currently registered, either globally or
+    /// for the dev guild depending on the `global_slash` feature.
+    #[cold]
+    pub async fn registered_commands() -> Result<Vec<Command>> {
+        let req = if cfg!(feature = "global_slash") {
+            Context::interaction().global_commands()
+        } else {
+            Context::interaction().guild_commands(BotConfig::get().dev_guild)
+        };
+
+        req.await
+            .wrap_err("Failed to get commands")?
+            .models()
+            .await
+            .wrap_err("Failed to deserialize commands")
+    }
+
+    /// Overwrite the registered commands, either globally or for the dev guild
+    /// depending on the `global_slash` feature.
+    #[cold]
+    pub async fn register_commands(cmds: Vec<Command>) -> Result<Vec<Command>> {
+        if cfg!(feature = "global_slash") {
+            Self::set_global_commands(cmds).await
+        } else {
+            Self::set_guild_commands(cmds).await
+        }
+    }
+
     #[cold]
     pub async fn set_guild_commands(cmds: Vec<Command>) -> Result<Vec<Command>> {
         Context::interaction()
@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use papaya::HashMap as PapayaMap;
+use twilight_gateway::ShardState;
+
+use super::Context;
+
+/// Mapping shard ids to their gateway connection details.
+pub type ShardStats = PapayaMap<u32, ShardStatsEntry>;
+
+#[derive(Copy, Clone)]
+pub struct ShardStatsEntry {
+    pub state: ShardState,
+    /// Average latency of the recent heartbeats
+    pub latency: Option<Duration>,
+    /// Amount of events received since the shard was created
+    pub events: u64,
+}
+
+impl Context {
+    /// Returns the stats of all shards sorted by their id.
+    pub fn shard_stats(&self) -> Vec<(u32, ShardStatsEntry)> {
+        let mut stats: Vec<_> = self
+            .data
+            .shard_stats
+            .pin()
+            .iter()
+            .map(|(shard_id, entry)| (*shard_id, *entry))
+            .collect();
+
+        stats.sort_unstable_by_key(|(shard_id, _)| *shard_id);
+
+        stats
+    }
+
+    pub fn update_shard_connection(
+        &self,
+        shard_id: u32,
+        state: ShardState,
+        latency: Option<Duration>,
+    ) {
+        let entry = ShardStatsEntry {
+            state,
+            latency,
+            events: 0,
+        };
+
+        self.data.shard_stats.pin().update_or_insert(
+            shard_id,
+            |prev| ShardStatsEntry {
+                events: prev.events,
+                ..entry
+            },
+            entry,
+        );
+    }
+
+    pub fn inc_shard_events(&self, shard_id: u32) {
+        self.data
+            .shard_stats
+            .pin()
+            .update(shard_id, |entry| ShardStatsEntry {
+                events: entry.events + 1,
+                ..*entry
+            });
+    }
+
+    pub(super) fn clear_shard_stats(&self) {
+        self.data.shard_stats.pin().clear();
+    }
+}
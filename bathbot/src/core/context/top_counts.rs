@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use rosu_v2::prelude::GameMode;
+
+use super::{Context, ttl_cache::TtlCache};
+use crate::util::osu::TopCounts;
+
+/// How long leaderboard counts of a user remain cached.
+pub(super) const TOP_COUNTS_LIFETIME: Duration = Duration::from_secs(600);
+
+/// Mapping user ids and modes to the amount of leaderboards the user is in.
+pub type CachedTopCounts = TtlCache<(u32, u8), TopCounts>;
+
+impl Context {
+    pub fn top_counts(&self, user_id: u32, mode: GameMode) -> Option<TopCounts> {
+        self.data.top_counts.get(&(user_id, mode as u8))
+    }
+
+    pub fn store_top_counts(&self, user_id: u32, mode: GameMode, counts: TopCounts) {
+        self.data.top_counts.insert((user_id, mode as u8), counts);
+    }
+}
@@ -0,0 +1,61 @@
+use std::{sync::Arc, time::Duration};
+
+use rosu_v2::prelude::{GameMode, Score};
+
+use super::{Context, ttl_cache::TtlCache};
+use crate::{commands::utility::ScoreEmbedDataHalf, core::BotMetrics};
+
+/// How long processed top scores remain cached.
+pub(super) const TOP_SCORES_LIFETIME: Duration = Duration::from_secs(120);
+
+/// Mapping user ids, modes, and whether scores are legacy to their processed
+/// top scores.
+pub type CachedTopScores = TtlCache<(u32, GameMode, bool), Arc<TopScoresBundle>>;
+
+/// Top scores of a user alongside their processed data.
+///
+/// Scores whose map could not be retrieved are not included.
+pub struct TopScoresBundle {
+    pub scores: Arc<[Score]>,
+    /// Processed data of the score at the same index in `scores`
+    pub halves: Box<[ScoreEmbedDataHalf]>,
+}
+
+impl TopScoresBundle {
+    pub fn iter(&self) -> impl Iterator<Item = (&Score, &ScoreEmbedDataHalf)> {
+        self.scores.iter().zip(self.halves.iter())
+    }
+}
+
+impl Context {
+    pub fn top_scores(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+    ) -> Option<Arc<TopScoresBundle>> {
+        let bundle = self.data.top_scores.get(&(user_id, mode, legacy_scores));
+        BotMetrics::inc_top_scores_cache(bundle.is_some());
+
+        bundle
+    }
+
+    pub fn store_top_scores(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        legacy_scores: bool,
+        bundle: Arc<TopScoresBundle>,
+    ) {
+        self.data
+            .top_scores
+            .insert((user_id, mode, legacy_scores), bundle);
+    }
+
+    /// Remove cached top scores e.g. because the user set a new top play.
+    pub fn invalidate_top_scores(&self, user_id: u32, mode: GameMode) {
+        let top_scores = &self.data.top_scores;
+        top_scores.remove(&(user_id, mode, false));
+        top_scores.remove(&(user_id, mode, true));
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+use papaya::HashMap as PapayaMap;
+use tokio::time::{MissedTickBehavior, interval};
+
+use super::Context;
+
+/// How often expired entries are removed from all caches.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Concurrent map whose entries expire after a fixed lifetime.
+///
+/// Expired entries are no longer returned and are removed periodically by
+/// [`Context::ttl_cache_eviction_loop`].
+pub struct TtlCache<K, V> {
+    entries: PapayaMap<K, (Instant, V)>,
+    lifetime: Duration,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            entries: PapayaMap::default(),
+            lifetime,
+        }
+    }
+
+    /// Returns a clone of the value if it's cached and not yet expired.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.entries
+            .pin()
+            .get(key)
+            .filter(|(cached_at, _)| cached_at.elapsed() < self.lifetime)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.entries.pin().insert(key, (Instant::now(), value));
+    }
+
+    pub fn remove(&self, key: &K) {
+        self.entries.pin().remove(key);
+    }
+
+    fn evict_expired(&self) {
+        self.entries
+            .pin()
+            .retain(|_, (cached_at, _)| cached_at.elapsed() < self.lifetime);
+    }
+}
+
+impl Context {
+    /// Periodically remove expired entries of all [`TtlCache`]s.
+    pub async fn ttl_cache_eviction_loop() {
+        let mut interval = interval(EVICTION_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let data = &Context::get().data;
+
+        loop {
+            interval.tick().await;
+
+            data.mod_leaderboard_indices.evict_expired();
+            data.nochoke_leaderboards.evict_expired();
+            data.number_formats.evict_expired();
+            data.pp_ceilings.evict_expired();
+            data.top_counts.evict_expired();
+            data.top_scores.evict_expired();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::TtlCache;
+
+    #[test]
+    fn expiration() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        cache.insert(1, "a");
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.get(&2), None);
+
+        let expired = TtlCache::new(Duration::ZERO);
+        expired.insert(1, "a");
+        assert_eq!(expired.get(&1), None);
+
+        expired.evict_expired();
+        assert!(expired.entries.pin().is_empty());
+    }
+}
@@ -1,23 +1,37 @@
-use std::{mem, time::Instant};
+use std::time::Instant;
 
+use bathbot_psql::model::configs::ResponseVisibility;
 use eyre::Result;
 
 use crate::{
     core::{
         BotConfig, BotMetrics, Context,
         commands::{
-            checks::check_authority,
+            checks::{canonical_command_name, check_authority, check_disabled},
             interaction::{InteractionCommandKind, InteractionCommands, SlashCommand},
         },
         events::{EventKind, ProcessResult},
     },
-    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand, locale},
 };
 
-pub async fn handle_command(mut command: InteractionCommand) {
+pub async fn handle_command(command: InteractionCommand) {
+    execute_command(command, false).await
+}
+
+/// Run a command again after it failed due to the osu!api.
+///
+/// The retrying component must have been deferred already so the command
+/// won't be deferred again and instead updates the component's message.
+pub async fn retry_command(command: InteractionCommand) {
+    execute_command(command, true).await
+}
+
+async fn execute_command(command: InteractionCommand, is_retry: bool) {
     let start = Instant::now();
 
-    let name = mem::take(&mut command.data.name);
+    // The name is kept in the data in case the command is stored to be retried
+    let name = command.data.name.clone();
     EventKind::InteractionCommand.log(&command, &name).await;
 
     let Some(cmd) = InteractionCommands::get().command(&name) else {
@@ -25,8 +39,20 @@ pub async fn handle_command(mut command: InteractionCommand) {
     };
 
     let group_sub = command.group_sub();
+    let user_id = command.user_id().ok();
+    let guild_id = command.guild_id;
+
+    let process_fut = async {
+        if is_retry {
+            process_retry(command, cmd).await
+        } else {
+            process_command(command, cmd).await
+        }
+    };
+
+    let res = locale::with_number_format(user_id, guild_id, process_fut).await;
 
-    match process_command(command, cmd).await {
+    match res {
         Ok(ProcessResult::Success) => info!(%name, "Processed interaction command"),
         Ok(reason) => info!(?reason, "Interaction command `{name}` was not processed"),
         Err(err) => {
@@ -52,16 +78,20 @@ async fn process_command(
     cmd: InteractionCommandKind,
 ) -> Result<ProcessResult> {
     match cmd {
-        InteractionCommandKind::Chat(cmd) => match pre_process_command(&command, cmd).await? {
-            Some(result) => return Ok(result),
-            None => {
-                if cmd.flags.defer() {
-                    command.defer(cmd.flags.ephemeral()).await?;
-                }
-
-                (cmd.exec)(command).await?;
+        InteractionCommandKind::Chat(cmd) => {
+            if let Some(result) = pre_process_command(&command, cmd, false).await? {
+                return Ok(result);
             }
-        },
+
+            if cmd.flags.defer() {
+                let ephemeral = cmd.flags.ephemeral()
+                    || (cmd.flags.personal() && personal_ephemeral(&command).await);
+
+                command.defer(ephemeral).await?;
+            }
+
+            (cmd.exec)(command).await?;
+        }
         InteractionCommandKind::Message(cmd) => {
             if cmd.flags.defer() {
                 command.defer(cmd.flags.ephemeral()).await?;
@@ -74,16 +104,75 @@ async fn process_command(
     Ok(ProcessResult::Success)
 }
 
+/// Same as [`process_command`] but without deferring since the retrying
+/// component was deferred already.
+///
+/// The checks are run again because the config or ratelimits may have changed
+/// since the command was first invoked.
+async fn process_retry(
+    command: InteractionCommand,
+    cmd: InteractionCommandKind,
+) -> Result<ProcessResult> {
+    match cmd {
+        InteractionCommandKind::Chat(cmd) => {
+            if let Some(result) = pre_process_command(&command, cmd, true).await? {
+                return Ok(result);
+            }
+
+            (cmd.exec)(command).await?;
+        }
+        InteractionCommandKind::Message(cmd) => (cmd.exec)(command).await?,
+    }
+
+    Ok(ProcessResult::Success)
+}
+
+/// Whether the response to a command that displays personal data should be
+/// ephemeral.
+///
+/// A guild forcing public responses takes precedence over the author's
+/// preference.
+async fn personal_ephemeral(command: &InteractionCommand) -> bool {
+    if let Some(guild_id) = command.guild_id {
+        let force_public = Context::guild_config()
+            .peek(guild_id, |config| config.force_public_responses)
+            .await
+            .unwrap_or(false);
+
+        if force_public {
+            return false;
+        }
+    }
+
+    let Ok(user_id) = command.user_id() else {
+        return false;
+    };
+
+    match Context::user_config().response_visibility(user_id).await {
+        Ok(visibility) => visibility.is_some_and(ResponseVisibility::is_ephemeral),
+        Err(err) => {
+            warn!(?err, "Failed to get response visibility");
+
+            false
+        }
+    }
+}
+
+/// Checks whether the command may be executed.
+///
+/// If `deferred` is true, errors update the deferred response instead of
+/// creating a new one.
 async fn pre_process_command(
     command: &InteractionCommand,
     slash: &SlashCommand,
+    deferred: bool,
 ) -> Result<Option<ProcessResult>> {
     let user_id = command.user_id()?;
 
     // Only for owner?
     if slash.flags.only_owner() && user_id != BotConfig::get().owner {
         let content = "That command can only be used by the bot owner";
-        command.error_callback(content).await?;
+        respond_error(command, content, deferred).await?;
 
         return Ok(Some(ProcessResult::NoOwner));
     }
@@ -93,18 +182,28 @@ async fn pre_process_command(
     // that's no longer the case.
     if slash.flags.only_guilds() && command.guild_id.is_none() {
         let content = "That command is only available in servers";
-        command.error_callback(content).await?;
+        respond_error(command, content, deferred).await?;
 
         return Ok(Some(ProcessResult::NoDM));
     }
 
+    // Disabled in the guild?
+    let name = canonical_command_name(slash.name).unwrap_or(slash.name);
+
+    if check_disabled(command.guild_id, name).await {
+        let content = "Sorry, this command has been disabled in this server";
+        respond_error(command, content, deferred).await?;
+
+        return Ok(Some(ProcessResult::Disabled));
+    }
+
     // Ratelimited?
     if let Some(bucket) = slash.bucket {
         if let Some(cooldown) = Context::check_ratelimit(user_id, bucket) {
             trace!("Ratelimiting user {user_id} on bucket `{bucket:?}` for {cooldown} seconds");
 
             let content = format!("Command on cooldown, try again in {cooldown} seconds");
-            command.error_callback(content).await?;
+            respond_error(command, content, deferred).await?;
 
             return Ok(Some(ProcessResult::Ratelimited(bucket)));
         }
@@ -115,13 +214,13 @@ async fn pre_process_command(
         match check_authority(user_id, command.guild_id).await {
             Ok(None) => {}
             Ok(Some(content)) => {
-                command.error_callback(content).await?;
+                respond_error(command, content, deferred).await?;
 
                 return Ok(Some(ProcessResult::NoAuthority));
             }
             Err(err) => {
                 let content = "Error while checking authority status";
-                let _ = command.error_callback(content).await;
+                let _ = respond_error(command, content, deferred).await;
 
                 return Err(err.wrap_err("failed to check authority status"));
             }
@@ -130,3 +229,18 @@ async fn pre_process_command(
 
     Ok(None)
 }
+
+/// Respond with an error depending on whether the command was deferred.
+async fn respond_error(
+    command: &InteractionCommand,
+    content: impl Into<String>,
+    deferred: bool,
+) -> Result<()> {
+    if deferred {
+        command.error(content).await?;
+    } else {
+        command.error_callback(content).await?;
+    }
+
+    Ok(())
+}
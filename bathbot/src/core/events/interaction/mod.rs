@@ -1,13 +1,17 @@
 use twilight_model::application::interaction::{Interaction, InteractionData, InteractionType};
 
-use self::{autocomplete::handle_autocomplete, command::handle_command};
+use self::{autocomplete::handle_autocomplete, command::handle_command, retry::handle_retry};
 use crate::{
     active::ActiveMessages,
-    util::interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+    util::{
+        RETRY_BUTTON_PREFIX,
+        interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+    },
 };
 
 mod autocomplete;
 mod command;
+mod retry;
 
 pub async fn handle_interaction(interaction: Interaction) {
     let Interaction {
@@ -64,7 +68,16 @@ pub async fn handle_interaction(interaction: Interaction) {
                 user,
             };
 
-            ActiveMessages::handle_component(component).await
+            let retry_id = component
+                .data
+                .custom_id
+                .strip_prefix(RETRY_BUTTON_PREFIX)
+                .and_then(|id| id.parse().ok());
+
+            match retry_id {
+                Some(interaction_id) => handle_retry(component, interaction_id).await,
+                None => ActiveMessages::handle_component(component).await,
+            }
         }
         Some(InteractionData::ModalSubmit(data)) => {
             let modal = InteractionModal {
@@ -0,0 +1,70 @@
+use std::future::IntoFuture;
+
+use twilight_model::id::{Id, marker::InteractionMarker};
+
+use super::command::retry_command;
+use crate::{
+    core::Context,
+    util::{
+        Authored, ComponentExt,
+        interaction::{InteractionCommand, InteractionComponent},
+    },
+};
+
+/// Handle a click on the retry button of a command that failed due to the
+/// osu!api.
+pub async fn handle_retry(component: InteractionComponent, interaction_id: Id<InteractionMarker>) {
+    if let Err(err) = component.defer().await {
+        return error!(?err, "Failed to defer retry component");
+    }
+
+    let user_id = match component.user_id() {
+        Ok(user_id) => user_id,
+        Err(err) => return error!(?err, "Missing user for retry component"),
+    };
+
+    let content = match Context::get().failed_command(interaction_id) {
+        Some(failed) if failed.user_id != user_id => "Only the author of the command can retry it",
+        // Only one retry per failure
+        Some(failed) if Context::get().remove_failed_command(interaction_id) => {
+            let remove_fut = Context::interaction()
+                .update_response(&component.token)
+                .components(Some(&[][..]))
+                .into_future();
+
+            if let Err(err) = remove_fut.await {
+                warn!(?err, "Failed to remove retry button");
+            }
+
+            let InteractionComponent {
+                permissions,
+                channel_id,
+                guild_id,
+                id,
+                member,
+                token,
+                user,
+                ..
+            } = component;
+
+            let command = InteractionCommand {
+                permissions,
+                channel_id,
+                data: Box::new(failed.data),
+                guild_id,
+                id,
+                member,
+                token,
+                user,
+            };
+
+            return retry_command(command).await;
+        }
+        Some(_) => "The command was already retried",
+        None => "The command can no longer be retried",
+    };
+
+    if let Err(err) = component.error_followup(content).await {
+        warn!(?err, "Failed to respond to retry component");
+    }
+}
@@ -14,9 +14,9 @@ use crate::{
     core::{
         BotMetrics, Context,
         buckets::BucketName,
-        commands::checks::{check_authority, check_channel_permissions},
+        commands::checks::{check_authority, check_channel_permissions, check_disabled},
     },
-    util::ChannelExt,
+    util::{ChannelExt, locale},
 };
 
 mod parse;
@@ -59,7 +59,17 @@ pub async fn handle_message(msg: Message) {
     let name = invoke.cmd.name();
     EventKind::PrefixCommand.log(&msg, name).await;
 
-    match process_command(invoke, &msg).await {
+    // Discord hiccups may cause users to send the same command twice
+    let is_duplicate =
+        Context::get().is_duplicate_prefix_command(msg.author.id, msg.channel_id, content);
+
+    let process_res = if is_duplicate {
+        Ok(ProcessResult::Duplicate)
+    } else {
+        process_command(invoke, &msg).await
+    };
+
+    match process_res {
         Ok(ProcessResult::Success) => info!(%name, "Processed command"),
         Ok(reason) => info!(?reason, "Command `{name}` was not processed"),
         Err(err) => {
@@ -102,6 +112,14 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
         _ => None,
     };
 
+    // Disabled in the guild?
+    if check_disabled(msg.guild_id, cmd.name()).await {
+        let content = "Sorry, this command has been disabled in this server";
+        msg.error(content).await?;
+
+        return Ok(ProcessResult::Disabled);
+    }
+
     // Ratelimited?
     if let Some(cooldown) = Context::check_ratelimit(msg.author.id, BucketName::All) {
         trace!("Ratelimiting user {} for {cooldown} seconds", msg.author.id);
@@ -147,7 +165,8 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     }
 
     // Call command function
-    (cmd.exec)(msg, args, permissions).await?;
+    let exec_fut = (cmd.exec)(msg, args, permissions);
+    locale::with_number_format(Some(msg.author.id), msg.guild_id, exec_fut).await?;
 
     Ok(ProcessResult::Success)
 }
@@ -5,6 +5,7 @@ use std::{
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_model::twilight::{channel::ArchivedCachedChannel, guild::ArchivedCachedGuild};
+use bathbot_psql::model::configs::GuildConfig;
 use bathbot_util::constants::MISS_ANALYZER_ID;
 use eyre::Result;
 use tokio::{
@@ -32,6 +33,8 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    Duplicate,
+    Disabled,
 }
 
 pub enum EventKind {
@@ -177,6 +180,10 @@ async fn runner(shard: Arc<Mutex<Shard>>, mut reshard_rx: Receiver<()>) {
                     standby.process(&event);
                     let change = cache.update(&event).await;
                     BotMetrics::event(&event, change);
+
+                    let latency = shard.latency().average();
+                    Context::get().update_shard_connection(shard_id, shard.state(), latency);
+
                     tokio::spawn(handle_event(event, shard_id));
                 }
                 Some(Err(err)) => error!(?err, "Event error"),
@@ -213,6 +220,33 @@ async fn handle_event(event: Event, shard_id: u32) {
             Event::GatewayReconnect => {
                 info!(shard_id, "Gateway requested shard to reconnect")
             }
+            Event::ChannelDelete(e) => {
+                if let Err(err) = Context::psql().delete_daily_recap(e.id).await {
+                    warn!(?err, "Failed to remove daily recap of deleted channel");
+                }
+
+                if let Err(err) = Context::psql().delete_news_channel(e.id).await {
+                    warn!(
+                        ?err,
+                        "Failed to remove news subscription of deleted channel"
+                    );
+                }
+
+                if let Some(guild_id) = e.guild_id {
+                    let auto_track_channel = Context::guild_config()
+                        .peek(guild_id, |config| config.auto_track_channel)
+                        .await;
+
+                    if auto_track_channel == Some(e.id) {
+                        let update_fut = Context::guild_config()
+                            .update(guild_id, |config| config.auto_track_channel = None);
+
+                        if let Err(err) = update_fut.await {
+                            warn!(?err, "Failed to remove auto-track of deleted channel");
+                        }
+                    }
+                }
+            }
             Event::GuildCreate(e) => {
                 let guild_id = e.id();
                 let ctx = Context::get();
@@ -263,12 +297,28 @@ async fn handle_event(event: Event, shard_id: u32) {
             }
             Event::Ready(_) => info!(shard_id, "Shard is ready"),
             Event::Resumed => info!(shard_id, "Shard is resumed"),
+            Event::RoleDelete(e) => {
+                let is_authority = Context::guild_config()
+                    .peek(e.guild_id, |config| config.authorities.contains(&e.role_id))
+                    .await;
+
+                if is_authority {
+                    let f =
+                        |config: &mut GuildConfig| config.authorities.retain(|id| *id != e.role_id);
+
+                    if let Err(err) = Context::guild_config().update(e.guild_id, f).await {
+                        warn!(?err, "Failed to remove deleted authority role");
+                    }
+                }
+            }
             _ => {}
         }
 
         Ok(())
     }
 
+    Context::get().inc_shard_events(shard_id);
+
     if let Err(err) = inner(event, shard_id).await {
         error!(?err, "Failed to handle event");
     }
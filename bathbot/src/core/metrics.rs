@@ -1,19 +1,30 @@
-use std::time::Duration;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use bathbot_cache::{Cache, model::CacheChange};
 use metrics::{
-    SharedString, Unit, counter, describe_counter, describe_gauge, describe_histogram, gauge,
-    histogram,
+    Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+    counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
 };
 use rosu_v2::model::GameMode;
 use twilight_gateway::Event;
 
 const GATEWAY_EVENTS: &str = "gateway_events";
 const COMMANDS_PROCESS_TIME: &str = "commands_process_time";
+const COMMAND_INVOCATIONS: &str = "command_invocations";
 const COMMAND_ERRORS: &str = "command_errors";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
 const OSU_TRACKING_HIT: &str = "osu_tracking_hit";
+const OSU_TRACKING_NOTIFS: &str = "osu_tracking_notifications";
+const OSU_TRACKING_QUEUE: &str = "osu_tracking_queue";
+const OSU_API_LAST_SUCCESS: &str = "osu_api_last_success";
+const TOP_SCORES_CACHE: &str = "top_scores_cache";
+
+/// Recorded by rosu-v2 for every response of the osu!api
+const OSU_RESPONSE_TIME: &str = "bathbot.osu_response_time";
 
 pub struct BotMetrics;
 
@@ -25,6 +36,11 @@ impl BotMetrics {
             Unit::Seconds,
             "Time to process a command in seconds"
         );
+        describe_counter!(
+            COMMAND_INVOCATIONS,
+            Unit::Count,
+            "Number of times a command was invoked"
+        );
         describe_counter!(
             COMMAND_ERRORS,
             Unit::Count,
@@ -36,6 +52,26 @@ impl BotMetrics {
             Unit::Count,
             "Number of times redis contained a cached value"
         );
+        describe_counter!(
+            TOP_SCORES_CACHE,
+            Unit::Count,
+            "Number of lookups for cached top scores"
+        );
+        describe_counter!(
+            OSU_TRACKING_NOTIFS,
+            Unit::Count,
+            "Number of messages sent for tracked osu! scores"
+        );
+        describe_gauge!(
+            OSU_TRACKING_QUEUE,
+            Unit::Count,
+            "Number of tracked osu! scores waiting to be processed"
+        );
+        describe_gauge!(
+            OSU_API_LAST_SUCCESS,
+            Unit::Seconds,
+            "Unix timestamp of the last response of the osu!api"
+        );
 
         let stats = cache.stats();
 
@@ -66,6 +102,8 @@ impl BotMetrics {
     }
 
     pub fn observe_command(kind: &'static str, name: impl Into<SharedString>, duration: Duration) {
+        let name: SharedString = name.into();
+        counter!(COMMAND_INVOCATIONS, "kind" => kind, "name" => name.clone()).increment(1);
         histogram!(COMMANDS_PROCESS_TIME, "kind" => kind, "name" => name).record(duration);
     }
 
@@ -75,6 +113,19 @@ impl BotMetrics {
         sub: impl Into<SharedString>,
         duration: Duration,
     ) {
+        let name: SharedString = name.into();
+        let group: SharedString = group.into();
+        let sub: SharedString = sub.into();
+
+        counter!(
+            COMMAND_INVOCATIONS,
+            "kind" => "slash",
+            "name" => name.clone(),
+            "group" => group.clone(),
+            "sub" => sub.clone()
+        )
+        .increment(1);
+
         histogram!(
             COMMANDS_PROCESS_TIME,
             "kind" => "slash",
@@ -89,10 +140,34 @@ impl BotMetrics {
         counter!(OSU_TRACKING_HIT, "mode" => mode.as_str()).increment(1);
     }
 
+    pub fn inc_osu_tracking_notif() {
+        counter!(OSU_TRACKING_NOTIFS).increment(1);
+    }
+
+    /// Counts a tracked score as queued until the returned guard is dropped.
+    pub fn osu_tracking_queued() -> OsuTrackingQueueGuard {
+        gauge!(OSU_TRACKING_QUEUE).increment(1.0);
+
+        OsuTrackingQueueGuard
+    }
+
+    fn osu_api_success() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        gauge!(OSU_API_LAST_SUCCESS).set(now as f64);
+    }
+
     pub fn inc_redis_hit(kind: impl Into<SharedString>) {
         counter!(REDIS_CACHE_HITS, "kind" => kind).increment(1);
     }
 
+    pub fn inc_top_scores_cache(hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        counter!(TOP_SCORES_CACHE, "result" => result).increment(1);
+    }
+
     pub fn event(event: &Event, change: Option<CacheChange>) {
         if let Some(change) = change {
             gauge!(CACHE_ENTRIES, "kind" => "Guilds").increment(change.guilds as f64);
@@ -108,3 +183,50 @@ impl BotMetrics {
         }
     }
 }
+
+pub struct OsuTrackingQueueGuard;
+
+impl Drop for OsuTrackingQueueGuard {
+    fn drop(&mut self) {
+        gauge!(OSU_TRACKING_QUEUE).decrement(1.0);
+    }
+}
+
+/// Keeps track of the last osu!api response.
+///
+/// Since all osu!api requests go through rosu-v2, its response time
+/// histogram is recorded for every request so hooking into it covers all of
+/// them without having to wrap each request.
+pub struct OsuApiRecorder;
+
+impl Recorder for OsuApiRecorder {
+    fn register_counter(&self, _: &Key, _: &Metadata<'_>) -> Counter {
+        Counter::noop()
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        if key.name() == OSU_RESPONSE_TIME {
+            Histogram::from_arc(Arc::new(OsuApiResponse))
+        } else {
+            Histogram::noop()
+        }
+    }
+
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+}
+
+struct OsuApiResponse;
+
+impl HistogramFn for OsuApiResponse {
+    fn record(&self, _: f64) {
+        BotMetrics::osu_api_success();
+    }
+}
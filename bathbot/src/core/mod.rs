@@ -1,8 +1,11 @@
 pub use self::{
     config::BotConfig,
-    context::Context,
+    context::{
+        Context, NochokeLeaderboardEntry, PpCeilingEntry, PpCeilings, ShardStatsEntry,
+        TopScoresBundle,
+    },
     events::{EventKind, event_loop},
-    metrics::BotMetrics,
+    metrics::{BotMetrics, OsuApiRecorder},
 };
 
 mod config;
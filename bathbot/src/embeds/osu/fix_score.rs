@@ -41,8 +41,13 @@ impl FixScoreEmbed {
         );
 
         // The user has a score on the map
-        let description = if let Some(fix_score) = score {
-            let FixScore { score, top, if_fc } = fix_score;
+        let mut description = if let Some(fix_score) = score {
+            let FixScore {
+                score,
+                top,
+                if_fc,
+                unchoked_total: _,
+            } = fix_score;
 
             // The score can be unchoked
             if let Some(if_fc) = if_fc {
@@ -130,6 +135,23 @@ impl FixScoreEmbed {
             "No score on the map".to_owned()
         };
 
+        let unchoked_total = score
+            .as_ref()
+            .and_then(|fix_score| fix_score.unchoked_total)
+            .zip(user.statistics.as_ref());
+
+        if let Some((unchoked_total, stats)) = unchoked_total {
+            let pp = stats.pp.to_native();
+
+            let _ = write!(
+                description,
+                "\n\nWithout any chokes in their top100, they would have \
+                **{total}pp** (+{diff}).",
+                total = WithComma::new(round(unchoked_total)),
+                diff = WithComma::new(round((unchoked_total - pp).max(0.0))),
+            );
+        }
+
         Self {
             author,
             description,
@@ -183,3 +183,90 @@ impl Display for HitResultFormatter<'_> {
         write!(f, "{}}}", self.stats.miss)
     }
 }
+
+/// Ratio of a mania score's perfect hits (geki) to its great hits (300s).
+pub struct ManiaRatioFormatter {
+    perfect: u32,
+    great: u32,
+}
+
+impl ManiaRatioFormatter {
+    pub fn new(stats: &ScoreStatistics) -> Self {
+        Self {
+            perfect: stats.perfect,
+            great: stats.great,
+        }
+    }
+}
+
+impl Display for ManiaRatioFormatter {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.great == 0 {
+            f.write_str("∞")
+        } else {
+            write!(f, "{:.2}", self.perfect as f32 / self.great as f32)
+        }
+    }
+}
+
+/// Accuracy of a mania score in percent with perfect hits weighing more than
+/// great hits, as is the case for ScoreV2.
+pub fn mania_accuracy(stats: &ScoreStatistics) -> f32 {
+    let total = stats.perfect + stats.great + stats.good + stats.ok + stats.meh + stats.miss;
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    let weighted = 305 * stats.perfect
+        + 300 * stats.great
+        + 200 * stats.good
+        + 100 * stats.ok
+        + 50 * stats.meh;
+
+    100.0 * weighted as f32 / (305 * total) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(perfect: u32, great: u32) -> ScoreStatistics {
+        ScoreStatistics {
+            perfect,
+            great,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn mania_ratio() {
+        let ratio = ManiaRatioFormatter::new(&stats(1234, 100));
+        assert_eq!(ratio.to_string(), "12.34");
+
+        let ratio = ManiaRatioFormatter::new(&stats(2, 3));
+        assert_eq!(ratio.to_string(), "0.67");
+
+        let ratio = ManiaRatioFormatter::new(&stats(0, 5));
+        assert_eq!(ratio.to_string(), "0.00");
+    }
+
+    #[test]
+    fn mania_ratio_without_greats() {
+        let ratio = ManiaRatioFormatter::new(&stats(500, 0));
+        assert_eq!(ratio.to_string(), "∞");
+
+        let ratio = ManiaRatioFormatter::new(&stats(0, 0));
+        assert_eq!(ratio.to_string(), "∞");
+    }
+
+    #[test]
+    fn mania_accuracy_weighting() {
+        assert_eq!(mania_accuracy(&stats(100, 0)), 100.0);
+        assert_eq!(mania_accuracy(&ScoreStatistics::default()), 0.0);
+
+        let acc = mania_accuracy(&stats(0, 100));
+        assert!((acc - 100.0 * 300.0 / 305.0).abs() < 1e-4);
+    }
+}
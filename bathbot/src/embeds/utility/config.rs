@@ -1,7 +1,9 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_psql::model::configs::{
+    Lang, ListSize, NumberFormat, OsuUsername, ResponseVisibility, Retries, ScoreData, UserConfig,
+};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -10,6 +12,7 @@ use crate::embeds::EmbedData;
 
 pub struct ConfigEmbed {
     author: AuthorBuilder,
+    description: &'static str,
     fields: Vec<EmbedField>,
     footer: Option<FooterBuilder>,
     title: &'static str,
@@ -108,6 +111,32 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Responses*",
+                config.response_visibility.unwrap_or_default(),
+                &[
+                    (ResponseVisibility::Public, "public"),
+                    (ResponseVisibility::Ephemeral, "ephemeral"),
+                ],
+            ),
+            create_field(
+                "Numbers",
+                config.number_format.unwrap_or_default(),
+                &[
+                    (NumberFormat::Comma, "1,234.5"),
+                    (NumberFormat::Dot, "1.234,5"),
+                    (NumberFormat::Space, "1 234,5"),
+                ],
+            ),
+            create_field(
+                "Language",
+                config.lang.unwrap_or_default(),
+                &[
+                    (Lang::English, "english"),
+                    (Lang::German, "german"),
+                    (Lang::Russian, "russian"),
+                ],
+            ),
         ];
 
         if let Some(skin_url) = skin_url {
@@ -124,8 +153,12 @@ impl ConfigEmbed {
             .map(|tz| format!("Timezone: UTC{tz:+}"))
             .map(FooterBuilder::new);
 
+        let description = "*: Applies to `/top`, `/profile`, `/recent`, and `/rank`. \
+            Servers that force public responses override this setting.";
+
         Self {
             author,
+            description,
             fields,
             footer,
             title,
@@ -163,6 +196,7 @@ impl EmbedData for ConfigEmbed {
     fn build(self) -> EmbedBuilder {
         let mut builder = EmbedBuilder::new()
             .author(self.author)
+            .description(self.description)
             .fields(self.fields)
             .title(self.title);
 
@@ -2,9 +2,12 @@ use std::fmt::Write;
 
 use bathbot_cache::model::CachedArchive;
 use bathbot_macros::EmbedData;
-use bathbot_model::twilight::guild::ArchivedCachedGuild;
-use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
+use bathbot_model::{embed_builder::SettingsImage, twilight::guild::ArchivedCachedGuild};
+use bathbot_psql::model::configs::{
+    ErrorMessageTtl, GuildConfig, HideSolutions, Lang, ListSize, NumberFormat, Retries, ScoreData,
+};
 use bathbot_util::AuthorBuilder;
+use rosu_v2::prelude::GameMode;
 use twilight_model::channel::message::embed::EmbedField;
 
 use super::config::create_field;
@@ -65,6 +68,19 @@ impl ServerConfigEmbed {
             }
         }
 
+        description.push_str("\nDisabled commands: ");
+        let mut disabled = config.disabled_commands.iter();
+
+        if let Some(name) = disabled.next() {
+            let _ = write!(description, "{name}");
+
+            for name in disabled {
+                let _ = write!(description, ", {name}");
+            }
+        } else {
+            description.push_str("None");
+        }
+
         description.push_str("\n```");
 
         let fields = vec![
@@ -128,6 +144,89 @@ impl ServerConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Use threads",
+                config.use_threads.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            create_field(
+                "Default mode*",
+                config.default_mode,
+                &[
+                    (None, "none"),
+                    (Some(GameMode::Osu), "osu"),
+                    (Some(GameMode::Taiko), "taiko"),
+                    (Some(GameMode::Catch), "catch"),
+                    (Some(GameMode::Mania), "mania"),
+                ],
+            ),
+            create_field(
+                "Personal responses",
+                config.force_public_responses.unwrap_or(false),
+                &[(true, "force public"), (false, "let user decide")],
+            ),
+            create_field(
+                "Numbers*",
+                config.number_format.unwrap_or_default(),
+                &[
+                    (NumberFormat::Comma, "1,234.5"),
+                    (NumberFormat::Dot, "1.234,5"),
+                    (NumberFormat::Space, "1 234,5"),
+                ],
+            ),
+            create_field(
+                "Language*",
+                config.lang.unwrap_or_default(),
+                &[
+                    (Lang::English, "english"),
+                    (Lang::German, "german"),
+                    (Lang::Russian, "russian"),
+                ],
+            ),
+            create_field(
+                "Score images*",
+                config.score_embed_image.unwrap_or_default(),
+                &[
+                    (SettingsImage::Thumbnail, "thumbnail"),
+                    (SettingsImage::Image, "image"),
+                ],
+            ),
+            create_field(
+                "Delete errors",
+                config.error_message_ttl.unwrap_or_default(),
+                &[
+                    (ErrorMessageTtl::Off, "off"),
+                    (ErrorMessageTtl::ThirtySeconds, "after 30s"),
+                    (ErrorMessageTtl::SixtySeconds, "after 60s"),
+                    (ErrorMessageTtl::FiveMinutes, "after 5min"),
+                ],
+            ),
+            create_field(
+                "Delete invoke on error",
+                config.error_delete_invoke.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            EmbedField {
+                inline: true,
+                name: "Pagination lifetime".to_owned(),
+                value: format!("```\n{}s\n```", config.pagination_lifetime.unwrap_or(60)),
+            },
+            EmbedField {
+                inline: true,
+                name: "Auto-track on link".to_owned(),
+                value: match config.auto_track_channel {
+                    Some(channel) => format!("<#{channel}>"),
+                    None => "```\ndisabled\n```".to_owned(),
+                },
+            },
+            EmbedField {
+                inline: true,
+                name: "Embed color".to_owned(),
+                value: match config.embed_color {
+                    Some(color) => format!("```\n#{color:06X}\n```"),
+                    None => "```\ndefault\n```".to_owned(),
+                },
+            },
         ];
 
         Self {
@@ -103,6 +103,33 @@ async fn async_main() -> Result<()> {
         tokio::spawn(Context::match_live_loop());
     }
 
+    // Spawn daily recap worker
+    tokio::spawn(tracking::daily_recap_loop());
+
+    // Spawn osu! tracking reconciliation worker
+    tokio::spawn(tracking::osu_tracking_reconcile_loop());
+
+    // Spawn osu! tracking startup summary worker
+    tokio::spawn(tracking::osu_tracking_startup_summary());
+
+    // Spawn notable scores pruning worker
+    tokio::spawn(tracking::notable_scores_prune_loop());
+
+    // Spawn osu! news worker
+    tokio::spawn(tracking::news_loop());
+
+    // Spawn map file eviction worker
+    tokio::spawn(manager::map_file_eviction_loop());
+
+    // Spawn background image hashing worker
+    tokio::spawn(manager::bg_image_hash_loop());
+
+    // Spawn prefix command dedupe cleanup worker
+    tokio::spawn(Context::prefix_dedupe_cleanup_loop());
+
+    // Spawn cache eviction worker
+    tokio::spawn(Context::ttl_cache_eviction_loop());
+
     // Request members
     tokio::spawn(async move {
         let ctx = Context::get();
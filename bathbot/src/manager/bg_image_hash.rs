@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use bathbot_psql::model::games::DbUnhashedBgImage;
+use eyre::{Result, WrapErr, bail};
+use image::{DynamicImage, imageops::FilterType};
+use rosu_v2::prelude::GameMode;
+use tokio::{
+    fs, task,
+    time::{self, MissedTickBehavior},
+};
+
+use crate::core::{BotConfig, Context};
+
+/// Delay after startup until background images are first hashed.
+const STARTUP_DELAY: Duration = Duration::from_secs(10 * 60);
+
+/// How often newly added background images are hashed.
+const INDEX_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Amount of images fetched from the database at once.
+const BATCH_SIZE: usize = 50;
+
+/// Delay between hashing two images to not hog the disk and cpu.
+const IMAGE_DELAY: Duration = Duration::from_millis(200);
+
+/// Periodically hash the images of the background game so that `/whatmap`
+/// can find the mapset of an image.
+///
+/// Every hash is stored right away so an interrupted run continues with the
+/// remaining images on the next iteration.
+pub async fn bg_image_hash_loop() {
+    time::sleep(STARTUP_DELAY).await;
+
+    let mut interval = time::interval(INDEX_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        match hash_unhashed_images().await {
+            Ok(0) => {}
+            Ok(count) => info!(count, "Hashed background images"),
+            Err(err) => warn!(?err, "Failed to hash background images"),
+        }
+    }
+}
+
+async fn hash_unhashed_images() -> Result<usize> {
+    let games = Context::games();
+    let mut count = 0;
+
+    loop {
+        let images = games.bggame_unhashed_images(BATCH_SIZE).await?;
+
+        if images.is_empty() {
+            return Ok(count);
+        }
+
+        for image in images {
+            let mapset_id = image.mapset_id as u32;
+
+            // Unhashable images are stored too so they won't be retried
+            let hash = match hash_bg_file(&image).await {
+                Ok(hash) => Some(hash),
+                Err(err) => {
+                    warn!(mapset_id, ?err, "Failed to hash background image");
+
+                    None
+                }
+            };
+
+            games
+                .bggame_upsert_image_hash(mapset_id, &image.image_filename, hash)
+                .await?;
+
+            count += 1;
+            time::sleep(IMAGE_DELAY).await;
+        }
+    }
+}
+
+async fn hash_bg_file(image: &DbUnhashedBgImage) -> Result<u64> {
+    let mut path = BotConfig::get().paths.backgrounds.clone();
+
+    match GameMode::from(image.gamemode as u8) {
+        GameMode::Osu => path.push("osu"),
+        GameMode::Mania => path.push("mania"),
+        mode => bail!("background game not available for {mode}"),
+    }
+
+    path.push(&image.image_filename);
+
+    let bytes = fs::read(&path)
+        .await
+        .wrap_err_with(|| format!("Failed to read file at {path:?}"))?;
+
+    task::spawn_blocking(move || hash_image_bytes(&bytes))
+        .await
+        .wrap_err("Hashing task panicked")?
+}
+
+/// Decode the bytes of an image and calculate its [`dhash`].
+pub fn hash_image_bytes(bytes: &[u8]) -> Result<u64> {
+    let img = image::load_from_memory(bytes).wrap_err("Failed to load image from memory")?;
+
+    Ok(dhash(&img))
+}
+
+/// Difference hash of an image.
+///
+/// The image is shrunk to 9x8 grayscale pixels and each bit denotes whether a
+/// pixel is brighter than its right neighbor. Similar images have hashes with
+/// a small [`hash_distance`], regardless of their size or compression.
+pub fn dhash(img: &DynamicImage) -> u64 {
+    let small = img.resize_exact(9, 8, FilterType::Triangle).to_luma8();
+    let mut hash = 0;
+
+    for y in 0..8 {
+        for x in 0..8 {
+            let left = small.get_pixel(x, y)[0];
+            let right = small.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | (left > right) as u64;
+        }
+    }
+
+    hash
+}
+
+/// Amount of differing bits between two hashes.
+pub fn hash_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{GrayImage, Luma};
+
+    use super::*;
+
+    fn gradient(width: u32, height: u32, reverse: bool) -> DynamicImage {
+        let img = GrayImage::from_fn(width, height, |x, _| {
+            let value = (x * 255 / (width - 1)) as u8;
+
+            Luma([if reverse { 255 - value } else { value }])
+        });
+
+        DynamicImage::ImageLuma8(img)
+    }
+
+    #[test]
+    fn scaled_images_match() {
+        let small = dhash(&gradient(90, 80, true));
+        let large = dhash(&gradient(900, 800, true));
+
+        assert!(hash_distance(small, large) <= 2);
+    }
+
+    #[test]
+    fn different_images_differ() {
+        let ascending = dhash(&gradient(90, 80, false));
+        let descending = dhash(&gradient(90, 80, true));
+
+        assert!(hash_distance(ascending, descending) > 32);
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(hash_distance(0, 0), 0);
+        assert_eq!(hash_distance(0b1011, 0b0001), 2);
+        assert_eq!(hash_distance(u64::MAX, 0), 64);
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use bathbot_model::{BgGameScore, HlGameScore, HlVersion};
 use bathbot_psql::{
     Database,
-    model::games::{DbMapTagsParams, MapsetTagsEntries},
+    model::games::{DbMapTagsParams, DbUnhashedBgImage, MapsetTagsEntries},
 };
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
@@ -78,17 +78,20 @@ impl GameManager {
     pub async fn bggame_increment_scores(
         self,
         scores: &HashMap<Id<UserMarker>, u32, IntHasher>,
+        streaks: &HashMap<Id<UserMarker>, u32, IntHasher>,
     ) -> Result<()> {
         let mut user_ids = Vec::with_capacity(scores.len());
         let mut amounts = Vec::with_capacity(scores.len());
+        let mut best_streaks = Vec::with_capacity(scores.len());
 
         for (user_id, amount) in scores {
             user_ids.push(user_id.get() as i64);
             amounts.push(*amount as i32);
+            best_streaks.push(streaks.get(user_id).map_or(0, |streak| *streak as i32));
         }
 
         self.psql
-            .increment_bggame_scores(&user_ids, &amounts)
+            .increment_bggame_scores(&user_ids, &amounts, &best_streaks)
             .await
             .wrap_err("failed to increment score")
     }
@@ -104,4 +107,39 @@ impl GameManager {
             .await
             .wrap_err("failed to upsert mapset")
     }
+
+    pub async fn bggame_unhashed_images(self, limit: usize) -> Result<Vec<DbUnhashedBgImage>> {
+        self.psql
+            .select_unhashed_bg_images(limit as i64)
+            .await
+            .wrap_err("Failed to get unhashed background images")
+    }
+
+    pub async fn bggame_upsert_image_hash(
+        self,
+        mapset_id: u32,
+        filename: &str,
+        hash: Option<u64>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_bg_image_hash(mapset_id, filename, hash)
+            .await
+            .wrap_err("Failed to upsert background image hash")
+    }
+
+    /// All background image hashes as `(mapset_id, hash)` pairs
+    pub async fn bggame_image_hashes(self) -> Result<Vec<(u32, u64)>> {
+        let hashes = self
+            .psql
+            .select_bg_image_hashes()
+            .await
+            .wrap_err("Failed to get background image hashes")?;
+
+        let hashes = hashes
+            .into_iter()
+            .map(|entry| (entry.mapset_id as u32, entry.hash as u64))
+            .collect();
+
+        Ok(hashes)
+    }
 }
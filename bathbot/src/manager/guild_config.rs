@@ -1,7 +1,14 @@
-use bathbot_psql::{Database, model::configs::GuildConfig};
+use std::num::NonZeroU32;
+
+use bathbot_model::embed_builder::ScoreEmbedSettings;
+use bathbot_psql::{
+    Database,
+    model::configs::{GuildConfig, Lang, NumberFormat},
+};
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
 use papaya::HashMap as PapayaMap;
+use rosu_v2::prelude::GameMode;
 use twilight_model::id::{Id, marker::GuildMarker};
 
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
@@ -52,6 +59,64 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| GuildConfig::DEFAULT_PREFIX.to_owned())
     }
 
+    /// The guild's default mode for commands that don't get a mode from their
+    /// arguments or the user config.
+    pub async fn mode(self, guild_id: Option<Id<GuildMarker>>) -> Option<GameMode> {
+        match guild_id {
+            Some(guild_id) => self.peek(guild_id, |config| config.default_mode).await,
+            None => None,
+        }
+    }
+
+    /// The guild's language for user-facing messages.
+    pub async fn lang(self, guild_id: Option<Id<GuildMarker>>) -> Option<Lang> {
+        match guild_id {
+            Some(guild_id) => self.peek(guild_id, |config| config.lang).await,
+            None => None,
+        }
+    }
+
+    /// The guild's separators for displaying numbers.
+    pub async fn number_format(self, guild_id: Option<Id<GuildMarker>>) -> Option<NumberFormat> {
+        match guild_id {
+            Some(guild_id) => self.peek(guild_id, |config| config.number_format).await,
+            None => None,
+        }
+    }
+
+    /// The member's score embed settings or, if they have none, the default
+    /// settings with the guild's image layout.
+    pub async fn score_embed_settings(
+        self,
+        guild_id: Option<Id<GuildMarker>>,
+        settings: Option<ScoreEmbedSettings>,
+    ) -> ScoreEmbedSettings {
+        if let Some(settings) = settings {
+            return settings;
+        }
+
+        let image = match guild_id {
+            Some(guild_id) => self.peek(guild_id, |config| config.score_embed_image).await,
+            None => None,
+        };
+
+        ScoreEmbedSettings {
+            image: image.unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// The guild's custom color for embeds.
+    ///
+    /// Unlike [`GuildConfigManager::peek`], this does not store a default
+    /// config for unknown guilds so it can be used synchronously.
+    pub fn embed_color(self, guild_id: Option<Id<GuildMarker>>) -> Option<NonZeroU32> {
+        let guild_id = guild_id?;
+        let color = self.guild_configs.pin().get(&guild_id)?.embed_color?;
+
+        NonZeroU32::new(color)
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
@@ -0,0 +1,155 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use eyre::{Result, WrapErr};
+use tokio::{
+    task,
+    time::{self, MissedTickBehavior},
+};
+
+use crate::core::BotConfig;
+
+/// How often the map directory is checked for files to evict.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Mark a map file as recently used by bumping its modification time.
+///
+/// Access times are unreliable since many systems mount with `noatime` so
+/// eviction is based on the modification time instead.
+pub(super) fn touch_map_file(path: &Path) {
+    let res = fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+
+    if let Err(err) = res {
+        debug!(?path, ?err, "Failed to touch map file");
+    }
+}
+
+/// Periodically delete the least recently used `.osu` files once the map
+/// directory exceeds the configured limits.
+///
+/// Evicted files are not removed from the database; the [`MapManager`] notices
+/// missing files and downloads them again.
+///
+/// [`MapManager`]: super::MapManager
+pub async fn map_file_eviction_loop() {
+    let config = BotConfig::get();
+    let limits = &config.map_cache;
+
+    if limits.max_size_mb.is_none() && limits.max_age_days.is_none() {
+        return;
+    }
+
+    let max_size = limits.max_size_mb.map(|mb| mb * 1024 * 1024);
+    let max_age = limits
+        .max_age_days
+        .map(|days| Duration::from_secs(days * SECONDS_PER_DAY));
+
+    let mut interval = time::interval(EVICTION_INTERVAL);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let dir = config.paths.maps.clone();
+        let evict_fut = task::spawn_blocking(move || evict_map_files(&dir, max_size, max_age));
+
+        match evict_fut.await {
+            Ok(Ok(EvictionSummary { count: 0, .. })) => {}
+            Ok(Ok(summary)) => info!(
+                count = summary.count,
+                bytes = summary.bytes,
+                "Evicted map files"
+            ),
+            Ok(Err(err)) => warn!(?err, "Failed to evict map files"),
+            Err(err) => warn!(?err, "Map file eviction task panicked"),
+        }
+    }
+}
+
+struct MapFileEntry {
+    path: PathBuf,
+    size: u64,
+    last_used: SystemTime,
+}
+
+#[derive(Default)]
+struct EvictionSummary {
+    count: usize,
+    bytes: u64,
+}
+
+fn evict_map_files(
+    dir: &Path,
+    max_size: Option<u64>,
+    max_age: Option<Duration>,
+) -> Result<EvictionSummary> {
+    let mut entries = Vec::new();
+
+    for entry_res in fs::read_dir(dir).wrap_err("Failed to read map directory")? {
+        let Ok(entry) = entry_res else { continue };
+        let path = entry.path();
+
+        if path.extension().is_none_or(|ext| ext != "osu") {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        // Files are touched whenever they're used so the modification time
+        // denotes the last usage
+        let Ok(last_used) = metadata.modified() else {
+            continue;
+        };
+
+        entries.push(MapFileEntry {
+            path,
+            size: metadata.len(),
+            last_used,
+        });
+    }
+
+    // Most recently used first so that files are evicted from the back
+    entries.sort_unstable_by(|a, b| b.last_used.cmp(&a.last_used));
+
+    let now = SystemTime::now();
+    let mut total_size: u64 = entries.iter().map(|entry| entry.size).sum();
+    let mut summary = EvictionSummary::default();
+
+    while let Some(entry) = entries.last() {
+        let too_large = max_size.is_some_and(|max| total_size > max);
+
+        let too_old = max_age.is_some_and(|max| {
+            now.duration_since(entry.last_used)
+                .is_ok_and(|age| age > max)
+        });
+
+        if !(too_large || too_old) {
+            break;
+        }
+
+        match fs::remove_file(&entry.path) {
+            Ok(_) => {
+                summary.count += 1;
+                summary.bytes += entry.size;
+            }
+            // Someone else removed it in the meanwhile
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => warn!(path = ?entry.path, ?err, "Failed to remove map file"),
+        }
+
+        total_size -= entry.size;
+        entries.pop();
+    }
+
+    Ok(summary)
+}
@@ -1,11 +1,13 @@
 #[cfg(feature = "twitch")]
 pub use self::twitch::TwitchManager;
 pub use self::{
+    bg_image_hash::{bg_image_hash_loop, hash_distance, hash_image_bytes},
     bookmarks::BookmarkManager,
     games::GameManager,
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
+    map_file_cache::map_file_eviction_loop,
     osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
     osu_scores::ScoresManager,
     osu_user::OsuUserManager,
@@ -17,11 +19,13 @@ pub use self::{
 
 pub mod redis;
 
+mod bg_image_hash;
 mod bookmarks;
 mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod map_file_cache;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
@@ -1,9 +1,17 @@
-use std::{collections::HashMap, fmt::Debug, io::Error as IoError, ops::Deref, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    iter,
+    ops::Deref,
+    path::PathBuf,
+};
 
 use bathbot_client::ClientError;
 use bathbot_psql::model::osu::{ArtistTitle, DbBeatmap, DbBeatmapset, DbMapFilename, MapVersion};
 use bathbot_util::{ExponentialBackoff, IntHasher};
 use eyre::{ContextCompat, Report, WrapErr};
+use md5::{Digest, Md5};
 use rosu_pp::{
     Beatmap,
     any::DifficultyAttributes,
@@ -14,7 +22,7 @@ use thiserror::Error;
 use time::OffsetDateTime;
 use tokio::{fs, time::sleep};
 
-use super::{PpManager, pp::Mods};
+use super::{PpManager, map_file_cache, pp::Mods};
 use crate::{
     core::{BotConfig, Context},
     util::query::{FilterCriteria, RegularCriteria, Searchable},
@@ -36,8 +44,10 @@ impl MapManager {
         let mut map_path = BotConfig::get().paths.maps.clone();
 
         if let Some((map, mapset, filename)) = map_fut.await.wrap_err("Failed to get map")? {
+            // Only the caller's checksum is verified; the stored one may be
+            // outdated if the map was updated since
             let (pp_map, map_opt) = self
-                .prepare_map(map_id, filename, &mut map_path)
+                .prepare_map(map_id, filename, checksum, &mut map_path)
                 .await
                 .wrap_err("Failed to prepare map")?;
 
@@ -48,7 +58,8 @@ impl MapManager {
         } else {
             // Otherwise retrieve mapset and store
             let map_fut = self.retrieve_map(map_id);
-            let prepare_fut = self.prepare_map(map_id, DbMapFilename::Missing, &mut map_path);
+            let prepare_fut =
+                self.prepare_map(map_id, DbMapFilename::Missing, checksum, &mut map_path);
             let (map, (pp_map, _)) = tokio::try_join!(map_fut, prepare_fut)?;
 
             Ok(OsuMap::new(map, pp_map))
@@ -65,7 +76,7 @@ impl MapManager {
         let mut map_path = BotConfig::get().paths.maps.clone();
 
         let (pp_map, _) = self
-            .prepare_map(map_id, filename, &mut map_path)
+            .prepare_map(map_id, filename, None, &mut map_path)
             .await
             .wrap_err("Failed to prepare map")?;
 
@@ -111,6 +122,23 @@ impl MapManager {
         }
     }
 
+    /// Resolve the id of a map through the md5 hash of its .osu file.
+    pub async fn map_id_by_checksum(self, checksum: &str) -> Result<u32> {
+        match Context::psql().select_map_id_by_checksum(checksum).await {
+            Ok(Some(map_id)) => return Ok(map_id),
+            Ok(None) => {}
+            Err(err) => warn!(?err, "Failed to get map id by checksum"),
+        }
+
+        match Context::osu().beatmap().checksum(checksum).await {
+            Ok(map) => Ok(map.map_id),
+            Err(OsuError::NotFound) => Err(MapError::NotFound),
+            Err(err) => Err(MapError::Report(
+                Report::new(err).wrap_err("Failed to retrieve map by checksum"),
+            )),
+        }
+    }
+
     pub async fn maps(
         self,
         maps_id_checksum: &HashMap<i32, Option<&str>, IntHasher>,
@@ -121,17 +149,19 @@ impl MapManager {
             .wrap_err("Failed to get maps")?;
 
         let iter = maps_id_checksum
-            .keys()
-            .map(|map_id| (*map_id as u32, db_maps.remove(map_id)));
+            .iter()
+            .map(|(map_id, checksum)| (*map_id as u32, *checksum, db_maps.remove(map_id)));
 
         let mut maps = HashMap::with_capacity_and_hasher(maps_id_checksum.len(), IntHasher);
         let mut map_path = BotConfig::get().paths.maps.clone();
 
         // Having this non-async is pretty sad but the many I/O operations appear
         // to cause thread-limitation issues when collected into a FuturesUnordered.
-        for (map_id, map_opt) in iter {
+        for (map_id, checksum, map_opt) in iter {
             let map = if let Some((map, mapset, filename)) = map_opt {
-                let (pp_map, map_opt) = self.prepare_map(map_id, filename, &mut map_path).await?;
+                let (pp_map, map_opt) = self
+                    .prepare_map(map_id, filename, checksum, &mut map_path)
+                    .await?;
 
                 match map_opt {
                     Some(map) => OsuMap::new(map, pp_map),
@@ -139,7 +169,8 @@ impl MapManager {
                 }
             } else {
                 let map_fut = self.retrieve_map(map_id);
-                let prepare_fut = self.prepare_map(map_id, DbMapFilename::Missing, &mut map_path);
+                let prepare_fut =
+                    self.prepare_map(map_id, DbMapFilename::Missing, checksum, &mut map_path);
                 let (map, (pp_map, _)) = tokio::try_join!(map_fut, prepare_fut)?;
 
                 OsuMap::new(map, pp_map)
@@ -277,11 +308,14 @@ impl MapManager {
         }
     }
 
-    /// Make sure the map's current file is available
+    /// Make sure the map's current file is available.
+    ///
+    /// If a checksum is given, downloaded files are verified against it.
     async fn prepare_map(
         self,
         map_id: u32,
         filename: DbMapFilename,
+        checksum: Option<&str>,
         map_path: &mut PathBuf,
     ) -> Result<(Beatmap, Option<OsuMapSlim>)> {
         match filename {
@@ -289,7 +323,21 @@ impl MapManager {
                 map_path.push(filename.as_ref());
 
                 let res = match Beatmap::from_path(&*map_path) {
-                    Ok(map) => Ok((map, None)),
+                    Ok(map) => {
+                        map_file_cache::touch_map_file(&*map_path);
+
+                        Ok((map, None))
+                    }
+                    // The file was evicted from the disk cache
+                    Err(err) if err.kind() == IoErrorKind::NotFound => {
+                        info!("Evicted map {map_id}, downloading...");
+
+                        self.download_map_file(map_id, checksum)
+                            .await
+                            .map(|map| (map, None))
+                            .wrap_err("Failed to download evicted map file")
+                            .map_err(MapError::from)
+                    }
                     Err(err) => {
                         if let Err(err) = fs::remove_file(&*map_path).await {
                             warn!(?map_path, ?err, "Failed to delete file");
@@ -308,7 +356,7 @@ impl MapManager {
             DbMapFilename::ChecksumMismatch => {
                 info!("Checksum mismatch for map {map_id}, re-downloading...");
 
-                let map_fut = self.download_map_file(map_id);
+                let map_fut = self.download_map_file(map_id, checksum);
                 let map_slim_fut = self.retrieve_map(map_id);
 
                 let (map_res, map_slim_res) = tokio::join!(map_fut, map_slim_fut);
@@ -330,7 +378,7 @@ impl MapManager {
                 info!("Missing map {map_id}, downloading...");
 
                 let map = self
-                    .download_map_file(map_id)
+                    .download_map_file(map_id, checksum)
                     .await
                     .wrap_err("Failed to download map file")?;
 
@@ -339,8 +387,12 @@ impl MapManager {
         }
     }
 
-    /// Download a map's file and retry if it failed
-    async fn download_map_file(&self, map_id: u32) -> Result<Beatmap> {
+    /// Download a map's file and retry if it failed.
+    ///
+    /// If osu! fails to provide the file, the configured mirrors are tried in
+    /// order before backing off. Files that don't match the given md5 checksum
+    /// are discarded.
+    async fn download_map_file(&self, map_id: u32, checksum: Option<&str>) -> Result<Beatmap> {
         let backoff = ExponentialBackoff::new(2).factor(500).max_delay(10_000);
         const ATTEMPTS: usize = 10;
 
@@ -348,45 +400,72 @@ impl MapManager {
         enum BackoffReason {
             NoContent,
             Ratelimited,
+            ChecksumMismatch,
             DecodeFail(
                 // false positive; used when logging
                 #[allow(unused)] IoError,
             ),
         }
 
-        for (duration, i) in backoff.take(ATTEMPTS).zip(2..) {
-            let bytes_fut = Context::client().get_map_file(map_id);
+        let mirrors = &BotConfig::get().map_mirrors;
 
-            let reason = match bytes_fut.await {
-                Ok(bytes) if !bytes.is_empty() => match Beatmap::from_bytes(&bytes) {
-                    Ok(map) => {
-                        let mut map_path = BotConfig::get().paths.maps.clone();
-                        let filename = format!("{map_id}.osu");
-                        map_path.push(&filename);
+        for (duration, i) in backoff.take(ATTEMPTS).zip(2..) {
+            let mut reason = None;
+            let mut osu_err = None;
+
+            let sources =
+                iter::once(None).chain(mirrors.iter().map(|mirror| Some(mirror.as_ref())));
+
+            for mirror in sources {
+                let bytes_res = match mirror {
+                    None => Context::client().get_map_file(map_id).await,
+                    Some(mirror) => {
+                        Context::client()
+                            .get_map_file_from_mirror(mirror, map_id)
+                            .await
+                    }
+                };
 
-                        let write_fut = fs::write(&map_path, &bytes);
-                        let db_fut = Context::psql().insert_beatmap_file(map_id, &filename);
+                let source = mirror.unwrap_or("osu!");
 
-                        let (write_res, db_res) = tokio::join!(write_fut, db_fut);
-                        write_res.wrap_err("Failed writing to file")?;
+                let curr_reason = match bytes_res {
+                    Ok(bytes) if bytes.is_empty() => BackoffReason::NoContent,
+                    Ok(bytes) if !checksum_matches(&bytes, checksum) => {
+                        BackoffReason::ChecksumMismatch
+                    }
+                    Ok(bytes) => match Beatmap::from_bytes(&bytes) {
+                        Ok(map) => {
+                            Self::store_map_file(map_id, &bytes).await?;
+                            info!(source, "Downloaded {map_id}.osu successfully");
 
-                        if let Err(err) = db_res {
-                            warn!(?err, "Failed to insert map file");
+                            return Ok(map);
                         }
+                        Err(err) => BackoffReason::DecodeFail(err),
+                    },
+                    Err(ClientError::Ratelimited) => BackoffReason::Ratelimited,
+                    Err(err) => {
+                        let err = Report::new(err).wrap_err("Failed to request map file");
 
-                        info!("Downloaded {map_id}.osu successfully");
+                        if mirror.is_none() {
+                            osu_err = Some(err);
+                        } else {
+                            warn!(source, ?err, "Failed map download from mirror");
+                        }
 
-                        return Ok(map);
+                        continue;
                     }
-                    Err(err) => BackoffReason::DecodeFail(err),
-                },
-                Ok(_) => BackoffReason::NoContent,
-                Err(ClientError::Ratelimited) => BackoffReason::Ratelimited,
-                Err(err) => {
-                    let err = Report::new(err).wrap_err("Failed to request map file");
-
-                    return Err(err.into());
-                }
+                };
+
+                debug!(source, reason = ?curr_reason, "Failed map download");
+                reason = Some(curr_reason);
+            }
+
+            // Only retry if a source failed in a way that might resolve itself
+            let Some(reason) = reason else {
+                let err = osu_err
+                    .unwrap_or_else(|| eyre!("Failed to request {map_id}.osu from all sources"));
+
+                return Err(err.into());
             };
 
             warn!(
@@ -401,6 +480,32 @@ impl MapManager {
 
         Err(MapError::Report(err))
     }
+
+    async fn store_map_file(map_id: u32, bytes: &[u8]) -> Result<()> {
+        let mut map_path = BotConfig::get().paths.maps.clone();
+        let filename = format!("{map_id}.osu");
+        map_path.push(&filename);
+
+        let write_fut = fs::write(&map_path, bytes);
+        let db_fut = Context::psql().insert_beatmap_file(map_id, &filename);
+
+        let (write_res, db_res) = tokio::join!(write_fut, db_fut);
+        write_res.wrap_err("Failed writing to file")?;
+
+        if let Err(err) = db_res {
+            warn!(?err, "Failed to insert map file");
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the md5 hash of the file matches the checksum, if one is given.
+fn checksum_matches(bytes: &[u8], checksum: Option<&str>) -> bool {
+    match checksum.filter(|checksum| !checksum.is_empty()) {
+        Some(checksum) => format!("{:x}", Md5::digest(bytes)).eq_ignore_ascii_case(checksum),
+        None => true,
+    }
 }
 
 #[derive(Clone)]
@@ -437,6 +542,7 @@ impl OsuMapSlim {
             map_id: map.map_id as i32,
             mapset_id: map.mapset_id as i32,
             user_id: map.creator_id as i32,
+            checksum: map.checksum.unwrap_or_default(),
             map_version: map.version,
             seconds_drain: map.seconds_drain as i32,
             count_circles: map.count_circles as i32,
@@ -460,6 +566,10 @@ impl OsuMapSlim {
         self.map.map_version.as_str()
     }
 
+    pub fn checksum(&self) -> Option<&str> {
+        Some(self.map.checksum.as_str()).filter(|checksum| !checksum.is_empty())
+    }
+
     pub fn artist(&self) -> &str {
         self.mapset.artist.as_str()
     }
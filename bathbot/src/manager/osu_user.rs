@@ -1,10 +1,11 @@
 use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_model::{RankingEntries, UserModeStatsColumn, UserStatsColumn};
-use bathbot_psql::Database;
+use bathbot_psql::{Database, model::osu::DbUserSnapshot};
 use bathbot_util::{CowUtils, IntHasher};
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{GameMode, UserExtended, Username};
+use time::Date;
 
 use crate::core::Context;
 
@@ -85,6 +86,18 @@ impl OsuUserManager {
             .wrap_err("Failed to get user mode stats")
     }
 
+    pub async fn snapshots(
+        self,
+        user_id: u32,
+        mode: GameMode,
+        since: Option<Date>,
+    ) -> Result<Vec<DbUserSnapshot>> {
+        self.psql
+            .select_osu_user_snapshots(user_id, mode, since)
+            .await
+            .wrap_err("Failed to get user snapshots")
+    }
+
     pub async fn store(self, user: &UserExtended, mode: GameMode) {
         if let Err(err) = self.psql.upsert_osu_user(user, mode).await {
             warn!(?err, "Failed to upsert osu user");
@@ -1,22 +1,27 @@
 use std::borrow::Cow;
 
 use bathbot_model::{OsuStatsScore, ScoreSlim};
+use bathbot_psql::model::osu::DbMapPp;
 use rosu_pp::{
     Beatmap, Difficulty,
     any::{DifficultyAttributes, PerformanceAttributes, ScoreState},
     model::mode::GameMode as Mode,
 };
 use rosu_v2::{
-    model::mods::GameMods,
+    model::mods::{GameMods, GameModsIntermode},
     prelude::{GameMode, Grade, Score, ScoreStatistics},
 };
 
 use super::OsuMap;
-use crate::commands::{osu::LeaderboardScore, utility::ScoreEmbedDataRaw};
+use crate::{
+    commands::{osu::LeaderboardScore, utility::ScoreEmbedDataRaw},
+    core::Context,
+};
 
 #[derive(Clone)]
 pub struct PpManager<'m> {
     map: Cow<'m, Beatmap>,
+    checksum: Option<&'m str>,
     attrs: Option<DifficultyAttributes>,
     mods: Mods,
     state: Option<ScoreState>,
@@ -25,13 +30,23 @@ pub struct PpManager<'m> {
 }
 
 impl<'m> PpManager<'m> {
+    /// Version of the pp calculation.
+    ///
+    /// Must be bumped whenever calculated values change e.g. due to a rosu-pp
+    /// update so that values stored in the database are recalculated.
+    pub const CALC_VERSION: i16 = 1;
+
     pub fn new(map: &'m OsuMap) -> Self {
-        Self::from_parsed(&map.pp_map)
+        Self {
+            checksum: map.checksum(),
+            ..Self::from_parsed(&map.pp_map)
+        }
     }
 
     pub fn from_parsed(map: &'m Beatmap) -> Self {
         Self {
             map: Cow::Borrowed(map),
+            checksum: None,
             attrs: None,
             mods: Mods::default(),
             state: None,
@@ -153,6 +168,102 @@ impl<'m> PpManager<'m> {
 
         calc.calculate()
     }
+
+    /// Calculate performance attributes on the full map for the given
+    /// accuracy and amount of misses, ignoring any score state.
+    pub async fn accuracy_performance(
+        &mut self,
+        accuracy: f32,
+        misses: u32,
+    ) -> PerformanceAttributes {
+        if self.partial {
+            self.partial = false;
+            self.attrs = None;
+        }
+
+        let mut calc = self
+            .difficulty()
+            .await
+            .to_owned()
+            .performance()
+            .mods(self.mods.inner.clone())
+            .lazer(self.lazer)
+            .accuracy(accuracy as f64)
+            .misses(misses);
+
+        if let Some(clock_rate) = self.mods.clock_rate {
+            calc = calc.clock_rate(clock_rate);
+        }
+
+        calc.calculate()
+    }
+
+    /// Max pp, stars, and max combo on the full map, ignoring any score state.
+    ///
+    /// If the map's checksum is known and the mods can be represented by
+    /// legacy bits, the values are cached in the database.
+    pub async fn max_pp(&mut self) -> DbMapPp {
+        let mode = GameMode::from(self.map.mode as u8);
+        let cache_key = self.checksum.zip(self.cacheable_mods_bits());
+
+        if let Some((checksum, bits)) = cache_key {
+            let cached_fut =
+                Context::psql().select_map_pp(checksum, mode, bits, self.lazer, Self::CALC_VERSION);
+
+            match cached_fut.await {
+                Ok(Some(cached)) => return cached,
+                Ok(None) => {}
+                Err(err) => warn!(?err, "Failed to get cached map pp"),
+            }
+        }
+
+        let attrs = self.accuracy_performance(100.0, 0).await;
+
+        let map_pp = DbMapPp {
+            max_pp: attrs.pp() as f32,
+            stars: attrs.stars() as f32,
+            max_combo: attrs.max_combo() as i32,
+        };
+
+        if let Some((checksum, bits)) = cache_key {
+            let checksum = checksum.to_owned();
+            let lazer = self.lazer;
+
+            tokio::spawn(async move {
+                let upsert_fut = Context::psql().upsert_map_pp(
+                    &checksum,
+                    mode,
+                    bits,
+                    lazer,
+                    Self::CALC_VERSION,
+                    &map_pp,
+                );
+
+                if let Err(err) = upsert_fut.await {
+                    warn!(?err, "Failed to cache map pp");
+                }
+            });
+        }
+
+        map_pp
+    }
+
+    /// Legacy bits of the mods if they fully describe the mods i.e. there are
+    /// no mods without bits and no custom clock rate.
+    fn cacheable_mods_bits(&self) -> Option<u32> {
+        let rosu_pp::GameMods::Lazer(ref mods) = self.mods.inner else {
+            return None;
+        };
+
+        let bits = mods.checked_bits()?;
+        let legacy_clock_rate = GameModsIntermode::from_bits(bits).legacy_clock_rate();
+
+        self.mods
+            .clock_rate
+            .or_else(|| mods.clock_rate())
+            .is_none_or(|clock_rate| (clock_rate - legacy_clock_rate).abs() < f64::EPSILON)
+            .then_some(bits)
+    }
 }
 
 pub struct ScoreData {
@@ -8,12 +8,17 @@ use bathbot_cache::{
 use bathbot_model::{
     ArchivedOsekaiBadge, ArchivedOsekaiMedal, ArchivedOsuStatsBestScores, ArchivedSnipeCountries,
     OsekaiRanking, OsuStatsBestTimeframe,
-    rosu_v2::ranking::{ArchivedRankings, RankingsRkyv},
+    rosu_v2::{
+        news::{ArchivedNewsPost, NewsPostRkyv},
+        ranking::{ArchivedRankings, RankingsRkyv},
+    },
 };
 use bathbot_psql::model::osu::MapVersion;
 use bathbot_util::{matcher, osu::MapIdType};
 use eyre::{Report, Result, WrapErr};
-use rkyv::{Archived, Serialize, bytecheck::CheckBytes, rancor::BoxedError, vec::ArchivedVec};
+use rkyv::{
+    Archived, Serialize, bytecheck::CheckBytes, rancor::BoxedError, vec::ArchivedVec, with::Map,
+};
 use rosu_v2::prelude::GameMode;
 use thiserror::Error as ThisError;
 
@@ -192,6 +197,38 @@ impl RedisManager {
         CachedArchive::new(bytes).map_err(RedisError::Validation)
     }
 
+    pub async fn news(self) -> RedisResult<ArchivedVec<ArchivedNewsPost>> {
+        const EXPIRE: u64 = 3600;
+        const KEY: &str = "osu_news";
+
+        let mut conn = match Context::cache().fetch(KEY).await {
+            Ok(Ok(news)) => {
+                BotMetrics::inc_redis_hit("osu! news");
+
+                return Ok(news);
+            }
+            Ok(Err(conn)) => Some(conn),
+            Err(err) => {
+                warn!(?err, "Failed to fetch news");
+
+                None
+            }
+        };
+
+        let news = Context::osu().news().await.map_err(Report::new)?;
+
+        let bytes = serialize_using_arena_and_with::<_, Map<NewsPostRkyv>>(&news.posts)
+            .map_err(RedisError::Serialization)?;
+
+        if let Some(ref mut conn) = conn {
+            if let Err(err) = Cache::store(conn, KEY, bytes.as_slice(), EXPIRE).await {
+                warn!(?err, "Failed to store news");
+            }
+        }
+
+        CachedArchive::new(bytes).map_err(RedisError::Validation)
+    }
+
     pub async fn osustats_best(
         self,
         timeframe: OsuStatsBestTimeframe,
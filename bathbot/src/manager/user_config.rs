@@ -1,7 +1,7 @@
 use bathbot_model::embed_builder::ScoreEmbedSettings;
 use bathbot_psql::{
     Database,
-    model::configs::{OsuUserId, SkinEntry, UserConfig},
+    model::configs::{Lang, NumberFormat, OsuUserId, ResponseVisibility, SkinEntry, UserConfig},
 };
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
@@ -48,6 +48,41 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
+    pub async fn response_visibility(
+        self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<ResponseVisibility>> {
+        self.psql
+            .select_user_response_visibility(user_id)
+            .await
+            .wrap_err("Failed to get response visibility from DB")
+    }
+
+    pub async fn lang(self, user_id: Id<UserMarker>) -> Result<Option<Lang>> {
+        self.psql
+            .select_user_lang(user_id)
+            .await
+            .wrap_err("Failed to get language from DB")
+    }
+
+    pub async fn number_format(self, user_id: Id<UserMarker>) -> Result<Option<NumberFormat>> {
+        let ctx = Context::get();
+
+        if let Some(number_format) = ctx.number_format(user_id) {
+            return Ok(number_format);
+        }
+
+        let number_format = self
+            .psql
+            .select_user_number_format(user_id)
+            .await
+            .wrap_err("Failed to get number format from DB")?;
+
+        ctx.store_number_format(user_id, number_format);
+
+        Ok(number_format)
+    }
+
     pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
         self.psql
             .select_osu_id_by_discord_id(user_id)
@@ -114,7 +149,11 @@ impl UserConfigManager {
         self.psql
             .upsert_user_config(user_id, config)
             .await
-            .wrap_err("failed to store user config")
+            .wrap_err("failed to store user config")?;
+
+        Context::get().store_number_format(user_id, config.number_format);
+
+        Ok(())
     }
 
     pub async fn store_score_embed_settings(
@@ -0,0 +1,218 @@
+use std::{fmt::Write, future::ready};
+
+use bathbot_psql::model::{configs::ScoreData, osu::DbDailyRecap};
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, ModsFormatter,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+    numbers::round,
+};
+use eyre::{Result, WrapErr};
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::{GameMode, Score};
+use time::{Duration as TimeDuration, OffsetDateTime};
+use tokio::time::{Duration, sleep};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{Context, manager::redis::osu::UserArgsSlim, util::ChannelExt};
+
+/// How many users' recent scores are requested simultaneously
+const CONCURRENCY: usize = 4;
+
+/// How many scores are shown in a recap
+const RECAP_LEN: usize = 10;
+
+#[cold]
+pub async fn daily_recap_loop() {
+    loop {
+        // Wait until the start of the next hour
+        let now = OffsetDateTime::now_utc();
+        let hour = (now.hour() + 1) % 24;
+        let secs_into_hour = now.minute() as u64 * 60 + now.second() as u64;
+        sleep(Duration::from_secs(3600 - secs_into_hour)).await;
+
+        let recaps = match Context::psql().select_daily_recaps().await {
+            Ok(recaps) => recaps,
+            Err(err) => {
+                warn!(?err, "Failed to get daily recaps");
+
+                continue;
+            }
+        };
+
+        let recaps = recaps
+            .into_iter()
+            .filter(|recap| recap.hour == i16::from(hour));
+
+        for recap in recaps {
+            let channel = Id::new(recap.channel_id as u64);
+
+            if let Err(err) = post_recap(recap).await {
+                warn!(%channel, ?err, "Failed to post daily recap");
+            }
+        }
+    }
+}
+
+async fn post_recap(recap: DbDailyRecap) -> Result<()> {
+    let DbDailyRecap {
+        channel_id,
+        guild_id,
+        gamemode,
+        hour: _,
+        skip_quiet,
+    } = recap;
+
+    let channel = Id::new(channel_id as u64);
+    let guild = Id::new(guild_id as u64);
+    let mode = GameMode::from(gamemode as u8);
+
+    let members: Vec<_> = Context::cache()
+        .members(guild)
+        .await?
+        .into_iter()
+        .map(|id| id as i64)
+        .collect();
+
+    let osu_ids = Context::psql()
+        .select_osu_ids_by_discord_ids(&members)
+        .await
+        .wrap_err("Failed to get osu ids of members")?;
+
+    let legacy_scores = Context::guild_config()
+        .peek(guild, |config| config.score_data)
+        .await
+        .is_some_and(ScoreData::is_legacy);
+
+    let since = OffsetDateTime::now_utc() - TimeDuration::DAY;
+
+    let mut scores: Vec<_> = stream::iter(osu_ids)
+        .map(|user_id| best_recent_score(user_id, mode, legacy_scores, since))
+        .buffer_unordered(CONCURRENCY)
+        .filter_map(ready)
+        .collect()
+        .await;
+
+    scores.sort_unstable_by(|a, b| pp(b).total_cmp(&pp(a)));
+    scores.truncate(RECAP_LEN);
+
+    if scores.is_empty() {
+        if skip_quiet {
+            return Ok(());
+        }
+
+        let embed = EmbedBuilder::new()
+            .description("Quiet day, nobody set a new score in the last 24 hours")
+            .footer(FooterBuilder::new(footer_text(mode)));
+
+        let builder = MessageBuilder::new().embed(embed);
+
+        return send_recap(channel, builder).await;
+    }
+
+    let mut description = String::with_capacity(scores.len() * 160);
+
+    for (score, i) in scores.iter().zip(1..) {
+        let username = score
+            .user
+            .as_ref()
+            .map_or("<unknown user>", |user| user.username.as_str());
+
+        let title = score
+            .mapset
+            .as_ref()
+            .map_or("<unknown map>", |mapset| mapset.title.as_str());
+
+        let version = score.map.as_ref().map_or("", |map| map.version.as_str());
+
+        let _ = writeln!(
+            description,
+            "**#{i}** `{username}` • **{pp:.2}pp**\n\
+            [{title} [{version}]]({OSU_BASE}b/{map_id}) +{mods} • {acc}%",
+            pp = pp(score),
+            map_id = score.map_id,
+            mods = ModsFormatter::new(&score.mods),
+            acc = round(score.accuracy),
+        );
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(footer_text(mode)))
+        .title("Best scores of the last 24 hours")
+        .timestamp(OffsetDateTime::now_utc());
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    send_recap(channel, builder).await
+}
+
+async fn best_recent_score(
+    user_id: u32,
+    mode: GameMode,
+    legacy_scores: bool,
+    since: OffsetDateTime,
+) -> Option<Score> {
+    let args = UserArgsSlim::user_id(user_id).mode(mode);
+
+    let scores_fut = Context::osu_scores()
+        .recent(legacy_scores)
+        .limit(100)
+        .include_fails(false)
+        .exec(args);
+
+    match scores_fut.await {
+        Ok(scores) => scores
+            .into_iter()
+            .filter(|score| score.ended_at > since && score.pp.is_some())
+            .max_by(|a, b| pp(a).total_cmp(&pp(b))),
+        Err(err) => {
+            debug!(user_id, ?err, "Failed to get recent scores for daily recap");
+
+            None
+        }
+    }
+}
+
+async fn send_recap(channel: Id<ChannelMarker>, builder: MessageBuilder<'_>) -> Result<()> {
+    let Err(err) = channel.create_message(builder, None).await else {
+        return Ok(());
+    };
+
+    if let ErrorType::Response {
+        error:
+            ApiError::General(GeneralApiError {
+                code: UNKNOWN_CHANNEL,
+                ..
+            }),
+        ..
+    } = err.kind()
+    {
+        Context::psql()
+            .delete_daily_recap(channel)
+            .await
+            .wrap_err("Failed to remove daily recap of unknown channel")?;
+
+        debug!(%channel, "Removed daily recap of unknown channel");
+
+        return Ok(());
+    }
+
+    Err(err).wrap_err("Failed to send daily recap")
+}
+
+fn pp(score: &Score) -> f32 {
+    score.pp.unwrap_or(0.0)
+}
+
+fn footer_text(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "Daily recap • osu!standard",
+        GameMode::Taiko => "Daily recap • osu!taiko",
+        GameMode::Catch => "Daily recap • osu!ctb",
+        GameMode::Mania => "Daily recap • osu!mania",
+    }
+}
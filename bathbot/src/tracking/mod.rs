@@ -3,11 +3,18 @@ pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
 pub use self::twitch::twitch_loop::twitch_tracking_loop;
 pub use self::{
+    daily_recap::daily_recap_loop,
+    news::news_loop,
     ordr::{Ordr, OrdrReceivers},
-    osu::{OsuTracking, TrackEntryParams},
+    osu::{
+        OsuTracking, TrackEntryParams, notable_scores_prune_loop, osu_tracking_reconcile_loop,
+        osu_tracking_startup_summary,
+    },
     scores_ws::{ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
+mod daily_recap;
+mod news;
 mod ordr;
 mod osu;
 mod scores_ws;
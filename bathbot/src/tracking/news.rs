@@ -0,0 +1,130 @@
+use bathbot_model::rosu_v2::news::ArchivedNewsPost;
+use bathbot_psql::model::osu::DbNewsChannel;
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+};
+use eyre::{Result, WrapErr};
+use rkyv::rancor::{Panic, ResultExt};
+use tokio::time::{Duration, interval};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use crate::{Context, util::ChannelExt};
+
+/// How often news are checked
+const NEWS_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Maximum amount of posts sent to a channel at once
+const MAX_POSTS: usize = 5;
+
+#[cold]
+pub async fn news_loop() {
+    let mut interval = interval(NEWS_INTERVAL);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let channels = match Context::psql().select_news_channels().await {
+            Ok(channels) => channels,
+            Err(err) => {
+                warn!(?err, "Failed to get news channels");
+
+                continue;
+            }
+        };
+
+        if channels.is_empty() {
+            continue;
+        }
+
+        let news = match Context::redis().news().await {
+            Ok(news) => news,
+            Err(err) => {
+                warn!(?err, "Failed to get news");
+
+                continue;
+            }
+        };
+
+        for channel in channels {
+            let channel_id = Id::new(channel.channel_id as u64);
+
+            if let Err(err) = post_news(channel, &news).await {
+                warn!(%channel_id, ?err, "Failed to post news");
+            }
+        }
+    }
+}
+
+async fn post_news(channel: DbNewsChannel, news: &[ArchivedNewsPost]) -> Result<()> {
+    let channel_id = Id::new(channel.channel_id as u64);
+    let last_post_id = channel.last_post_id as u32;
+
+    // News are sorted from newest to oldest
+    let new_posts: Vec<_> = news
+        .iter()
+        .take_while(|post| post.post_id.to_native() > last_post_id)
+        .take(MAX_POSTS)
+        .collect();
+
+    let Some(newest) = new_posts.first() else {
+        return Ok(());
+    };
+
+    let newest_id = newest.post_id.to_native();
+
+    for post in new_posts.into_iter().rev() {
+        let published_at = post.published_at.try_deserialize::<Panic>().always_ok();
+
+        let mut embed = EmbedBuilder::new()
+            .author(AuthorBuilder::new(post.author.as_str()))
+            .footer(FooterBuilder::new("osu! news"))
+            .timestamp(published_at)
+            .title(post.title.as_str())
+            .url(format!("{OSU_BASE}home/news/{}", post.slug));
+
+        if let Some(preview) = post.preview.as_deref() {
+            embed = embed.description(preview.trim());
+        }
+
+        let builder = MessageBuilder::new().embed(embed);
+        send_post(channel_id, builder).await?;
+    }
+
+    Context::psql()
+        .update_news_channel_last_post(channel_id, newest_id)
+        .await
+        .wrap_err("Failed to update last news post")
+}
+
+async fn send_post(channel: Id<ChannelMarker>, builder: MessageBuilder<'_>) -> Result<()> {
+    let Err(err) = channel.create_message(builder, None).await else {
+        return Ok(());
+    };
+
+    if let ErrorType::Response {
+        error:
+            ApiError::General(GeneralApiError {
+                code: UNKNOWN_CHANNEL,
+                ..
+            }),
+        ..
+    } = err.kind()
+    {
+        Context::psql()
+            .delete_news_channel(channel)
+            .await
+            .wrap_err("Failed to remove news subscription of unknown channel")?;
+
+        debug!(%channel, "Removed news subscription of unknown channel");
+
+        return Ok(());
+    }
+
+    Err(err).wrap_err("Failed to send news post")
+}
@@ -13,9 +13,12 @@ use bathbot_util::IntHasher;
 use rosu_v2::{model::GameMode, prelude::Score};
 use time::OffsetDateTime;
 
-use super::TrackEntryParams;
+use super::{OsuTracking, TrackEntryParams, milestones::MilestoneStats};
 use crate::core::Context;
 
+/// Minimum amount of seconds between two milestone checks of an entry
+const MILESTONE_CHECK_INTERVAL: i64 = 15 * 60;
+
 type Channels = HashMap<NonZeroU64, TrackEntryParams, IntHasher>;
 
 #[derive(Default)]
@@ -25,6 +28,10 @@ pub struct TrackEntry {
     /// Unix timestamp of the last update
     last_ended_at: AtomicI64,
     channels: RwLock<Channels>,
+    /// Statistics snapshot to detect milestones
+    milestone_stats: RwLock<Option<MilestoneStats>>,
+    /// Unix timestamp of the last milestone check
+    last_milestone_check: AtomicI64,
 }
 
 impl TrackEntry {
@@ -49,6 +56,35 @@ impl TrackEntry {
         (pp, last_updated)
     }
 
+    /// Whether milestones should be checked, i.e. a channel has them enabled
+    /// and the last check was long enough ago.
+    ///
+    /// Returning `true` marks the entry as checked.
+    pub fn milestone_check_due(&self) -> bool {
+        let enabled = self
+            .channels()
+            .keys()
+            .any(|channel_id| OsuTracking::milestones_enabled(*channel_id));
+
+        if !enabled {
+            return false;
+        }
+
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let last = self.last_milestone_check.load(Ordering::SeqCst);
+
+        now - last >= MILESTONE_CHECK_INTERVAL
+            && self
+                .last_milestone_check
+                .compare_exchange(last, now, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+    }
+
+    /// Stores the new milestone statistics and returns the previous ones.
+    pub fn replace_milestone_stats(&self, stats: MilestoneStats) -> Option<MilestoneStats> {
+        self.milestone_stats.write().unwrap().replace(stats)
+    }
+
     fn is_empty(&self) -> bool {
         self.channels.read().unwrap().is_empty()
     }
@@ -102,6 +138,12 @@ impl TrackEntry {
     fn insert(&self, user: DbTrackedOsuUser) {
         self.store_last_pp(user.last_pp, user.last_updated);
 
+        let stats = MilestoneStats::from_db(user.playcount, user.ranked_score, user.total_hits);
+
+        if stats.is_some() {
+            *self.milestone_stats.write().unwrap() = stats;
+        }
+
         let Some(channel_id) = NonZeroU64::new(user.channel_id as u64) else {
             return;
         };
@@ -152,4 +194,14 @@ impl TrackedUser {
     pub fn insert(&self, user: DbTrackedOsuUser) {
         self.modes[user.gamemode as usize].insert(user);
     }
+
+    /// Increments the subscription count of each channel the user is tracked
+    /// in, once per mode.
+    pub fn count_channels(&self, counts: &mut HashMap<NonZeroU64, usize, IntHasher>) {
+        for entry in self.modes.iter() {
+            for channel_id in entry.channels().keys() {
+                *counts.entry(*channel_id).or_default() += 1;
+            }
+        }
+    }
 }
@@ -0,0 +1,235 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice,
+    sync::Arc,
+};
+
+use bathbot_util::{EmbedBuilder, numbers::WithComma};
+use rosu_v2::model::GameMode;
+use twilight_model::id::Id;
+
+use super::{OsuTracking, entry::TrackEntry};
+use crate::{
+    core::Context,
+    manager::redis::osu::{UserArgs, UserArgsSlim},
+    util::CachedUserExt,
+};
+
+const PLAYCOUNT_STEP: u64 = 10_000;
+const RANKED_SCORE_STEP: u64 = 10_000_000_000;
+const TOTAL_HITS_STEP: u64 = 1_000_000;
+
+/// Statistics of a tracked user that are checked for milestones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MilestoneStats {
+    pub playcount: u32,
+    pub ranked_score: u64,
+    pub total_hits: u64,
+}
+
+impl MilestoneStats {
+    /// Returns `None` if no snapshot has been stored yet.
+    pub fn from_db(
+        playcount: Option<i32>,
+        ranked_score: Option<i64>,
+        total_hits: Option<i64>,
+    ) -> Option<Self> {
+        Some(Self {
+            playcount: playcount? as u32,
+            ranked_score: ranked_score? as u64,
+            total_hits: total_hits? as u64,
+        })
+    }
+
+    /// Milestones that were reached between `self` and `new`.
+    ///
+    /// If multiple thresholds of the same kind were crossed, only the highest
+    /// one is included.
+    fn crossed(self, new: Self) -> Vec<Milestone> {
+        let playcount = crossed_threshold(
+            u64::from(self.playcount),
+            u64::from(new.playcount),
+            PLAYCOUNT_STEP,
+        )
+        .map(Milestone::Playcount);
+
+        let ranked_score =
+            crossed_threshold(self.ranked_score, new.ranked_score, RANKED_SCORE_STEP)
+                .map(Milestone::RankedScore);
+
+        let total_hits = crossed_threshold(self.total_hits, new.total_hits, TOTAL_HITS_STEP)
+            .map(Milestone::TotalHits);
+
+        [playcount, ranked_score, total_hits]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+fn crossed_threshold(old: u64, new: u64, step: u64) -> Option<u64> {
+    (new / step > old / step).then_some(new / step * step)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Milestone {
+    Playcount(u64),
+    RankedScore(u64),
+    TotalHits(u64),
+}
+
+impl Display for Milestone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Playcount(n) => write!(f, "Reached **{}** plays", WithComma::new(*n)),
+            Self::RankedScore(n) => write!(f, "Reached **{}** ranked score", WithComma::new(*n)),
+            Self::TotalHits(n) => write!(f, "Reached **{}** total hits", WithComma::new(*n)),
+        }
+    }
+}
+
+/// Fetches the user's current statistics, compares them with the previous
+/// snapshot, and notifies channels with enabled milestones about reached
+/// thresholds.
+pub async fn check_milestones(user_id: u32, mode: GameMode, entry: Arc<TrackEntry>) {
+    let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+    let user = match Context::redis().osu_user(UserArgs::Args(user_args)).await {
+        Ok(user) => user,
+        Err(err) => {
+            log!(warn: user = user_id, ?mode, ?err, "Failed to get user for milestones");
+
+            return;
+        }
+    };
+
+    let Some(stats) = user.statistics.as_ref() else {
+        return;
+    };
+
+    let curr = MilestoneStats {
+        playcount: stats.playcount.to_native(),
+        ranked_score: stats.ranked_score.to_native(),
+        total_hits: stats.total_hits.to_native(),
+    };
+
+    let prev = entry.replace_milestone_stats(curr);
+
+    if prev == Some(curr) {
+        return;
+    }
+
+    let update_fut = Context::psql().update_tracked_milestone_stats(
+        user_id,
+        mode,
+        curr.playcount,
+        curr.ranked_score,
+        curr.total_hits,
+    );
+
+    if let Err(err) = update_fut.await {
+        log!(error: user = user_id, ?mode, ?err, "Failed to update milestone stats");
+    }
+
+    // The first snapshot only serves as reference
+    let Some(prev) = prev else { return };
+
+    let milestones = prev.crossed(curr);
+
+    if milestones.is_empty() {
+        return;
+    }
+
+    log!(info: user = user_id, ?mode, ?milestones, "Reached milestones");
+
+    let description = milestones
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let title = if milestones.len() == 1 {
+        "Milestone reached 🎉"
+    } else {
+        "Milestones reached 🎉"
+    };
+
+    let embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .thumbnail(user.avatar_url.as_ref())
+        .title(title)
+        .build();
+
+    let channels: Vec<_> = entry
+        .channels()
+        .keys()
+        .copied()
+        .filter(|channel_id| OsuTracking::milestones_enabled(*channel_id))
+        .collect();
+
+    let http = Context::http();
+
+    for channel_id in channels {
+        let channel = Id::new(channel_id.get());
+
+        if let Err(err) = http
+            .create_message(channel)
+            .embeds(slice::from_ref(&embed))
+            .await
+        {
+            log!(warn: %channel, ?err, "Error while sending milestone notif");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(playcount: u32, ranked_score: u64, total_hits: u64) -> MilestoneStats {
+        MilestoneStats {
+            playcount,
+            ranked_score,
+            total_hits,
+        }
+    }
+
+    #[test]
+    fn no_milestone() {
+        let prev = stats(10_001, 10_000_000_001, 1_000_001);
+        let curr = stats(19_999, 19_999_999_999, 1_999_999);
+
+        assert!(prev.crossed(curr).is_empty());
+    }
+
+    #[test]
+    fn exact_threshold() {
+        let prev = stats(9_999, 0, 0);
+        let curr = stats(10_000, 0, 0);
+
+        assert_eq!(prev.crossed(curr), [Milestone::Playcount(10_000)]);
+    }
+
+    #[test]
+    fn multiple_milestones_combined() {
+        let prev = stats(29_990, 9_999_000_000, 4_999_900);
+        let curr = stats(30_010, 10_000_100_000, 5_000_100);
+
+        let expected = [
+            Milestone::Playcount(30_000),
+            Milestone::RankedScore(10_000_000_000),
+            Milestone::TotalHits(5_000_000),
+        ];
+
+        assert_eq!(prev.crossed(curr), expected);
+    }
+
+    #[test]
+    fn only_highest_of_kind() {
+        let prev = stats(5_000, 0, 0);
+        let curr = stats(32_000, 0, 0);
+
+        assert_eq!(prev.crossed(curr), [Milestone::Playcount(30_000)]);
+    }
+}
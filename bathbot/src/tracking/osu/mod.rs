@@ -5,31 +5,63 @@ macro_rules! log {
     };
 }
 
-use std::{collections::HashMap, sync::RwLock};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU64,
+    sync::{Arc, RwLock},
+};
 
-use bathbot_psql::Database;
+use bathbot_psql::{Database, model::osu::TrackEmbedStyle};
 use bathbot_util::{IntHasher, datetime::NAIVE_DATETIME_FORMAT};
 use eyre::{Result, WrapErr};
 use rosu_v2::{model::GameMode, prelude::Score};
 use twilight_model::id::{Id, marker::ChannelMarker};
 
 use self::{entry::TrackedUser, require_top::RequireTopScores};
-pub use self::{params::TrackEntryParams, stats::OsuTrackingStats};
+pub use self::{
+    notable_prune::notable_scores_prune_loop, params::TrackEntryParams,
+    reconcile::osu_tracking_reconcile_loop, startup_summary::osu_tracking_startup_summary,
+    stats::OsuTrackingStats,
+};
 use crate::core::Context;
 
 mod entry;
+mod milestones;
+mod notable_prune;
 mod params;
 mod process_score;
+mod reconcile;
 mod require_top;
+mod startup_summary;
 mod stats;
 
 type TrackedUsers = RwLock<HashMap<u32, TrackedUser, IntHasher>>;
 
+/// Embed styles of channels that don't use [`TrackEmbedStyle::Full`].
+type ChannelStyles = RwLock<HashMap<NonZeroU64, TrackEmbedStyle, IntHasher>>;
+
+/// Channels that are notified about milestones of tracked users.
+type MilestoneChannels = RwLock<HashSet<NonZeroU64, IntHasher>>;
+
+/// Channels with a custom pp threshold for first place checks.
+type FirstPlaceThresholds = RwLock<HashMap<NonZeroU64, f32, IntHasher>>;
+
+/// Channels that receive a summary of their tracked users on startup.
+type StartupSummaryChannels = RwLock<HashSet<NonZeroU64, IntHasher>>;
+
 pub struct OsuTracking {
     users: TrackedUsers,
+    styles: ChannelStyles,
+    milestones: MilestoneChannels,
+    first_place: FirstPlaceThresholds,
+    startup_summaries: StartupSummaryChannels,
 }
 
 impl OsuTracking {
+    /// Top scores with at least this much pp are checked for being #1 on the
+    /// map's leaderboard unless the channel specifies its own threshold.
+    pub const DEFAULT_FIRST_PLACE_MIN_PP: f32 = 300.0;
+
     // `Context` won't be initialized at this point so we require an explicit
     // `Database` argument.
     pub async fn new(psql: &Database) -> Result<Self> {
@@ -44,8 +76,49 @@ impl OsuTracking {
             users.entry(user.user_id as u32).or_default().insert(user);
         }
 
+        let channels = psql
+            .select_tracking_channels()
+            .await
+            .wrap_err("Failed to fetch tracking channels")?;
+
+        let milestones = channels
+            .iter()
+            .filter(|channel| channel.milestones)
+            .filter_map(|channel| NonZeroU64::new(channel.channel_id as u64))
+            .collect();
+
+        let startup_summaries = channels
+            .iter()
+            .filter(|channel| channel.startup_summary)
+            .filter_map(|channel| NonZeroU64::new(channel.channel_id as u64))
+            .collect();
+
+        let first_place = channels
+            .iter()
+            .filter_map(|channel| {
+                let channel_id = NonZeroU64::new(channel.channel_id as u64)?;
+
+                Some((channel_id, channel.first_place_min_pp?))
+            })
+            .collect();
+
+        let styles = channels
+            .into_iter()
+            .filter_map(|channel| {
+                let channel_id = NonZeroU64::new(channel.channel_id as u64)?;
+                let style = TrackEmbedStyle::try_from(channel.embed_style).ok()?;
+
+                Some((channel_id, style))
+            })
+            .filter(|(_, style)| *style != TrackEmbedStyle::Full)
+            .collect();
+
         Ok(Self {
             users: RwLock::new(users),
+            styles: RwLock::new(styles),
+            milestones: RwLock::new(milestones),
+            first_place: RwLock::new(first_place),
+            startup_summaries: RwLock::new(startup_summaries),
         })
     }
 
@@ -58,8 +131,6 @@ impl OsuTracking {
     }
 
     pub(super) fn process_score(score: Score) {
-        let Some(pp) = score.pp else { return };
-
         let entry_opt = Self::users()
             .read()
             .unwrap()
@@ -70,6 +141,15 @@ impl OsuTracking {
             return;
         };
 
+        if entry.milestone_check_due() {
+            let check_fut =
+                milestones::check_milestones(score.user_id, score.mode, Arc::clone(&entry));
+
+            tokio::spawn(check_fut);
+        }
+
+        let Some(pp) = score.pp else { return };
+
         let (last_pp, last_updated) = entry.last_entry();
 
         log!(info:
@@ -88,6 +168,152 @@ impl OsuTracking {
         tokio::spawn(process_score::process_score(score, entry));
     }
 
+    pub fn embed_style(channel_id: NonZeroU64) -> TrackEmbedStyle {
+        Context::tracking()
+            .styles
+            .read()
+            .unwrap()
+            .get(&channel_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn milestones_enabled(channel_id: NonZeroU64) -> bool {
+        Context::tracking()
+            .milestones
+            .read()
+            .unwrap()
+            .contains(&channel_id)
+    }
+
+    pub fn startup_summary_enabled(channel_id: NonZeroU64) -> bool {
+        Context::tracking()
+            .startup_summaries
+            .read()
+            .unwrap()
+            .contains(&channel_id)
+    }
+
+    pub fn first_place_min_pp(channel_id: NonZeroU64) -> f32 {
+        Context::tracking()
+            .first_place
+            .read()
+            .unwrap()
+            .get(&channel_id)
+            .copied()
+            .unwrap_or(Self::DEFAULT_FIRST_PLACE_MIN_PP)
+    }
+
+    pub async fn set_embed_style(channel: Id<ChannelMarker>, style: TrackEmbedStyle) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+
+        // Only non-default styles are stored
+        if style == TrackEmbedStyle::Full {
+            Context::tracking()
+                .styles
+                .write()
+                .unwrap()
+                .remove(&channel_id);
+        } else {
+            Context::tracking()
+                .styles
+                .write()
+                .unwrap()
+                .insert(channel_id, style);
+        }
+
+        Self::store_channel(channel).await
+    }
+
+    pub async fn set_milestones(channel: Id<ChannelMarker>, enabled: bool) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+
+        {
+            let mut milestones = Context::tracking().milestones.write().unwrap();
+
+            if enabled {
+                milestones.insert(channel_id);
+            } else {
+                milestones.remove(&channel_id);
+            }
+        }
+
+        Self::store_channel(channel).await
+    }
+
+    pub async fn set_startup_summary(channel: Id<ChannelMarker>, enabled: bool) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+
+        {
+            let mut startup_summaries = Context::tracking().startup_summaries.write().unwrap();
+
+            if enabled {
+                startup_summaries.insert(channel_id);
+            } else {
+                startup_summaries.remove(&channel_id);
+            }
+        }
+
+        Self::store_channel(channel).await
+    }
+
+    /// Specify the channel's pp threshold for first place checks or reset it
+    /// to the default if `None`.
+    pub async fn set_first_place_min_pp(
+        channel: Id<ChannelMarker>,
+        min_pp: Option<f32>,
+    ) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+
+        {
+            let mut first_place = Context::tracking().first_place.write().unwrap();
+
+            match min_pp {
+                Some(min_pp) => first_place.insert(channel_id, min_pp),
+                None => first_place.remove(&channel_id),
+            };
+        }
+
+        Self::store_channel(channel).await
+    }
+
+    /// Stores the channel's settings or deletes them if they're all default.
+    async fn store_channel(channel: Id<ChannelMarker>) -> Result<()> {
+        let channel_id = channel.into_nonzero();
+        let style = Self::embed_style(channel_id);
+        let milestones = Self::milestones_enabled(channel_id);
+        let startup_summary = Self::startup_summary_enabled(channel_id);
+
+        let first_place_min_pp = Context::tracking()
+            .first_place
+            .read()
+            .unwrap()
+            .get(&channel_id)
+            .copied();
+
+        if style == TrackEmbedStyle::Full
+            && !milestones
+            && first_place_min_pp.is_none()
+            && !startup_summary
+        {
+            return Context::psql()
+                .delete_tracking_channel(channel.get())
+                .await
+                .wrap_err("Failed to delete tracking channel");
+        }
+
+        Context::psql()
+            .upsert_tracking_channel(
+                channel.get(),
+                style,
+                milestones,
+                first_place_min_pp,
+                startup_summary,
+            )
+            .await
+            .wrap_err("Failed to upsert tracking channel")
+    }
+
     pub async fn remove_channel(channel: Id<ChannelMarker>, mode: Option<GameMode>) {
         let channel_id = channel.into_nonzero();
 
@@ -105,6 +331,36 @@ impl OsuTracking {
         if let Err(err) = delete_fut.await {
             error!(%channel, ?mode, ?err, "Failed to remove tracked users of channel");
         }
+
+        if mode.is_some() {
+            return;
+        }
+
+        let tracking = Context::tracking();
+        let removed_style = tracking
+            .styles
+            .write()
+            .unwrap()
+            .remove(&channel_id)
+            .is_some();
+        let removed_milestones = tracking.milestones.write().unwrap().remove(&channel_id);
+        let removed_first_place = tracking
+            .first_place
+            .write()
+            .unwrap()
+            .remove(&channel_id)
+            .is_some();
+        let removed_startup_summary = tracking
+            .startup_summaries
+            .write()
+            .unwrap()
+            .remove(&channel_id);
+
+        if removed_style || removed_milestones || removed_first_place || removed_startup_summary {
+            if let Err(err) = Context::psql().delete_tracking_channel(channel.get()).await {
+                error!(%channel, ?err, "Failed to remove tracking channel settings");
+            }
+        }
     }
 
     pub async fn remove_user(user_id: u32, mode: Option<GameMode>, channel: Id<ChannelMarker>) {
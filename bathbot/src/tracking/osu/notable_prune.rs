@@ -0,0 +1,28 @@
+use time::OffsetDateTime;
+use tokio::time::{self, Duration};
+
+use crate::core::Context;
+
+/// How long scores are kept for the weekly best feed
+const NOTABLE_SCORE_LIFETIME: time::Duration = time::Duration::WEEK;
+
+/// How often outdated notable scores are removed
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Removes notable scores that no longer belong into the weekly best feed.
+#[cold]
+pub async fn notable_scores_prune_loop() {
+    let mut interval = time::interval(PRUNE_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let before = OffsetDateTime::now_utc() - NOTABLE_SCORE_LIFETIME;
+
+        match Context::psql().delete_notable_scores_before(before).await {
+            Ok(0) => {}
+            Ok(deleted) => log!(debug: deleted, "Removed outdated notable scores"),
+            Err(err) => log!(warn: ?err, "Failed to delete old notable scores"),
+        }
+    }
+}
@@ -1,18 +1,33 @@
-use std::{slice, sync::Arc, time::Duration};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    slice,
+    sync::Arc,
+    time::Duration,
+};
 
 use bathbot_model::embed_builder::{
     ComboValue, HitresultsValue, ScoreEmbedSettings, SettingValue, SettingsButtons, SettingsImage,
     Value,
 };
-use bathbot_psql::model::configs::ScoreData;
-use bathbot_util::{EmbedBuilder, constants::UNKNOWN_CHANNEL};
+use bathbot_psql::model::{
+    configs::ScoreData,
+    osu::{DbNotableScore, TrackEmbedStyle},
+};
+use bathbot_util::{
+    EmbedBuilder, ModsFormatter,
+    constants::{OSU_BASE, UNKNOWN_CHANNEL},
+    numbers::{WithComma, round},
+};
 use rand::Rng;
-use rosu_v2::{model::GameMode, prelude::Score};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{GameMods, Score, Username},
+};
 use twilight_http::{
     api_error::{ApiError, GeneralApiError},
     error::ErrorType as TwilightErrorType,
 };
-use twilight_model::id::Id;
+use twilight_model::{channel::message::Embed, id::Id};
 
 use super::{OsuTracking, entry::TrackEntry};
 use crate::{
@@ -25,13 +40,21 @@ use crate::{
     },
 };
 
+/// Scores with at least this many pp are stored for the weekly best feed
+const NOTABLE_MIN_PP: f32 = 300.0;
+
+/// Scores within this many personal bests are stored for the weekly best feed
+const NOTABLE_TOP_INDEX: usize = 10;
+
 pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     let Some(pp) = score.pp else { return };
 
     // Add delay to improve chances that the score was processed fully and will
     // appear in the user's top100 scores. The jitter in the delay should
     // improve db & api congestion.
+    let queued = BotMetrics::osu_tracking_queued();
     tokio::time::sleep(jitter()).await;
+    drop(queued);
 
     let user_id = score.user_id;
     let score_id = score.id;
@@ -91,12 +114,20 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     };
 
     BotMetrics::osu_tracking_hit(score.mode);
+    Context::get().invalidate_top_scores(user_id, mode);
+
+    if pp >= NOTABLE_MIN_PP || idx < NOTABLE_TOP_INDEX {
+        store_notable_score(&user, &score, &map, pp).await;
+    }
 
     let combo = score.max_combo;
+    let total_score = score.score;
+    let compact = CompactNotif::new(&user, &score, &map, idx);
+    let compact_embed = EmbedBuilder::new().description(compact.to_string()).build();
+    let text = compact.text().to_string();
     let (builder, max_combo) = embed_builder(&user, score, map, idx).await;
     let idx = idx as u8 + 1;
     let embed = builder.build();
-    let embeds = slice::from_ref(&embed);
     let combo_percent = max_combo.map(|max| 100.0 * combo as f32 / max as f32);
 
     log!(info:
@@ -121,10 +152,44 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
         })
         .collect();
 
+    let first_place_min_pp = channels
+        .iter()
+        .map(|channel_id| OsuTracking::first_place_min_pp(*channel_id))
+        .min_by(f32::total_cmp);
+
+    let first_place = match first_place_min_pp {
+        Some(min_pp) if pp >= min_pp => {
+            FirstPlace::check(map_id, mode, score_id, total_score).await
+        }
+        Some(_) | None => None,
+    };
+
+    let first_place_notif = first_place.as_ref().map(|first_place| FirstPlaceNotif {
+        embed: first_place.extend_embed(&embed),
+        compact_embed: first_place.extend_embed(&compact_embed),
+        text: format!("{text}\n{first_place}"),
+    });
+
     for channel_id in channels {
         let channel = Id::new(channel_id.get());
+        let req = http.create_message(channel);
+
+        let (embed, compact_embed, text) = match first_place_notif {
+            Some(ref notif) if pp >= OsuTracking::first_place_min_pp(channel_id) => {
+                (&notif.embed, &notif.compact_embed, notif.text.as_str())
+            }
+            Some(_) | None => (&embed, &compact_embed, text.as_str()),
+        };
+
+        let res = match OsuTracking::embed_style(channel_id) {
+            TrackEmbedStyle::Full => req.embeds(slice::from_ref(embed)).await,
+            TrackEmbedStyle::Compact => req.embeds(slice::from_ref(compact_embed)).await,
+            TrackEmbedStyle::Text => req.content(text).await,
+        };
+
+        let Err(err) = res else {
+            BotMetrics::inc_osu_tracking_notif();
 
-        let Err(err) = http.create_message(channel).embeds(embeds).await else {
             continue;
         };
 
@@ -148,11 +213,171 @@ pub async fn process_score(score: Score, entry: Arc<TrackEntry>) {
     }
 }
 
+/// Single line notification for channels that don't use the full embed.
+struct CompactNotif<'a> {
+    username: &'a str,
+    map: &'a OsuMap,
+    mods: &'a GameMods,
+    pp: f32,
+    acc: f32,
+    idx: usize,
+    /// Whether the map link should not create a preview
+    suppress_preview: bool,
+}
+
+impl<'a> CompactNotif<'a> {
+    fn new(user: &'a CachedUser, score: &'a Score, map: &'a OsuMap, idx: usize) -> Self {
+        Self {
+            username: user.username.as_str(),
+            map,
+            mods: &score.mods,
+            pp: score.pp.unwrap_or(0.0),
+            acc: score.accuracy,
+            idx,
+            suppress_preview: false,
+        }
+    }
+
+    fn text(self) -> Self {
+        Self {
+            suppress_preview: true,
+            ..self
+        }
+    }
+}
+
+impl Display for CompactNotif<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (open, close) = if self.suppress_preview {
+            ("<", ">")
+        } else {
+            ("", "")
+        };
+
+        write!(
+            f,
+            "**{username}** • [{artist} - {title} [{version}]]({open}{OSU_BASE}b/{map_id}{close}) \
+            +{mods} • **{pp:.2}pp** • {acc}% • Personal best #{idx}",
+            username = self.username,
+            artist = self.map.artist(),
+            title = self.map.title(),
+            version = self.map.version(),
+            map_id = self.map.map_id(),
+            mods = ModsFormatter::new(self.mods),
+            pp = self.pp,
+            acc = round(self.acc),
+            idx = self.idx + 1,
+        )
+    }
+}
+
+/// A tracked score that is #1 on the map's global leaderboard.
+struct FirstPlace {
+    /// Runner-up on the leaderboard and by how much score they were beaten.
+    ///
+    /// Not necessarily the previous #1 since the leaderboard is only
+    /// retrieved after the score was set.
+    runner_up: Option<(Username, u32)>,
+}
+
+impl FirstPlace {
+    /// Returns `None` if the score is not #1 or the leaderboard could not be
+    /// retrieved.
+    async fn check(map_id: u32, mode: GameMode, score_id: u64, total_score: u32) -> Option<Self> {
+        let scores_fut = Context::osu_scores().map_leaderboard(map_id, mode, None, 2, false);
+
+        let scores = match scores_fut.await {
+            Ok(scores) => scores,
+            Err(err) => {
+                log!(warn: map = map_id, score_id, ?err, "Failed to get leaderboard for first place");
+
+                return None;
+            }
+        };
+
+        let mut scores = scores.into_iter();
+
+        if scores.next()?.id != score_id {
+            return None;
+        }
+
+        let runner_up = scores.next().map(|mut score| {
+            let username = score.user.take().map_or_else(
+                || format!("<user {}>", score.user_id).into(),
+                |user| user.username,
+            );
+
+            (username, total_score.saturating_sub(score.score))
+        });
+
+        Some(Self { runner_up })
+    }
+
+    /// Adds a crown to the title and the first place line to the description.
+    fn extend_embed(&self, embed: &Embed) -> Embed {
+        let mut embed = embed.clone();
+
+        if let Some(ref mut title) = embed.title {
+            title.insert_str(0, "👑 ");
+        }
+
+        embed.description = match embed.description.take() {
+            Some(description) => Some(format!("{description}\n{self}")),
+            None => Some(self.to_string()),
+        };
+
+        embed
+    }
+}
+
+impl Display for FirstPlace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("👑 **#1 on the map**")?;
+
+        if let Some((ref username, margin)) = self.runner_up {
+            write!(
+                f,
+                " • Runner-up: `{username}` (+{} score)",
+                WithComma::new(margin)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Notification variants for channels whose first place threshold is met.
+struct FirstPlaceNotif {
+    embed: Embed,
+    compact_embed: Embed,
+    text: String,
+}
+
 /// Random [`Duration`] between 30s and 60s
 fn jitter() -> Duration {
     rand::thread_rng().gen_range(Duration::from_secs(30)..Duration::from_secs(60))
 }
 
+/// Store the score for the weekly best feed.
+async fn store_notable_score(user: &CachedUser, score: &Score, map: &OsuMap, pp: f32) {
+    let notable = DbNotableScore {
+        score_id: score.id as i64,
+        user_id: score.user_id as i32,
+        username: user.username.as_str().to_owned(),
+        gamemode: score.mode as i16,
+        map_id: score.map_id as i32,
+        map_title: format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
+        mods: ModsFormatter::new(&score.mods).to_string(),
+        pp,
+        accuracy: score.accuracy,
+        ended_at: score.ended_at,
+    };
+
+    if let Err(err) = Context::psql().upsert_notable_score(&notable).await {
+        log!(warn: score_id = score.id, ?err, "Failed to store notable score");
+    }
+}
+
 async fn embed_builder(
     user: &CachedUser,
     score: Score,
@@ -256,6 +481,8 @@ fn create_settings() -> ScoreEmbedSettings {
         ],
         show_artist: true,
         show_sr_in_title: true,
+        show_mod_idx: false,
+        show_map_pb: false,
         image: SettingsImage::Thumbnail,
         buttons: SettingsButtons {
             pagination: false,
@@ -331,6 +558,8 @@ fn create_mania_settings() -> ScoreEmbedSettings {
         ],
         show_artist: true,
         show_sr_in_title: true,
+        show_mod_idx: false,
+        show_map_pb: false,
         image: SettingsImage::Thumbnail,
         buttons: SettingsButtons {
             pagination: false,
@@ -0,0 +1,117 @@
+use std::{collections::HashMap, num::NonZeroU64};
+
+use bathbot_util::{
+    IntHasher,
+    constants::{MISSING_ACCESS, UNKNOWN_CHANNEL},
+};
+use futures::future;
+use tokio::time::{self, Duration};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+use super::OsuTracking;
+use crate::core::Context;
+
+/// Delay before the first reconciliation so that startup isn't slowed down
+const STARTUP_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// How often tracked channels are reconciled
+const RECONCILE_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Amount of channels that are requested concurrently
+const BATCH_SIZE: usize = 10;
+
+/// Delay between two batches of channel requests
+const BATCH_DELAY: Duration = Duration::from_secs(1);
+
+/// Removes tracked channels that the bot can no longer access, e.g. because
+/// it was kicked from the guild, so that users tracked only in those channels
+/// no longer need to be processed.
+#[cold]
+pub async fn osu_tracking_reconcile_loop() {
+    time::sleep(STARTUP_DELAY).await;
+
+    let mut interval = time::interval(RECONCILE_INTERVAL);
+
+    loop {
+        interval.tick().await;
+        reconcile_channels().await;
+    }
+}
+
+async fn reconcile_channels() {
+    let mut subscriptions = HashMap::<NonZeroU64, usize, IntHasher>::default();
+
+    for user in OsuTracking::users().read().unwrap().values() {
+        user.count_channels(&mut subscriptions);
+    }
+
+    let channels: Vec<_> = subscriptions.keys().copied().map(Id::from).collect();
+
+    let mut pruned_channels = 0;
+    let mut pruned_subscriptions = 0;
+    let mut unchecked = 0;
+
+    for (i, batch) in channels.chunks(BATCH_SIZE).enumerate() {
+        if i > 0 {
+            time::sleep(BATCH_DELAY).await;
+        }
+
+        let statuses = future::join_all(batch.iter().copied().map(channel_status)).await;
+
+        for (&channel, status) in batch.iter().zip(statuses) {
+            match status {
+                ChannelStatus::Reachable => {}
+                ChannelStatus::Gone => {
+                    OsuTracking::remove_channel(channel, None).await;
+
+                    pruned_channels += 1;
+                    pruned_subscriptions += subscriptions[&channel.into_nonzero()];
+                }
+                ChannelStatus::Unknown => unchecked += 1,
+            }
+        }
+    }
+
+    log!(info:
+        checked = channels.len(),
+        pruned_channels,
+        pruned_subscriptions,
+        unchecked,
+        "Reconciled tracked channels"
+    );
+}
+
+enum ChannelStatus {
+    Reachable,
+    /// Discord confirmed that the channel is unknown or inaccessible
+    Gone,
+    /// Could not be determined, e.g. due to an outage, so the channel is kept
+    Unknown,
+}
+
+async fn channel_status(channel: Id<ChannelMarker>) -> ChannelStatus {
+    let err = match Context::http().channel(channel).await {
+        Ok(_) => return ChannelStatus::Reachable,
+        Err(err) => err,
+    };
+
+    match err.kind() {
+        ErrorType::Response {
+            error:
+                ApiError::General(GeneralApiError {
+                    code: UNKNOWN_CHANNEL | MISSING_ACCESS,
+                    ..
+                }),
+            ..
+        } => ChannelStatus::Gone,
+        _ => {
+            log!(warn: %channel, ?err, "Failed to check tracked channel");
+
+            ChannelStatus::Unknown
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{collections::HashMap, fmt::Write, num::NonZeroU64, slice};
+
+use bathbot_util::{CowUtils, EmbedBuilder, FooterBuilder, IntHasher, datetime::HowLongAgoDynamic};
+use rosu_v2::model::GameMode;
+use time::OffsetDateTime;
+use tokio::time::{self, Duration};
+use twilight_model::id::Id;
+
+use super::OsuTracking;
+use crate::core::Context;
+
+/// Delay after startup so the summaries don't compete with other startup
+/// requests
+const STARTUP_DELAY: Duration = Duration::from_secs(60);
+
+/// Time span over which all summaries are sent
+const SEND_SPREAD: Duration = Duration::from_secs(10 * 60);
+
+/// Minimum delay between two summaries
+const MIN_SEND_DELAY: Duration = Duration::from_secs(2);
+
+/// Channels with more tracked users only receive the count
+const MAX_LISTED_USERS: usize = 20;
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+struct SummaryEntry {
+    user_id: u32,
+    mode: GameMode,
+    last_updated: OffsetDateTime,
+}
+
+type ChannelEntries = HashMap<NonZeroU64, Vec<SummaryEntry>, IntHasher>;
+
+/// Sends a summary of the tracked users to each channel that enabled it.
+///
+/// The summaries are staggered over several minutes so that a restart doesn't
+/// send a burst of messages.
+#[cold]
+pub async fn osu_tracking_startup_summary() {
+    time::sleep(STARTUP_DELAY).await;
+
+    let channels = collect_entries();
+
+    if channels.is_empty() {
+        return;
+    }
+
+    let user_ids: Vec<_> = channels
+        .values()
+        .flatten()
+        .map(|entry| entry.user_id as i32)
+        .collect();
+
+    let names = match Context::osu_user().names(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            log!(warn: ?err, "Failed to get names for startup summary");
+
+            HashMap::default()
+        }
+    };
+
+    let send_delay = (SEND_SPREAD / channels.len() as u32).max(MIN_SEND_DELAY);
+    let http = Context::http();
+
+    let mut sent = 0;
+    let mut failed = 0;
+
+    for (i, (channel_id, mut entries)) in channels.into_iter().enumerate() {
+        if i > 0 {
+            time::sleep(send_delay).await;
+        }
+
+        entries.sort_unstable_by(|a, b| b.last_updated.cmp(&a.last_updated));
+
+        let mut description = String::with_capacity(entries.len().min(MAX_LISTED_USERS) * 48);
+
+        if entries.len() > MAX_LISTED_USERS {
+            let _ = write!(
+                description,
+                "Tracking **{}** users in this channel",
+                entries.len()
+            );
+        } else {
+            for entry in entries.iter() {
+                let name = match names.get(&entry.user_id) {
+                    Some(name) => name.as_str().cow_escape_markdown(),
+                    None => format!("User {}", entry.user_id).into(),
+                };
+
+                let _ = writeln!(
+                    description,
+                    "**{name}**{mode} • updated {ago}",
+                    mode = mode_str(entry.mode),
+                    ago = HowLongAgoDynamic::new(&entry.last_updated),
+                );
+            }
+        }
+
+        let footer = FooterBuilder::new("Disable this summary with /track startupsummary");
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .footer(footer)
+            .title("Tracking is up and running")
+            .build();
+
+        let channel = Id::new(channel_id.get());
+
+        match http
+            .create_message(channel)
+            .embeds(slice::from_ref(&embed))
+            .await
+        {
+            Ok(_) => sent += 1,
+            Err(err) => {
+                log!(warn: %channel, ?err, "Error while sending startup summary");
+                failed += 1;
+            }
+        }
+    }
+
+    log!(info: sent, failed, "Sent tracking startup summaries");
+}
+
+/// Groups the tracked users by channels that have the summary enabled.
+fn collect_entries() -> ChannelEntries {
+    let mut channels = ChannelEntries::default();
+
+    for (user_id, user) in OsuTracking::users().read().unwrap().iter() {
+        for mode in MODES {
+            let entry = user.get(mode);
+            let channels_guard = entry.channels();
+
+            if channels_guard.is_empty() {
+                continue;
+            }
+
+            let (_, last_updated) = entry.last_entry();
+
+            let enabled = channels_guard
+                .keys()
+                .copied()
+                .filter(|channel_id| OsuTracking::startup_summary_enabled(*channel_id));
+
+            for channel_id in enabled {
+                channels.entry(channel_id).or_default().push(SummaryEntry {
+                    user_id: *user_id,
+                    mode,
+                    last_updated,
+                });
+            }
+        }
+    }
+
+    channels
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "",
+        GameMode::Taiko => " (taiko)",
+        GameMode::Catch => " (ctb)",
+        GameMode::Mania => " (mania)",
+    }
+}
@@ -1,14 +1,21 @@
 use std::{future::IntoFuture, slice};
 
+use bathbot_psql::model::configs::ErrorMessageTtl;
 use bathbot_util::{EmbedBuilder, MessageBuilder};
-use twilight_http::response::ResponseFuture;
+use futures::future::BoxFuture;
+use twilight_http::{Error as HttpError, Response, response::ResponseFuture};
 use twilight_model::{
     channel::Message,
     guild::Permissions,
-    id::{Id, marker::ChannelMarker},
+    id::{
+        Id,
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
+    },
 };
 
-use crate::core::Context;
+use crate::core::{Context, commands::checks::check_channel_permissions};
+
+pub type ErrorFuture = BoxFuture<'static, Result<(), HttpError>>;
 
 pub trait ChannelExt {
     /// Create a message inside a green embed
@@ -19,7 +26,7 @@ pub trait ChannelExt {
     ) -> ResponseFuture<Message>;
 
     /// Create a message inside a red embed
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message>;
+    fn error(&self, content: impl Into<String>) -> ErrorFuture;
 
     /// Create a message without embed; only content
     fn plain_message(&self, content: &str) -> ResponseFuture<Message>;
@@ -55,13 +62,10 @@ impl ChannelExt for Id<ChannelMarker> {
         }
     }
 
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
-        let embed = EmbedBuilder::new().color_red().description(content).build();
+    fn error(&self, content: impl Into<String>) -> ErrorFuture {
+        let fut = create_error(*self, content);
 
-        Context::http()
-            .create_message(*self)
-            .embeds(&[embed])
-            .into_future()
+        Box::pin(async move { fut.await.map(|_| ()) })
     }
 
     fn plain_message(&self, content: &str) -> ResponseFuture<Message> {
@@ -75,17 +79,97 @@ impl ChannelExt for Id<ChannelMarker> {
 impl ChannelExt for Message {
     fn create_message(
         &self,
-        builder: MessageBuilder<'_>,
+        mut builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         self.channel_id.create_message(builder, permissions)
     }
 
-    fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
-        self.channel_id.error(content)
+    /// If the guild configured an error message TTL, the reply will be
+    /// deleted after the delay.
+    fn error(&self, content: impl Into<String>) -> ErrorFuture {
+        let fut = create_error(self.channel_id, content);
+        let guild_id = self.guild_id;
+        let channel_id = self.channel_id;
+        let msg_id = self.id;
+
+        Box::pin(async move {
+            let response = fut.await?;
+
+            if let Some(guild_id) = guild_id {
+                schedule_error_deletion(guild_id, channel_id, msg_id, response).await;
+            }
+
+            Ok(())
+        })
     }
 
     fn plain_message(&self, content: &str) -> ResponseFuture<Message> {
         self.channel_id.plain_message(content)
     }
 }
+
+fn create_error(
+    channel_id: Id<ChannelMarker>,
+    content: impl Into<String>,
+) -> ResponseFuture<Message> {
+    let embed = EmbedBuilder::new().color_red().description(content).build();
+
+    Context::http()
+        .create_message(channel_id)
+        .embeds(&[embed])
+        .into_future()
+}
+
+/// Delete the error reply and potentially the invoking message after the
+/// guild's configured delay.
+async fn schedule_error_deletion(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    msg_id: Id<MessageMarker>,
+    response: Response<Message>,
+) {
+    let (ttl, delete_invoke) = Context::guild_config()
+        .peek(guild_id, |config| {
+            (config.error_message_ttl, config.error_delete_invoke)
+        })
+        .await;
+
+    let Some(ttl) = ttl.and_then(ErrorMessageTtl::duration) else {
+        return;
+    };
+
+    let reply_id = match response.model().await {
+        Ok(reply) => reply.id,
+        Err(err) => return warn!(?err, "Failed to deserialize error reply"),
+    };
+
+    // Without permission to manage messages, only our own reply is deleted
+    let delete_invoke = delete_invoke.unwrap_or(false)
+        && match Context::cache().current_user().await {
+            Ok(Some(user)) => check_channel_permissions(user.id.to_native(), channel_id, guild_id)
+                .await
+                .contains(Permissions::MANAGE_MESSAGES),
+            _ => false,
+        };
+
+    tokio::spawn(async move {
+        tokio::time::sleep(ttl).await;
+
+        if let Err(err) = Context::http().delete_message(channel_id, reply_id).await {
+            warn!(?err, "Failed to delete error reply");
+        }
+
+        if !delete_invoke {
+            return;
+        }
+
+        if let Err(err) = Context::http().delete_message(channel_id, msg_id).await {
+            warn!(?err, "Failed to delete message that caused an error");
+        }
+    });
+}
@@ -1,9 +1,9 @@
 use std::{borrow::Cow, future::IntoFuture, slice};
 
-use bathbot_util::{MessageBuilder, modal::ModalBuilder};
+use bathbot_util::{EmbedBuilder, MessageBuilder, modal::ModalBuilder};
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
 use twilight_model::{
-    channel::Message,
+    channel::{Message, message::MessageFlags},
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
@@ -24,10 +24,18 @@ pub trait ComponentExt {
 
     /// Acknowledge a component by responding with a modal.
     fn modal(&self, modal: ModalBuilder) -> ResponseFuture<EmptyBody>;
+
+    /// After having already ackowledged the component, send an ephemeral
+    /// message with some content in a red embed.
+    fn error_followup(&self, content: impl Into<String>) -> ResponseFuture<Message>;
 }
 
 impl ComponentExt for InteractionComponent {
-    fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, mut builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -65,7 +73,11 @@ impl ComponentExt for InteractionComponent {
             .into_future()
     }
 
-    fn update(&self, builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+    fn update(&self, mut builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         let client = Context::interaction();
 
         let mut req = client.update_response(&self.token);
@@ -101,4 +113,14 @@ impl ComponentExt for InteractionComponent {
             .create_response(self.id, &self.token, &response)
             .into_future()
     }
+
+    fn error_followup(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+        let embed = EmbedBuilder::new().description(content).color_red().build();
+
+        Context::interaction()
+            .create_followup(&self.token)
+            .embeds(slice::from_ref(&embed))
+            .flags(MessageFlags::EPHEMERAL)
+            .into_future()
+    }
 }
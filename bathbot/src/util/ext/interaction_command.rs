@@ -1,4 +1,4 @@
-use std::{borrow::Cow, future::IntoFuture, mem, slice};
+use std::{borrow::Cow, future::IntoFuture, slice};
 
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
@@ -8,16 +8,27 @@ use twilight_model::{
         command::{CommandOptionChoice, CommandType},
         interaction::application_command::CommandOptionValue,
     },
-    channel::{Message, message::MessageFlags},
+    channel::{
+        Message,
+        message::{
+            MessageFlags,
+            component::{ActionRow, Button, ButtonStyle, Component},
+        },
+    },
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+    id::{Id, marker::InteractionMarker},
 };
 
 use crate::{
     core::Context,
-    util::{CheckPermissions, interaction::InteractionCommand},
+    util::{Authored, CheckPermissions, interaction::InteractionCommand},
 };
 
+/// Custom id prefix of the button that retries a failed command, followed by
+/// the interaction id of the failed command.
+pub const RETRY_BUTTON_PREFIX: &str = "retry_";
+
 pub trait InteractionCommandExt {
     /// Extract input data containing options and resolved values
     fn input_data(&mut self) -> CommandInputData<'static>;
@@ -51,6 +62,12 @@ pub trait InteractionCommandExt {
         self.update(builder)
     }
 
+    /// Same as [`InteractionCommandExt::error`] but the command is stored so
+    /// that its author can retry it through a button.
+    ///
+    /// Intended for failures that are likely temporary such as osu!api issues.
+    fn error_retryable(&self, content: impl Into<String>) -> ResponseFuture<Message>;
+
     /// Respond to a command with some content in a red embed.
     ///
     /// Be sure the command was **not** deferred beforehand.
@@ -67,9 +84,11 @@ pub trait InteractionCommandExt {
 
 impl InteractionCommandExt for InteractionCommand {
     fn input_data(&mut self) -> CommandInputData<'static> {
+        // The data is kept intact so that it's still available if the command
+        // fails and is stored to be retried
         CommandInputData {
-            options: mem::take(&mut self.data.options),
-            resolved: self.data.resolved.take().map(Cow::Owned),
+            options: self.data.options.clone(),
+            resolved: self.data.resolved.clone().map(Cow::Owned),
         }
     }
 
@@ -100,7 +119,27 @@ impl InteractionCommandExt for InteractionCommand {
         Some(group_sub)
     }
 
-    fn callback(&self, builder: MessageBuilder<'_>, ephemeral: bool) -> ResponseFuture<EmptyBody> {
+    fn error_retryable(&self, content: impl Into<String>) -> ResponseFuture<Message> {
+        let embed = EmbedBuilder::new().description(content).color_red();
+        let mut builder = MessageBuilder::new().embed(embed);
+
+        if let Ok(user_id) = self.user_id() {
+            Context::get().store_failed_command(self.id, user_id, (*self.data).clone());
+            builder = builder.components(retry_components(self.id));
+        }
+
+        self.update(builder)
+    }
+
+    fn callback(
+        &self,
+        mut builder: MessageBuilder<'_>,
+        ephemeral: bool,
+    ) -> ResponseFuture<EmptyBody> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         let attachments = builder
             .attachment
             .filter(|_| self.can_attach_file())
@@ -141,7 +180,11 @@ impl InteractionCommandExt for InteractionCommand {
             .into_future()
     }
 
-    fn update<'l>(&'l self, builder: MessageBuilder<'l>) -> ResponseFuture<Message> {
+    fn update<'l>(&'l self, mut builder: MessageBuilder<'l>) -> ResponseFuture<Message> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         InteractionToken(&self.token).update(builder, self.permissions)
     }
 
@@ -162,6 +205,23 @@ impl InteractionCommandExt for InteractionCommand {
     }
 }
 
+/// A button to retry the failed command of the given interaction.
+pub fn retry_components(interaction_id: Id<InteractionMarker>) -> Vec<Component> {
+    let button = Button {
+        custom_id: Some(format!("{RETRY_BUTTON_PREFIX}{interaction_id}")),
+        disabled: false,
+        emoji: None,
+        label: Some("Retry".to_owned()),
+        style: ButtonStyle::Secondary,
+        url: None,
+        sku_id: None,
+    };
+
+    vec![Component::ActionRow(ActionRow {
+        components: vec![Component::Button(button)],
+    })]
+}
+
 pub struct InteractionToken<'a>(pub &'a str);
 
 impl InteractionToken<'_> {
@@ -96,9 +96,13 @@ impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
 impl MessageExt for Message {
     fn update(
         &self,
-        builder: MessageBuilder<'_>,
+        mut builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> Option<ResponseFuture<Message>> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         (self.id, self.channel_id).update(builder, permissions)
     }
 
@@ -108,9 +112,13 @@ impl MessageExt for Message {
 
     fn reply(
         &self,
-        builder: MessageBuilder<'_>,
+        mut builder: MessageBuilder<'_>,
         permissions: Option<Permissions>,
     ) -> ResponseFuture<Message> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         (self.id, self.channel_id).reply(builder, permissions)
     }
 }
@@ -3,7 +3,9 @@ pub use self::{
     cached_user::CachedUserExt,
     channel::ChannelExt,
     component::ComponentExt,
-    interaction_command::{InteractionCommandExt, InteractionToken},
+    interaction_command::{
+        InteractionCommandExt, InteractionToken, RETRY_BUTTON_PREFIX, retry_components,
+    },
     message::MessageExt,
     modal::*,
 };
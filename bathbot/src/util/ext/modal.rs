@@ -24,7 +24,11 @@ pub trait ModalExt {
 }
 
 impl ModalExt for InteractionModal {
-    fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, mut builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -61,7 +65,11 @@ impl ModalExt for InteractionModal {
             .into_future()
     }
 
-    fn update(&self, builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+    fn update(&self, mut builder: MessageBuilder<'_>) -> ResponseFuture<Message> {
+        builder
+            .embed
+            .default_color(Context::guild_config().embed_color(self.guild_id));
+
         let client = Context::interaction();
 
         let mut req = client.update_response(&self.token);
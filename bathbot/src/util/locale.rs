@@ -0,0 +1,336 @@
+use std::fmt::{Display, Write};
+
+use bathbot_psql::model::configs::{Lang, NumberFormat};
+use bathbot_util::{
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    numbers,
+};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
+
+use crate::core::{Context, commands::CommandOrigin};
+
+/// Defines the [`Msg`] catalog.
+///
+/// Every key requires a translation for each [`Lang`] so a missing
+/// translation fails to compile.
+macro_rules! catalog {
+    ( $(
+        $( #[$meta:meta] )*
+        $key:ident {
+            en: $en:expr,
+            de: $de:expr,
+            ru: $ru:expr $(,)?
+        }
+    )* ) => {
+        /// Keys for user-facing messages that are available in every [`Lang`].
+        ///
+        /// Templates may contain `{name}` placeholders which are filled in by
+        /// [`Msg::format`].
+        #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+        pub enum Msg {
+            $( $( #[$meta] )* $key, )*
+        }
+
+        impl Msg {
+            #[cfg(test)]
+            const ALL: &[Self] = &[ $( Self::$key, )* ];
+
+            /// The message template in the given language.
+            pub fn text(self, lang: Lang) -> &'static str {
+                match (self, lang) {
+                    $(
+                        (Self::$key, Lang::English) => $en,
+                        (Self::$key, Lang::German) => $de,
+                        (Self::$key, Lang::Russian) => $ru,
+                    )*
+                }
+            }
+        }
+    };
+}
+
+catalog! {
+    GeneralIssue {
+        en: GENERAL_ISSUE,
+        de: "Etwas ist schiefgelaufen, beschwer dich bei bade",
+        ru: "Что-то пошло не так, вините bade",
+    }
+    OsuApiIssue {
+        en: OSU_API_ISSUE,
+        de: "Ein Problem mit der osu!-API, beschwer dich bei bade",
+        ru: "Проблема с API osu!, вините bade",
+    }
+    /// Arguments: `link`
+    RequireLink {
+        en: "Either specify an osu! username or link yourself to an osu! profile via {link}",
+        de: "Gib entweder einen osu!-Benutzernamen an oder verknüpfe dich über {link} \
+            mit einem osu!-Profil",
+        ru: "Укажите имя пользователя osu! или привяжите свой профиль osu! через {link}",
+    }
+    /// Arguments: `name`, `n`
+    TopScoresOnly {
+        en: "`{name}` only has {n} top scores",
+        de: "`{name}` hat nur {n} Top-Scores",
+        ru: "У `{name}` только {n} лучших скоров",
+    }
+    /// Arguments: `name`, `n`
+    TopScoresOnlyFiltered {
+        en: "`{name}` only has {n} top scores with the specified properties",
+        de: "`{name}` hat nur {n} Top-Scores mit den angegebenen Eigenschaften",
+        ru: "У `{name}` только {n} лучших скоров с указанными параметрами",
+    }
+    /// Arguments: `suggestion`
+    ModsSuggestion {
+        en: "Did you mean `{suggestion}`?",
+        de: "Meintest du `{suggestion}`?",
+        ru: "Может, вы имели в виду `{suggestion}`?",
+    }
+    TopParseAcc {
+        en: "Failed to parse `accuracy`.\n\
+            Must be either decimal number \
+            or two decimal numbers of the form `a..b` e.g. `97.5..98.5`.",
+        de: "`accuracy` konnte nicht gelesen werden.\n\
+            Muss entweder eine Dezimalzahl \
+            oder zwei Dezimalzahlen der Form `a..b` sein, z.B. `97.5..98.5`.",
+        ru: "Не удалось разобрать `accuracy`.\n\
+            Должно быть либо десятичным числом, \
+            либо двумя десятичными числами вида `a..b`, например `97.5..98.5`.",
+    }
+    /// Arguments: `option`
+    TopParseBool {
+        en: "Failed to parse `{option}`. Must be either `true` or `false`.",
+        de: "`{option}` konnte nicht gelesen werden. Muss entweder `true` oder `false` sein.",
+        ru: "Не удалось разобрать `{option}`. Должно быть `true` или `false`.",
+    }
+    TopParseCombo {
+        en: "Failed to parse `combo`.\n\
+            Must be either a positive integer \
+            or two positive integers of the form `a..b` e.g. `501..1234`.",
+        de: "`combo` konnte nicht gelesen werden.\n\
+            Muss entweder eine positive Ganzzahl \
+            oder zwei positive Ganzzahlen der Form `a..b` sein, z.B. `501..1234`.",
+        ru: "Не удалось разобрать `combo`.\n\
+            Должно быть либо положительным целым числом, \
+            либо двумя положительными целыми числами вида `a..b`, например `501..1234`.",
+    }
+    TopParseGrade {
+        en: "Failed to parse `grade`.\n\
+            Valid grades are: `SS`, `S`, `A`, `B`, `C`, `D`, or `F`",
+        de: "`grade` konnte nicht gelesen werden.\n\
+            Gültige Grades sind: `SS`, `S`, `A`, `B`, `C`, `D` oder `F`",
+        ru: "Не удалось разобрать `grade`.\n\
+            Допустимые оценки: `SS`, `S`, `A`, `B`, `C`, `D` или `F`",
+    }
+    TopParseIndex {
+        en: "Failed to parse `index`.\n\
+            Must be either a positive integer, two positive integers of the form `a..b` \
+            e.g. `20..30`, or `random` / `?`.",
+        de: "`index` konnte nicht gelesen werden.\n\
+            Muss entweder eine positive Ganzzahl, zwei positive Ganzzahlen der Form `a..b` \
+            wie z.B. `20..30`, oder `random` / `?` sein.",
+        ru: "Не удалось разобрать `index`.\n\
+            Должно быть положительным целым числом, двумя положительными целыми числами \
+            вида `a..b`, например `20..30`, или `random` / `?`.",
+    }
+    TopParseMods {
+        en: "Failed to parse mods.\n\
+            If you want included mods, specify it e.g. as `+hrdt`.\n\
+            If you want exact mods, specify it e.g. as `+hdhr!`.\n\
+            And if you want to exclude mods, specify it e.g. as `-hdnf!`.",
+        de: "Mods konnten nicht gelesen werden.\n\
+            Für enthaltene Mods gib sie z.B. als `+hrdt` an.\n\
+            Für exakte Mods gib sie z.B. als `+hdhr!` an.\n\
+            Und um Mods auszuschließen, gib sie z.B. als `-hdnf!` an.",
+        ru: "Не удалось разобрать моды.\n\
+            Чтобы включить моды, укажите их, например, как `+hrdt`.\n\
+            Для точных модов укажите их, например, как `+hdhr!`.\n\
+            А чтобы исключить моды, укажите их, например, как `-hdnf!`.",
+    }
+    TopParseSort {
+        en: "Failed to parse `sort`.\n\
+            Must be either `acc`, `combo`, `date`, `length`, or `pp`",
+        de: "`sort` konnte nicht gelesen werden.\n\
+            Muss entweder `acc`, `combo`, `date`, `length` oder `pp` sein",
+        ru: "Не удалось разобрать `sort`.\n\
+            Должно быть `acc`, `combo`, `date`, `length` или `pp`",
+    }
+    TopParseStatus {
+        en: "Failed to parse `status`.\n\
+            Must be a comma-separated list of `ranked`, `loved`, `qualified`, or `approved`.",
+        de: "`status` konnte nicht gelesen werden.\n\
+            Muss eine kommagetrennte Liste aus `ranked`, `loved`, `qualified` oder `approved` sein.",
+        ru: "Не удалось разобрать `status`.\n\
+            Должно быть списком через запятую из `ranked`, `loved`, `qualified` или `approved`.",
+    }
+    TopUnterminatedQuery {
+        en: "Failed to parse `query`, the closing quote is missing.\n\
+            Queries with spaces must be wrapped in double quotes e.g. `query=\"ar>9 length<120\"`, \
+            quotes inside the query can be escaped as `\\\"`.",
+        de: "`query` konnte nicht gelesen werden, das schließende Anführungszeichen fehlt.\n\
+            Anfragen mit Leerzeichen müssen in doppelte Anführungszeichen gesetzt werden, \
+            z.B. `query=\"ar>9 length<120\"`, Anführungszeichen innerhalb der Anfrage können \
+            als `\\\"` maskiert werden.",
+        ru: "Не удалось разобрать `query`, отсутствует закрывающая кавычка.\n\
+            Запросы с пробелами должны быть заключены в двойные кавычки, \
+            например `query=\"ar>9 length<120\"`, кавычки внутри запроса можно экранировать \
+            как `\\\"`.",
+    }
+    /// Arguments: `key`
+    TopUnknownOption {
+        en: "Unrecognized option `{key}`.\n\
+            Available options are: `acc`, `combo`, `sort`, `grade`, `status`, \
+            `reverse`, `converts`, `equivalent`, `index`, or `query`.",
+        de: "Unbekannte Option `{key}`.\n\
+            Verfügbare Optionen sind: `acc`, `combo`, `sort`, `grade`, `status`, \
+            `reverse`, `converts`, `equivalent`, `index` oder `query`.",
+        ru: "Неизвестный параметр `{key}`.\n\
+            Доступные параметры: `acc`, `combo`, `sort`, `grade`, `status`, \
+            `reverse`, `converts`, `equivalent`, `index` или `query`.",
+    }
+}
+
+impl Msg {
+    /// The message in the given language with its `{name}` placeholders
+    /// replaced by the matching arguments.
+    ///
+    /// Placeholders without a matching argument are kept as is.
+    pub fn format(self, lang: Lang, args: &[(&str, &dyn Display)]) -> String {
+        let mut template = self.text(lang);
+        let mut content = String::with_capacity(template.len());
+
+        while let Some(start) = template.find('{') {
+            content.push_str(&template[..start]);
+            template = &template[start..];
+
+            let Some(end) = template.find('}') else { break };
+            let name = &template[1..end];
+
+            match args.iter().find(|(key, _)| *key == name) {
+                Some((_, value)) => {
+                    let _ = write!(content, "{value}");
+                }
+                None => content.push_str(&template[..=end]),
+            }
+
+            template = &template[end + 1..];
+        }
+
+        content.push_str(template);
+
+        content
+    }
+}
+
+/// The language for replies to the author of the command.
+///
+/// Uses the author's config, then the guild's config, then falls back to
+/// English.
+pub async fn lang(orig: &CommandOrigin<'_>) -> Lang {
+    let user_lang = match orig.user_id() {
+        Ok(user_id) => match Context::user_config().lang(user_id).await {
+            Ok(lang) => lang,
+            Err(err) => {
+                warn!(?err, "Failed to get user language");
+
+                None
+            }
+        },
+        Err(_) => None,
+    };
+
+    lang_with_user(user_lang, orig.guild_id()).await
+}
+
+/// Same as [`lang`] but for when the user config is already available.
+pub async fn lang_with_user(user_lang: Option<Lang>, guild_id: Option<Id<GuildMarker>>) -> Lang {
+    match user_lang {
+        Some(lang) => lang,
+        None => Context::guild_config()
+            .lang(guild_id)
+            .await
+            .unwrap_or_default(),
+    }
+}
+
+/// Run `fut` while numbers are displayed in the number format of the user.
+///
+/// Uses the user's config, then the guild's config, then falls back to
+/// [`NumberFormat::Comma`].
+pub async fn with_number_format<F: Future>(
+    user_id: Option<Id<UserMarker>>,
+    guild_id: Option<Id<GuildMarker>>,
+    fut: F,
+) -> F::Output {
+    let user_format = match user_id {
+        Some(user_id) => match Context::user_config().number_format(user_id).await {
+            Ok(number_format) => number_format,
+            Err(err) => {
+                warn!(?err, "Failed to get user number format");
+
+                None
+            }
+        },
+        None => None,
+    };
+
+    let number_format = match user_format {
+        Some(number_format) => number_format,
+        None => Context::guild_config()
+            .number_format(guild_id)
+            .await
+            .unwrap_or_default(),
+    };
+
+    let thousands = number_format.thousands_separator();
+    let decimal = number_format.decimal_separator();
+
+    numbers::with_separators(thousands, decimal, fut).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LANGS: [Lang; 3] = [Lang::English, Lang::German, Lang::Russian];
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<_> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}'))
+            .map(|(name, _)| name)
+            .collect();
+
+        names.sort_unstable();
+
+        names
+    }
+
+    #[test]
+    fn translations_have_same_placeholders() {
+        for msg in Msg::ALL {
+            let expected = placeholders(msg.text(Lang::English));
+
+            for lang in LANGS {
+                assert_eq!(
+                    placeholders(msg.text(lang)),
+                    expected,
+                    "{msg:?} in {lang:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn format_args() {
+        let content =
+            Msg::TopScoresOnly.format(Lang::English, &[("name", &"Badewanne3"), ("n", &42)]);
+        assert_eq!(content, "`Badewanne3` only has 42 top scores");
+
+        let content = Msg::TopScoresOnly.format(Lang::German, &[("name", &"Badewanne3")]);
+        assert_eq!(content, "`Badewanne3` hat nur {n} Top-Scores");
+    }
+}
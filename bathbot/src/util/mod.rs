@@ -6,6 +6,7 @@ pub use self::{
 };
 
 pub mod interaction;
+pub mod locale;
 pub mod osu;
 pub mod query;
 
@@ -167,6 +167,7 @@ impl Display for ScoreFormatter {
     }
 }
 
+#[derive(Clone)]
 pub struct TopCounts {
     pub top1s: Cow<'static, str>,
     pub top1s_rank: Option<String>,
@@ -189,7 +190,16 @@ impl TopCounts {
     }
 
     pub async fn request(user: &CachedUser, mode: GameMode) -> Result<Self> {
-        Self::request_osustats(user, mode).await
+        let user_id = user.user_id.to_native();
+
+        if let Some(counts) = Context::get().top_counts(user_id, mode) {
+            return Ok(counts);
+        }
+
+        let counts = Self::request_osustats(user, mode).await?;
+        Context::get().store_top_counts(user_id, mode, counts.clone());
+
+        Ok(counts)
     }
 
     async fn request_osustats(user: &CachedUser, mode: GameMode) -> Result<Self> {
@@ -440,6 +450,131 @@ impl<'a> IntoIterator for &'a TopCounts {
     }
 }
 
+/// How far into the map a failed score got.
+pub struct FailCompletion {
+    /// Completion in percent
+    pub percent: u32,
+    /// Amount of hit objects that were not reached
+    pub remaining: u32,
+    /// Seconds until the fail, adjusted to the clock rate
+    pub passed_secs: u32,
+    /// Seconds until the last hit object, adjusted to the clock rate
+    pub total_secs: u32,
+}
+
+impl FailCompletion {
+    /// Returns `None` for passed scores and for catch scores since
+    /// `n_objects` is not correct due to juicestreams.
+    pub fn new(score: &ScoreSlim, map: &OsuMap) -> Option<Self> {
+        if score.grade != Grade::F || score.mode == GameMode::Catch {
+            return None;
+        }
+
+        // For mania this compares the total amount of judgements with the
+        // amount of notes
+        let passed = score.total_hits();
+        let n_objects = map.n_objects();
+
+        let percent = if n_objects != 0 {
+            (100 * passed / n_objects).min(100)
+        } else {
+            100
+        };
+
+        let hit_objects = &map.pp_map.hit_objects;
+        let clock_rate = score.mods.clock_rate().unwrap_or(1.0);
+
+        let secs = |idx: usize| {
+            hit_objects
+                .get(idx.min(hit_objects.len().saturating_sub(1)))
+                .map_or(0, |obj| (obj.start_time / 1000.0 / clock_rate) as u32)
+        };
+
+        Some(Self {
+            percent,
+            remaining: n_objects.saturating_sub(passed),
+            passed_secs: secs(passed.saturating_sub(1) as usize),
+            total_secs: secs(usize::MAX),
+        })
+    }
+}
+
+impl Display for FailCompletion {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Completed: {}% ({}/{}) • {} object{} remaining",
+            self.percent,
+            SecToMinSec::new(self.passed_secs).pad_secs(),
+            SecToMinSec::new(self.total_secs).pad_secs(),
+            self.remaining,
+            if self.remaining == 1 { "" } else { "s" },
+        )
+    }
+}
+
+/// Whether the score was set on a map converted from osu!standard.
+pub fn is_convert(score: &Score) -> bool {
+    score
+        .map
+        .as_ref()
+        .is_some_and(|map| map.convert || map.mode != score.mode)
+}
+
+/// Percentage of a map's leaderboard scores that have less pp than the given
+/// score, ignoring the score itself should it be on the leaderboard.
+///
+/// Returns `None` for maps without pp or if the leaderboard is empty.
+pub fn pp_percentile(
+    score: &ScoreSlim,
+    user_id: u32,
+    status: RankStatus,
+    leaderboard: &[Score],
+) -> Option<f32> {
+    if !matches!(status, RankStatus::Ranked | RankStatus::Approved) {
+        return None;
+    }
+
+    let (below, total) = leaderboard
+        .iter()
+        .filter(|s| !(s.user_id == user_id && score.is_eq(*s)))
+        .filter_map(|s| s.pp)
+        .fold((0, 0), |(below, total), pp| {
+            (below + (pp < score.pp) as usize, total + 1)
+        });
+
+    (total > 0).then(|| 100.0 * below as f32 / total as f32)
+}
+
+/// Estimate the pp of a failed score if it had been finished by assuming the
+/// remaining objects are played with the same accuracy and miss rate.
+///
+/// Returns `None` for passed scores.
+pub async fn if_passed_pp(score: &ScoreSlim, map: &OsuMap) -> Option<f32> {
+    if score.grade != Grade::F {
+        return None;
+    }
+
+    let passed = score.total_hits();
+    let n_objects = map.n_objects();
+
+    if passed == 0 || n_objects == 0 {
+        return None;
+    }
+
+    let misses = (score.statistics.miss as u64 * n_objects as u64 / passed as u64) as u32;
+
+    let mut calc = Context::pp(map)
+        .mods(score.mods.clone())
+        .mode(score.mode)
+        .lazer(score.set_on_lazer);
+
+    let attrs = calc.accuracy_performance(score.accuracy, misses).await;
+
+    Some(attrs.pp() as f32)
+}
+
 #[derive(Clone)]
 pub struct IfFc {
     pub statistics: ScoreStatistics,
@@ -449,6 +584,16 @@ pub struct IfFc {
 
 impl IfFc {
     pub async fn new(score: &ScoreSlim, map: &OsuMap) -> Option<Self> {
+        Self::with_combo_tolerance(score, map, None).await
+    }
+
+    /// Same as [`IfFc::new`] but for osu!standard the amount of combo that
+    /// may be missing for the score to still count as FC can be specified.
+    pub async fn with_combo_tolerance(
+        score: &ScoreSlim,
+        map: &OsuMap,
+        combo_tolerance: Option<u32>,
+    ) -> Option<Self> {
         let mut calc = Context::pp(map)
             .mods(score.mods.clone())
             .mode(score.mode)
@@ -456,11 +601,12 @@ impl IfFc {
 
         let attrs = calc.difficulty().await;
 
-        if score.is_fc(score.mode, attrs.max_combo()) {
+        if score.is_fc_with_tolerance(score.mode, attrs.max_combo(), combo_tolerance) {
             return None;
         }
 
         let stats = &score.statistics;
+        let classic = score.mods.contains_intermode(GameModIntermode::Classic);
 
         let (pp, statistics) = match attrs {
             DifficultyAttributes::Osu(attrs) => {
@@ -477,8 +623,6 @@ impl IfFc {
                 let n100 = stats.ok + new100s;
                 let n50 = stats.meh;
 
-                let classic = score.mods.contains_intermode(GameModIntermode::Classic);
-
                 let attrs = OsuPerformance::from(attrs.to_owned())
                     .lazer(score.set_on_lazer)
                     .mods(score.mods.clone())
@@ -499,10 +643,11 @@ impl IfFc {
                 statistics.large_tick_hit = attrs.difficulty.n_large_ticks;
                 statistics.large_tick_miss = 0;
 
+                // Classic mode judges slider ends as small ticks
                 if classic {
-                    statistics.slider_tail_hit = attrs.difficulty.n_sliders;
-                } else {
                     statistics.small_tick_hit = attrs.difficulty.n_sliders;
+                } else {
+                    statistics.slider_tail_hit = attrs.difficulty.n_sliders;
                 }
 
                 (attrs.pp as f32, statistics)
@@ -571,31 +716,7 @@ impl IfFc {
         };
 
         let max_statistics = score.set_on_lazer.then(|| {
-            let total_hits = score.total_hits();
-
-            match attrs {
-                DifficultyAttributes::Osu(attrs) => ScoreStatistics {
-                    great: total_hits,
-                    large_tick_hit: attrs.n_large_ticks,
-                    small_tick_hit: attrs.n_sliders,
-                    slider_tail_hit: attrs.n_sliders,
-                    ..Default::default()
-                },
-                DifficultyAttributes::Taiko(_) => ScoreStatistics {
-                    great: map.n_circles() as u32,
-                    ..Default::default()
-                },
-                DifficultyAttributes::Catch(attrs) => ScoreStatistics {
-                    great: attrs.n_fruits,
-                    ok: attrs.n_droplets,
-                    meh: attrs.n_tiny_droplets,
-                    ..Default::default()
-                },
-                DifficultyAttributes::Mania(_) => ScoreStatistics {
-                    perfect: total_hits,
-                    ..Default::default()
-                },
-            }
+            Self::max_statistics(&attrs, score.total_hits(), map.n_circles() as u32, classic)
         });
 
         Some(Self {
@@ -604,6 +725,60 @@ impl IfFc {
             pp,
         })
     }
+
+    /// Statistics of a perfect play on lazer, used to calculate accuracy.
+    fn max_statistics(
+        attrs: &DifficultyAttributes,
+        total_hits: u32,
+        n_circles: u32,
+        classic: bool,
+    ) -> ScoreStatistics {
+        match attrs {
+            // Classic mode judges slider ends as small ticks
+            DifficultyAttributes::Osu(attrs) if classic => ScoreStatistics {
+                great: total_hits,
+                large_tick_hit: attrs.n_large_ticks,
+                small_tick_hit: attrs.n_sliders,
+                ..Default::default()
+            },
+            DifficultyAttributes::Osu(attrs) => ScoreStatistics {
+                great: total_hits,
+                large_tick_hit: attrs.n_large_ticks,
+                slider_tail_hit: attrs.n_sliders,
+                ..Default::default()
+            },
+            DifficultyAttributes::Taiko(_) => ScoreStatistics {
+                great: n_circles,
+                ..Default::default()
+            },
+            DifficultyAttributes::Catch(attrs) => ScoreStatistics {
+                great: attrs.n_fruits,
+                ok: attrs.n_droplets,
+                meh: attrs.n_tiny_droplets,
+                ..Default::default()
+            },
+            DifficultyAttributes::Mania(_) => ScoreStatistics {
+                perfect: total_hits,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Accuracy of the unchoked statistics.
+    ///
+    /// Returns `None` for mania since its scores are never unchoked.
+    pub fn accuracy(&self, mode: GameMode) -> Option<f32> {
+        if mode == GameMode::Mania {
+            return None;
+        }
+
+        let acc = match self.max_statistics {
+            Some(ref max_stats) => self.statistics.accuracy(mode, max_stats),
+            None => self.statistics.legacy_accuracy(mode),
+        };
+
+        Some(acc)
+    }
 }
 
 pub async fn get_combined_thumbnail<'s>(
@@ -918,3 +1093,187 @@ impl MapOrScore {
         inner(msg, 0).await
     }
 }
+
+/// The amount of entries with at least `min_pp` in a list that is sorted by
+/// pp in descending order and can only be requested page by page.
+///
+/// `pp_page` must return the pp values of the given 1-based page. Pages are
+/// binary searched so that only few of them need to be requested.
+pub async fn min_pp_cutoff<F, Fut>(
+    total: usize,
+    per_page: usize,
+    min_pp: f32,
+    mut pp_page: F,
+) -> Result<usize>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Vec<f32>>>,
+{
+    let mut low = 1;
+    let mut high = total.div_ceil(per_page);
+    let mut cutoff = 0;
+
+    while low <= high {
+        let page = (low + high) / 2;
+        let pps = pp_page(page).await?;
+        let count = pps.iter().take_while(|&&pp| pp >= min_pp).count();
+
+        if count == 0 {
+            high = page - 1;
+
+            continue;
+        }
+
+        cutoff = (page - 1) * per_page + count;
+
+        // The page contains the first entry below `min_pp`
+        if count < pps.len() {
+            break;
+        }
+
+        low = page + 1;
+    }
+
+    Ok(cutoff.min(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_pp::{
+        any::DifficultyAttributes, catch::CatchDifficultyAttributes, osu::OsuDifficultyAttributes,
+    };
+    use rosu_v2::prelude::{GameMode, ScoreStatistics};
+
+    use super::{IfFc, min_pp_cutoff};
+
+    fn if_fc_acc(mode: GameMode, statistics: ScoreStatistics, attrs: DifficultyAttributes) -> f32 {
+        let total_hits = statistics.great + statistics.ok + statistics.meh + statistics.miss;
+        let max_statistics = IfFc::max_statistics(&attrs, total_hits, total_hits, false);
+
+        let if_fc = IfFc {
+            statistics,
+            max_statistics: Some(max_statistics),
+            pp: 0.0,
+        };
+
+        if_fc.accuracy(mode).unwrap()
+    }
+
+    fn assert_acc(acc: f32, expected: f32) {
+        assert!((acc - expected).abs() < 0.01, "{acc} != {expected}");
+    }
+
+    fn osu_attrs() -> DifficultyAttributes {
+        DifficultyAttributes::Osu(OsuDifficultyAttributes {
+            n_sliders: 20,
+            n_large_ticks: 40,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn lazer_if_fc_acc_uses_max_statistics() {
+        let statistics = ScoreStatistics {
+            great: 490,
+            ok: 10,
+            large_tick_hit: 40,
+            slider_tail_hit: 20,
+            ..Default::default()
+        };
+
+        // 490*300 + 10*100 + 40*30 + 20*150 out of 500*300 + 40*30 + 20*150
+        assert_acc(if_fc_acc(GameMode::Osu, statistics, osu_attrs()), 98.703);
+    }
+
+    #[test]
+    fn lazer_if_fc_max_statistics_slider_ends() {
+        let max_statistics = IfFc::max_statistics(&osu_attrs(), 500, 400, false);
+        assert_eq!(max_statistics.slider_tail_hit, 20);
+        assert_eq!(max_statistics.small_tick_hit, 0);
+
+        let max_statistics = IfFc::max_statistics(&osu_attrs(), 500, 400, true);
+        assert_eq!(max_statistics.slider_tail_hit, 0);
+        assert_eq!(max_statistics.small_tick_hit, 20);
+    }
+
+    #[test]
+    fn taiko_if_fc_acc() {
+        let statistics = ScoreStatistics {
+            great: 950,
+            ok: 50,
+            ..Default::default()
+        };
+
+        let attrs = DifficultyAttributes::Taiko(Default::default());
+
+        assert_acc(if_fc_acc(GameMode::Taiko, statistics, attrs), 97.5);
+    }
+
+    #[test]
+    fn catch_if_fc_acc() {
+        let attrs = || {
+            DifficultyAttributes::Catch(CatchDifficultyAttributes {
+                n_fruits: 400,
+                n_droplets: 50,
+                n_tiny_droplets: 550,
+                ..Default::default()
+            })
+        };
+
+        let full = ScoreStatistics {
+            great: 400,
+            ok: 50,
+            meh: 550,
+            ..Default::default()
+        };
+
+        assert_acc(if_fc_acc(GameMode::Catch, full, attrs()), 100.0);
+
+        // Tiny droplet misses are kept as is
+        let missing_tiny_droplets = ScoreStatistics {
+            great: 400,
+            ok: 50,
+            meh: 540,
+            small_tick_miss: 10,
+            ..Default::default()
+        };
+
+        let acc = if_fc_acc(GameMode::Catch, missing_tiny_droplets, attrs());
+        assert!(acc < 100.0, "{acc} should be below 100");
+    }
+
+    #[test]
+    fn mania_if_fc_acc() {
+        let if_fc = IfFc {
+            statistics: ScoreStatistics {
+                perfect: 1000,
+                ..Default::default()
+            },
+            max_statistics: None,
+            pp: 0.0,
+        };
+
+        assert!(if_fc.accuracy(GameMode::Mania).is_none());
+    }
+
+    #[tokio::test]
+    async fn min_pp_cutoff_pages() {
+        // 7 pages of up to 10 entries with descending pp 1000, 995, ...
+        let pps: Vec<f32> = (0..65).map(|i| 1000.0 - 5.0 * i as f32).collect();
+
+        let cutoff = |min_pp| {
+            min_pp_cutoff(pps.len(), 10, min_pp, |page| {
+                let start = (page - 1) * 10;
+                let page = pps[start..(start + 10).min(pps.len())].to_vec();
+
+                async move { Ok(page) }
+            })
+        };
+
+        assert_eq!(cutoff(2000.0).await.unwrap(), 0);
+        assert_eq!(cutoff(1000.0).await.unwrap(), 1);
+        assert_eq!(cutoff(953.0).await.unwrap(), 10);
+        assert_eq!(cutoff(712.0).await.unwrap(), 58);
+        assert_eq!(cutoff(0.0).await.unwrap(), 65);
+    }
+}
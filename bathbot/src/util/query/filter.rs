@@ -39,14 +39,21 @@ impl<'q, F: IFilterCriteria<'q>> FilterCriteria<F> {
                 continue;
             };
 
+            let neg_match = capture.name("neg");
+
             let key = key_match.as_str().cow_to_ascii_lowercase();
-            let op = Operator::from(&capture["op"]);
             let value = value_match.as_str().cow_to_ascii_lowercase();
+            let mut op = Operator::from(&capture["op"]);
+
+            if neg_match.is_some() {
+                op = op.negate();
+            }
 
             if inner.try_parse_key_value(key, value, op) {
-                let range = key_match.start() - removed..value_match.end() - removed;
+                let start = neg_match.map_or(key_match.start(), |neg| neg.start());
+                let range = start - removed..value_match.end() - removed;
                 search_text.replace_range(range, "");
-                removed += value_match.end() - key_match.start();
+                removed += value_match.end() - start;
             }
         }
 
@@ -121,3 +128,158 @@ impl<F> Deref for FilterCriteria<F> {
         &self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month};
+
+    use super::*;
+    use crate::util::query::TopCriteria;
+
+    fn parse(query: &str) -> FilterCriteria<TopCriteria<'_>> {
+        TopCriteria::create(query)
+    }
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn negated_text() {
+        let criteria = parse("-creator=sotarks");
+
+        assert!(!criteria.creator.matches("Sotarks"));
+        assert!(criteria.creator.matches("monstrata"));
+        assert!(!criteria.has_search_terms());
+    }
+
+    #[test]
+    fn negated_range() {
+        let criteria = parse("-ar>9.5");
+
+        assert!(criteria.ar.contains(9.0));
+        assert!(criteria.ar.contains(9.5));
+        assert!(!criteria.ar.contains(9.7));
+        assert!(!criteria.has_search_terms());
+    }
+
+    #[test]
+    fn negation_combined_with_range() {
+        let criteria = parse("ar>8 -ar>=9.5 od>=9 -od<9.5");
+
+        assert!(criteria.ar.contains(9.0));
+        assert!(!criteria.ar.contains(8.0));
+        assert!(!criteria.ar.contains(9.5));
+
+        assert!(criteria.od.contains(9.5));
+        assert!(criteria.od.contains(10.0));
+        assert!(!criteria.od.contains(9.2));
+        assert!(!criteria.has_search_terms());
+    }
+
+    #[test]
+    fn negated_equality_on_range_is_kept() {
+        let criteria = parse("-ar=9 od=8");
+
+        assert!(criteria.ar.is_empty());
+        assert!(criteria.od.contains(8.0));
+        assert_eq!(criteria.search_terms().collect::<Vec<_>>(), ["-ar=9"]);
+    }
+
+    #[test]
+    fn quoted_phrase() {
+        let criteria = parse(r#"title="Blue Zenith" artist=xi freedom"#);
+
+        assert!(criteria.title.matches("Blue Zenith"));
+        assert!(!criteria.title.matches("Blue"));
+        assert!(criteria.artist.matches("xi"));
+        assert_eq!(criteria.search_terms().collect::<Vec<_>>(), ["freedom"]);
+    }
+
+    #[test]
+    fn multiple_quoted_phrases() {
+        let criteria = parse(r#"title="blue zenith" -version="four dimensions""#);
+
+        assert!(criteria.title.matches("blue zenith"));
+        assert!(!criteria.version.matches("four dimensions"));
+        assert!(criteria.version.matches("another"));
+        assert!(!criteria.has_search_terms());
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        let criteria = parse(r#"ar>9 title="blue zenith"#);
+
+        assert!(criteria.ar.contains(9.5));
+        assert!(criteria.title.matches("blue zenith"));
+        assert!(!criteria.has_search_terms());
+    }
+
+    #[test]
+    fn unknown_keys_are_search_terms() {
+        let criteria = parse("foo=bar ar>9 -baz<3");
+
+        assert!(criteria.ar.contains(9.5));
+        assert_eq!(
+            criteria.search_terms().collect::<Vec<_>>(),
+            ["foo=bar", "-baz<3"]
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_search_terms() {
+        let criteria = parse("ar>high year=abc");
+
+        assert!(criteria.ar.is_empty());
+        assert!(criteria.ranked_date.is_empty());
+        assert_eq!(
+            criteria.search_terms().collect::<Vec<_>>(),
+            ["ar>high", "year=abc"]
+        );
+    }
+
+    #[test]
+    fn year() {
+        let criteria = parse("year=2015");
+
+        assert!(criteria.ranked_date.contains(date(2015, Month::January, 1)));
+        assert!(
+            criteria
+                .ranked_date
+                .contains(date(2015, Month::December, 31))
+        );
+        assert!(
+            !criteria
+                .ranked_date
+                .contains(date(2014, Month::December, 31))
+        );
+        assert!(!criteria.ranked_date.contains(date(2016, Month::January, 1)));
+    }
+
+    #[test]
+    fn year_range() {
+        let criteria = parse("year>2012 year<=2015");
+
+        assert!(
+            !criteria
+                .ranked_date
+                .contains(date(2012, Month::December, 31))
+        );
+        assert!(criteria.ranked_date.contains(date(2013, Month::January, 1)));
+        assert!(
+            criteria
+                .ranked_date
+                .contains(date(2015, Month::December, 31))
+        );
+        assert!(!criteria.ranked_date.contains(date(2016, Month::January, 1)));
+
+        let criteria = parse("-year<2015");
+
+        assert!(
+            !criteria
+                .ranked_date
+                .contains(date(2014, Month::December, 31))
+        );
+        assert!(criteria.ranked_date.contains(date(2015, Month::January, 1)));
+    }
+}
@@ -44,6 +44,7 @@ impl<'q> IFilterCriteria<'q> for BookmarkCriteria<'q> {
             "bpm" => self.bpm.try_update(op, &value, 0.05),
             "length" | "len" => super::try_update_len(&mut self.length, op, &value),
             "ranked" | "rankeddate" | "ranked_date" => self.ranked_date.try_update_date(op, &value),
+            "year" | "rankedyear" | "ranked_year" => self.ranked_date.try_update_year(op, &value),
             "bookmarked" | "bookmarkdate" | "bookmark_date" | "insertdate" | "insert_date" => {
                 self.insert_date.try_update_date(op, &value)
             }
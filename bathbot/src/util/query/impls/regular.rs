@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use time::Date;
+
 use super::{display_range, display_text};
 use crate::util::query::{
     IFilterCriteria,
@@ -17,6 +19,7 @@ pub struct RegularCriteria<'q> {
     pub length: OptionalRange<f32>,
     pub bpm: OptionalRange<f32>,
     pub keys: OptionalRange<f32>,
+    pub ranked_date: OptionalRange<Date>,
 
     pub artist: OptionalText<'q>,
     pub creator: OptionalText<'q>,
@@ -42,6 +45,8 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             "artist" => self.artist.try_update(op, value),
             "title" => self.title.try_update(op, value),
             "key" | "keys" => self.keys.try_update(op, &value, 0.5),
+            "ranked" | "rankeddate" | "ranked_date" => self.ranked_date.try_update_date(op, &value),
+            "year" | "rankedyear" | "ranked_year" => self.ranked_date.try_update_year(op, &value),
             _ => false,
         }
     }
@@ -56,6 +61,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             length,
             bpm,
             keys,
+            ranked_date,
             artist,
             creator,
             title,
@@ -69,6 +75,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             && length.is_empty()
             && bpm.is_empty()
             && keys.is_empty()
+            && ranked_date.is_empty()
             && artist.is_empty()
             && creator.is_empty()
             && title.is_empty())
@@ -84,6 +91,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             length,
             bpm,
             keys,
+            ranked_date,
             artist,
             creator,
             title,
@@ -97,6 +105,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
         display_range(content, "Stars", stars);
         display_range(content, "BPM", bpm);
         display_range(content, "Keys", keys);
+        display_range(content, "Ranked", ranked_date);
         display_range(content, "AR", ar);
         display_range(content, "AR", ar);
 
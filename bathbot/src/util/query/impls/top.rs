@@ -60,6 +60,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
 
             "date" | "scoredate" | "ended_at" => self.date.try_update_date(op, &value),
             "ranked" | "rankeddate" | "ranked_date" => self.ranked_date.try_update_date(op, &value),
+            "year" | "rankedyear" | "ranked_year" => self.ranked_date.try_update_year(op, &value),
 
             "artist" => self.artist.try_update(op, value),
             "creator" | "mapper" => self.creator.try_update(op, value),
@@ -1,11 +1,27 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Operator {
     Equal,
+    NotEqual,
     Less,
     LessOrEqual,
     Greater,
     GreaterOrEqual,
 }
 
+impl Operator {
+    /// The operator that matches exactly the values this one doesn't.
+    pub fn negate(self) -> Self {
+        match self {
+            Self::Equal => Self::NotEqual,
+            Self::NotEqual => Self::Equal,
+            Self::Less => Self::GreaterOrEqual,
+            Self::LessOrEqual => Self::Greater,
+            Self::Greater => Self::LessOrEqual,
+            Self::GreaterOrEqual => Self::Less,
+        }
+    }
+}
+
 impl From<&str> for Operator {
     fn from(s: &str) -> Self {
         match s {
@@ -8,19 +8,23 @@ use std::{
 };
 
 use bathbot_util::{CowUtils, datetime::DATE_FORMAT};
-use time::Date;
+use time::{Date, Month};
 
 use super::operator::Operator;
 
 #[derive(Default)]
 pub struct OptionalText<'q> {
     search_term: Cow<'q, str>,
+    /// Whether values must *not* match the search term
+    negated: bool,
 }
 
 impl Debug for OptionalText<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         if self.search_term.is_empty() {
             f.write_str("<none>")
+        } else if self.negated {
+            write!(f, "not {}", self.search_term)
         } else {
             f.write_str(self.search_term.as_ref())
         }
@@ -33,12 +37,13 @@ impl<'q> OptionalText<'q> {
     }
 
     pub fn matches(&self, value: &str) -> bool {
-        self.is_empty() || self.search_term == value.cow_to_ascii_lowercase()
+        self.is_empty() || (self.search_term == value.cow_to_ascii_lowercase()) != self.negated
     }
 
     pub fn try_update(&mut self, op: Operator, value: Cow<'q, str>) -> bool {
         match op {
-            Operator::Equal => {
+            Operator::Equal | Operator::NotEqual => {
+                self.negated = op == Operator::NotEqual;
                 self.search_term = match value {
                     Cow::Borrowed(b) => b.trim_matches('"').into(),
                     Cow::Owned(o) => {
@@ -116,6 +121,8 @@ impl<N> OptionalRange<N> {
                 self.min = Some(value - tolerance);
                 self.is_lower_inclusive = true;
             }
+            // Excluding a single value can't be represented by one range
+            Operator::NotEqual => return false,
         }
 
         true
@@ -127,6 +134,34 @@ impl OptionalRange<Date> {
         Date::parse(value, &DATE_FORMAT)
             .is_ok_and(|date| self.try_update_value(op, date, Duration::ZERO))
     }
+
+    /// Update the range so that it covers the specified year.
+    pub fn try_update_year(&mut self, op: Operator, value: &str) -> bool {
+        let Ok(year) = value.parse() else {
+            return false;
+        };
+
+        let (Ok(first), Ok(last)) = (
+            Date::from_calendar_date(year, Month::January, 1),
+            Date::from_calendar_date(year, Month::December, 31),
+        ) else {
+            return false;
+        };
+
+        match op {
+            Operator::Equal => {
+                self.try_update_value(Operator::GreaterOrEqual, first, Duration::ZERO)
+                    && self.try_update_value(Operator::LessOrEqual, last, Duration::ZERO)
+            }
+            Operator::Less | Operator::GreaterOrEqual => {
+                self.try_update_value(op, first, Duration::ZERO)
+            }
+            Operator::LessOrEqual | Operator::Greater => {
+                self.try_update_value(op, last, Duration::ZERO)
+            }
+            Operator::NotEqual => false,
+        }
+    }
 }
 
 impl<T: PartialOrd> OptionalRange<T> {
@@ -7,8 +7,9 @@ use rosu_pp::{Beatmap as Map, model::beatmap::BeatmapAttributesBuilder};
 use rosu_v2::prelude::{
     BeatmapExtended, BeatmapsetExtended, GameModIntermode, GameMode, GameMods, Score,
 };
+use time::{Date, OffsetDateTime};
 
-use super::{FilterCriteria, RegularCriteria as RC};
+use super::{FilterCriteria, RegularCriteria as RC, optional::OptionalRange};
 use crate::{commands::osu::TopIfEntry, manager::OsuMap};
 
 pub trait Searchable<F> {
@@ -71,6 +72,7 @@ impl Searchable<RC<'_>> for BeatmapsetExtended {
         matches &= criteria.artist.matches(artist.as_ref());
         matches &= criteria.creator.matches(creator.as_ref());
         matches &= criteria.title.matches(title.as_ref());
+        matches &= ranked_date_matches(&criteria.ranked_date, self.ranked_date);
 
         if let Some(ref maps) = self.maps {
             matches &= maps.iter().any(|map| map.matches(criteria));
@@ -108,6 +110,7 @@ impl Searchable<RC<'_>> for DbBeatmapset {
         matches &= criteria.artist.matches(artist.as_ref());
         matches &= criteria.creator.matches(creator.as_ref());
         matches &= criteria.title.matches(title.as_ref());
+        matches &= ranked_date_matches(&criteria.ranked_date, self.ranked_date);
 
         if matches && criteria.has_search_terms() {
             let terms = [artist, creator, title];
@@ -179,6 +182,7 @@ impl Searchable<RC<'_>> for Score {
             matches &= criteria.artist.matches(artist.as_ref());
             matches &= criteria.creator.matches(creator.as_ref());
             matches &= criteria.title.matches(title.as_ref());
+            matches &= ranked_date_matches(&criteria.ranked_date, mapset.ranked_date);
         }
 
         if matches && criteria.has_search_terms() {
@@ -226,6 +230,7 @@ impl Searchable<RC<'_>> for (&'_ ScoreSlim, &'_ OsuMap) {
         matches &= criteria.od.contains(attrs.od as f32);
         matches &= criteria.length.contains(len);
         matches &= criteria.bpm.contains(map.bpm() * clock_rate);
+        matches &= ranked_date_matches(&criteria.ranked_date, map.ranked_date());
 
         matches &= score.mode != GameMode::Mania
             || criteria.keys.contains(keys(&score.mods, attrs.cs as f32));
@@ -251,6 +256,11 @@ impl Searchable<RC<'_>> for (&'_ ScoreSlim, &'_ OsuMap) {
     }
 }
 
+/// Maps without ranked date only match if no range was specified.
+fn ranked_date_matches(range: &OptionalRange<Date>, ranked_date: Option<OffsetDateTime>) -> bool {
+    range.is_empty() || ranked_date.is_some_and(|datetime| range.contains(datetime.date()))
+}
+
 fn keys(mods: &GameMods, cs: f32) -> f32 {
     [
         (GameModIntermode::OneKey, 1.0),